@@ -26,6 +26,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CommitUncompr
         let hash = self.hash_uncompressed(input);
 
         // Compute h^r.
+        // Note: The constraints are fixed by the serial numbers in `credits.aleo`, so this does not use windows.
         randomizer
             .to_bits_le()
             .iter()
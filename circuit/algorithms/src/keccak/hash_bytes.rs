@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input bytes as bytes, i.e. `VARIANT / 8` bytes.
+    ///
+    /// This matches the byte-oriented digest of other Keccak implementations, e.g. for Keccak-256,
+    /// the output is the 32-byte digest used by Ethereum, where each byte is in little-endian bit order.
+    #[inline]
+    pub fn hash_bytes(&self, input: &[U8<E>]) -> Vec<U8<E>> {
+        // Hash the bits of the input bytes.
+        let bits = self.hash(&input.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+        // Pack the digest into bytes.
+        bits.chunks(8).map(U8::from_bits_le).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn to_bits_le(bytes: impl IntoIterator<Item = u8>) -> Vec<bool> {
+        bytes.into_iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    macro_rules! check_equivalence {
+        ($console:expr, $circuit:expr) => {
+            use console::Hash as H;
+
+            let rng = &mut TestRng::default();
+
+            // Include the boundaries of the bitrates, which are 72, 104, 136, and 144 bytes.
+            let mut input_sizes = vec![1, 2, 3, 32, 64, 71, 72, 73, 103, 104, 135, 136, 137, 143, 144, 145, 288];
+            input_sizes.extend((0..5).map(|_| rng.gen_range(1..512)));
+
+            for mode in [Mode::Constant, Mode::Private] {
+                for num_bytes in input_sizes.iter() {
+                    // Prepare the preimage.
+                    let native_input = (0..*num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+                    let input = native_input
+                        .iter()
+                        .map(|byte| U8::<Circuit>::new(mode, console::U8::new(*byte)))
+                        .collect::<Vec<_>>();
+
+                    // Compute the console hash.
+                    let expected = $console.hash(&to_bits_le(native_input)).expect("Failed to hash console input");
+
+                    // Compute the circuit hash.
+                    let candidate = $circuit.hash_bytes(&input);
+                    assert_eq!(candidate.len() * 8, expected.len());
+                    assert_eq!(expected, to_bits_le(candidate.iter().map(|byte| *byte.eject_value())));
+                    assert!(Circuit::is_satisfied());
+                    Circuit::reset();
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_keccak_256_hash_bytes() {
+        // The Keccak-256 digest of "abc".
+        let expected = [
+            0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0, 0xd1,
+            0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+        ];
+
+        let input = b"abc".map(|byte| U8::<Circuit>::new(Mode::Private, console::U8::new(byte)));
+        let candidate = Keccak256::<Circuit>::new().hash_bytes(&input);
+        assert_eq!(expected.to_vec(), candidate.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_keccak_256_hash_bytes_equivalence() {
+        check_equivalence!(console::Keccak256::default(), Keccak256::<Circuit>::new());
+    }
+
+    #[test]
+    fn test_keccak_512_hash_bytes_equivalence() {
+        check_equivalence!(console::Keccak512::default(), Keccak512::<Circuit>::new());
+    }
+
+    #[test]
+    fn test_sha3_256_hash_bytes_equivalence() {
+        check_equivalence!(console::Sha3_256::default(), Sha3_256::<Circuit>::new());
+    }
+}
@@ -30,5 +30,8 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod secp256k1;
+pub use secp256k1::*;
+
 pub mod traits;
 pub use traits::*;
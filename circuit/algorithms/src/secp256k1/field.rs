@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in each limb of a non-native field element.
+const LIMB_BITS: usize = 32;
+/// The number of limbs in a non-native field element.
+const NUM_LIMBS: usize = 8;
+/// The number of bits in a shifted carry, when enforcing that an integer is zero.
+const CARRY_BITS: usize = 48;
+/// The number of bits in a shifted quotient, when reducing a linear combination.
+const QUOTIENT_BITS: usize = 8;
+
+/// An element of a non-native field, represented as `NUM_LIMBS` little-endian limbs of `LIMB_BITS` bits.
+///
+/// Each limb is at most `LIMB_BITS` bits, so the element is less than `2^256`. An element is canonical
+/// if it is less than the modulus, which is ensured for the outputs of the arithmetic operations.
+#[derive(Clone)]
+pub(super) struct NonNativeField<E: Environment> {
+    /// The little-endian limbs.
+    limbs: Vec<Field<E>>,
+}
+
+impl<E: Environment> NonNativeField<E> {
+    /// Initializes a constant non-native field element, from a value less than `2^256`.
+    pub(super) fn constant(value: &BigUint) -> Self {
+        Self {
+            limbs: to_limbs(value).into_iter().map(|limb| Field::constant(console::Field::from_u64(limb))).collect(),
+        }
+    }
+
+    /// Initializes a non-native field element from 256 little-endian bits, without reducing it.
+    pub(super) fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        Self { limbs: bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect() }
+    }
+
+    /// Returns the 256 little-endian bits of the non-native field element.
+    pub(super) fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_BITS)).collect()
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self { limbs }
+    }
+
+    /// Returns `true` if `self` is equal to `other`, where both elements are canonical.
+    pub(super) fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+    }
+
+    /// Returns `true` if `self` is zero, where `self` is canonical.
+    pub(super) fn is_zero(&self) -> Boolean<E> {
+        self.is_equal(&Self::constant(&BigUint::from(0u8)))
+    }
+}
+
+impl<E: Environment> NonNativeField<E> {
+    /// Returns `self * other` modulo `modulus`.
+    /// The product of the inputs must be less than `2^256 * modulus`.
+    pub(super) fn mul(&self, other: &Self, modulus: &BigUint) -> Self {
+        let output =
+            Self::new(Self::witness_mode(&[self, other]), modulus, &((self.value() * other.value()) % modulus));
+        Self::enforce_mul(self, other, &output, modulus);
+        output
+    }
+
+    /// Returns `numerator / denominator` modulo the prime `modulus`, where both inputs are canonical.
+    /// Enforces that the denominator is nonzero.
+    pub(super) fn div(numerator: &Self, denominator: &Self, modulus: &BigUint) -> Self {
+        E::assert(!denominator.is_zero());
+        // Compute the quotient as `numerator * denominator^(modulus - 2)`.
+        let exponent = modulus - BigUint::from(2u8);
+        let quotient = (numerator.value() * denominator.value().modpow(&exponent, modulus)) % modulus;
+        let output = Self::new(Self::witness_mode(&[numerator, denominator]), modulus, &quotient);
+        Self::enforce_mul(&output, denominator, numerator, modulus);
+        output
+    }
+
+    /// Returns the inverse of `self` modulo the prime `modulus`.
+    /// Enforces that `self` is nonzero modulo `modulus`.
+    pub(super) fn inverse(&self, modulus: &BigUint) -> Self {
+        let inverse = self.value().modpow(&(modulus - BigUint::from(2u8)), modulus);
+        let output = Self::new(Self::witness_mode(&[self]), modulus, &inverse);
+        Self::enforce_mul(self, &output, &Self::constant(&BigUint::from(1u8)), modulus);
+        output
+    }
+
+    /// Returns `self` modulo `modulus`, where `self` must be less than `2 * modulus`.
+    pub(super) fn reduce(&self, modulus: &BigUint) -> Self {
+        Self::linear_combination(&[(1, self)], modulus)
+    }
+
+    /// Returns the sum of the given terms, each scaled by a small integer, modulo `modulus`.
+    /// Each term must be less than `2 * modulus`, and the absolute values of the scalars must sum to at most 32.
+    pub(super) fn linear_combination(terms: &[(i128, &Self)], modulus: &BigUint) -> Self {
+        let mode = Self::witness_mode(&terms.iter().map(|(_, term)| *term).collect::<Vec<_>>());
+
+        // Compute the sum as an integer, and divide it by the modulus.
+        let sum = terms.iter().map(|(scalar, term)| BigInt::from(*scalar) * BigInt::from(term.value())).sum::<BigInt>();
+        let modulus_int = BigInt::from(modulus.clone());
+        let remainder = ((&sum % &modulus_int) + &modulus_int) % &modulus_int;
+        let quotient = i128::try_from((sum - &remainder) / &modulus_int).unwrap_or_default();
+
+        // Witness the remainder.
+        let output = Self::new(mode, modulus, &remainder.to_biguint().unwrap_or_default());
+        // Witness the quotient, shifted to be non-negative.
+        let offset = 1i128 << (QUOTIENT_BITS - 1);
+        let quotient_field = Self::witness_shifted(mode, quotient, offset, QUOTIENT_BITS);
+
+        // Enforce `sum - quotient * modulus - remainder == 0`.
+        let mut relation = Relation::new(NUM_LIMBS);
+        for (scalar, term) in terms {
+            relation.add(&term.terms(), *scalar);
+        }
+        relation.add_product(&(vec![quotient_field], vec![quotient]), &Self::constant(modulus).terms(), -1);
+        relation.add(&output.terms(), -1);
+        relation.enforce_zero();
+
+        output
+    }
+}
+
+impl<E: Environment> NonNativeField<E> {
+    /// Witnesses a canonical non-native field element, enforcing that it is less than `modulus`.
+    fn new(mode: Mode, modulus: &BigUint, value: &BigUint) -> Self {
+        let output = Self::new_unreduced(mode, value);
+        // Witness `modulus - 1 - value`, and enforce `value + (modulus - 1 - value) == modulus - 1`,
+        // which ensures `value < modulus`, as both are non-negative.
+        let modulus_minus_one = modulus - BigUint::from(1u8);
+        let difference = Self::new_unreduced(mode, &(&modulus_minus_one - value.min(&modulus_minus_one)));
+        let mut relation = Relation::new(NUM_LIMBS);
+        relation.add(&output.terms(), 1);
+        relation.add(&difference.terms(), 1);
+        relation.add(&Self::constant(&modulus_minus_one).terms(), -1);
+        relation.enforce_zero();
+        output
+    }
+
+    /// Witnesses a non-native field element, enforcing only that each limb is `LIMB_BITS` bits.
+    fn new_unreduced(mode: Mode, value: &BigUint) -> Self {
+        let limbs = to_limbs(value)
+            .into_iter()
+            .map(|limb| {
+                let limb = Field::new(mode, console::Field::from_u64(limb));
+                limb.to_lower_bits_le(LIMB_BITS);
+                limb
+            })
+            .collect();
+        Self { limbs }
+    }
+
+    /// Witnesses `value + offset` as a field element of `num_bits` bits, and returns it less the offset.
+    fn witness_shifted(mode: Mode, value: i128, offset: i128, num_bits: usize) -> Field<E> {
+        let shifted = Field::new(mode, console::Field::from_u128(value.saturating_add(offset).max(0) as u128));
+        shifted.to_lower_bits_le(num_bits);
+        shifted - Field::constant(console::Field::from_u128(offset as u128))
+    }
+
+    /// Enforces `a * b == c` modulo `modulus`, where `c` is canonical.
+    /// The product of `a` and `b` must be less than `2^256 * modulus`.
+    fn enforce_mul(a: &Self, b: &Self, c: &Self, modulus: &BigUint) {
+        // Witness the quotient, which is non-negative as `c` is canonical.
+        let (product, remainder) = (a.value() * b.value(), c.value());
+        let quotient = match product >= remainder {
+            true => (product - remainder) / modulus,
+            false => BigUint::from(0u8),
+        };
+        let quotient = Self::new_unreduced(Self::witness_mode(&[a, b, c]), &quotient);
+
+        // Enforce `a * b - quotient * modulus - c == 0`.
+        let mut relation = Relation::new(2 * NUM_LIMBS - 1);
+        relation.add_product(&a.terms(), &b.terms(), 1);
+        relation.add_product(&quotient.terms(), &Self::constant(modulus).terms(), -1);
+        relation.add(&c.terms(), -1);
+        relation.enforce_zero();
+    }
+
+    /// Returns the mode of a witness, which is constant if all of the given elements are constant.
+    fn witness_mode(elements: &[&Self]) -> Mode {
+        match elements.iter().all(|element| element.limbs.iter().all(|limb| limb.is_constant())) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    /// Returns the values of the limbs.
+    fn limb_values(&self) -> Vec<u64> {
+        self.limbs.iter().map(|limb| (*limb.eject_value()).to_bigint().as_ref()[0]).collect()
+    }
+
+    /// Returns the value of the non-native field element.
+    fn value(&self) -> BigUint {
+        BigUint::from_slice(&self.limb_values().into_iter().map(|limb| limb as u32).collect::<Vec<_>>())
+    }
+
+    /// Returns the limbs and their values, as terms of a relation.
+    fn terms(&self) -> (Vec<Field<E>>, Vec<i128>) {
+        (self.limbs.clone(), self.limb_values().into_iter().map(i128::from).collect())
+    }
+}
+
+/// An integer, represented as a polynomial in `2^LIMB_BITS`, with the limbs of non-native field elements
+/// in its coefficients. A relation between non-native field elements is enforced by enforcing the integer is zero.
+struct Relation<E: Environment> {
+    /// The coefficients, from the lowest degree.
+    coefficients: Vec<Field<E>>,
+    /// The values of the coefficients.
+    values: Vec<i128>,
+}
+
+impl<E: Environment> Relation<E> {
+    /// Initializes a zero integer with the given number of coefficients.
+    fn new(num_coefficients: usize) -> Self {
+        Self { coefficients: vec![Field::zero(); num_coefficients], values: vec![0; num_coefficients] }
+    }
+
+    /// Adds `scalar * a` to the integer.
+    fn add(&mut self, (limbs, values): &(Vec<Field<E>>, Vec<i128>), scalar: i128) {
+        let scalar_field = to_field::<E>(scalar);
+        for (i, (limb, value)) in limbs.iter().zip_eq(values).enumerate() {
+            self.coefficients[i] += limb * &scalar_field;
+            self.values[i] += scalar * value;
+        }
+    }
+
+    /// Adds `scalar * a * b` to the integer.
+    fn add_product(&mut self, a: &(Vec<Field<E>>, Vec<i128>), b: &(Vec<Field<E>>, Vec<i128>), scalar: i128) {
+        let scalar_field = to_field::<E>(scalar);
+        for (i, (a_limb, a_value)) in a.0.iter().zip_eq(&a.1).enumerate() {
+            for (j, (b_limb, b_value)) in b.0.iter().zip_eq(&b.1).enumerate() {
+                self.coefficients[i + j] += a_limb * b_limb * &scalar_field;
+                self.values[i + j] += scalar * a_value * b_value;
+            }
+        }
+    }
+
+    /// Enforces that the integer is zero.
+    ///
+    /// Each coefficient, plus the carry from the previous coefficient, must be a multiple of `2^LIMB_BITS`,
+    /// and the final sum must be zero. As the coefficients and carries are far smaller than the base field,
+    /// these constraints hold over the integers.
+    fn enforce_zero(self) {
+        let mode = match self.coefficients.iter().all(|coefficient| coefficient.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let base = to_field::<E>(1 << LIMB_BITS);
+        let offset = 1i128 << (CARRY_BITS - 1);
+
+        let (mut carry, mut carry_value) = (Field::zero(), 0i128);
+        let num_coefficients = self.coefficients.len();
+        for (i, (coefficient, value)) in self.coefficients.into_iter().zip_eq(self.values).enumerate() {
+            let sum = coefficient + &carry;
+            // Ensure the final sum is zero.
+            if i + 1 == num_coefficients {
+                E::assert_eq(sum, Field::<E>::zero());
+                break;
+            }
+            // Witness the carry, and ensure the sum is the carry times `2^LIMB_BITS`.
+            carry_value = (value + carry_value) >> LIMB_BITS;
+            carry = NonNativeField::witness_shifted(mode, carry_value, offset, CARRY_BITS);
+            E::assert_eq(sum, &carry * &base);
+        }
+    }
+}
+
+/// Returns the `NUM_LIMBS` little-endian limbs of the given value, which must be less than `2^256`.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().into_iter().map(u64::from).collect::<Vec<_>>();
+    limbs.resize(NUM_LIMBS, 0);
+    limbs
+}
+
+/// Returns the given integer as a constant field element.
+fn to_field<E: Environment>(value: i128) -> Field<E> {
+    let field = Field::constant(console::Field::from_u128(value.unsigned_abs()));
+    match value < 0 {
+        true => -field,
+        false => field,
+    }
+}
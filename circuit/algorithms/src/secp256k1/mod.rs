@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod field;
+mod verify;
+
+use field::NonNativeField;
+
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U8};
+
+use core::marker::PhantomData;
+use num_bigint::{BigInt, BigUint};
+
+/// The secp256k1 elliptic curve `y^2 = x^3 + 7`, which is used for ECDSA signatures in Bitcoin and Ethereum.
+///
+/// The base field and scalar field of secp256k1 are emulated with non-native field arithmetic,
+/// where each element is split into limbs that are small enough to multiply in the native field.
+pub struct Secp256k1<E: Environment>(PhantomData<E>);
+
+/// An affine point on secp256k1. Note: The point at infinity is not representable.
+#[derive(Clone)]
+struct Point<E: Environment> {
+    x: NonNativeField<E>,
+    y: NonNativeField<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point from its big-endian coordinates.
+    fn constant(x: &[u8; 32], y: &[u8; 32]) -> Self {
+        Self {
+            x: NonNativeField::constant(&BigUint::from_bytes_be(x)),
+            y: NonNativeField::constant(&BigUint::from_bytes_be(y)),
+        }
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: NonNativeField::ternary(condition, &first.x, &second.x),
+            y: NonNativeField::ternary(condition, &first.y, &second.y),
+        }
+    }
+}
+
+impl<E: Environment> Secp256k1<E> {
+    /// The x-coordinate of the offset point, in big-endian.
+    /// The offset point is the first point with an even y-coordinate, for which the x-coordinate
+    /// is at least `SHA-256("snarkVM.secp256k1.offset")`, so its discrete logarithm is unknown.
+    const OFFSET_X: [u8; 32] = [
+        0xf8, 0x8f, 0xf0, 0x48, 0x82, 0x36, 0x0e, 0x63, 0x9b, 0x75, 0x17, 0x0b, 0x15, 0xec, 0xa5, 0x1c, 0x8c, 0x67,
+        0x9e, 0x47, 0x22, 0x56, 0xeb, 0x26, 0x96, 0x47, 0x75, 0xac, 0x67, 0x16, 0x10, 0xbe,
+    ];
+    /// The y-coordinate of the offset point, in big-endian.
+    const OFFSET_Y: [u8; 32] = [
+        0x50, 0xe3, 0xe9, 0xfd, 0x00, 0x8b, 0x79, 0x1b, 0x95, 0xf4, 0xea, 0x01, 0x4d, 0x56, 0x6e, 0xa5, 0x28, 0xa8,
+        0x17, 0x70, 0x53, 0x69, 0xee, 0x5a, 0x2c, 0x5d, 0x4f, 0x95, 0x50, 0xa3, 0xd1, 0x20,
+    ];
+    /// The x-coordinate of `-2^256` times the offset point, in big-endian.
+    const OFFSET_CORRECTION_X: [u8; 32] = [
+        0x6f, 0x67, 0xc1, 0x46, 0xa5, 0x1d, 0xb1, 0xb1, 0xc1, 0xbc, 0x07, 0x70, 0x84, 0xd0, 0x32, 0x9d, 0xc3, 0xdc,
+        0x8a, 0x51, 0xdd, 0x1d, 0x47, 0xbb, 0x66, 0xf4, 0x6a, 0x85, 0x5c, 0x5b, 0x3f, 0xec,
+    ];
+    /// The y-coordinate of `-2^256` times the offset point, in big-endian.
+    const OFFSET_CORRECTION_Y: [u8; 32] = [
+        0x4e, 0x55, 0xcc, 0xbb, 0xbb, 0xc1, 0x5b, 0x0e, 0x6d, 0xb3, 0x0b, 0x09, 0xca, 0xdd, 0xb5, 0x8c, 0x60, 0x88,
+        0x1f, 0xa4, 0xed, 0x7f, 0x45, 0xcf, 0x92, 0xd5, 0x8c, 0xcc, 0xc3, 0xf1, 0xb8, 0xd0,
+    ];
+    /// The x-coordinate of the fallback point, in big-endian, which replaces an invalid public key.
+    /// The fallback point is the first point with an even y-coordinate, for which the x-coordinate
+    /// is at least `SHA-256("snarkVM.secp256k1.fallback")`, so its discrete logarithm is unknown.
+    const FALLBACK_X: [u8; 32] = [
+        0xeb, 0xee, 0xde, 0x2f, 0x2e, 0x29, 0x82, 0xd5, 0x17, 0x1e, 0xc8, 0x16, 0xdc, 0x05, 0x3e, 0x36, 0x4a, 0xd2,
+        0x85, 0x1d, 0x03, 0xbf, 0xe8, 0xb0, 0x5f, 0xd5, 0x0d, 0xd2, 0xa2, 0xb8, 0x01, 0xd8,
+    ];
+    /// The y-coordinate of the fallback point, in big-endian.
+    const FALLBACK_Y: [u8; 32] = [
+        0x56, 0x47, 0x99, 0x4c, 0x14, 0x9d, 0x38, 0x61, 0x36, 0xdd, 0x08, 0xb5, 0x19, 0xaf, 0x46, 0xa5, 0x5b, 0x89,
+        0x54, 0x9a, 0xe1, 0x3f, 0x24, 0xb2, 0xd9, 0x5a, 0xdf, 0x09, 0x80, 0x85, 0xe1, 0x44,
+    ];
+}
+
+impl<E: Environment> Secp256k1<E> {
+    /// Returns the modulus of the base field.
+    fn base_field_modulus() -> BigUint {
+        BigUint::from_bytes_be(&console::Secp256k1::BASE_FIELD_MODULUS)
+    }
+
+    /// Returns the modulus of the scalar field.
+    fn scalar_field_modulus() -> BigUint {
+        BigUint::from_bytes_be(&console::Secp256k1::SCALAR_FIELD_MODULUS)
+    }
+
+    /// Returns the generator.
+    fn generator() -> Point<E> {
+        Point::constant(&console::Secp256k1::GENERATOR_X, &console::Secp256k1::GENERATOR_Y)
+    }
+
+    /// Returns the fallback point, which replaces an invalid public key.
+    fn fallback() -> Point<E> {
+        Point::constant(&Self::FALLBACK_X, &Self::FALLBACK_Y)
+    }
+
+    /// Returns `true` if the given point is on the curve, where its coordinates are canonical.
+    fn is_on_curve(point: &Point<E>) -> Boolean<E> {
+        let p = Self::base_field_modulus();
+        let x2 = point.x.mul(&point.x, &p);
+        let x3 = x2.mul(&point.x, &p);
+        let seven = NonNativeField::constant(&BigUint::from(7u8));
+        let rhs = NonNativeField::linear_combination(&[(1, &x3), (1, &seven)], &p);
+        point.y.mul(&point.y, &p).is_equal(&rhs)
+    }
+
+    /// Returns the sum of the given points.
+    /// Enforces that the points have distinct x-coordinates, i.e. they are not equal or inverses.
+    fn add(a: &Point<E>, b: &Point<E>) -> Point<E> {
+        let p = Self::base_field_modulus();
+        // Compute the slope as `(b.y - a.y) / (b.x - a.x)`.
+        let numerator = NonNativeField::linear_combination(&[(1, &b.y), (-1, &a.y)], &p);
+        let denominator = NonNativeField::linear_combination(&[(1, &b.x), (-1, &a.x)], &p);
+        let lambda = NonNativeField::div(&numerator, &denominator, &p);
+        Self::from_slope(&lambda, a, &b.x)
+    }
+
+    /// Returns the given point doubled.
+    fn double(a: &Point<E>) -> Point<E> {
+        let p = Self::base_field_modulus();
+        // Compute the slope of the tangent as `3 * a.x^2 / (2 * a.y)`.
+        let x2 = a.x.mul(&a.x, &p);
+        let numerator = NonNativeField::linear_combination(&[(3, &x2)], &p);
+        let denominator = NonNativeField::linear_combination(&[(2, &a.y)], &p);
+        let lambda = NonNativeField::div(&numerator, &denominator, &p);
+        Self::from_slope(&lambda, a, &a.x)
+    }
+
+    /// Returns the third point on the line through `a` with the given slope, negated,
+    /// where `b_x` is the x-coordinate of the second point on the line.
+    fn from_slope(lambda: &NonNativeField<E>, a: &Point<E>, b_x: &NonNativeField<E>) -> Point<E> {
+        let p = Self::base_field_modulus();
+        // Compute `x = lambda^2 - a.x - b.x`.
+        let lambda2 = lambda.mul(lambda, &p);
+        let x = NonNativeField::linear_combination(&[(1, &lambda2), (-1, &a.x), (-1, b_x)], &p);
+        // Compute `y = lambda * (a.x - x) - a.y`.
+        let difference = NonNativeField::linear_combination(&[(1, &a.x), (-1, &x)], &p);
+        let product = lambda.mul(&difference, &p);
+        let y = NonNativeField::linear_combination(&[(1, &product), (-1, &a.y)], &p);
+        Point { x, y }
+    }
+
+    /// Returns `a * G + b * Q`, for the generator `G` and the given point `Q`.
+    ///
+    /// The scalars are processed together, from the most significant bit, so their doublings are shared.
+    /// The accumulator starts at an offset point, which is removed at the end, so that it never
+    /// becomes the point at infinity, which the incomplete addition formulas can not represent.
+    /// The point `Q` may be `G` or `-G`, for which `G + Q` is `2G` or the point at infinity.
+    fn double_scalar_mul(a: &NonNativeField<E>, b: &NonNativeField<E>, q: &Point<E>) -> Point<E> {
+        let generator = Self::generator();
+        let generator_doubled = Self::double(&generator);
+
+        // Determine if `Q` is `G` or `-G`, as `G + Q` is then an exceptional case of the addition formula.
+        let is_q_x_equal = q.x.is_equal(&generator.x);
+        let is_q_y_equal = q.y.is_equal(&generator.y);
+        let is_q_generator = &is_q_x_equal & &is_q_y_equal;
+        let is_q_negated_generator = &is_q_x_equal & !is_q_y_equal;
+
+        // Precompute `G + Q`. If `Q` is `G` or `-G`, add `2G` in its place, so the denominator is nonzero.
+        // Note: If `Q` is `-G`, then `G + Q` is the point at infinity, so its sum is never used below.
+        let q_or_doubled = Point::ternary(&is_q_x_equal, &generator_doubled, q);
+        let generator_plus_q =
+            Point::ternary(&is_q_generator, &generator_doubled, &Self::add(&generator, &q_or_doubled));
+
+        let mut output = Point::constant(&Self::OFFSET_X, &Self::OFFSET_Y);
+        for (bit_a, bit_b) in a.to_bits_le().iter().zip_eq(&b.to_bits_le()).rev() {
+            output = Self::double(&output);
+            // Select `G`, `Q`, or `G + Q`, and discard the sum if neither bit is set.
+            let addend = Point::ternary(bit_b, &Point::ternary(bit_a, &generator_plus_q, q), &generator);
+            let sum = Self::add(&output, &addend);
+            // If `Q` is `-G` and both bits are set, the addend is the point at infinity, so discard the sum.
+            let is_infinity = (bit_a & bit_b) & &is_q_negated_generator;
+            output = Point::ternary(&((bit_a | bit_b) & !is_infinity), &sum, &output);
+        }
+
+        // Remove the offset, which has been doubled 256 times.
+        Self::add(&output, &Point::constant(&Self::OFFSET_CORRECTION_X, &Self::OFFSET_CORRECTION_Y))
+    }
+}
+
+/// Returns the little-endian bits of the given big-endian bytes.
+fn to_bits_le<E: Environment>(bytes_be: &[U8<E>]) -> Vec<Boolean<E>> {
+    bytes_be.iter().rev().flat_map(|byte| byte.to_bits_le()).collect()
+}
+
+/// Returns `true` if the given little-endian bits are less than the given constant.
+fn is_less_than_constant<E: Environment>(bits_le: &[Boolean<E>], constant: &BigUint) -> Boolean<E> {
+    bits_le.iter().enumerate().fold(Boolean::constant(false), |is_less, (i, bit)| match constant.bit(i as u64) {
+        // If the bit of the constant is set, the value is less if its bit is unset, or if its lower bits are less.
+        true => !bit | is_less,
+        // Otherwise, the value is less if its bit is unset, and its lower bits are less.
+        false => !bit & is_less,
+    })
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Secp256k1<E> {
+    /// Returns `true` if the given signature is a valid ECDSA signature on the `digest` for the `public_key`.
+    ///
+    /// The signature is the concatenation of `(r, s)`, the public key is the concatenation of its uncompressed
+    /// coordinates `(x, y)`, and the digest is the 32-byte hash of the message, e.g. its Keccak-256 digest.
+    /// Each of these are encoded as 32-byte big-endian integers.
+    ///
+    /// Note: Unlike the console implementation, the circuit uses incomplete addition formulas.
+    /// A signature that is crafted to hit an exceptional case, e.g. where `u1 * G + u2 * Q` is the point
+    /// at infinity, is unsatisfiable, instead of evaluating to `false`. An invalid public key is replaced
+    /// with a fallback point whose discrete logarithm is unknown, so it evaluates to `false`.
+    pub fn verify_ecdsa(signature: &[U8<E>], public_key: &[U8<E>], digest: &[U8<E>]) -> Boolean<E> {
+        // Ensure the inputs are the correct number of bytes.
+        if signature.len() != 64 || public_key.len() != 64 || digest.len() != 32 {
+            E::halt("ECDSA verification expects a 64-byte signature, a 64-byte public key, and a 32-byte digest")
+        }
+        let (p, n) = (Self::base_field_modulus(), Self::scalar_field_modulus());
+        let one = NonNativeField::constant(&BigUint::from(1u8));
+
+        // Ensure `r` and `s` are in `[1, n - 1]`.
+        let (r_bits, s_bits) = (to_bits_le(&signature[..32]), to_bits_le(&signature[32..]));
+        let is_r_valid = is_nonzero(&r_bits) & is_less_than_constant(&r_bits, &n);
+        let is_s_valid = is_nonzero(&s_bits) & is_less_than_constant(&s_bits, &n);
+        let r = NonNativeField::from_bits_le(&r_bits);
+        // If `s` is invalid, use `1` in its place, so that its inverse exists.
+        let s = NonNativeField::ternary(&is_s_valid, &NonNativeField::from_bits_le(&s_bits), &one);
+
+        // Ensure the public key is on the curve. If it is not, use the fallback point in its place,
+        // so that the scalar multiplication is well-defined.
+        // Note: The fallback point is not `G` or `-G`, so that the sum `G + Q` has a nonzero denominator.
+        let fallback = Self::fallback();
+        let (x_bits, y_bits) = (to_bits_le(&public_key[..32]), to_bits_le(&public_key[32..]));
+        let is_canonical = is_less_than_constant(&x_bits, &p) & is_less_than_constant(&y_bits, &p);
+        let candidate = Point {
+            x: NonNativeField::ternary(&is_canonical, &NonNativeField::from_bits_le(&x_bits), &fallback.x),
+            y: NonNativeField::ternary(&is_canonical, &NonNativeField::from_bits_le(&y_bits), &fallback.y),
+        };
+        let is_public_key_valid = is_canonical & Self::is_on_curve(&candidate);
+        let public_key = Point::ternary(&is_public_key_valid, &candidate, &fallback);
+
+        // Compute `u1 = z / s` and `u2 = r / s`.
+        let z = NonNativeField::from_bits_le(&to_bits_le(digest));
+        let s_inverse = s.inverse(&n);
+        let u1 = z.mul(&s_inverse, &n);
+        let u2 = r.mul(&s_inverse, &n);
+
+        // Compute `u1 * G + u2 * Q`, and check its x-coordinate is `r`, modulo `n`.
+        let point = Self::double_scalar_mul(&u1, &u2, &public_key);
+        let is_r_equal = point.x.reduce(&n).is_equal(&r);
+
+        is_r_valid & is_s_valid & is_public_key_valid & is_r_equal
+    }
+}
+
+/// Returns `true` if any of the given bits are set.
+fn is_nonzero<E: Environment>(bits: &[Boolean<E>]) -> Boolean<E> {
+    bits.iter().fold(Boolean::constant(false), |is_nonzero, bit| is_nonzero | bit)
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::TestRng;
+
+    /// The public key `(x, y)` for the private key `SHA-256("snarkVM secp256k1 test key")`.
+    const PUBLIC_KEY: [&str; 2] = [
+        "9a2248dea0ac6d8cd585e41c03ecd212ca9670008e6bae48b996c676c0775745",
+        "138a52d5c4c8c334e31c8611c75dd85782c188626b5cb114e464c718089624ed",
+    ];
+    /// The signature `(r, s)` on the digest, for the nonce `SHA-256("snarkVM secp256k1 test nonce")`.
+    const SIGNATURE: [&str; 2] = [
+        "8a67352e424caa9fe6b94efc36cff2e87827f360bb0272e4016e30d8e66f5a41",
+        "b1d3e11deaf39a71ae2d6b8834a07f7118e9d8b479d096736c2e7b66ef230a6a",
+    ];
+    /// The Keccak-256 digest of "abc".
+    const DIGEST: &str = "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+    /// Returns the bytes of the given hex strings.
+    fn decode(strings: &[&str]) -> Vec<u8> {
+        let string = strings.concat();
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn check_verify_ecdsa(mode: Mode, signature: &[u8], public_key: &[u8], digest: &[u8]) {
+        // Compute the expected native result.
+        let expected = console::Secp256k1::verify_ecdsa(
+            signature.try_into().unwrap(),
+            public_key.try_into().unwrap(),
+            digest.try_into().unwrap(),
+        );
+
+        // Initialize the inputs.
+        let inject = |bytes: &[u8]| {
+            bytes.iter().map(|byte| U8::<Circuit>::new(mode, console::U8::new(*byte))).collect::<Vec<_>>()
+        };
+        let (signature, public_key, digest) = (inject(signature), inject(public_key), inject(digest));
+
+        // Verify the signature.
+        Circuit::scope("Secp256k1::verify_ecdsa", || {
+            let candidate = Secp256k1::verify_ecdsa(&signature, &public_key, &digest);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_ecdsa() {
+        let (signature, public_key, digest) = (decode(&SIGNATURE), decode(&PUBLIC_KEY), decode(&[DIGEST]));
+        check_verify_ecdsa(Mode::Private, &signature, &public_key, &digest);
+
+        // Ensure a different digest is rejected.
+        let mut candidate = digest.clone();
+        candidate[31] ^= 1;
+        check_verify_ecdsa(Mode::Private, &signature, &public_key, &candidate);
+
+        // Ensure a public key that is not on the curve is rejected.
+        let mut candidate = public_key.clone();
+        candidate[63] ^= 1;
+        check_verify_ecdsa(Mode::Private, &signature, &candidate, &digest);
+
+        // Ensure `s` must be nonzero.
+        let mut candidate = signature;
+        candidate[32..].fill(0);
+        check_verify_ecdsa(Mode::Private, &candidate, &public_key, &digest);
+    }
+
+    #[test]
+    fn test_verify_ecdsa_generator_public_key() {
+        // The generator `G`, and the y-coordinate of `-G`.
+        let generator = [
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        ];
+        let negated_y = "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777";
+        // The signatures on the digest for the private keys `1` and `n - 1`, i.e. the public keys `G` and `-G`.
+        let r = "737fd610f2530465e73d57a71074431af156f4fdd4cc008a3874241753ca9744";
+        let s_generator = "b96782b31eedf4c46f2c68877383c9037007e1cce404a008a62c53a1034a5e45";
+        let s_negated = "dfe9fd132e56670648932ace62bd6171a3e90a072a83af1b2705eb035c1f554f";
+
+        let digest = decode(&[DIGEST]);
+        for (public_key, s) in [(generator, s_generator), ([generator[0], negated_y], s_negated)] {
+            let (signature, public_key) = (decode(&[r, s]), decode(&public_key));
+            // Ensure the signature is accepted, as `G + Q` is not an exceptional case.
+            check_verify_ecdsa(Mode::Private, &signature, &public_key, &digest);
+            // Ensure a different digest is rejected.
+            let mut candidate = digest.clone();
+            candidate[31] ^= 1;
+            check_verify_ecdsa(Mode::Private, &signature, &public_key, &candidate);
+        }
+    }
+
+    #[test]
+    fn test_verify_ecdsa_random() {
+        let mut rng = TestRng::default();
+
+        // Ensure random inputs are rejected.
+        let mut sample = |num_bytes: usize| (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let (signature, public_key, digest) = (sample(64), sample(64), sample(32));
+        check_verify_ecdsa(Mode::Private, &signature, &public_key, &digest);
+    }
+
+    #[test]
+    fn test_non_native_field() {
+        let mut rng = TestRng::default();
+        let p = Secp256k1::<Circuit>::base_field_modulus();
+
+        // Sample canonical elements.
+        let a_value = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &p;
+        let b_value = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &p;
+        let bits = |value: &BigUint| {
+            (0..256).map(|i| Boolean::<Circuit>::new(Mode::Private, value.bit(i))).collect::<Vec<_>>()
+        };
+        let (a, b) = (NonNativeField::from_bits_le(&bits(&a_value)), NonNativeField::from_bits_le(&bits(&b_value)));
+
+        // Ensure the arithmetic operations are correct.
+        let expected = NonNativeField::constant(&((&a_value * &b_value) % &p));
+        assert!(a.mul(&b, &p).is_equal(&expected).eject_value());
+        let expected = NonNativeField::constant(&((&a_value + &p - &b_value) % &p));
+        assert!(NonNativeField::linear_combination(&[(1, &a), (-1, &b)], &p).is_equal(&expected).eject_value());
+        let product = NonNativeField::div(&a, &b, &p).mul(&b, &p);
+        assert!(product.is_equal(&a).eject_value());
+        assert!(a.inverse(&p).mul(&a, &p).is_equal(&NonNativeField::constant(&BigUint::from(1u8))).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the inverse of zero is unsatisfiable.
+        let zero = NonNativeField::<Circuit>::from_bits_le(&bits(&BigUint::from(0u8)));
+        zero.inverse(&p);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -29,6 +29,13 @@ pub struct MerklePath<E: Environment, const DEPTH: u8> {
     siblings: Vec<Field<E>>,
 }
 
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns the leaf index for the path.
+    pub const fn leaf_index(&self) -> &U64<E> {
+        &self.leaf_index
+    }
+}
+
 #[cfg(console)]
 impl<E: Environment, const DEPTH: u8> Inject for MerklePath<E, DEPTH> {
     type Primitive = console::merkle_tree::MerklePath<E::Network, DEPTH>;
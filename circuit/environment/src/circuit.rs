@@ -22,6 +22,7 @@ type Field = <console::Testnet3 as console::Environment>::Field;
 thread_local! {
     pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static IS_PROFILING: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -112,7 +113,8 @@ impl Environment for Circuit {
                 CIRCUIT.with(|circuit| {
                     // Set the entire environment to the new scope.
                     let name = name.into();
-                    if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
+                    let is_profiling = IS_PROFILING.with(|is_profiling| *(**is_profiling).borrow());
+                    if let Err(error) = (**circuit).borrow_mut().push_scope(&name, is_profiling) {
                         Self::halt(error)
                     }
 
@@ -239,6 +241,25 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().num_nonzeros_in_scope())
     }
 
+    /// Sets whether the count of each scope is saved when it exits.
+    /// Turning profiling off clears the scopes that have exited.
+    fn set_profiling(is_profiling: bool) {
+        IS_PROFILING.with(|profiling| *(**profiling).borrow_mut() = is_profiling);
+        if !is_profiling {
+            CIRCUIT.with(|circuit| (**circuit).borrow_mut().clear_exited_scopes());
+        }
+    }
+
+    /// Returns the number of scopes that have exited while profiling.
+    fn num_exited_scopes() -> usize {
+        CIRCUIT.with(|circuit| (**circuit).borrow().num_exited_scopes())
+    }
+
+    /// Returns the full name and count of each scope that has exited, starting from the given index.
+    fn exited_scopes(start: usize) -> Vec<(String, (u64, u64, u64, u64, (u64, u64, u64)))> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().exited_scopes(start).to_vec())
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -372,4 +393,30 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_circuit_exited_scopes() {
+        // Ensure no scopes are saved when not profiling.
+        Circuit::scope("unprofiled", || Circuit::scope("inner", || ()));
+        assert_eq!(0, Circuit::num_exited_scopes());
+
+        Circuit::set_profiling(true);
+        let start = Circuit::num_exited_scopes();
+        Circuit::scope("outer", || {
+            let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one());
+            Circuit::scope("inner", || a.square());
+        });
+
+        // Ensure the inner scope exits first, and the outer scope includes the count of the inner scope.
+        let scopes = Circuit::exited_scopes(start)
+            .into_iter()
+            .map(|(name, (constants, public, private, constraints, _))| (name, constants, public, private, constraints))
+            .collect::<Vec<_>>();
+        assert_eq!(scopes, [("outer.inner".to_string(), 0, 0, 1, 1), ("outer".to_string(), 0, 0, 2, 1)]);
+
+        // Ensure turning profiling off clears the saved scopes.
+        Circuit::set_profiling(false);
+        assert_eq!(0, Circuit::num_exited_scopes());
+        Circuit::reset();
+    }
 }
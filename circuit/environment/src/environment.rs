@@ -155,6 +155,20 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         )
     }
 
+    /// Sets whether the count of each scope is saved when it exits.
+    fn set_profiling(_is_profiling: bool) {}
+
+    /// Returns the number of scopes that have exited while profiling.
+    fn num_exited_scopes() -> usize {
+        0
+    }
+
+    /// Returns the full name and count of each scope that has exited while profiling, starting from the given index.
+    /// The scopes are in the order they exited, and the count of a scope includes its nested scopes.
+    fn exited_scopes(_start: usize) -> Vec<(String, (u64, u64, u64, u64, (u64, u64, u64)))> {
+        Vec::new()
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
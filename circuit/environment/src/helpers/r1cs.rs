@@ -30,6 +30,10 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Rc<Constraint<F>>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
+    /// The counts of the constraint system when each open scope was entered, if it was entered while profiling.
+    scope_starts: Vec<Option<(u64, u64, u64, u64, (u64, u64, u64))>>,
+    /// The name and count of each scope that has exited while profiling, in the order they exited.
+    exited_scopes: Vec<(Scope, (u64, u64, u64, u64, (u64, u64, u64)))>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,17 +46,35 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
+            scope_starts: Default::default(),
+            exited_scopes: Default::default(),
         }
     }
 
     /// Appends the given scope to the current environment.
-    pub(crate) fn push_scope<S: Into<String>>(&mut self, name: S) -> Result<(), String> {
-        self.counter.push(name)
+    /// If `is_profiling` is `true`, the count of the scope is saved when it exits.
+    pub(crate) fn push_scope<S: Into<String>>(&mut self, name: S, is_profiling: bool) -> Result<(), String> {
+        self.counter.push(name)?;
+        self.scope_starts.push(is_profiling.then(|| self.count()));
+        Ok(())
     }
 
     /// Removes the given scope from the current environment.
     pub(crate) fn pop_scope<S: Into<String>>(&mut self, name: S) -> Result<(), String> {
-        self.counter.pop(name)
+        let scope = self.counter.scope();
+        self.counter.pop(name)?;
+        // If the scope was entered while profiling, save the count of the scope, including its nested scopes.
+        if let Some(Some((constants, public, private, constraints, nonzeros))) = self.scope_starts.pop() {
+            let count = (
+                self.num_constants() - constants,
+                self.num_public() - public,
+                self.num_private() - private,
+                self.num_constraints() - constraints,
+                (self.nonzeros.0 - nonzeros.0, self.nonzeros.1 - nonzeros.1, self.nonzeros.2 - nonzeros.2),
+            );
+            self.exited_scopes.push((scope, count));
+        }
+        Ok(())
     }
 
     /// Returns a new constant with the given value and scope.
@@ -106,6 +128,26 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.scope()
     }
 
+    /// Clears the scopes that have exited.
+    pub(crate) fn clear_exited_scopes(&mut self) {
+        self.exited_scopes.clear();
+    }
+
+    /// Returns the number of scopes that have exited while profiling.
+    pub(crate) fn num_exited_scopes(&self) -> usize {
+        self.exited_scopes.len()
+    }
+
+    /// Returns the name and count of each scope that has exited, starting from the given index.
+    pub(crate) fn exited_scopes(&self, start: usize) -> &[(Scope, (u64, u64, u64, u64, (u64, u64, u64)))] {
+        self.exited_scopes.get(start..).unwrap_or_default()
+    }
+
+    /// Returns the number of constants, public variables, private variables, constraints, and nonzeros.
+    fn count(&self) -> (u64, u64, u64, u64, (u64, u64, u64)) {
+        (self.num_constants(), self.num_public(), self.num_private(), self.num_constraints(), self.num_nonzeros())
+    }
+
     /// Returns the number of constants in the constraint system.
     pub fn num_constants(&self) -> u64 {
         self.constants.len() as u64
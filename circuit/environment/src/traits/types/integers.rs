@@ -27,6 +27,8 @@ pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16
     + ShrAssign<U8>
     + ShrChecked<U8, Output = Self>
     + ShrWrapped<U8, Output = Self>
+    + RotateLeft<U8, Output = Self>
+    + RotateRight<U8, Output = Self>
     + PowChecked<U16, Output = Self>
     + PowWrapped<U16, Output = Self>
     + Shl<U16, Output = Self>
@@ -37,6 +39,8 @@ pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16
     + ShrAssign<U16>
     + ShrChecked<U16, Output = Self>
     + ShrWrapped<U16, Output = Self>
+    + RotateLeft<U16, Output = Self>
+    + RotateRight<U16, Output = Self>
     + PowChecked<U32, Output = Self>
     + PowWrapped<U32, Output = Self>
     + Shl<U32, Output = Self>
@@ -47,6 +51,8 @@ pub trait IntegerTrait<I: IntegerType, U8: IntegerCore<u8>, U16: IntegerCore<u16
     + ShrAssign<U32>
     + ShrChecked<U32, Output = Self>
     + ShrWrapped<U32, Output = Self>
+    + RotateLeft<U32, Output = Self>
+    + RotateRight<U32, Output = Self>
 {
 }
 
@@ -56,6 +62,7 @@ pub trait IntegerCore<I: IntegerType>:
     + AddAssign
     + Add<Output = Self>
     + AddChecked<Output = Self>
+    + AddSaturating<Output = Self>
     + AddWrapped<Output = Self>
     + BitAndAssign
     + BitAnd<Output = Self>
@@ -76,6 +83,7 @@ pub trait IntegerCore<I: IntegerType>:
     + MulAssign
     + Mul<Output = Self>
     + MulChecked<Output = Self>
+    + MulSaturating<Output = Self>
     + MulWrapped<Output = Self>
     + Neg<Output = Self>
     + Not<Output = Self>
@@ -88,6 +96,7 @@ pub trait IntegerCore<I: IntegerType>:
     + SubAssign
     + Sub<Output = Self>
     + SubChecked<Output = Self>
+    + SubSaturating<Output = Self>
     + SubWrapped<Output = Self>
     + Ternary
     + ToBits
@@ -461,6 +461,21 @@ impl Environment for AleoV0 {
         E::num_nonzeros_in_scope()
     }
 
+    /// Sets whether the count of each scope is saved when it exits.
+    fn set_profiling(is_profiling: bool) {
+        E::set_profiling(is_profiling)
+    }
+
+    /// Returns the number of scopes that have exited while profiling.
+    fn num_exited_scopes() -> usize {
+        E::num_exited_scopes()
+    }
+
+    /// Returns the full name and count of each scope that has exited, starting from the given index.
+    fn exited_scopes(start: usize) -> Vec<(String, (u64, u64, u64, u64, (u64, u64, u64)))> {
+        E::exited_scopes(start)
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         E::halt(message)
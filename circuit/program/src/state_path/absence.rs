@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The absence proof shows that a serial number is not in a sorted set of serial numbers.
+pub struct AbsenceProof<A: Aleo> {
+    /// The serial numbers root (Public).
+    serial_numbers_root: Field<A>,
+    /// The serial number that is absent.
+    serial_number: Field<A>,
+    /// The greatest serial number in the tree that is less than the absent serial number.
+    lower: Field<A>,
+    /// The Merkle path for the lower serial number.
+    lower_path: SerialNumbersPath<A>,
+    /// The least serial number in the tree that is greater than the absent serial number.
+    upper: Field<A>,
+    /// The Merkle path for the upper serial number.
+    upper_path: SerialNumbersPath<A>,
+}
+
+impl<A: Aleo> AbsenceProof<A> {
+    /// Returns the absent serial number.
+    pub const fn serial_number(&self) -> &Field<A> {
+        &self.serial_number
+    }
+
+    /// Returns `true` if the absence proof is valid for its serial numbers root.
+    pub fn verify(&self) -> Boolean<A> {
+        // Ensure the serial number is enclosed by the lower and upper serial numbers.
+        let check_order = self.lower.is_less_than(&self.serial_number) & self.serial_number.is_less_than(&self.upper);
+
+        // Ensure the leaves are adjacent.
+        let check_adjacent =
+            self.lower_path.leaf_index().add_wrapped(&U64::one()).is_equal(self.upper_path.leaf_index());
+
+        // Ensure the lower and upper paths are valid.
+        let check_lower_path =
+            A::verify_merkle_path_bhp(&self.lower_path, &self.serial_numbers_root, &self.lower.to_bits_le());
+        let check_upper_path =
+            A::verify_merkle_path_bhp(&self.upper_path, &self.serial_numbers_root, &self.upper.to_bits_le());
+
+        // Check the absence proof.
+        check_order & check_adjacent & check_lower_path & check_upper_path
+    }
+}
+
+impl<A: Aleo> Inject for AbsenceProof<A> {
+    type Primitive = console::AbsenceProof<A::Network>;
+
+    /// Initializes a new absence proof circuit from a primitive.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        Self {
+            serial_numbers_root: Field::new(Mode::Public, *proof.serial_numbers_root()),
+            serial_number: Field::new(mode, *proof.serial_number()),
+            lower: Field::new(mode, *proof.lower()),
+            lower_path: SerialNumbersPath::new(mode, proof.lower_path().clone()),
+            upper: Field::new(mode, *proof.upper()),
+            upper_path: SerialNumbersPath::new(mode, proof.upper_path().clone()),
+        }
+    }
+}
+
+impl<A: Aleo> Eject for AbsenceProof<A> {
+    type Primitive = console::AbsenceProof<A::Network>;
+
+    /// Ejects the mode of the absence proof.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            self.serial_numbers_root.eject_mode(),
+            [
+                self.serial_number.eject_mode(),
+                self.lower.eject_mode(),
+                self.lower_path.eject_mode(),
+                self.upper.eject_mode(),
+                self.upper_path.eject_mode(),
+            ],
+        )
+    }
+
+    /// Ejects the absence proof.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::from(
+            self.serial_numbers_root.eject_value(),
+            self.serial_number.eject_value(),
+            self.lower.eject_value(),
+            self.lower_path.eject_value(),
+            self.upper.eject_value(),
+            self.upper_path.eject_value(),
+        ) {
+            Ok(proof) => proof,
+            Err(error) => A::halt(format!("Failed to eject the absence proof: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_absence_proof_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample the serial numbers.
+                let serial_numbers = (0..16).map(|_| console::Field::rand(rng)).collect::<Vec<_>>();
+                // Construct the console absence proof.
+                let console_proof =
+                    console::AbsenceProof::<CurrentNetwork>::prove(serial_numbers, &console::Field::rand(rng))?;
+                console_proof.verify()?;
+
+                // Inject the absence proof.
+                let circuit_proof = AbsenceProof::<Circuit>::new(mode, console_proof.clone());
+                assert_eq!(console_proof, circuit_proof.eject_value());
+
+                // Ensure the absence proof is valid.
+                assert!(circuit_proof.verify().eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -15,19 +15,28 @@
 mod helpers;
 pub use helpers::*;
 
+mod absence;
+pub use absence::*;
+
+mod transaction;
+pub use transaction::*;
+
 mod verify;
+mod verify_batch;
 
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U8};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U64, U8};
 
 /// The depth of the Merkle tree for the blocks.
 const BLOCKS_DEPTH: u8 = console::BLOCKS_DEPTH;
 /// The depth of the Merkle tree for the block header.
 const HEADER_DEPTH: u8 = console::HEADER_DEPTH;
+/// The depth of the Merkle tree for the sorted serial numbers.
+const SERIAL_NUMBERS_DEPTH: u8 = console::SERIAL_NUMBERS_DEPTH;
 /// The depth of the Merkle tree for transactions in a block.
 const TRANSACTIONS_DEPTH: u8 = console::TRANSACTIONS_DEPTH;
 /// The depth of the Merkle tree for the transaction.
@@ -37,6 +46,7 @@ const TRANSITION_DEPTH: u8 = console::TRANSITION_DEPTH;
 
 type BlockPath<A> = MerklePath<A, BLOCKS_DEPTH>;
 type HeaderPath<A> = MerklePath<A, HEADER_DEPTH>;
+type SerialNumbersPath<A> = MerklePath<A, SERIAL_NUMBERS_DEPTH>;
 type TransactionsPath<A> = MerklePath<A, TRANSACTIONS_DEPTH>;
 type TransactionPath<A> = MerklePath<A, TRANSACTION_DEPTH>;
 type TransitionPath<A> = MerklePath<A, TRANSITION_DEPTH>;
@@ -132,7 +142,7 @@ impl<A: Aleo> Eject for StatePath<A> {
 
     /// Ejects the state path.
     fn eject_value(&self) -> Self::Primitive {
-        Self::Primitive::from(
+        match Self::Primitive::from(
             self.global_state_root.eject_value().into(),
             self.block_path.eject_value(),
             self.block_hash.eject_value().into(),
@@ -148,7 +158,10 @@ impl<A: Aleo> Eject for StatePath<A> {
             self.tcm.eject_value(),
             self.transition_path.eject_value(),
             self.transition_leaf.eject_value(),
-        )
+        ) {
+            Ok(state_path) => state_path,
+            Err(error) => A::halt(format!("Failed to eject the state path: {error}")),
+        }
     }
 }
 
@@ -203,4 +216,38 @@ mod tests {
     fn test_state_path_new_private() -> Result<()> {
         check_new(Mode::Private, 0, 1, 446, 376)
     }
+
+    #[test]
+    fn test_state_path_witness_matches_assignment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the console state path.
+            let console_state_path =
+                console::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng)?;
+            // Compute the witness.
+            let witness = console_state_path.to_witness();
+
+            // Inject the state path.
+            let _candidate = StatePath::<Circuit>::new(Mode::Private, console_state_path);
+
+            // Ensure the number of inputs matches the number of variables, skipping the constant `1` public variable.
+            assert_eq!(witness.public_inputs().len() as u64, Circuit::num_public() - 1);
+            assert_eq!(witness.private_inputs().len() as u64, Circuit::num_private());
+
+            // Eject the assignment.
+            let assignment = Circuit::eject_assignment_and_reset();
+
+            // Ensure the public inputs match, skipping the constant `1` at index 0.
+            let public_inputs = assignment.public_inputs().values().skip(1).copied().collect::<Vec<_>>();
+            let expected_public_inputs = witness.public_inputs().iter().map(|field| **field).collect::<Vec<_>>();
+            assert_eq!(expected_public_inputs, public_inputs);
+
+            // Ensure the private inputs match, in allocation order.
+            let private_inputs = assignment.private_inputs().values().copied().collect::<Vec<_>>();
+            let expected_private_inputs = witness.private_inputs().iter().map(|field| **field).collect::<Vec<_>>();
+            assert_eq!(expected_private_inputs, private_inputs);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The transaction state path proves existence of a transaction ID to a global state root.
+pub struct TransactionStatePath<A: Aleo> {
+    /// The global state root (Public).
+    global_state_root: Field<A>,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<A>,
+    /// The block hash.
+    block_hash: Field<A>,
+    /// The previous block hash.
+    previous_block_hash: Field<A>,
+    /// The block header root.
+    header_root: Field<A>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<A>,
+    /// The block header leaf.
+    header_leaf: HeaderLeaf<A>,
+    /// The Merkle path for the transaction ID.
+    transactions_path: TransactionsPath<A>,
+    /// The transaction ID.
+    transaction_id: Field<A>,
+}
+
+impl<A: Aleo> TransactionStatePath<A> {
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &Field<A> {
+        &self.transaction_id
+    }
+
+    /// Returns `true` if the transaction state path is valid.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///
+    ///  [[ global_state_root ]]
+    ///           |
+    ///      block_path
+    ///          |
+    ///     block_hash := Hash( previous_block_hash || header_root )
+    ///                                                     |
+    ///                                                header_path
+    ///                                                    |
+    ///                                               header_leaf
+    ///                                                   |
+    ///                                            transactions_path
+    ///                                                  |
+    ///                                           transaction_id
+    /// ```
+    pub fn verify(&self) -> Boolean<A> {
+        // Ensure the transactions path is valid.
+        let check_transactions_path = A::verify_merkle_path_bhp(
+            &self.transactions_path,
+            self.header_leaf.id(),
+            &self.transaction_id.to_bits_le(),
+        );
+
+        // Ensure the header path is valid.
+        let check_header_path =
+            A::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le())
+                & self.header_leaf.index().is_equal(&U8::one()); // Index = 1 (Header::transactions_root)
+
+        // Construct the block hash preimage.
+        let mut block_hash_preimage = self.previous_block_hash.to_bits_le();
+        self.header_root.write_bits_le(&mut block_hash_preimage);
+
+        // Ensure the block path is valid.
+        let check_block_hash = A::hash_bhp1024(&block_hash_preimage).is_equal(&self.block_hash);
+
+        // Ensure the global state root is correct.
+        let check_state_root =
+            A::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le());
+
+        // Check the transaction state path.
+        check_transactions_path & check_header_path & check_block_hash & check_state_root
+    }
+}
+
+impl<A: Aleo> Inject for TransactionStatePath<A> {
+    type Primitive = console::TransactionStatePath<A::Network>;
+
+    /// Initializes a new transaction state path circuit from a primitive.
+    fn new(mode: Mode, path: Self::Primitive) -> Self {
+        Self {
+            global_state_root: Field::new(Mode::Public, *path.global_state_root()),
+            block_path: BlockPath::new(mode, path.block_path().clone()),
+            block_hash: Field::new(mode, *path.block_hash()),
+            previous_block_hash: Field::new(mode, *path.previous_block_hash()),
+            header_root: Field::new(mode, *path.header_root()),
+            header_path: HeaderPath::new(mode, path.header_path().clone()),
+            header_leaf: HeaderLeaf::new(mode, *path.header_leaf()),
+            transactions_path: TransactionsPath::new(mode, path.transactions_path().clone()),
+            transaction_id: Field::new(mode, **path.transaction_id()),
+        }
+    }
+}
+
+impl<A: Aleo> Eject for TransactionStatePath<A> {
+    type Primitive = console::TransactionStatePath<A::Network>;
+
+    /// Ejects the mode of the transaction state path.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            self.global_state_root.eject_mode(),
+            [
+                self.block_path.eject_mode(),
+                self.block_hash.eject_mode(),
+                self.previous_block_hash.eject_mode(),
+                self.header_root.eject_mode(),
+                self.header_path.eject_mode(),
+                self.header_leaf.eject_mode(),
+                self.transactions_path.eject_mode(),
+                self.transaction_id.eject_mode(),
+            ],
+        )
+    }
+
+    /// Ejects the transaction state path.
+    fn eject_value(&self) -> Self::Primitive {
+        match Self::Primitive::from(
+            self.global_state_root.eject_value().into(),
+            self.block_path.eject_value(),
+            self.block_hash.eject_value().into(),
+            self.previous_block_hash.eject_value().into(),
+            self.header_root.eject_value(),
+            self.header_path.eject_value(),
+            self.header_leaf.eject_value(),
+            self.transactions_path.eject_value(),
+            self.transaction_id.eject_value().into(),
+        ) {
+            Ok(path) => path,
+            Err(error) => A::halt(format!("Failed to eject the transaction state path: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use snarkvm_utilities::TestRng;
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: usize = 20;
+
+    fn sample_console_path(rng: &mut TestRng) -> Result<console::TransactionStatePath<CurrentNetwork>> {
+        let state_path = console::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        Ok(state_path.to_transaction_state_path())
+    }
+
+    #[test]
+    fn test_transaction_state_path_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample the console transaction state path.
+            let console_path = sample_console_path(rng)?;
+
+            let candidate = TransactionStatePath::<Circuit>::new(mode, console_path.clone());
+            assert_eq!(console_path, candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_transaction_state_path_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                // Sample the console transaction state path.
+                let console_path = sample_console_path(rng)?;
+                // Ensure the console transaction state path is valid.
+                console_path.verify()?;
+
+                // Inject the transaction state path.
+                let circuit_path = TransactionStatePath::<Circuit>::new(mode, console_path);
+
+                // Ensure the transaction state path is valid.
+                assert!(circuit_path.verify().eject_value());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> StatePath<A> {
+    /// Returns `true` if every state path in the batch is valid.
+    ///
+    /// The block-level segment (header path, block hash, and block path) is checked once, on the first
+    /// state path, and every other global state path is verified against its header leaf and global state root.
+    /// As such, every global state path in the batch must be from the same block, and if the batch contains a
+    /// global state path, the first state path must be one of them. Local state paths are checked exactly as
+    /// in [`StatePath::verify`].
+    ///
+    /// # Parameters
+    ///  - `state_paths` is the batch of state paths, anchored on the first state path.
+    ///  - `is_global` contains, for each state path, whether it is for a global or local state root.
+    ///  - `local_state_roots` contains, for each state path, the local transaction root for the current execution.
+    pub fn verify_batch(state_paths: &[Self], is_global: &[Boolean<A>], local_state_roots: &[Field<A>]) -> Boolean<A> {
+        match state_paths.first() {
+            Some(anchor) => Self::verify_batch_with_anchor(anchor, state_paths, is_global, local_state_roots),
+            None => Boolean::constant(true),
+        }
+    }
+
+    /// Returns `true` if every state path in the batch is valid, with the block-level segment of the given anchor.
+    ///
+    /// This is [`StatePath::verify_batch`] with the anchor given separately from the batch, so the state paths
+    /// may be in any order. As such, every global state path in the batch must be from the block of the anchor.
+    /// If the batch has no global state path, the anchor is unused, and may be any state path.
+    ///
+    /// # Parameters
+    ///  - `anchor` is the state path with the block-level segment for the global state paths.
+    ///  - `state_paths` is the batch of state paths.
+    ///  - `is_global` contains, for each state path, whether it is for a global or local state root.
+    ///  - `local_state_roots` contains, for each state path, the local transaction root for the current execution.
+    pub fn verify_batch_with_anchor(
+        anchor: &Self,
+        state_paths: &[Self],
+        is_global: &[Boolean<A>],
+        local_state_roots: &[Field<A>],
+    ) -> Boolean<A> {
+        // Ensure the number of state paths matches the number of flags and local state roots.
+        if state_paths.len() != is_global.len() || state_paths.len() != local_state_roots.len() {
+            A::halt("The number of state paths must match the number of flags and local state roots")
+        }
+
+        // Ensure the header path of the anchor is valid.
+        let check_header_path =
+            A::verify_merkle_path_bhp(&anchor.header_path, &anchor.header_root, &anchor.header_leaf.to_bits_le())
+                & anchor.header_leaf.index().is_equal(&U8::one()); // Index = 1 (Header::transactions_root)
+
+        // Construct the block hash preimage of the anchor.
+        let mut block_hash_preimage = anchor.previous_block_hash.to_bits_le();
+        anchor.header_root.write_bits_le(&mut block_hash_preimage);
+
+        // Ensure the block hash of the anchor is valid.
+        let check_block_hash = A::hash_bhp1024(&block_hash_preimage).is_equal(&anchor.block_hash);
+
+        // Ensure the global state root of the anchor is correct.
+        let check_state_root =
+            A::verify_merkle_path_bhp(&anchor.block_path, &anchor.global_state_root, &anchor.block_hash.to_bits_le());
+
+        // Combine the block-level checks, which are shared by every global state path in the batch.
+        let check_block = check_header_path & check_block_hash & check_state_root;
+
+        state_paths.iter().zip_eq(is_global).zip_eq(local_state_roots).fold(
+            Boolean::constant(true),
+            |is_valid, ((state_path, is_global), local_state_root)| {
+                // Ensure the transition and transaction paths are valid.
+                let check_transition_and_transaction_path = state_path.check_transition_and_transaction_path();
+
+                // Ensure the transactions path is valid against the header leaf of the anchor.
+                let check_transactions_path = A::verify_merkle_path_bhp(
+                    &state_path.transactions_path,
+                    anchor.header_leaf.id(),
+                    &state_path.transaction_id.to_bits_le(),
+                );
+
+                // Ensure the global state root matches the anchor.
+                let check_global_state_root = state_path.global_state_root.is_equal(&anchor.global_state_root);
+
+                // Check the state path.
+                let check_local =
+                    &check_transition_and_transaction_path & local_state_root.is_equal(&state_path.transaction_id);
+                let check_global = check_transition_and_transaction_path
+                    & check_transactions_path
+                    & check_global_state_root
+                    & &check_block;
+
+                // If the state path is for a global root, use 'check_global'. Else, use 'check_local'.
+                is_valid & Boolean::ternary(is_global, &check_global, &check_local)
+            },
+        )
+    }
+
+    /// Returns `true` if the transition path, transaction leaf, and transaction path are valid.
+    fn check_transition_and_transaction_path(&self) -> Boolean<A> {
+        // Ensure the transition path is valid.
+        let check_transition_path =
+            A::verify_merkle_path_bhp(&self.transition_path, &self.transition_root, &self.transition_leaf.to_bits_le())
+                & self.transition_leaf.variant().is_equal(&U8::constant(console::U8::new(3))); // Variant = 3 (Input::Record)
+
+        // Ensure the transaction leaf is valid.
+        let check_transaction_leaf =
+            A::hash_bhp512(&(&self.transition_root, &self.tcm).to_bits_le()).is_equal(self.transaction_leaf.id());
+
+        // Ensure the transaction path is valid.
+        let check_transaction_path = A::verify_merkle_path_bhp(
+            &self.transaction_path,
+            &self.transaction_id,
+            &self.transaction_leaf.to_bits_le(),
+        ) & self.transaction_leaf.variant().is_equal(&U8::one()); // Variant = 1 (Transaction::Execution)
+
+        check_transition_path & check_transaction_path & check_transaction_leaf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: usize = 5;
+
+    /// Injects the given console state paths, flags, and local state roots, and returns the batch result.
+    fn verify_batch(
+        mode: Mode,
+        state_paths: &[console::StatePath<CurrentNetwork>],
+        is_global: &[bool],
+        local_state_roots: &[console::Field<CurrentNetwork>],
+    ) -> bool {
+        let state_paths = state_paths.iter().map(|path| StatePath::<Circuit>::new(mode, path.clone())).collect_vec();
+        let is_global = is_global.iter().map(|flag| Boolean::new(mode, *flag)).collect_vec();
+        let local_state_roots = local_state_roots.iter().map(|root| Field::new(mode, *root)).collect_vec();
+        let is_valid = StatePath::verify_batch(&state_paths, &is_global, &local_state_roots).eject_value();
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        is_valid
+    }
+
+    #[test]
+    fn test_verify_batch_global() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_paths in 1..=ITERATIONS {
+                // Sample state paths from the same block.
+                let state_paths =
+                    console::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(
+                        num_paths, rng,
+                    )
+                    .unwrap();
+                let local_state_roots = (0..num_paths).map(|_| console::Field::rand(rng)).collect_vec();
+
+                // Ensure the batch is valid as global state paths.
+                assert!(verify_batch(mode, &state_paths, &vec![true; num_paths], &local_state_roots));
+                // Ensure the batch is invalid as local state paths.
+                assert!(!verify_batch(mode, &state_paths, &vec![false; num_paths], &local_state_roots));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_batch_mixed() {
+        let rng = &mut TestRng::default();
+
+        // Sample global state paths from the same block, followed by a local state path.
+        let mut state_paths =
+            console::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(2, rng).unwrap();
+        state_paths.push(console::state_path::test_helpers::sample_local_state_path(None, rng).unwrap());
+        let local_state_roots =
+            vec![console::Field::rand(rng), console::Field::rand(rng), **state_paths[2].transaction_id()];
+
+        // Ensure the batch is valid.
+        assert!(verify_batch(Mode::Private, &state_paths, &[true, true, false], &local_state_roots));
+        // Ensure the batch is invalid if the local state path is treated as global.
+        assert!(!verify_batch(Mode::Private, &state_paths, &[true, true, true], &local_state_roots));
+    }
+
+    #[test]
+    fn test_verify_batch_different_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Sample global state paths from different blocks.
+        let state_paths = vec![
+            console::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap(),
+            console::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap(),
+        ];
+        let local_state_roots = vec![console::Field::rand(rng), console::Field::rand(rng)];
+
+        // Ensure each state path is valid on its own.
+        for (state_path, local_state_root) in state_paths.iter().zip_eq(&local_state_roots) {
+            assert!(verify_batch(Mode::Private, &[state_path.clone()], &[true], &[*local_state_root]));
+        }
+        // Ensure the batch is invalid, as the state paths do not share a block.
+        assert!(!verify_batch(Mode::Private, &state_paths, &[true, true], &local_state_roots));
+    }
+
+    #[test]
+    fn test_verify_batch_with_anchor() {
+        let rng = &mut TestRng::default();
+
+        // Sample a local state path, followed by global state paths from the same block.
+        let global_state_paths =
+            console::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(2, rng).unwrap();
+        let mut state_paths = vec![console::state_path::test_helpers::sample_local_state_path(None, rng).unwrap()];
+        state_paths.extend(global_state_paths.iter().cloned());
+        let local_state_roots =
+            vec![**state_paths[0].transaction_id(), console::Field::rand(rng), console::Field::rand(rng)];
+        let is_global = [false, true, true];
+
+        // Injects the state paths and anchor, and returns the batch result.
+        let verify_batch_with_anchor = |anchor: &console::StatePath<CurrentNetwork>| {
+            let anchor = StatePath::<Circuit>::new(Mode::Private, anchor.clone());
+            let state_paths =
+                state_paths.iter().map(|path| StatePath::<Circuit>::new(Mode::Private, path.clone())).collect_vec();
+            let is_global = is_global.iter().map(|flag| Boolean::new(Mode::Private, *flag)).collect_vec();
+            let local_state_roots = local_state_roots.iter().map(|root| Field::new(Mode::Private, *root)).collect_vec();
+            let is_valid = StatePath::verify_batch_with_anchor(&anchor, &state_paths, &is_global, &local_state_roots)
+                .eject_value();
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+            is_valid
+        };
+
+        // Ensure the batch is invalid when anchored on the local state path.
+        assert!(!verify_batch(Mode::Private, &state_paths, &is_global, &local_state_roots));
+        assert!(!verify_batch_with_anchor(&state_paths[0]));
+        // Ensure the batch is valid when anchored on a global state path.
+        assert!(verify_batch_with_anchor(&global_state_paths[0]));
+        assert!(verify_batch_with_anchor(&global_state_paths[1]));
+    }
+
+    #[test]
+    fn test_verify_batch_empty() {
+        assert!(verify_batch(Mode::Private, &[], &[], &[]));
+    }
+}
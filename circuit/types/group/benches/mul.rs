@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_circuit_environment::*;
+use snarkvm_circuit_types_group::Group;
+use snarkvm_circuit_types_scalar::Scalar;
+
+use console::{TestRng, Uniform};
+use criterion::Criterion;
+
+fn bench_mul(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+
+    let cases = [(Mode::Constant, Mode::Private), (Mode::Private, Mode::Constant), (Mode::Private, Mode::Private)];
+
+    for (base_mode, scalar_mode) in cases {
+        let base = Group::<Circuit>::new(base_mode, Uniform::rand(&mut rng));
+        let scalar = Scalar::<Circuit>::new(scalar_mode, Uniform::rand(&mut rng)).to_bits_be();
+
+        // Print the number of constraints for double-and-add and for windows.
+        let double_and_add = Circuit::scope("double-and-add", || {
+            let _product = &base * scalar.as_slice();
+            Circuit::num_constraints_in_scope()
+        });
+        let windowed = Circuit::scope("windowed", || {
+            let _product = base.mul_windowed(&scalar);
+            Circuit::num_constraints_in_scope()
+        });
+        println!(
+            "Group::mul ({base_mode} base, {scalar_mode} scalar): {double_and_add} constraints with double-and-add, \
+             {windowed} constraints with windows ({} fewer)",
+            double_and_add.saturating_sub(windowed)
+        );
+
+        c.bench_function(&format!("Group::mul ({base_mode} base, {scalar_mode} scalar)"), |b| {
+            b.iter(|| {
+                let _product = &base * scalar.as_slice();
+            })
+        });
+
+        c.bench_function(&format!("Group::mul_windowed ({base_mode} base, {scalar_mode} scalar)"), |b| {
+            b.iter(|| {
+                let _product = base.mul_windowed(&scalar);
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = mul;
+    config = Criterion::default().sample_size(10);
+    targets = bench_mul
+}
+
+criterion_main!(mul);
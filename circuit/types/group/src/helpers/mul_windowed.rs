@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Returns the product of the group element and the given big-endian bits, using windows of 2 bits.
+    ///
+    /// If the group element is a constant, each window selects a constant multiple of the base with a
+    /// linear combination, so the product is the sum of the selected multiples, without any doublings.
+    /// Otherwise, the multiples `[0, P, 2P, 3P]` are computed once, and each window doubles the output twice
+    /// before adding the selected multiple, instead of adding and selecting after every doubling.
+    ///
+    /// This produces the same product as `self * bits_be`, with fewer constraints.
+    pub fn mul_windowed(&self, bits_be: &[Boolean<E>]) -> Group<E> {
+        // Split the bits into `(low, high)` windows, from the least significant window.
+        // If there is an odd number of bits, the most significant window is padded with a zero bit.
+        let zero_bit = Boolean::constant(false);
+        let windows = bits_be.rchunks(2).map(|window| match window {
+            [high, low] => (low, high),
+            [low] => (low, &zero_bit),
+            _ => unreachable!("The windows are 2 bits"),
+        });
+
+        match self.is_constant() {
+            // Compute the sum of the multiples `(2 * high + low) * 4^i * P`, for each window `i`.
+            true => {
+                let mut base = self.clone();
+                let mut output = Group::zero();
+                for (low, high) in windows {
+                    let double = base.double();
+                    let table = [Group::zero(), base.clone(), double.clone(), &double + &base];
+                    output += Self::lookup(&table, low, high);
+                    base = double.double();
+                }
+                output
+            }
+            // Compute the output with a double-and-add over the windows, from the most significant window.
+            false => {
+                let double = self.double();
+                let table = [Group::zero(), self.clone(), double.clone(), &double + self];
+                let mut output = Group::zero();
+                for (low, high) in windows.rev() {
+                    output = output.double().double();
+                    output += Self::lookup(&table, low, high);
+                }
+                output
+            }
+        }
+    }
+
+    /// Returns the entry of the table at index `2 * high + low`.
+    fn lookup(table: &[Group<E>; 4], low: &Boolean<E>, high: &Boolean<E>) -> Group<E> {
+        match table.iter().all(|entry| entry.is_constant()) {
+            // If the table is constant, select the entry with a linear combination of the bits,
+            // which only requires a constraint for the product of the bits.
+            true => {
+                let (low, high, both) =
+                    (Field::from_boolean(low), Field::from_boolean(high), Field::from_boolean(&(low & high)));
+                let select = |t0: &Field<E>, t1: &Field<E>, t2: &Field<E>, t3: &Field<E>| {
+                    t0 + &low * (t1 - t0) + &high * (t2 - t0) + &both * (t3 - t2 - t1 + t0)
+                };
+                Group {
+                    x: select(&table[0].x, &table[1].x, &table[2].x, &table[3].x),
+                    y: select(&table[0].y, &table[1].y, &table[2].y, &table[3].y),
+                }
+            }
+            // Otherwise, select the entry with ternaries.
+            false => {
+                let first = Group::ternary(low, &table[1], &table[0]);
+                let second = Group::ternary(low, &table[3], &table[2]);
+                Group::ternary(high, &second, &first)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_mul_windowed(base_mode: Mode, scalar_mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let base = Uniform::rand(&mut rng);
+            let scalar = Uniform::rand(&mut rng);
+
+            let expected = base * scalar;
+            let a = Group::<Circuit>::new(base_mode, base);
+            let b = Scalar::<Circuit>::new(scalar_mode, scalar).to_bits_be();
+
+            // Compute the product with double-and-add.
+            let num_constraints = Circuit::scope(format!("Mul {base_mode} {scalar_mode} {i}"), || {
+                let candidate = &a * b.as_slice();
+                assert_eq!(expected, candidate.eject_value());
+                Circuit::num_constraints_in_scope()
+            });
+
+            // Compute the product with windows, and ensure it requires fewer constraints.
+            Circuit::scope(format!("MulWindowed {base_mode} {scalar_mode} {i}"), || {
+                let candidate = a.mul_windowed(&b);
+                assert_eq!(expected, candidate.eject_value());
+                match base_mode.is_constant() && scalar_mode.is_constant() {
+                    true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                    false => assert!(Circuit::num_constraints_in_scope() < num_constraints),
+                }
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_mul_windowed_constant_times_scalar_constant() {
+        check_mul_windowed(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_mul_windowed_constant_times_scalar_private() {
+        check_mul_windowed(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_mul_windowed_public_times_scalar_constant() {
+        check_mul_windowed(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_mul_windowed_private_times_scalar_constant() {
+        check_mul_windowed(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_mul_windowed_public_times_scalar_private() {
+        check_mul_windowed(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_mul_windowed_private_times_scalar_private() {
+        check_mul_windowed(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    fn test_mul_windowed_odd_number_of_bits() {
+        let mut rng = TestRng::default();
+
+        for num_bits in [1, 2, 3, 5, 8] {
+            let base: console::Group<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let a = Group::<Circuit>::new(Mode::Private, base);
+            let b = (0..num_bits).map(|_| Boolean::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+
+            let expected = (&a * b.as_slice()).eject_value();
+            assert_eq!(expected, a.mul_windowed(&b).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -165,6 +165,11 @@ impl<E: Environment, const N: usize> MulAssign<[Boolean<E>; N]> for Group<E> {
 }
 
 impl<E: Environment> MulAssign<&[Boolean<E>]> for Group<E> {
+    /// Multiplies the group element by the given big-endian bits, with double-and-add.
+    ///
+    /// Note: This is intentionally not `Group::mul_windowed`, which requires fewer constraints,
+    /// as this gadget is in the signature verification of every request, so its constraints are
+    /// fixed by the verifying keys of `credits.aleo`. Changing it requires regenerating those keys.
     #[allow(clippy::suspicious_op_assign_impl)]
     fn mul_assign(&mut self, other: &[Boolean<E>]) {
         let base = self.clone();
@@ -21,6 +21,7 @@ mod helpers;
 pub mod abs_checked;
 pub mod abs_wrapped;
 pub mod add_checked;
+pub mod add_saturating;
 pub mod add_wrapped;
 pub mod and;
 pub mod compare;
@@ -29,6 +30,7 @@ pub mod div_wrapped;
 pub mod equal;
 pub mod modulo;
 pub mod mul_checked;
+pub mod mul_saturating;
 pub mod mul_wrapped;
 pub mod neg;
 pub mod not;
@@ -37,11 +39,14 @@ pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
 pub mod rem_wrapped;
+pub mod rotate_left;
+pub mod rotate_right;
 pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
 pub mod shr_wrapped;
 pub mod sub_checked;
+pub mod sub_saturating;
 pub mod sub_wrapped;
 pub mod ternary;
 pub mod xor;
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MulSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn mul_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the product and return the new constant.
+            Integer::constant(self.eject_value().mul_saturating(&other.eject_value()))
+        } else if I::is_signed() {
+            // Compute the product of `abs(self)` and `abs(other)`, along with an overflow bit.
+            // Note: it is safe to use `abs_wrapped` as we want `Integer::MIN` to be interpreted as an unsigned number.
+            let (product, is_unsigned_overflow) = Self::mul_with_overflow(&self.abs_wrapped(), &other.abs_wrapped());
+
+            // If the product should be positive, then it overflows if it exceeds the signed maximum.
+            // If the product should be negative, then it overflows if it exceeds the absolute value of the minimum.
+            let operands_same_sign = &self.msb().is_equal(other.msb());
+            let lower_product_bits_nonzero =
+                product.bits_le[..(I::BITS as usize - 1)].iter().fold(Boolean::constant(false), |a, b| a | b);
+            let negative_product_overflows = product.msb() & lower_product_bits_nonzero;
+            let is_overflow =
+                is_unsigned_overflow | Boolean::ternary(operands_same_sign, product.msb(), &negative_product_overflows);
+
+            // Return the product of `self` and `other` with the appropriate sign, saturated if an overflow occurs.
+            let product = Self::ternary(operands_same_sign, &product, &Self::zero().sub_wrapped(&product));
+            Self::saturate(&is_overflow, &!operands_same_sign, &product)
+        } else {
+            // Compute the product of `self` and `other`, saturated if an overflow occurs.
+            let (product, is_overflow) = Self::mul_with_overflow(self, other);
+            Self::saturate(&is_overflow, &Boolean::constant(false), &product)
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Multiply the integer bits of `this` and `that`, returning the wrapped product and whether an overflow occurred.
+    /// This function assumes that `this` and `that` are non-negative.
+    #[inline]
+    fn mul_with_overflow(this: &Integer<E, I>, that: &Integer<E, I>) -> (Integer<E, I>, Boolean<E>) {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Compute the full product in the base field, and extract its lower and upper bits.
+            // Note: The multiplication is safe as the field twice as large as the maximum integer type supported.
+            let bits_le = (this.to_field() * that.to_field()).to_lower_bits_le(2 * I::BITS as usize);
+            let (bits_le, upper_bits_le) = bits_le.split_at(I::BITS as usize);
+
+            // The product overflows if any of the upper bits are set.
+            let is_overflow = upper_bits_le.iter().fold(Boolean::constant(false), |a, b| a | b);
+            (Integer::from_bits_le(bits_le), is_overflow)
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            // Use Karatsuba multiplication to compute the product of `self` and `other`.
+            let (product, z_1_upper_bits, z2) = Self::karatsuba_multiply(this, that);
+
+            // The product overflows if any of the upper bits of `z1` are set, or if `z2` is nonzero.
+            let is_overflow = z_1_upper_bits.iter().fold(!z2.is_zero(), |a, b| a | b);
+            (product, is_overflow)
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MulSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
+        if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (_, _) => Count::less_than(4 * I::BITS, 0, (13 * I::BITS) + 8, (13 * I::BITS) + 16),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (_, _) => Count::less_than(0, 0, 4 * I::BITS, (4 * I::BITS) + 1),
+                },
+            }
+        }
+        // Case 2 - 1.5 integers fit in 1 field element (u128, i128).
+        else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
+            match I::is_signed() {
+                // Signed case
+                true => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (_, _) => Count::less_than(896, 0, 1408, 1424),
+                },
+                // Unsigned case
+                false => match (case.0, case.1) {
+                    (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                    (_, _) => Count::less_than(256, 0, 4 * I::BITS, (4 * I::BITS) + 8),
+                },
+            }
+        } else {
+            E::halt(format!("Multiplication of integers of size {} is not supported", I::BITS))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MulSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_mul_saturating<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = first.mul_saturating(&second);
+        Circuit::scope(name, || {
+            let candidate = a.mul_saturating(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(MulSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("MulSaturating: {mode_a} * {mode_b} {i}");
+            check_mul_saturating::<I>(&name, first, second, mode_a, mode_b);
+            check_mul_saturating::<I>(&name, second, first, mode_a, mode_b); // Commute the operation.
+
+            let name = format!("Double: {mode_a} * {mode_b} {i}");
+            let two = console::Integer::one() + console::Integer::one();
+            check_mul_saturating::<I>(&name, first, two, mode_a, mode_b);
+        }
+
+        // Check specific cases common to signed and unsigned integers.
+        check_mul_saturating::<I>("1 * MAX", console::Integer::one(), console::Integer::MAX, mode_a, mode_b);
+        check_mul_saturating::<I>("0 * MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+        check_mul_saturating::<I>("MAX * MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+
+        // Check additional corner cases for signed integers.
+        if I::is_signed() {
+            check_mul_saturating::<I>("MIN * -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_mul_saturating::<I>("MIN * 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+            check_mul_saturating::<I>("MIN * MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+            check_mul_saturating::<I>("MIN * MIN", console::Integer::MIN, console::Integer::MIN, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("MulSaturating: ({first} * {second})");
+                check_mul_saturating::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, times_saturating);
+    test_integer_binary!(run_test, i16, times_saturating);
+    test_integer_binary!(run_test, i32, times_saturating);
+    test_integer_binary!(run_test, i64, times_saturating);
+    test_integer_binary!(run_test, i128, times_saturating);
+
+    test_integer_binary!(run_test, u8, times_saturating);
+    test_integer_binary!(run_test, u16, times_saturating);
+    test_integer_binary!(run_test, u32, times_saturating);
+    test_integer_binary!(run_test, u64, times_saturating);
+    test_integer_binary!(run_test, u128, times_saturating);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, times_saturating, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, times_saturating, exhaustive);
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn rotate_left(&self, rhs: &Integer<E, M>) -> Self::Output {
+        // Retrieve the number of bits in `self`.
+        let num_bits = I::BITS as usize;
+        // Retrieve the index for the first upper bit from the RHS that we mask.
+        // By masking the upper bits, we rotate by `rhs mod I::BITS`, as `I::BITS` is a power of two.
+        let first_upper_bit_index = I::BITS.trailing_zeros() as usize;
+
+        // Perform the rotation as a barrel shifter, which rotates the bits by `2^i` if the `i`-th bit of `rhs` is set.
+        // Note: If `rhs` is a constant, each rotation is a permutation of the bits of `self`, and costs nothing.
+        let mut bits_le = self.bits_le.clone();
+        for (i, bit) in rhs.bits_le[..first_upper_bit_index].iter().enumerate() {
+            let shift = 1 << i;
+            bits_le = (0..num_bits)
+                .map(|j| Boolean::ternary(bit, &bits_le[(j + num_bits - shift) % num_bits], &bits_le[j]))
+                .collect();
+        }
+        Self { bits_le, phantom: Default::default() }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> Metrics<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Retrieve the number of rotations in the barrel shifter.
+        let num_rotations = I::BITS.trailing_zeros() as u64;
+
+        match (case.0, case.1) {
+            (_, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (Mode::Constant, _) => Count::less_than(0, 0, num_rotations * I::BITS, num_rotations * I::BITS),
+            (_, _) => Count::is(0, 0, num_rotations * I::BITS, num_rotations * I::BITS),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> OutputMode<dyn RotateLeft<Integer<E, M>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode, bool);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1, case.2) {
+            (mode_a, Mode::Constant, _) => mode_a,
+            // If the bits of `self` are all equal, then every rotation of `self` is a constant.
+            (Mode::Constant, _, true) => Mode::Constant,
+            (_, _, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rol<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, M>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let expected = I::rotate_left(*first, (second.to_u64().unwrap() % I::BITS) as u32);
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, M>::new(mode_b, second);
+        let a_is_uniform = *first == I::zero() || *first == I::MAX;
+        Circuit::scope(name, || {
+            let candidate = a.rotate_left(&b);
+            assert_eq!(expected, *candidate.eject_value());
+            assert_eq!(first.rotate_left(&second), candidate.eject_value());
+            assert_count!(RotateLeft(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(RotateLeft(Integer<I>, Integer<M>) => Integer<I>, &(mode_a, mode_b, a_is_uniform), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Rol: {mode_a} <<< {mode_b} {i}");
+            check_rol::<I, M>(&name, first, second, mode_a, mode_b);
+
+            // Check that rotating by one is computed correctly.
+            let name = format!("Rol by one: {mode_a} <<< {mode_b} {i}");
+            check_rol::<I, M>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            // Check that rotating by the number of bits is the identity.
+            let name = format!("Rol by bits: {mode_a} <<< {mode_b} {i}");
+            let bits = console::Integer::new(M::from(I::BITS).unwrap());
+            check_rol::<I, M>(&name, first, bits, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe, M: Magnitude + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+        RangeInclusive<M>: Iterator<Item = M>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in M::MIN..=M::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, M>::new(second);
+
+                let name = format!("Rol: ({first} <<< {second})");
+                check_rol::<I, M>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, u8, u8, rol);
+    test_integer_binary!(run_test, u8, u16, rol);
+    test_integer_binary!(run_test, u8, u32, rol);
+
+    test_integer_binary!(run_test, u16, u8, rol);
+    test_integer_binary!(run_test, u16, u16, rol);
+    test_integer_binary!(run_test, u16, u32, rol);
+
+    test_integer_binary!(run_test, u32, u8, rol);
+    test_integer_binary!(run_test, u32, u16, rol);
+    test_integer_binary!(run_test, u32, u32, rol);
+
+    test_integer_binary!(run_test, u64, u8, rol);
+    test_integer_binary!(run_test, u64, u16, rol);
+    test_integer_binary!(run_test, u64, u32, rol);
+
+    test_integer_binary!(run_test, u128, u8, rol);
+    test_integer_binary!(run_test, u128, u16, rol);
+    test_integer_binary!(run_test, u128, u32, rol);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, u8, rol, exhaustive);
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> SubSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn sub_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the difference and return the new constant.
+            Integer::constant(self.eject_value().sub_saturating(&other.eject_value()))
+        } else {
+            // Instead of subtracting the bits of `self` and `other` directly, the integers are
+            // converted into a field elements, and subtracted, before converting back to integers.
+            // Note: This is safe as the field is larger than the maximum integer type supported.
+            let difference = self.to_field() + (!other).to_field() + Field::one();
+
+            // Extract the integer bits from the field element, with a carry bit.
+            let (difference, carry) = match difference.to_lower_bits_le(I::BITS as usize + 1).split_last() {
+                Some((carry, bits_le)) => (Integer::from_bits_le(bits_le), carry.clone()),
+                // Note: `E::halt` should never be invoked as `I::BITS as usize + 1` is greater than zero.
+                None => E::halt("Malformed difference detected during integer subtraction"),
+            };
+
+            match I::is_signed() {
+                // For signed subtraction, an overflow occurs if `a` and `b` have different signs,
+                // and `a - b` has the sign of `b`.
+                // The difference saturates towards the sign of `a`.
+                true => {
+                    let is_different_signs = self.msb().is_not_equal(other.msb());
+                    let is_overflow = is_different_signs & difference.msb().is_equal(other.msb());
+                    Self::saturate(&is_overflow, self.msb(), &difference)
+                }
+                // For unsigned subtraction, an underflow occurs if the carry bit is not set.
+                false => Self::saturate(&!carry, &Boolean::constant(true), &difference),
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn SubSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match I::is_signed() {
+            true => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::less_than(0, 0, (2 * I::BITS) + 4, (2 * I::BITS) + 5),
+            },
+            false => match (case.0, case.1) {
+                (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+                (_, _) => Count::is(0, 0, (2 * I::BITS) + 1, (2 * I::BITS) + 2),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn SubSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_sub_saturating<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        let expected = first.sub_saturating(&second);
+        Circuit::scope(name, || {
+            let candidate = a.sub_saturating(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(SubSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(SubSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("SubSaturating: {mode_a} - {mode_b} {i}");
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_sub_saturating::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Overflow
+        if I::is_signed() {
+            check_sub_saturating::<I>("MAX - (-1)", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_sub_saturating::<I>("0 - MIN", console::Integer::zero(), console::Integer::MIN, mode_a, mode_b);
+        }
+        // Underflow
+        check_sub_saturating::<I>("MIN - 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_sub_saturating::<I>("MIN - MAX", console::Integer::MIN, console::Integer::MAX, mode_a, mode_b);
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("SubSaturating: ({first} - {second})");
+                check_sub_saturating::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, minus_saturating);
+    test_integer_binary!(run_test, i16, minus_saturating);
+    test_integer_binary!(run_test, i32, minus_saturating);
+    test_integer_binary!(run_test, i64, minus_saturating);
+    test_integer_binary!(run_test, i128, minus_saturating);
+
+    test_integer_binary!(run_test, u8, minus_saturating);
+    test_integer_binary!(run_test, u16, minus_saturating);
+    test_integer_binary!(run_test, u32, minus_saturating);
+    test_integer_binary!(run_test, u64, minus_saturating);
+    test_integer_binary!(run_test, u128, minus_saturating);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, minus_saturating, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, minus_saturating, exhaustive);
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to allocate a string of size {num_bytes}"))
+        }
+
+        Self {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(other.bytes.iter()).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two random strings. Take 1/8th to ensure we fit for all code points.
+            let first = rng.next_string(Circuit::MAX_STRING_BYTES / 8, true);
+            let second = rng.next_string(Circuit::MAX_STRING_BYTES / 8, true);
+            let expected = console::StringType::<<Circuit as Environment>::Network>::new(&format!("{first}{second}"));
+
+            let a = StringType::<Circuit>::new(mode_a, console::StringType::new(&first));
+            let b = StringType::<Circuit>::new(mode_b, console::StringType::new(&second));
+
+            Circuit::scope(format!("{mode_a} {mode_b} {i}"), || {
+                let candidate = a.concat(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(candidate.is_equal(&StringType::constant(expected.clone())).eject_value());
+            });
+            Circuit::scope(format!("{mode_a} {mode_b} {i} cost"), || {
+                let _candidate = a.concat(&b);
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_concat_constant_constant() {
+        check_concat(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_concat_constant_private() {
+        check_concat(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_concat_public_private() {
+        check_concat(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_concat_private_private() {
+        check_concat(Mode::Private, Mode::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_exceeds_capacity() {
+        let half = "a".repeat(Circuit::MAX_STRING_BYTES as usize / 2 + 1);
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new(&half));
+        let _candidate = string.concat(&string);
+    }
+}
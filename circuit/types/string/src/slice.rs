@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `length` bytes, starting at the byte index `start`.
+    ///
+    /// Note: The number of bytes in a string is fixed by the shape of the circuit,
+    /// so the range is taken from the values of `start` and `length`, which are then enforced.
+    pub fn slice(&self, start: &U32<E>, length: &U32<E>) -> Self {
+        // Retrieve the range.
+        let (start_value, length_value) = (*start.eject_value(), *length.eject_value());
+        let end_value = start_value as usize + length_value as usize;
+        // Ensure the range is within the string.
+        let num_bytes = self.bytes.len();
+        if end_value > num_bytes {
+            E::halt(format!("Slice [{start_value}..{end_value}] is out of bounds for a string of {num_bytes} bytes"))
+        }
+
+        // Retrieve the bytes of the substring.
+        let bytes = self.bytes[start_value as usize..end_value].to_vec();
+        // Ensure the substring lies on character boundaries.
+        if let Err(error) = String::from_utf8(bytes.eject_value().into_iter().map(|byte| *byte).collect()) {
+            E::halt(format!("Slice [{start_value}..{end_value}] does not lie on character boundaries: {error}"))
+        }
+
+        // Ensure `start` and `length` match the range.
+        E::assert_eq(start.to_field(), Field::constant(console::Field::from_u32(start_value)));
+        E::assert_eq(length.to_field(), Field::constant(console::Field::from_u32(length_value)));
+
+        Self {
+            mode: Mode::combine(self.mode, [start.eject_mode(), length.eject_mode()]),
+            bytes,
+            size_in_bytes: length.to_field(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use rand::Rng;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_slice(
+        mode: Mode,
+        range_mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random string. Take 1/4th to ensure we fit for all code points.
+            let given = rng.next_string(Circuit::MAX_STRING_BYTES / 4, true);
+            let string = StringType::<Circuit>::new(mode, console::StringType::new(&given));
+
+            // Sample a random range on character boundaries.
+            let boundaries = given.char_indices().map(|(index, _)| index).chain([given.len()]).collect::<Vec<_>>();
+            let start_value = boundaries[rng.gen_range(0..boundaries.len())];
+            let end_value = boundaries[rng.gen_range(0..boundaries.len())].max(start_value);
+            let start = U32::new(range_mode, console::Integer::new(start_value as u32));
+            let length = U32::new(range_mode, console::Integer::new((end_value - start_value) as u32));
+
+            Circuit::scope(format!("{mode} {range_mode} {i}"), || {
+                let candidate = string.slice(&start, &length);
+                assert_eq!(&given[start_value..end_value], &*candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::scope(format!("{mode} {range_mode} {i} length"), || {
+                let candidate = string.slice(&start, &length);
+                assert_eq!((end_value - start_value) as u32, *candidate.length().eject_value());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_slice_constant() {
+        check_slice(Mode::Constant, Mode::Constant, 2, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_slice_private_constant() {
+        check_slice(Mode::Private, Mode::Constant, 2, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_slice_public_public() {
+        check_slice(Mode::Public, Mode::Public, 2, 0, 0, 2);
+    }
+
+    #[test]
+    fn test_slice_private_private() {
+        check_slice(Mode::Private, Mode::Private, 2, 0, 0, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("hello"));
+        let start = U32::new(Mode::Private, console::Integer::new(3));
+        let length = U32::new(Mode::Private, console::Integer::new(3));
+        let _candidate = string.slice(&start, &length);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_not_on_character_boundary() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("héllo"));
+        let start = U32::new(Mode::Private, console::Integer::new(0));
+        let length = U32::new(Mode::Private, console::Integer::new(2));
+        let _candidate = string.slice(&start, &length);
+    }
+}
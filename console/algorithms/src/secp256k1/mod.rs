@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use num_bigint::BigUint;
+
+/// An affine point on secp256k1, where `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// The secp256k1 elliptic curve `y^2 = x^3 + 7`, which is used for ECDSA signatures in Bitcoin and Ethereum.
+///
+/// The base field and scalar field of secp256k1 are not native to the proof system,
+/// so their arithmetic is performed on big integers. Note: This implementation is not constant-time,
+/// and must only be used to verify signatures.
+pub struct Secp256k1;
+
+impl Secp256k1 {
+    /// The modulus of the base field, `p = 2^256 - 2^32 - 977`, in big-endian.
+    pub const BASE_FIELD_MODULUS: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+    ];
+    /// The x-coordinate of the generator, in big-endian.
+    pub const GENERATOR_X: [u8; 32] = [
+        0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b, 0x07, 0x02, 0x9b,
+        0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17, 0x98,
+    ];
+    /// The y-coordinate of the generator, in big-endian.
+    pub const GENERATOR_Y: [u8; 32] = [
+        0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e, 0x11, 0x08, 0xa8, 0xfd, 0x17,
+        0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f, 0xfb, 0x10, 0xd4, 0xb8,
+    ];
+    /// The modulus of the scalar field, i.e. the order of the generator, in big-endian.
+    pub const SCALAR_FIELD_MODULUS: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xba, 0xae,
+        0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+    ];
+}
+
+impl Secp256k1 {
+    /// Returns the modulus of the base field.
+    fn base_field_modulus() -> BigUint {
+        BigUint::from_bytes_be(&Self::BASE_FIELD_MODULUS)
+    }
+
+    /// Returns the modulus of the scalar field.
+    fn scalar_field_modulus() -> BigUint {
+        BigUint::from_bytes_be(&Self::SCALAR_FIELD_MODULUS)
+    }
+
+    /// Returns the generator.
+    fn generator() -> Point {
+        Some((BigUint::from_bytes_be(&Self::GENERATOR_X), BigUint::from_bytes_be(&Self::GENERATOR_Y)))
+    }
+
+    /// Returns the multiplicative inverse of `value` modulo the prime `modulus`.
+    /// Note: The inverse of zero is defined as zero.
+    fn inverse(value: &BigUint, modulus: &BigUint) -> BigUint {
+        value.modpow(&(modulus - BigUint::from(2u8)), modulus)
+    }
+
+    /// Returns `true` if `(x, y)` is a point on the curve, with coordinates in the base field.
+    fn is_on_curve(x: &BigUint, y: &BigUint) -> bool {
+        let p = Self::base_field_modulus();
+        x < &p && y < &p && (y * y) % &p == (x * x * x + BigUint::from(7u8)) % &p
+    }
+
+    /// Returns the sum of the given points.
+    fn add(a: &Point, b: &Point) -> Point {
+        let p = Self::base_field_modulus();
+        let ((x1, y1), (x2, y2)) = match (a, b) {
+            (None, _) => return b.clone(),
+            (_, None) => return a.clone(),
+            (Some(a), Some(b)) => (a, b),
+        };
+        // Compute the slope of the line through the points, or of the tangent if the points are equal.
+        let lambda = if x1 == x2 {
+            // If the points are inverses of each other, the sum is the point at infinity.
+            if (y1 + y2) % &p == BigUint::from(0u8) {
+                return None;
+            }
+            (BigUint::from(3u8) * x1 * x1 * Self::inverse(&(y1 + y1), &p)) % &p
+        } else {
+            ((y2 + &p - y1) * Self::inverse(&((x2 + &p - x1) % &p), &p)) % &p
+        };
+        // Compute the sum as (lambda^2 - x1 - x2, lambda * (x1 - x3) - y1).
+        let x3 = (&lambda * &lambda + &p + &p - x1 - x2) % &p;
+        let y3 = (lambda * (x1 + &p - &x3) + &p - y1) % &p;
+        Some((x3, y3))
+    }
+
+    /// Returns the product of the given scalar and point.
+    fn mul(scalar: &BigUint, point: &Point) -> Point {
+        let mut output = None;
+        // Double-and-add, from the most significant bit of the scalar.
+        for i in (0..scalar.bits()).rev() {
+            output = Self::add(&output, &output);
+            if scalar.bit(i) {
+                output = Self::add(&output, point);
+            }
+        }
+        output
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Secp256k1 {
+    /// Returns `true` if the given signature is a valid ECDSA signature on the `digest` for the `public_key`.
+    ///
+    /// The signature is the concatenation of `(r, s)`, the public key is the concatenation of its uncompressed
+    /// coordinates `(x, y)`, and the digest is the 32-byte hash of the message, e.g. its Keccak-256 digest.
+    /// Each of these are encoded as 32-byte big-endian integers.
+    pub fn verify_ecdsa(signature: &[u8; 64], public_key: &[u8; 64], digest: &[u8; 32]) -> bool {
+        let n = Self::scalar_field_modulus();
+        let zero = BigUint::from(0u8);
+
+        // Ensure `r` and `s` are in `[1, n - 1]`.
+        let r = BigUint::from_bytes_be(&signature[..32]);
+        let s = BigUint::from_bytes_be(&signature[32..]);
+        if r == zero || s == zero || r >= n || s >= n {
+            return false;
+        }
+
+        // Ensure the public key is on the curve.
+        let x = BigUint::from_bytes_be(&public_key[..32]);
+        let y = BigUint::from_bytes_be(&public_key[32..]);
+        if !Self::is_on_curve(&x, &y) {
+            return false;
+        }
+
+        // Reduce the digest into the scalar field.
+        let z = BigUint::from_bytes_be(digest) % &n;
+
+        // Compute `u1 = z / s` and `u2 = r / s`.
+        let s_inverse = Self::inverse(&s, &n);
+        let u1 = (z * &s_inverse) % &n;
+        let u2 = (&r * s_inverse) % &n;
+
+        // Compute `u1 * G + u2 * Q`, and ensure its x-coordinate is `r`, modulo `n`.
+        match Self::add(&Self::mul(&u1, &Self::generator()), &Self::mul(&u2, &Some((x, y)))) {
+            Some((x, _)) => x % &n == r,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::prelude::*;
+
+    const ITERATIONS: u64 = 10;
+
+    /// The public key `(x, y)` for the private key `SHA-256("snarkVM secp256k1 test key")`.
+    const PUBLIC_KEY: [&str; 2] = [
+        "9a2248dea0ac6d8cd585e41c03ecd212ca9670008e6bae48b996c676c0775745",
+        "138a52d5c4c8c334e31c8611c75dd85782c188626b5cb114e464c718089624ed",
+    ];
+    /// The signature `(r, s)` on the digest, for the nonce `SHA-256("snarkVM secp256k1 test nonce")`.
+    const SIGNATURE: [&str; 2] = [
+        "8a67352e424caa9fe6b94efc36cff2e87827f360bb0272e4016e30d8e66f5a41",
+        "b1d3e11deaf39a71ae2d6b8834a07f7118e9d8b479d096736c2e7b66ef230a6a",
+    ];
+    /// The Keccak-256 digest of "abc".
+    const DIGEST: &str = "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+    /// Returns the given big integer as 32 big-endian bytes.
+    fn to_bytes_be(value: &BigUint) -> [u8; 32] {
+        let bytes = value.to_bytes_be();
+        let mut output = [0u8; 32];
+        output[32 - bytes.len()..].copy_from_slice(&bytes);
+        output
+    }
+
+    /// Returns the concatenation of the given big integers, as 64 big-endian bytes.
+    fn to_pair_bytes_be(first: &BigUint, second: &BigUint) -> [u8; 64] {
+        let mut output = [0u8; 64];
+        output[..32].copy_from_slice(&to_bytes_be(first));
+        output[32..].copy_from_slice(&to_bytes_be(second));
+        output
+    }
+
+    /// Returns the public key and an ECDSA signature on the `digest`, for the given private key and nonce.
+    fn sign(private_key: &BigUint, nonce: &BigUint, digest: &[u8; 32]) -> ([u8; 64], [u8; 64]) {
+        let n = Secp256k1::scalar_field_modulus();
+        // Compute the public key.
+        let (x, y) = Secp256k1::mul(private_key, &Secp256k1::generator()).unwrap();
+        // Compute `r = (k * G).x mod n` and `s = (z + r * d) / k`.
+        let (k_x, _) = Secp256k1::mul(nonce, &Secp256k1::generator()).unwrap();
+        let r = k_x % &n;
+        let z = BigUint::from_bytes_be(digest) % &n;
+        let s = ((z + &r * private_key) * Secp256k1::inverse(nonce, &n)) % &n;
+        (to_pair_bytes_be(&x, &y), to_pair_bytes_be(&r, &s))
+    }
+
+    #[test]
+    fn test_verify_ecdsa() {
+        let public_key: [u8; 64] = hex::decode(PUBLIC_KEY.concat()).unwrap().try_into().unwrap();
+        let signature: [u8; 64] = hex::decode(SIGNATURE.concat()).unwrap().try_into().unwrap();
+        let digest: [u8; 32] = hex::decode(DIGEST).unwrap().try_into().unwrap();
+        assert!(Secp256k1::verify_ecdsa(&signature, &public_key, &digest));
+
+        // Ensure a different digest is rejected.
+        let mut candidate = digest;
+        candidate[31] ^= 1;
+        assert!(!Secp256k1::verify_ecdsa(&signature, &public_key, &candidate));
+
+        // Ensure a different public key is rejected.
+        let (other_public_key, _) = sign(&BigUint::from(2u8), &BigUint::from(3u8), &digest);
+        assert!(!Secp256k1::verify_ecdsa(&signature, &other_public_key, &digest));
+
+        // Ensure the negation of `s` is also a valid signature, as malleability is not restricted.
+        let n = Secp256k1::scalar_field_modulus();
+        let r = BigUint::from_bytes_be(&signature[..32]);
+        let s = BigUint::from_bytes_be(&signature[32..]);
+        assert!(Secp256k1::verify_ecdsa(&to_pair_bytes_be(&r, &(&n - &s)), &public_key, &digest));
+
+        // Ensure `r` and `s` must be in `[1, n - 1]`.
+        let zero = BigUint::from(0u8);
+        assert!(!Secp256k1::verify_ecdsa(&to_pair_bytes_be(&zero, &s), &public_key, &digest));
+        assert!(!Secp256k1::verify_ecdsa(&to_pair_bytes_be(&r, &zero), &public_key, &digest));
+        assert!(!Secp256k1::verify_ecdsa(&to_pair_bytes_be(&n, &s), &public_key, &digest));
+        assert!(!Secp256k1::verify_ecdsa(&to_pair_bytes_be(&r, &n), &public_key, &digest));
+
+        // Ensure a public key that is not on the curve is rejected.
+        let mut candidate = public_key;
+        candidate[63] ^= 1;
+        assert!(!Secp256k1::verify_ecdsa(&signature, &candidate, &digest));
+    }
+
+    #[test]
+    fn test_verify_ecdsa_random() {
+        let mut rng = TestRng::default();
+        let n = Secp256k1::scalar_field_modulus();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key, nonce, and digest.
+            let private_key = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &n;
+            let nonce = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &n;
+            let digest = rng.gen::<[u8; 32]>();
+
+            // Ensure the signature is valid.
+            let (public_key, signature) = sign(&private_key, &nonce, &digest);
+            assert!(Secp256k1::verify_ecdsa(&signature, &public_key, &digest));
+
+            // Ensure the signature is invalid for a different digest.
+            let other_digest = rng.gen::<[u8; 32]>();
+            assert!(!Secp256k1::verify_ecdsa(&signature, &public_key, &other_digest));
+        }
+    }
+}
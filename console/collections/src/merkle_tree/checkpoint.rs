@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The version of the on-disk layout of a Merkle tree checkpoint.
+const CHECKPOINT_VERSION: u8 = 1;
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Writes a checkpoint of the Merkle tree to a buffer, so it may be restored without re-hashing its leaves.
+    ///
+    /// The layout is: the version, the depth, the number of leaves, the number of nodes, the nodes, and the root.
+    pub fn write_checkpoint<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        CHECKPOINT_VERSION.write_le(&mut writer)?;
+        // Write the depth.
+        DEPTH.write_le(&mut writer)?;
+        // Write the number of leaves.
+        u64::try_from(self.number_of_leaves).map_err(error)?.write_le(&mut writer)?;
+        // Write the number of nodes.
+        u64::try_from(self.tree.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the nodes.
+        for node in &self.tree {
+            node.write_le(&mut writer)?;
+        }
+        // Write the root.
+        self.root.write_le(&mut writer)
+    }
+
+    /// Restores a Merkle tree from a checkpoint, for the given leaf and path hashers.
+    ///
+    /// This checks the layout of the checkpoint, that the padding nodes are empty, and that the root
+    /// is the root of the restored nodes. It does not re-hash the internal nodes, so callers should
+    /// additionally check the root against a trusted root.
+    pub fn read_checkpoint<R: Read>(leaf_hasher: &LH, path_hasher: &PH, mut reader: R) -> Result<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == CHECKPOINT_VERSION, "Invalid Merkle tree checkpoint version '{version}'");
+        // Read the depth.
+        let depth = u8::read_le(&mut reader)?;
+        ensure!(depth == DEPTH, "Merkle tree checkpoint is for depth {depth}, expected depth {DEPTH}");
+
+        // Read the number of leaves.
+        let number_of_leaves = usize::try_from(u64::read_le(&mut reader)?)?;
+        // Ensure the number of leaves fits in the Merkle tree.
+        ensure!(
+            (number_of_leaves as u128) <= (1u128 << DEPTH),
+            "Merkle tree checkpoint has {number_of_leaves} leaves, exceeding depth {DEPTH}"
+        );
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the number of nodes.
+        let num_nodes = max_leaves - 1;
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = max_leaves + num_nodes;
+
+        // Read the number of nodes, and ensure it matches the number of leaves.
+        let size = usize::try_from(u64::read_le(&mut reader)?)?;
+        ensure!(size == tree_size, "Merkle tree checkpoint has {size} nodes, expected {tree_size}");
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
+        // Compute the number of padded levels.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Read the nodes.
+        // Note: The nodes are not preallocated, so that a corrupted size cannot trigger a large allocation.
+        let mut tree = Vec::new();
+        for _ in 0..tree_size {
+            tree.push(Field::read_le(&mut reader)?);
+        }
+        // Read the root.
+        let root = Field::read_le(&mut reader)?;
+
+        // Compute the empty hash.
+        let empty_hash = path_hasher.hash_empty()?;
+        // Ensure the padding leaves are empty.
+        ensure!(
+            tree[num_nodes + number_of_leaves..].iter().all(|node| *node == empty_hash),
+            "Merkle tree checkpoint has non-empty padding leaves"
+        );
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = path_hasher.hash_children(&root_hash, &empty_hash)?;
+        }
+        // Ensure the root matches the restored nodes.
+        ensure!(root_hash == root, "Merkle tree checkpoint root does not match its nodes");
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root,
+            tree,
+            empty_hash,
+            number_of_leaves,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_algorithms::{BHP1024, BHP512};
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    const DEPTH: u8 = 8;
+
+    #[test]
+    fn test_checkpoint() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in [0, 1, 2, 3, 7, 8, 100] {
+            // Construct the Merkle tree.
+            let leaves =
+                (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+            let expected = MerkleTree::<_, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+            // Ensure the checkpoint restores the Merkle tree.
+            let mut checkpoint = vec![];
+            expected.write_checkpoint(&mut checkpoint)?;
+            let candidate =
+                MerkleTree::<_, LH, PH, DEPTH>::read_checkpoint(&leaf_hasher, &path_hasher, &checkpoint[..])?;
+            assert_eq!(expected.root(), candidate.root());
+            assert_eq!(expected.tree(), candidate.tree());
+            assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+
+            // Ensure the restored Merkle tree can be appended to.
+            let mut candidate = candidate;
+            let leaf = Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le();
+            candidate.append(&[leaf.clone()])?;
+            let expected = MerkleTree::<_, LH, PH, DEPTH>::new(
+                &leaf_hasher,
+                &path_hasher,
+                &leaves.into_iter().chain([leaf]).collect::<Vec<_>>(),
+            )?;
+            assert_eq!(expected.root(), candidate.root());
+
+            // Ensure a checkpoint for a different depth fails.
+            assert!(MerkleTree::<_, LH, PH, 16>::read_checkpoint(&leaf_hasher, &path_hasher, &checkpoint[..]).is_err());
+            // Ensure a truncated checkpoint fails.
+            let truncated = &checkpoint[..checkpoint.len() - 1];
+            assert!(MerkleTree::<_, LH, PH, DEPTH>::read_checkpoint(&leaf_hasher, &path_hasher, truncated).is_err());
+            // Ensure a checkpoint with a corrupted root fails.
+            let mut corrupted = checkpoint.clone();
+            let root_offset = corrupted.len() - Field::<CurrentEnvironment>::size_in_bytes();
+            corrupted[root_offset] ^= 1;
+            assert!(
+                MerkleTree::<_, LH, PH, DEPTH>::read_checkpoint(&leaf_hasher, &path_hasher, &corrupted[..]).is_err()
+            );
+        }
+        Ok(())
+    }
+}
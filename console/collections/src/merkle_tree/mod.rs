@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod checkpoint;
+
 mod helpers;
 pub use helpers::*;
 
@@ -234,69 +236,149 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             self.number_of_leaves
         );
 
-        // Allocate a vector to store the path hashes.
-        let mut path_hashes = Vec::with_capacity(DEPTH as usize);
+        // Compute the new hashes for the path from the leaf to the root, and the new root hash.
+        let (start, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+        lap!(timer, "Hashed the path of 1 new leaf");
 
-        // Compute and add the new leaf hash to the path hashes.
-        path_hashes.push(self.leaf_hasher.hash_leaf(new_leaf)?);
-        lap!(timer, "Hashed 1 new leaf");
+        // Initialize the Merkle tree.
+        let mut tree = Vec::with_capacity(self.tree.len());
+        // Extend the new Merkle tree with the existing leaf hashes.
+        tree.extend(&self.tree);
+
+        // Update the rest of the tree with the new path hashes.
+        let mut index = Some(start + leaf_index);
+        for path_hash in path_hashes {
+            tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash: self.empty_hash,
+            number_of_leaves: self.number_of_leaves,
+        })
+    }
+
+    #[inline]
+    /// Updates the Merkle tree in place at the location of the given leaf index with the new leaf.
+    ///
+    /// Unlike `MerkleTree::update`, this does not copy the tree, and only writes the nodes on the path
+    /// from the leaf to the root. The tree is not altered in case of failure.
+    pub fn update_leaf(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
+        let timer = timer!("MerkleTree::update_leaf");
+
+        // Check that the leaf index is within the bounds of the Merkle tree.
+        ensure!(
+            leaf_index < self.number_of_leaves,
+            "Leaf index must be less than the number of leaves in the Merkle tree {leaf_index} , {}",
+            self.number_of_leaves
+        );
+
+        // Compute the new hashes for the path from the leaf to the root, and the new root hash.
+        let (start, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+        lap!(timer, "Hashed the path of 1 new leaf");
+
+        // Write the new path hashes into the tree.
+        let mut index = Some(start + leaf_index);
+        for path_hash in path_hashes {
+            // This unwrap is safe, as there is exactly one path hash for each level up to the root.
+            self.tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+        self.root = root_hash;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Updates the Merkle tree in place with the given new leaves appended to it.
+    ///
+    /// If the new leaves fit in the current power-of-two capacity of the tree, this does not copy the tree,
+    /// and only writes the new leaf hashes and their ancestors. Otherwise, the tree must grow, and this
+    /// falls back to `MerkleTree::append`. The tree is not altered in case of failure.
+    pub fn append_in_place(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        let timer = timer!("MerkleTree::append_in_place");
+
+        // If there are no new leaves, there is nothing to do.
+        if new_leaves.is_empty() {
+            finish!(timer);
+            return Ok(());
+        }
 
         // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
         let start = match self.number_of_leaves.checked_next_power_of_two() {
             Some(num_leaves) => num_leaves - 1,
             None => bail!("Integer overflow when computing the Merkle tree start index"),
         };
+        // Compute the number of leaves after appending.
+        let number_of_leaves = match self.number_of_leaves.checked_add(new_leaves.len()) {
+            Some(number_of_leaves) => number_of_leaves,
+            None => bail!("Integer overflow when computing the number of leaves in the Merkle tree"),
+        };
+        // If the new leaves do not fit in the current tree, grow the tree instead.
+        if start + number_of_leaves > self.tree.len() {
+            self.append(new_leaves)?;
+            finish!(timer);
+            return Ok(());
+        }
 
-        // Compute the new hashes for the path from the leaf to the root.
-        let mut index = start + leaf_index;
-        while let Some(parent) = parent(index) {
-            // Get the left and right child hashes of the parent.
-            let (left, right) = match is_left_child(index) {
-                true => (path_hashes.last().unwrap(), &self.tree[right_child(parent)]),
-                false => (&self.tree[left_child(parent)], path_hashes.last().unwrap()),
+        // Compute the new leaf hashes.
+        let mut level = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        // Compute the new hashes for each level, iterating from the leaf level to the root level.
+        // The new hashes in each level are the contiguous range of nodes from `first` to `first + level.len()`.
+        let mut first = start + self.number_of_leaves;
+        let mut levels = Vec::with_capacity(DEPTH as usize);
+        while let Some(parent_first) = parent(first) {
+            // This unwrap is safe, as each level contains at least one new hash.
+            let parent_last = parent(first + level.len() - 1).unwrap();
+            // Returns the hash at the given index, preferring the new hashes in the current level.
+            let node = |index: usize| match index >= first && index < first + level.len() {
+                true => level[index - first],
+                false => self.tree[index],
             };
-            // Compute and add the new parent hash to the path hashes.
-            path_hashes.push(self.path_hasher.hash_children(left, right)?);
-            // Update the index to the parent.
-            index = parent;
+            // Construct the children for the new parent hashes.
+            let tuples =
+                (parent_first..=parent_last).map(|i| (node(left_child(i)), node(right_child(i)))).collect::<Vec<_>>();
+            // Compute the new parent hashes.
+            let parent_level = self.path_hasher.hash_all_children(&tuples)?;
+            // Store the current level, and proceed to the parent level.
+            levels.push((first, core::mem::replace(&mut level, parent_level)));
+            first = parent_first;
         }
+        levels.push((first, level));
+        lap!(timer, "Hashed {} levels", levels.len());
 
         // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
         let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
         // Compute the padding depth.
         let padding_depth = DEPTH - tree_depth;
 
-        // Update the root hash.
-        // This unwrap is safe, as the path hashes vector is guaranteed to have at least one element.
-        let mut root_hash = *path_hashes.last().unwrap();
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        // This unwrap is safe, as the root level contains exactly one hash.
+        let mut root_hash = levels.last().unwrap().1[0];
         for _ in 0..padding_depth {
             // Update the root hash, by hashing the current root hash with the empty hash.
             root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
         }
         lap!(timer, "Hashed {} padding levels", padding_depth);
 
-        // Initialize the Merkle tree.
-        let mut tree = Vec::with_capacity(self.tree.len());
-        // Extend the new Merkle tree with the existing leaf hashes.
-        tree.extend(&self.tree);
-
-        // Update the rest of the tree with the new path hashes.
-        let mut index = Some(start + leaf_index);
-        for path_hash in path_hashes {
-            tree[index.unwrap()] = path_hash;
-            index = parent(index.unwrap());
+        // Write the new hashes into the tree.
+        for (first, level) in levels {
+            self.tree[first..first + level.len()].copy_from_slice(&level);
         }
+        self.root = root_hash;
+        self.number_of_leaves = number_of_leaves;
 
         finish!(timer);
-
-        Ok(Self {
-            leaf_hasher: self.leaf_hasher.clone(),
-            path_hasher: self.path_hasher.clone(),
-            root: root_hash,
-            tree,
-            empty_hash: self.empty_hash,
-            number_of_leaves: self.number_of_leaves,
-        })
+        Ok(())
     }
 
     #[inline]
@@ -608,6 +690,52 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Returns the start index of the leaf hashes level, the new hashes for the path from the given leaf
+    /// to the root of the tree, and the new root hash, for the given leaf index and new leaf.
+    #[inline]
+    fn compute_updated_path(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<(usize, Vec<Field<E>>, Field<E>)> {
+        // Allocate a vector to store the path hashes.
+        let mut path_hashes = Vec::with_capacity(DEPTH as usize);
+
+        // Compute and add the new leaf hash to the path hashes.
+        path_hashes.push(self.leaf_hasher.hash_leaf(new_leaf)?);
+
+        // Compute the start index (on the left) for the leaf hashes level in the Merkle tree.
+        let start = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+
+        // Compute the new hashes for the path from the leaf to the root.
+        let mut index = start + leaf_index;
+        while let Some(parent) = parent(index) {
+            // Get the left and right child hashes of the parent.
+            let (left, right) = match is_left_child(index) {
+                true => (path_hashes.last().unwrap(), &self.tree[right_child(parent)]),
+                false => (&self.tree[left_child(parent)], path_hashes.last().unwrap()),
+            };
+            // Compute and add the new parent hash to the path hashes.
+            path_hashes.push(self.path_hasher.hash_children(left, right)?);
+            // Update the index to the parent.
+            index = parent;
+        }
+
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(self.tree.len())?;
+        // Compute the padding depth.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Update the root hash.
+        // This unwrap is safe, as the path hashes vector is guaranteed to have at least one element.
+        let mut root_hash = *path_hashes.last().unwrap();
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+
+        Ok((start, path_hashes, root_hash))
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
@@ -773,5 +901,9 @@ const fn is_left_child(index: usize) -> bool {
 /// Returns the index of the parent, given the index of a child.
 #[inline]
 const fn parent(index: usize) -> Option<usize> {
-    if index > 0 { Some((index - 1) >> 1) } else { None }
+    if index > 0 {
+        Some((index - 1) >> 1)
+    } else {
+        None
+    }
 }
@@ -104,17 +104,56 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         // Ensure the final hash matches the given root.
         current_hash == *root
     }
+
+    /// Returns the hashes computed while verifying the Merkle path for the given leaf, from the leaf hash
+    /// up to the root. For a valid Merkle path, the last hash is the root.
+    pub fn trace<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaf: &LH::Leaf,
+    ) -> Result<Vec<Field<E>>> {
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*self.leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
+        // Ensure the path length matches the expected depth.
+        ensure!(self.siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+
+        // Initialize the trace with the leaf hash.
+        let mut trace = Vec::with_capacity(DEPTH as usize + 1);
+        trace.push(leaf_hasher.hash_leaf(leaf)?);
+
+        // Compute the hash on each level, with the same ordering as `MerklePath::verify`.
+        for (i, sibling_hash) in self.siblings.iter().enumerate() {
+            let current_hash = trace[i];
+            let (left, right) = match ((*self.leaf_index >> i) & 1) == 0 {
+                true => (current_hash, *sibling_hash),
+                false => (*sibling_hash, current_hash),
+            };
+            trace.push(path_hasher.hash_children(&left, &right)?);
+        }
+        Ok(trace)
+    }
 }
 
 impl<E: Environment, const DEPTH: u8> FromBytes for MerklePath<E, DEPTH> {
     /// Reads in a Merkle path from a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Ensure the Merkle tree depth is within bounds, before reading the siblings.
+        if DEPTH == 0 || DEPTH > 64 {
+            return Err(error("Merkle tree depth must be between 1 and 64"));
+        }
         // Read the leaf index.
         let leaf_index = u64::read_le(&mut reader)?;
+        // Ensure the leaf index is within the tree depth, before reading the siblings.
+        if (leaf_index as u128) >= (1u128 << DEPTH) {
+            return Err(error("Found an out of bounds Merkle leaf index"));
+        }
         // Read the Merkle path siblings.
-        let siblings =
-            (0..DEPTH).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        let mut siblings = Vec::with_capacity(DEPTH as usize);
+        for _ in 0..DEPTH {
+            siblings.push(Field::new(FromBytes::read_le(&mut reader)?));
+        }
         // Return the Merkle path.
         Self::try_from((U64::new(leaf_index), siblings)).map_err(error)
     }
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: u128 = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Append the additional leaves in place, one batch at a time.
+/// 3. Update each leaf in place.
+/// 4. Check after each step that the Merkle tree is equal to a full rebuild from its leaves.
+fn check_in_place_matches_rebuild<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaves: &[LH::Leaf],
+    updated_leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Checks that the Merkle tree is equal to a full rebuild from the given leaves.
+    let check_rebuild = |merkle_tree: &MerkleTree<E, LH, PH, DEPTH>, leaves: &[LH::Leaf]| -> Result<()> {
+        let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
+        assert_eq!(expected.number_of_leaves(), merkle_tree.number_of_leaves());
+        Ok(())
+    };
+
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let mut all_leaves = leaves.to_vec();
+
+    // Append the additional leaves in place, in batches of increasing size.
+    let mut remaining = additional_leaves;
+    let mut batch_size = 1;
+    while !remaining.is_empty() {
+        let (batch, rest) = remaining.split_at(core::cmp::min(batch_size, remaining.len()));
+        merkle_tree.append_in_place(batch)?;
+        all_leaves.extend_from_slice(batch);
+        check_rebuild(&merkle_tree, &all_leaves)?;
+        remaining = rest;
+        batch_size += 1;
+    }
+
+    // Update each leaf in place.
+    for (leaf_index, leaf) in updated_leaves.iter().enumerate().take(all_leaves.len()) {
+        merkle_tree.update_leaf(leaf_index, leaf)?;
+        all_leaves[leaf_index] = leaf.clone();
+        check_rebuild(&merkle_tree, &all_leaves)?;
+    }
+
+    // Ensure an out of bounds update fails, and does not alter the Merkle tree.
+    if let Some(leaf) = updated_leaves.first() {
+        assert!(merkle_tree.update_leaf(all_leaves.len(), leaf).is_err());
+        check_rebuild(&merkle_tree, &all_leaves)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_in_place_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Samples the given number of leaves.
+        let mut sample = |num_leaves| {
+            (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>()
+        };
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_in_place_matches_rebuild::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &sample(num_leaves),
+                &sample(num_additional_leaves),
+                &sample(ITERATIONS),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 10, 16, 32]);
+    Ok(())
+}
+
+#[test]
+fn test_in_place_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        // Samples the given number of leaves.
+        let mut sample = |num_leaves| (0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and additional leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+            let num_additional_leaves = core::cmp::min(2u128.pow(DEPTH as u32) - num_leaves, ITERATIONS - i);
+
+            // Check the Merkle tree.
+            check_in_place_matches_rebuild::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &sample(num_leaves),
+                &sample(num_additional_leaves),
+                &sample(ITERATIONS),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Spot check important depths.
+    run_tests!(&mut rng, [1, 2, 3, 4, 5, 10, 16, 32]);
+    Ok(())
+}
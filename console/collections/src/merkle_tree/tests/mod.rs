@@ -15,6 +15,7 @@
 use super::*;
 
 mod append;
+mod in_place;
 mod remove;
 mod update;
 mod update_many;
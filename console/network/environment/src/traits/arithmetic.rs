@@ -126,6 +126,20 @@ pub trait RemWrapped<Rhs: ?Sized = Self> {
     fn rem_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for rotating the bits of a value to the left, wrapping the rhs to the number of bits in self.
+pub trait RotateLeft<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotate_left(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for rotating the bits of a value to the right, wrapping the rhs to the number of bits in self.
+pub trait RotateRight<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rotate_right(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for left shifting a value, checking that the rhs is less than the number
 /// of bits in self.
 pub trait ShlChecked<Rhs: ?Sized = Self> {
@@ -223,6 +223,8 @@ pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: I
     + ShrChecked<U8, Output = Self>
     + ShrWrapped<U8, Output = Self>
     + ShrAssign<U8>
+    + RotateLeft<U8, Output = Self>
+    + RotateRight<U8, Output = Self>
     + Pow<U16, Output = Self>
     + Shl<U16, Output = Self>
     + for<'a> Shl<&'a U16, Output = Self>
@@ -234,6 +236,8 @@ pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: I
     + ShrChecked<U16, Output = Self>
     + ShrWrapped<U16, Output = Self>
     + ShrAssign<U16>
+    + RotateLeft<U16, Output = Self>
+    + RotateRight<U16, Output = Self>
     + Pow<U32, Output = Self>
     + Shl<U32, Output = Self>
     + for<'a> Shl<&'a U32, Output = Self>
@@ -245,6 +249,8 @@ pub trait IntegerTrait<I: integer_type::IntegerType, U8: IntegerCore<u8>, U16: I
     + ShrChecked<U32, Output = Self>
     + ShrWrapped<U32, Output = Self>
     + ShrAssign<U32>
+    + RotateLeft<U32, Output = Self>
+    + RotateRight<U32, Output = Self>
 {
 }
 
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_collections::merkle_tree::{LeafHash, MerklePath, PathHash};
+use snarkvm_console_types::Field;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// The BHP hash functions of a BHP Merkle tree, with a leaf hasher of 1024-bits and a path hasher of 512-bits.
+///
+/// The hash functions share their bases, so a single instance is cheap to clone and may be reused
+/// across many Merkle path verifications. The hasher counts the hashes it computes, and may be given
+/// a budget, after which every hash fails. Clones of a hasher share its count and budget.
+#[derive(Clone, Debug)]
+pub struct BHPMerkleHasher<E: Environment> {
+    /// The BHP hash functions.
+    hashers: Hashers<E>,
+    /// The number of hashes computed.
+    num_hashes: Arc<AtomicUsize>,
+    /// The maximum number of hashes, if any.
+    max_hashes: Option<usize>,
+}
+
+/// A BHP hash function, from the input bits to the hash.
+type HashFunction<E> = fn(&[bool]) -> Result<Field<E>>;
+
+/// The BHP hash functions, with an input hasher of 1024-bits and an input hasher of 512-bits.
+#[derive(Clone)]
+enum Hashers<E: Environment> {
+    /// The BHP hash functions, as instances.
+    Instances(BHP1024<E>, BHP512<E>),
+    /// The BHP hash functions, as the hash functions of a network.
+    Functions(HashFunction<E>, HashFunction<E>),
+}
+
+impl<E: Environment> Debug for Hashers<E> {
+    /// Prints the hash functions.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Instances(bhp1024, bhp512) => f.debug_tuple("Instances").field(bhp1024).field(bhp512).finish(),
+            Self::Functions(..) => f.debug_tuple("Functions").finish(),
+        }
+    }
+}
+
+impl<E: Environment> BHPMerkleHasher<E> {
+    /// Initializes a new BHP Merkle hasher from the given BHP hash functions.
+    pub fn new(bhp1024: BHP1024<E>, bhp512: BHP512<E>) -> Self {
+        Self { hashers: Hashers::Instances(bhp1024, bhp512), num_hashes: Default::default(), max_hashes: None }
+    }
+
+    /// Initializes a new BHP Merkle hasher from the given BHP hash functions of a network,
+    /// with an input hasher of 1024-bits and an input hasher of 512-bits.
+    pub fn from_hash_functions(hash_bhp1024: HashFunction<E>, hash_bhp512: HashFunction<E>) -> Self {
+        Self {
+            hashers: Hashers::Functions(hash_bhp1024, hash_bhp512),
+            num_hashes: Default::default(),
+            max_hashes: None,
+        }
+    }
+
+    /// Returns a copy of this hasher, with a new count, which computes at most `max_hashes` hashes.
+    pub fn with_budget(&self, max_hashes: usize) -> Self {
+        Self { hashers: self.hashers.clone(), num_hashes: Default::default(), max_hashes: Some(max_hashes) }
+    }
+
+    /// Returns the number of hashes computed, including any hash that failed for exceeding the budget.
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes.load(Ordering::SeqCst)
+    }
+
+    /// Returns `true` if the hasher has attempted more hashes than its budget.
+    pub fn is_budget_exceeded(&self) -> bool {
+        matches!(self.max_hashes, Some(max_hashes) if self.num_hashes() > max_hashes)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    pub fn hash_bhp512(&self, input: &[bool]) -> Result<Field<E>> {
+        self.count_hash()?;
+        match &self.hashers {
+            Hashers::Instances(_, bhp512) => bhp512.hash(input),
+            Hashers::Functions(_, hash_bhp512) => hash_bhp512(input),
+        }
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    pub fn hash_bhp1024(&self, input: &[bool]) -> Result<Field<E>> {
+        self.count_hash()?;
+        match &self.hashers {
+            Hashers::Instances(bhp1024, _) => bhp1024.hash(input),
+            Hashers::Functions(hash_bhp1024, _) => hash_bhp1024(input),
+        }
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    #[allow(clippy::ptr_arg)]
+    pub fn verify_merkle_path_bhp<const DEPTH: u8>(
+        &self,
+        path: &MerklePath<E, DEPTH>,
+        root: &Field<E>,
+        leaf: &Vec<bool>,
+    ) -> bool {
+        path.verify(&LeafHasher(self), &PathHasher(self), root, leaf)
+    }
+
+    /// Returns `true` if every given Merkle path is valid for its given root and leaf.
+    pub fn verify_merkle_paths_bhp<const DEPTH: u8>(
+        &self,
+        paths: &[(&MerklePath<E, DEPTH>, &Field<E>, &Vec<bool>)],
+    ) -> bool {
+        paths.iter().all(|(path, root, leaf)| self.verify_merkle_path_bhp(path, root, leaf))
+    }
+
+    /// Returns the hashes computed while verifying the given Merkle path, from the leaf hash up to the root.
+    #[allow(clippy::ptr_arg)]
+    pub fn trace_merkle_path_bhp<const DEPTH: u8>(
+        &self,
+        path: &MerklePath<E, DEPTH>,
+        leaf: &Vec<bool>,
+    ) -> Result<Vec<Field<E>>> {
+        path.trace(&LeafHasher(self), &PathHasher(self), leaf)
+    }
+
+    /// Counts a hash, and ensures the hasher is within its budget.
+    fn count_hash(&self) -> Result<()> {
+        let num_hashes = self.num_hashes.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(max_hashes) = self.max_hashes {
+            ensure!(num_hashes <= max_hashes, "Exceeded the budget of {max_hashes} hashes");
+        }
+        Ok(())
+    }
+}
+
+/// The leaf hasher of a BHP Merkle tree, which hashes with the given hasher.
+#[derive(Clone)]
+struct LeafHasher<'a, E: Environment>(&'a BHPMerkleHasher<E>);
+
+impl<E: Environment> LeafHash for LeafHasher<'_, E> {
+    type Hash = Field<E>;
+    type Leaf = Vec<bool>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `false` bit.
+        input.push(false);
+        input.extend(leaf);
+        // Hash the input.
+        self.0.hash_bhp1024(&input)
+    }
+}
+
+/// The path hasher of a BHP Merkle tree, which hashes with the given hasher.
+#[derive(Clone)]
+struct PathHasher<'a, E: Environment>(&'a BHPMerkleHasher<E>);
+
+impl<E: Environment> PathHash for PathHasher<'_, E> {
+    type Hash = Field<E>;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(1 + Field::<E>::size_in_bits() * 2);
+        // Prepend the nodes with a `true` bit.
+        input.push(true);
+        left.write_bits_le(&mut input);
+        right.write_bits_le(&mut input);
+        // Hash the input.
+        self.0.hash_bhp512(&input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_hash_functions_match_instances() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the hashers.
+        let instances = CurrentNetwork::bhp_merkle_hasher();
+        let functions = BHPMerkleHasher::from_hash_functions(CurrentNetwork::hash_bhp1024, CurrentNetwork::hash_bhp512);
+
+        // Sample a Merkle tree.
+        let leaves = (0..4).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<8>(&leaves).unwrap();
+        let path = tree.prove(1, &leaves[1]).unwrap();
+
+        // Ensure both hashers agree with the Merkle tree.
+        for hasher in [&instances, &functions] {
+            assert!(hasher.verify_merkle_path_bhp(&path, tree.root(), &leaves[1]));
+            assert!(!hasher.verify_merkle_path_bhp(&path, tree.root(), &leaves[0]));
+            assert!(hasher.verify_merkle_paths_bhp(&[(&path, tree.root(), &leaves[1])]));
+            assert_eq!(hasher.trace_merkle_path_bhp(&path, &leaves[1]).unwrap().last(), Some(tree.root()));
+        }
+        assert_eq!(instances.hash_bhp1024(&leaves[0]).unwrap(), functions.hash_bhp1024(&leaves[0]).unwrap());
+        assert_eq!(instances.hash_bhp512(&leaves[0]).unwrap(), functions.hash_bhp512(&leaves[0]).unwrap());
+    }
+
+    #[test]
+    fn test_budget() {
+        let rng = &mut TestRng::default();
+
+        // Sample a Merkle tree.
+        let leaves = (0..4).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<8>(&leaves).unwrap();
+        let path = tree.prove(1, &leaves[1]).unwrap();
+
+        // Ensure a Merkle path requires one leaf hash and one hash per level.
+        let hasher = CurrentNetwork::bhp_merkle_hasher().with_budget(9);
+        assert!(hasher.verify_merkle_path_bhp(&path, tree.root(), &leaves[1]));
+        assert_eq!(hasher.num_hashes(), 9);
+        assert!(!hasher.is_budget_exceeded());
+
+        // Ensure a hash beyond the budget fails.
+        assert!(hasher.hash_bhp1024(&leaves[0]).is_err());
+        assert!(hasher.is_budget_exceeded());
+        let hasher = CurrentNetwork::bhp_merkle_hasher().with_budget(8);
+        assert!(!hasher.verify_merkle_path_bhp(&path, tree.root(), &leaves[1]));
+        assert!(hasher.is_budget_exceeded());
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bhp_hasher;
+pub use bhp_hasher::*;
+
 mod id;
 pub use id::*;
 
@@ -118,7 +118,7 @@ pub trait Network:
     const MAX_DATA_ENTRIES: usize = 32;
     /// The maximum recursive depth of an entry.
     /// Note: This value must be strictly less than u8::MAX.
-    const MAX_DATA_DEPTH: usize = 32;
+    const MAX_DATA_DEPTH: usize = 64;
     /// The maximum number of fields in data (must not exceed u16::MAX).
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
@@ -127,6 +127,12 @@ pub trait Network:
     const MIN_STRUCT_ENTRIES: usize = 1; // This ensures the struct is not empty.
     /// The maximum number of entries in a struct.
     const MAX_STRUCT_ENTRIES: usize = Self::MAX_DATA_ENTRIES;
+    /// The number of times a self-referential struct is nested within itself.
+    /// Note: Up to this depth, the self-referential vector of a struct is padded to its maximum length,
+    /// and past this depth, it is the empty sentinel, so every value of the struct has the same shape.
+    const MAX_STRUCT_RECURSION_DEPTH: usize = 4;
+    /// The maximum number of nested copies of a self-referential struct, including the padding.
+    const MAX_STRUCT_RECURSION_ELEMENTS: usize = 1 << 8;
 
     /// The minimum number of elements in an array.
     const MIN_ARRAY_ELEMENTS: usize = 1; // This ensures the array is not empty.
@@ -156,6 +162,17 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
 
+    /// The depth of the BHP Merkle tree for the blocks.
+    const BLOCKS_DEPTH: u8 = 32;
+    /// The depth of the BHP Merkle tree for the block header.
+    const HEADER_DEPTH: u8 = 3;
+    /// The depth of the BHP Merkle tree for transactions in a block.
+    const TRANSACTIONS_DEPTH: u8 = 16;
+    /// The depth of the BHP Merkle tree for the transaction.
+    const TRANSACTION_DEPTH: u8 = 5;
+    /// The depth of the BHP Merkle tree for the transition.
+    const TRANSITION_DEPTH: u8 = 5;
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -332,9 +349,20 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the BHP hasher of a BHP Merkle tree, which may be shared across many Merkle path verifications.
+    ///
+    /// The default implementation hashes with `Network::hash_bhp1024` and `Network::hash_bhp512`.
+    fn bhp_merkle_hasher() -> BHPMerkleHasher<Self> {
+        BHPMerkleHasher::from_hash_functions(Self::hash_bhp1024, Self::hash_bhp512)
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// restored from the given checkpoint (see `MerkleTree::write_checkpoint`).
+    fn merkle_tree_bhp_from_checkpoint<const DEPTH: u8>(checkpoint: &[u8]) -> Result<BHPMerkleTree<Self, DEPTH>>;
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>>;
 
@@ -346,6 +374,16 @@ pub trait Network:
         leaf: &Vec<bool>,
     ) -> bool;
 
+    /// Returns `true` if every given Merkle path is valid for its given root and leaf.
+    ///
+    /// The default implementation verifies the paths with a single `Network::bhp_merkle_hasher`,
+    /// which is shared across the paths.
+    fn verify_merkle_paths_bhp<const DEPTH: u8>(
+        paths: &[(&MerklePath<Self, DEPTH>, &Field<Self>, &Vec<bool>)],
+    ) -> bool {
+        Self::bhp_merkle_hasher().verify_merkle_paths_bhp(paths)
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     #[allow(clippy::ptr_arg)]
     fn verify_merkle_path_psd<const DEPTH: u8>(
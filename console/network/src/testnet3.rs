@@ -452,11 +452,22 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns the BHP hasher of a BHP Merkle tree, which may be shared across many Merkle path verifications.
+    fn bhp_merkle_hasher() -> BHPMerkleHasher<Self> {
+        BHPMerkleHasher::new(BHP_1024.clone(), BHP_512.clone())
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
     }
 
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits,
+    /// restored from the given checkpoint (see `MerkleTree::write_checkpoint`).
+    fn merkle_tree_bhp_from_checkpoint<const DEPTH: u8>(checkpoint: &[u8]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::read_checkpoint(&*BHP_1024, &*BHP_512, checkpoint)
+    }
+
     /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
     fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
@@ -494,4 +505,28 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_verify_merkle_paths_bhp() {
+        let rng = &mut TestRng::default();
+
+        // Sample the leaves and the Merkle tree.
+        let leaves = (0..4).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_bhp::<8>(&leaves).unwrap();
+        let paths = leaves.iter().enumerate().map(|(i, leaf)| tree.prove(i, leaf).unwrap()).collect::<Vec<_>>();
+
+        // Ensure the batched result agrees with the individual results.
+        let batch = paths.iter().zip_eq(&leaves).map(|(path, leaf)| (path, tree.root(), leaf)).collect::<Vec<_>>();
+        assert!(batch.iter().all(|(path, root, leaf)| CurrentNetwork::verify_merkle_path_bhp(path, root, leaf)));
+        assert!(CurrentNetwork::verify_merkle_paths_bhp(&batch));
+
+        // Ensure a single invalid path fails the batch.
+        let invalid_root = Field::rand(rng);
+        let mut batch = batch;
+        batch[2].1 = &invalid_root;
+        assert!(!CurrentNetwork::verify_merkle_path_bhp(batch[2].0, batch[2].1, batch[2].2));
+        assert!(!CurrentNetwork::verify_merkle_paths_bhp(&batch));
+        // Ensure the empty batch is valid.
+        assert!(CurrentNetwork::verify_merkle_paths_bhp::<8>(&[]));
+    }
 }
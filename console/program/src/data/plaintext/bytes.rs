@@ -16,7 +16,22 @@ use super::*;
 
 impl<N: Network> FromBytes for Plaintext<N> {
     /// Reads the plaintext from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Reads the plaintext from a buffer, at the given depth.
+    fn read_le_internal<R: Read>(mut reader: R, depth: usize) -> IoResult<Self> {
+        // Ensure the plaintext does not exceed the maximum depth.
+        if depth > N::MAX_DATA_DEPTH {
+            return Err(error(format!(
+                "Failed to deserialize plaintext: exceeds maximum depth of {}",
+                N::MAX_DATA_DEPTH
+            )));
+        }
+
         // Read the index.
         let index = u8::read_le(&mut reader)?;
         // Read the plaintext.
@@ -35,7 +50,7 @@ impl<N: Network> FromBytes for Plaintext<N> {
                     // Read the plaintext bytes.
                     let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                     // Recover the plaintext value.
-                    let plaintext = Plaintext::read_le(&mut bytes.as_slice())?;
+                    let plaintext = Plaintext::read_le_internal(&mut bytes.as_slice(), depth + 1)?;
                     // Add the member.
                     members.insert(identifier, plaintext);
                 }
@@ -56,7 +71,7 @@ impl<N: Network> FromBytes for Plaintext<N> {
                     // Read the plaintext bytes.
                     let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                     // Recover the plaintext value.
-                    let plaintext = Plaintext::read_le(&mut bytes.as_slice())?;
+                    let plaintext = Plaintext::read_le_internal(&mut bytes.as_slice(), depth + 1)?;
                     // Add the element.
                     elements.push(plaintext);
                 }
@@ -134,6 +149,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bytes_depth() -> Result<()> {
+        // Construct a plaintext nested up to the maximum depth.
+        let mut plaintext = Plaintext::<CurrentNetwork>::from(Literal::U8(U8::new(1)));
+        for _ in 0..CurrentNetwork::MAX_DATA_DEPTH {
+            plaintext = Plaintext::Array(vec![plaintext], Default::default());
+        }
+        check_bytes(plaintext.clone())?;
+        assert_eq!(plaintext, Plaintext::from_bits_le(&plaintext.to_bits_le())?);
+
+        // Ensure a plaintext exceeding the maximum depth fails to deserialize.
+        let plaintext = Plaintext::Array(vec![plaintext], Default::default());
+        assert!(Plaintext::<CurrentNetwork>::read_le(&plaintext.to_bytes_le()?[..]).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&plaintext.to_bits_le()).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
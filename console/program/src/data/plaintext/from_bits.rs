@@ -17,6 +17,21 @@ use super::*;
 impl<N: Network> FromBits for Plaintext<N> {
     /// Initializes a new plaintext from a list of little-endian bits *without* trailing zeros.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Self::from_bits_le_internal(bits_le, 0)
+    }
+
+    /// Initializes a new plaintext from a list of big-endian bits *without* trailing zeros.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits_be_internal(bits_be, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new plaintext from a list of little-endian bits *without* trailing zeros, at the given depth.
+    fn from_bits_le_internal(bits_le: &[bool], depth: usize) -> Result<Self> {
+        // Ensure the plaintext does not exceed the maximum depth.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
         let bits = bits_le;
 
         // The starting index used to create subsequent subslices of the `bits` slice.
@@ -62,7 +77,7 @@ impl<N: Network> FromBits for Plaintext<N> {
                 let identifier = Identifier::from_bits_le(next_bits(identifier_size as usize)?)?;
 
                 let member_size = u16::from_bits_le(next_bits(16)?)?;
-                let value = Plaintext::from_bits_le(next_bits(member_size as usize)?)?;
+                let value = Plaintext::from_bits_le_internal(next_bits(member_size as usize)?, depth + 1)?;
 
                 if members.insert(identifier, value).is_some() {
                     bail!("Duplicate identifier in struct.");
@@ -82,7 +97,7 @@ impl<N: Network> FromBits for Plaintext<N> {
             let mut elements = Vec::with_capacity(num_elements as usize);
             for _ in 0..num_elements {
                 let element_size = u16::from_bits_le(next_bits(16)?)?;
-                let element = Plaintext::from_bits_le(next_bits(element_size as usize)?)?;
+                let element = Plaintext::from_bits_le_internal(next_bits(element_size as usize)?, depth + 1)?;
 
                 elements.push(element);
             }
@@ -96,8 +111,11 @@ impl<N: Network> FromBits for Plaintext<N> {
         }
     }
 
-    /// Initializes a new plaintext from a list of big-endian bits *without* trailing zeros.
-    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+    /// Initializes a new plaintext from a list of big-endian bits *without* trailing zeros, at the given depth.
+    fn from_bits_be_internal(bits_be: &[bool], depth: usize) -> Result<Self> {
+        // Ensure the plaintext does not exceed the maximum depth.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
+
         let bits = bits_be;
 
         // The starting index used to create subsequent subslices of the `bits` slice.
@@ -143,7 +161,7 @@ impl<N: Network> FromBits for Plaintext<N> {
                 let identifier = Identifier::from_bits_be(next_bits(identifier_size as usize)?)?;
 
                 let member_size = u16::from_bits_be(next_bits(16)?)?;
-                let value = Plaintext::from_bits_be(next_bits(member_size as usize)?)?;
+                let value = Plaintext::from_bits_be_internal(next_bits(member_size as usize)?, depth + 1)?;
 
                 if members.insert(identifier, value).is_some() {
                     bail!("Duplicate identifier in struct.");
@@ -163,7 +181,7 @@ impl<N: Network> FromBits for Plaintext<N> {
             let mut elements = Vec::with_capacity(num_elements as usize);
             for _ in 0..num_elements {
                 let element_size = u16::from_bits_be(next_bits(16)?)?;
-                let element = Plaintext::from_bits_be(next_bits(element_size as usize)?)?;
+                let element = Plaintext::from_bits_be_internal(next_bits(element_size as usize)?, depth + 1)?;
 
                 elements.push(element);
             }
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::VectorType;
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new vector from the given length and elements,
+    /// where the elements are padded to the maximum length of the vector.
+    pub fn new_vector(length: U32<N>, elements: Vec<Plaintext<N>>) -> Result<Self> {
+        // Ensure the length does not exceed the number of elements.
+        ensure!(*length as usize <= elements.len(), "Vector length {length} exceeds its {} elements", elements.len());
+        // Construct the vector.
+        let members = IndexMap::from_iter([
+            (VectorType::length_member(), Plaintext::from(Literal::U32(length))),
+            (VectorType::elements_member(), Plaintext::Array(elements, Default::default())),
+        ]);
+        Ok(Self::Struct(members, Default::default()))
+    }
+
+    /// Returns the length of the vector.
+    pub fn vector_length(&self) -> Result<U32<N>> {
+        match self.find(&[VectorType::<N>::length_member()])? {
+            Self::Literal(Literal::U32(length), ..) => Ok(length),
+            _ => bail!("'{self}' is not a vector"),
+        }
+    }
+
+    /// Returns the elements of the vector, including the padding past its length.
+    pub fn vector_elements(&self) -> Result<&[Plaintext<N>]> {
+        match self {
+            Self::Struct(members, ..) => match members.get(&VectorType::elements_member()) {
+                Some(Self::Array(elements, ..)) => Ok(elements),
+                _ => bail!("'{self}' is not a vector"),
+            },
+            _ => bail!("'{self}' is not a vector"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_vector() -> Result<()> {
+        let elements = vec![Plaintext::<CurrentNetwork>::from_str("1field")?, Plaintext::from_str("0field")?];
+        let vector = Plaintext::new_vector(U32::new(1), elements.clone())?;
+        assert_eq!(vector, Plaintext::from_str("{ length: 1u32, elements: [1field, 0field] }")?);
+        assert_eq!(vector.vector_length()?, U32::new(1));
+        assert_eq!(vector.vector_elements()?, &elements[..]);
+
+        // Ensure the length cannot exceed the number of elements.
+        assert!(Plaintext::new_vector(U32::new(3), elements).is_err());
+        // Ensure a plaintext that is not a vector is rejected.
+        assert!(Plaintext::<CurrentNetwork>::from_str("1field")?.vector_length().is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ length: 1u32 }")?.vector_elements().is_err());
+        Ok(())
+    }
+}
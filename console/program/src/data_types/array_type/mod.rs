@@ -37,6 +37,8 @@ impl<N: Network> ArrayType<N> {
         // Check that the number of dimensions are valid.
         ensure!(!dimensions.is_empty(), "An array must have at least one dimension");
         ensure!(dimensions.len() <= N::MAX_DATA_DEPTH, "An array can have at most {} dimensions", N::MAX_DATA_DEPTH);
+        // Ensure the element type is not a vector.
+        ensure!(!matches!(plaintext_type, PlaintextType::Vector(..)), "An array element cannot be a vector");
         // Check that each dimension is valid.
         for length in &dimensions {
             ensure!(**length as usize >= N::MIN_ARRAY_ELEMENTS, "An array must have {} element", N::MIN_ARRAY_ELEMENTS);
@@ -16,10 +16,10 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{ArrayType, Identifier, LiteralType};
+use crate::{ArrayType, Identifier, LiteralType, VectorType};
 use snarkvm_console_network::prelude::*;
 
-/// A `PlaintextType` defines the type parameter for a literal, struct, or array.
+/// A `PlaintextType` defines the type parameter for a literal, struct, array, or vector.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum PlaintextType<N: Network> {
     /// A literal type contains its type name.
@@ -31,6 +31,9 @@ pub enum PlaintextType<N: Network> {
     /// An array type contains its element type and length.
     /// The format of the type is `[<element_type>; <length>]`.
     Array(ArrayType<N>),
+    /// A vector type contains its element type and maximum length.
+    /// The format of the type is `[<element_type>; <=<max_length>]`.
+    Vector(VectorType<N>),
 }
 
 impl<N: Network> From<LiteralType> for PlaintextType<N> {
@@ -53,3 +56,10 @@ impl<N: Network> From<ArrayType<N>> for PlaintextType<N> {
         PlaintextType::Array(array)
     }
 }
+
+impl<N: Network> From<VectorType<N>> for PlaintextType<N> {
+    /// Initializes a plaintext type from a vector type.
+    fn from(vector: VectorType<N>) -> Self {
+        PlaintextType::Vector(vector)
+    }
+}
@@ -41,6 +41,8 @@ impl<N: Network> FromBytes for StructType<N> {
                 return Err(error(format!("Duplicate identifier in struct '{name}'")));
             };
         }
+        // Ensure the struct only references itself through a vector.
+        Self::check_self_references(&name, &members).map_err(|e| error(e.to_string()))?;
 
         Ok(Self { name, members })
     }
@@ -41,6 +41,52 @@ impl<N: Network> StructType<N> {
     pub const fn members(&self) -> &IndexMap<Identifier<N>, PlaintextType<N>> {
         &self.members
     }
+
+    /// Returns `true` if the given member type is a vector of this struct, i.e. a self-reference.
+    #[inline]
+    pub fn is_recursive_member(&self, member_type: &PlaintextType<N>) -> bool {
+        match member_type {
+            PlaintextType::Vector(vector_type) => vector_type.element_type() == &PlaintextType::Struct(self.name),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of nested copies of this struct in each of its values, including itself and the padding.
+    /// Each self-referential vector is padded to its maximum length, up to the given recursion depth.
+    pub fn num_recursive_elements(&self, max_recursion_depth: usize) -> usize {
+        // Compute the number of copies of the struct nested directly in each copy.
+        let num_children = self
+            .members
+            .values()
+            .map(|member_type| match member_type {
+                PlaintextType::Vector(vector_type) if self.is_recursive_member(member_type) => {
+                    **vector_type.max_length() as usize
+                }
+                _ => 0,
+            })
+            .fold(0usize, |sum, num| sum.saturating_add(num));
+
+        // Sum the number of copies at each level of the recursion.
+        let (mut num_elements, mut num_level) = (1usize, 1usize);
+        for _ in 0..max_recursion_depth {
+            num_level = num_level.saturating_mul(num_children);
+            num_elements = num_elements.saturating_add(num_level);
+        }
+        num_elements
+    }
+
+    /// Ensures the struct only references itself through a vector, which ends in the empty sentinel at a fixed depth.
+    fn check_self_references(name: &Identifier<N>, members: &IndexMap<Identifier<N>, PlaintextType<N>>) -> Result<()> {
+        for (identifier, member_type) in members {
+            let is_direct_reference = match member_type {
+                PlaintextType::Struct(struct_name) => struct_name == name,
+                PlaintextType::Array(array_type) => array_type.base_element_type() == &PlaintextType::Struct(*name),
+                PlaintextType::Literal(..) | PlaintextType::Vector(..) => false,
+            };
+            ensure!(!is_direct_reference, "Member '{identifier}' in struct '{name}' may only reference it in a vector");
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> TypeName for StructType<N> {
@@ -67,10 +67,13 @@ impl<N: Network> Parser for StructType<N> {
             if members.len() > N::MAX_STRUCT_ENTRIES {
                 return Err(error("Failed to parse struct: too many members"));
             }
+            let members = IndexMap::from_iter(members.into_iter());
+            // Ensure the struct only references itself through a vector.
+            Self::check_self_references(&name, &members).map_err(|e| error(e.to_string()))?;
             Ok(members)
         })(string)?;
         // Return the struct.
-        Ok((string, Self { name, members: IndexMap::from_iter(members.into_iter()) }))
+        Ok((string, Self { name, members }))
     }
 }
 
@@ -175,6 +178,22 @@ struct message:
         assert!(struct_.is_err());
     }
 
+    #[test]
+    fn test_parse_recursive() -> Result<()> {
+        // A struct may reference itself through a vector.
+        let (_, struct_) =
+            StructType::<CurrentNetwork>::parse("struct node:\n    value as u64;\n    children as [node; <=2u32];")?;
+        assert!(!struct_.is_recursive_member(&PlaintextType::from_str("u64")?));
+        assert!(struct_.is_recursive_member(&PlaintextType::from_str("[node; <=2u32]")?));
+        assert!(!struct_.is_recursive_member(&PlaintextType::from_str("[leaf; <=2u32]")?));
+
+        // A struct may not reference itself directly, or through an array.
+        assert!(StructType::<CurrentNetwork>::parse("struct node:\n    value as u64;\n    next as node;").is_err());
+        let candidate = "struct node:\n    value as u64;\n    next as [node; 2u32];";
+        assert!(StructType::<CurrentNetwork>::parse(candidate).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         let expected = "struct message:\n    first as field;\n    second as field;";
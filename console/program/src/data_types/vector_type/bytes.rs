@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for VectorType<N> {
+    /// Reads a vector type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        let element_type = PlaintextType::read_le(&mut reader)?;
+        // Read the maximum length.
+        let max_length = U32::read_le(&mut reader)?;
+        // Construct the vector type.
+        Self::new(element_type, max_length).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network> ToBytes for VectorType<N> {
+    /// Writes a vector type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        self.element_type.write_le(&mut writer)?;
+        // Write the maximum length.
+        self.max_length.write_le(&mut writer)
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, PlaintextType, U32};
+use snarkvm_console_network::prelude::*;
+
+use core::fmt::{Debug, Display};
+
+/// A `VectorType` defines the element type and maximum length of a vector.
+///
+/// A vector is represented as a struct with a `length` member and an `elements` member,
+/// where the elements are padded to the maximum length, so that its circuit has a fixed shape.
+/// The elements past the length are ignored by the vector opcodes.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorType<N: Network> {
+    /// The element type.
+    element_type: Box<PlaintextType<N>>,
+    /// The maximum length of the vector.
+    max_length: U32<N>,
+}
+
+impl<N: Network> VectorType<N> {
+    /// Initializes a new vector type.
+    pub fn new(element_type: PlaintextType<N>, max_length: U32<N>) -> Result<Self> {
+        // Ensure the element type is a literal or a struct.
+        ensure!(
+            matches!(element_type, PlaintextType::Literal(..) | PlaintextType::Struct(..)),
+            "A vector element must be a literal or a struct, found '{element_type}'"
+        );
+        // Ensure the maximum length is valid.
+        ensure!(*max_length as usize >= N::MIN_ARRAY_ELEMENTS, "A vector must have {} element", N::MIN_ARRAY_ELEMENTS);
+        ensure!(
+            *max_length as usize <= N::MAX_ARRAY_ELEMENTS,
+            "A vector can contain {} elements",
+            N::MAX_ARRAY_ELEMENTS
+        );
+        Ok(Self { element_type: Box::new(element_type), max_length })
+    }
+}
+
+impl<N: Network> VectorType<N> {
+    /// Returns the element type.
+    pub const fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the maximum length of the vector.
+    pub const fn max_length(&self) -> &U32<N> {
+        &self.max_length
+    }
+
+    /// Returns the name of the member that contains the length of a vector.
+    pub fn length_member() -> Identifier<N> {
+        // Note: This unwrap is safe, as the identifier is valid.
+        Identifier::from_str("length").unwrap()
+    }
+
+    /// Returns the name of the member that contains the elements of a vector.
+    pub fn elements_member() -> Identifier<N> {
+        // Note: This unwrap is safe, as the identifier is valid.
+        Identifier::from_str("elements").unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LiteralType;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_vector_type() -> Result<()> {
+        // Test a literal vector type.
+        let vector = VectorType::<CurrentNetwork>::from_str("[field; <=32u32]")?;
+        assert_eq!(vector, VectorType::new(PlaintextType::from_str("field")?, U32::new(32))?);
+        assert_eq!(vector, VectorType::from_bytes_le(&vector.to_bytes_le()?)?);
+        assert_eq!(vector.element_type(), &PlaintextType::Literal(LiteralType::Field));
+        assert_eq!(vector.max_length(), &U32::new(32));
+        assert_eq!(vector.to_string(), "[field; <=32u32]");
+
+        // Test a struct vector type.
+        let vector = VectorType::<CurrentNetwork>::from_str("[ foo ;  <= 4u32 ]")?;
+        assert_eq!(vector, VectorType::new(PlaintextType::from_str("foo")?, U32::new(4))?);
+        assert_eq!(vector, VectorType::from_bytes_le(&vector.to_bytes_le()?)?);
+        assert_eq!(vector.element_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+        assert_eq!(vector.to_string(), "[foo; <=4u32]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_type_fails() {
+        // The maximum length must be within bounds.
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=0u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=33u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=4u8]").is_err());
+        // A vector must have a maximum length, rather than a length.
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; 4u32]").is_err());
+        // The elements cannot be arrays or vectors.
+        assert!(VectorType::<CurrentNetwork>::from_str("[[field; 2u32]; <=4u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[[field; <=2u32]; <=4u32]").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::LiteralType;
+
+impl<N: Network> Parser for VectorType<N> {
+    /// Parses a string into a vector type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // A helper function to parse the element type.
+        fn parse_element_type<N: Network>(string: &str) -> ParserResult<PlaintextType<N>> {
+            alt((map(LiteralType::parse, PlaintextType::from), map(Identifier::parse, PlaintextType::from)))(string)
+        }
+
+        // A helper function to parse the maximum length.
+        fn parse_max_length<N: Network>(string: &str) -> ParserResult<U32<N>> {
+            // Parse the whitespaces from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon from the string.
+            let (string, _) = tag(";")(string)?;
+            // Parse the whitespaces from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "<=" from the string.
+            let (string, _) = tag("<=")(string)?;
+            // Parse the whitespaces from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the maximum length.
+            let (string, max_length) = U32::parse(string)?;
+            // Parse the whitespaces from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the closing bracket.
+            let (string, _) = tag("]")(string)?;
+            // Return the maximum length.
+            Ok((string, max_length))
+        }
+
+        // Parse the opening bracket from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespaces from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type and the maximum length, and return the vector type.
+        map_res(pair(parse_element_type, parse_max_length), |(element_type, max_length)| {
+            VectorType::new(element_type, max_length)
+        })(string)
+    }
+}
+
+impl<N: Network> FromStr for VectorType<N> {
+    type Err = Error;
+
+    /// Returns a vector type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for VectorType<N> {
+    /// Prints the vector type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for VectorType<N> {
+    /// Prints the vector type as a string, i.e. `[field; <=32u32]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}; <={}]", self.element_type(), self.max_length())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for VectorType<N> {
+    /// Serializes the vector type into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for VectorType<N> {
+    /// Deserializes the vector type from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "vector type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        for case in ["[field; <=32u32]", "[u8; <=1u32]", "[foo; <=4u32]"] {
+            let expected = VectorType::<CurrentNetwork>::from_str(case)?;
+            // Serialize
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(case, serde_json::Value::from_str(&candidate_string)?.as_str().unwrap());
+            // Deserialize
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        for case in ["[field; <=32u32]", "[u8; <=1u32]", "[foo; <=4u32]"] {
+            let expected = VectorType::<CurrentNetwork>::from_str(case)?;
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+            // Deserialize
+            assert_eq!(expected, VectorType::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
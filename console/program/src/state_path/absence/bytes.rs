@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for AbsenceProof<N> {
+    /// Reads the absence proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid absence proof version"));
+        }
+
+        // Read the absence proof.
+        let serial_numbers_root = Field::read_le(&mut reader)?;
+        let serial_number = Field::read_le(&mut reader)?;
+        let lower = Field::read_le(&mut reader)?;
+        let lower_path = SerialNumbersPath::read_le(&mut reader)?;
+        let upper = Field::read_le(&mut reader)?;
+        let upper_path = SerialNumbersPath::read_le(&mut reader)?;
+
+        // Construct the absence proof.
+        Self::from(serial_numbers_root, serial_number, lower, lower_path, upper, upper_path).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for AbsenceProof<N> {
+    /// Writes the absence proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the absence proof.
+        self.serial_numbers_root.write_le(&mut writer)?;
+        self.serial_number.write_le(&mut writer)?;
+        self.lower.write_le(&mut writer)?;
+        self.lower_path.write_le(&mut writer)?;
+        self.upper.write_le(&mut writer)?;
+        self.upper_path.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the absence proof.
+            let expected = test_helpers::sample_absence_proof::<CurrentNetwork>(&mut rng).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, AbsenceProof::read_le(&expected_bytes[..]).unwrap());
+            assert!(AbsenceProof::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+
+/// The absence proof shows that a serial number is not in a sorted set of serial numbers,
+/// by opening the two adjacent leaves of the serial numbers tree that enclose it.
+///
+/// The serial numbers tree is padded with a lower sentinel leaf of `0` and an upper sentinel
+/// leaf of `-1`, so that every other serial number is enclosed by two adjacent leaves.
+/// The proof is only meaningful for a serial numbers root that the verifier trusts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbsenceProof<N: Network> {
+    /// The serial numbers root (Public).
+    serial_numbers_root: Field<N>,
+    /// The serial number that is absent.
+    serial_number: Field<N>,
+    /// The greatest serial number in the tree that is less than the absent serial number.
+    lower: Field<N>,
+    /// The Merkle path for the lower serial number.
+    lower_path: SerialNumbersPath<N>,
+    /// The least serial number in the tree that is greater than the absent serial number.
+    upper: Field<N>,
+    /// The Merkle path for the upper serial number.
+    upper_path: SerialNumbersPath<N>,
+}
+
+impl<N: Network> AbsenceProof<N> {
+    /// Initializes a new instance of `AbsenceProof`.
+    ///
+    /// This does not check the absence proof; use `AbsenceProof::verify` to check it.
+    pub fn from(
+        serial_numbers_root: Field<N>,
+        serial_number: Field<N>,
+        lower: Field<N>,
+        lower_path: SerialNumbersPath<N>,
+        upper: Field<N>,
+        upper_path: SerialNumbersPath<N>,
+    ) -> Result<Self> {
+        // Ensure the leaves are adjacent.
+        ensure!(
+            lower_path.leaf_index().checked_add(1) == Some(*upper_path.leaf_index()),
+            "The lower leaf index '{}' and upper leaf index '{}' are not adjacent",
+            lower_path.leaf_index(),
+            upper_path.leaf_index()
+        );
+        // Return the absence proof.
+        Ok(Self { serial_numbers_root, serial_number, lower, lower_path, upper, upper_path })
+    }
+
+    /// Returns the absence proof for the given serial number, in the tree of the given serial numbers.
+    ///
+    /// This builds the full serial numbers tree, so its cost is linear in the number of serial numbers.
+    pub fn prove(serial_numbers: impl IntoIterator<Item = Field<N>>, serial_number: &Field<N>) -> Result<Self> {
+        let (lower_sentinel, upper_sentinel) = Self::sentinels();
+        // Ensure the serial number is not a sentinel.
+        ensure!(
+            lower_sentinel < *serial_number && *serial_number < upper_sentinel,
+            "Cannot prove the absence of a sentinel serial number"
+        );
+
+        // Sort the serial numbers, and remove any duplicates or sentinels.
+        let mut serial_numbers = serial_numbers
+            .into_iter()
+            .filter(|candidate| lower_sentinel < *candidate && *candidate < upper_sentinel)
+            .collect::<Vec<_>>();
+        serial_numbers.sort_unstable();
+        serial_numbers.dedup();
+
+        // Find the position of the serial number, ensuring it is absent.
+        let position = match serial_numbers.binary_search(serial_number) {
+            Ok(_) => bail!("Serial number '{serial_number}' is in the serial numbers tree"),
+            Err(position) => position,
+        };
+
+        // Enclose the serial numbers with the sentinels.
+        let serial_numbers = core::iter::once(lower_sentinel)
+            .chain(serial_numbers)
+            .chain(core::iter::once(upper_sentinel))
+            .collect::<Vec<_>>();
+        // Construct the serial numbers tree.
+        let leaves = serial_numbers.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>();
+        let tree = N::merkle_tree_bhp::<SERIAL_NUMBERS_DEPTH>(&leaves)?;
+
+        // The lower leaf is at the position of the serial number, as the lower sentinel is prepended.
+        let (lower_index, upper_index) = (position, position + 1);
+        let lower_path = tree.prove(lower_index, &leaves[lower_index])?;
+        let upper_path = tree.prove(upper_index, &leaves[upper_index])?;
+
+        Self::from(
+            *tree.root(),
+            *serial_number,
+            serial_numbers[lower_index],
+            lower_path,
+            serial_numbers[upper_index],
+            upper_path,
+        )
+    }
+
+    /// Returns the lower and upper sentinel leaves of the serial numbers tree.
+    pub fn sentinels() -> (Field<N>, Field<N>) {
+        (Field::zero(), -Field::one())
+    }
+
+    /// Returns the serial numbers root.
+    pub const fn serial_numbers_root(&self) -> &Field<N> {
+        &self.serial_numbers_root
+    }
+
+    /// Returns the absent serial number.
+    pub const fn serial_number(&self) -> &Field<N> {
+        &self.serial_number
+    }
+
+    /// Returns the lower serial number.
+    pub const fn lower(&self) -> &Field<N> {
+        &self.lower
+    }
+
+    /// Returns the Merkle path for the lower serial number.
+    pub const fn lower_path(&self) -> &SerialNumbersPath<N> {
+        &self.lower_path
+    }
+
+    /// Returns the upper serial number.
+    pub const fn upper(&self) -> &Field<N> {
+        &self.upper
+    }
+
+    /// Returns the Merkle path for the upper serial number.
+    pub const fn upper_path(&self) -> &SerialNumbersPath<N> {
+        &self.upper_path
+    }
+
+    /// Checks if the absence proof is valid for its serial numbers root.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the serial number is enclosed by the lower and upper serial numbers.
+        ensure!(
+            self.lower < self.serial_number && self.serial_number < self.upper,
+            "Serial number '{}' is not between '{}' and '{}'",
+            self.serial_number,
+            self.lower,
+            self.upper
+        );
+        // Ensure the leaves are adjacent.
+        ensure!(
+            self.lower_path.leaf_index().checked_add(1) == Some(*self.upper_path.leaf_index()),
+            "The lower leaf index '{}' and upper leaf index '{}' are not adjacent",
+            self.lower_path.leaf_index(),
+            self.upper_path.leaf_index()
+        );
+        // Ensure the lower path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.lower_path, &self.serial_numbers_root, &self.lower.to_bits_le()),
+            "'{}' (a serial number) does not belong to '{}' (a serial numbers root)",
+            self.lower,
+            self.serial_numbers_root
+        );
+        // Ensure the upper path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.upper_path, &self.serial_numbers_root, &self.upper.to_bits_le()),
+            "'{}' (a serial number) does not belong to '{}' (a serial numbers root)",
+            self.upper,
+            self.serial_numbers_root
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// Randomly sample an absence proof over a random set of serial numbers.
+    pub fn sample_absence_proof<N: Network>(rng: &mut TestRng) -> Result<AbsenceProof<N>> {
+        let serial_numbers = (0..rng.gen_range(0..16)).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+        AbsenceProof::prove(serial_numbers, &Field::rand(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_verify() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the serial numbers.
+            let serial_numbers = (0..16).map(|_| Field::<CurrentNetwork>::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure an absent serial number has a valid absence proof.
+            let serial_number = Field::rand(&mut rng);
+            let proof = AbsenceProof::prove(serial_numbers.clone(), &serial_number).unwrap();
+            proof.verify().unwrap();
+            assert_eq!(*proof.serial_number(), serial_number);
+
+            // Ensure every serial number in the tree has no absence proof.
+            for serial_number in &serial_numbers {
+                AbsenceProof::prove(serial_numbers.clone(), serial_number).unwrap_err();
+            }
+
+            // Ensure the lower serial number cannot be claimed absent with the same openings.
+            let candidate = AbsenceProof { serial_number: proof.lower, ..proof.clone() };
+            candidate.verify().unwrap_err();
+
+            // Ensure a different serial numbers root is invalid.
+            let candidate = AbsenceProof { serial_numbers_root: Field::rand(&mut rng), ..proof.clone() };
+            candidate.verify().unwrap_err();
+
+            // Ensure non-adjacent openings are invalid.
+            let candidate = AbsenceProof { upper_path: proof.lower_path.clone(), ..proof };
+            candidate.verify().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_prove_empty_and_sentinels() {
+        let mut rng = TestRng::default();
+        let (lower_sentinel, upper_sentinel) = AbsenceProof::<CurrentNetwork>::sentinels();
+
+        // Ensure the empty set has an absence proof between the sentinels.
+        let proof = AbsenceProof::<CurrentNetwork>::prove(vec![], &Field::rand(&mut rng)).unwrap();
+        proof.verify().unwrap();
+        assert_eq!(*proof.lower(), lower_sentinel);
+        assert_eq!(*proof.upper(), upper_sentinel);
+
+        // Ensure the sentinels have no absence proof.
+        AbsenceProof::<CurrentNetwork>::prove(vec![], &lower_sentinel).unwrap_err();
+        AbsenceProof::<CurrentNetwork>::prove(vec![], &upper_sentinel).unwrap_err();
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> StatePath<N> {
+    /// Returns `true` if the transition ID in the state path is in the given set of known transition IDs.
+    ///
+    /// This is a cheap admission check, which performs **no** Merkle path verification.
+    /// It is **not** a substitute for `StatePath::verify`, which must still be called
+    /// before the state path is trusted.
+    pub fn quick_admit(&self, known_transition_ids: &HashSet<Field<N>>) -> bool {
+        // Note: The transaction leaf ID is the transition ID.
+        known_transition_ids.contains(&self.transaction_leaf.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_quick_admit() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Sample unrelated transition IDs.
+            let mut known_transition_ids: HashSet<_> = (0..10).map(|_| Field::rand(&mut rng)).collect();
+            // Ensure the state path is not admitted.
+            assert!(!state_path.quick_admit(&known_transition_ids));
+
+            // Add the transition ID of the state path.
+            known_transition_ids.insert(state_path.transaction_leaf().id());
+            // Ensure the state path is admitted.
+            assert!(state_path.quick_admit(&known_transition_ids));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the anchor digest of the state path, as `Hash( global_state_root || transaction_id || transition_leaf.id )`.
+    ///
+    /// The anchor digest uniquely identifies the position the state path anchors to,
+    /// and is suitable as a key for indexing state paths.
+    pub fn anchor_digest(&self) -> Field<N> {
+        // Note: The preimage is always three field elements, which is within the input size of BHP1024,
+        // so the hash can not fail.
+        N::hash_bhp1024(&to_bits_le![(*self.global_state_root), (*self.transaction_id), self.transition_leaf.id()])
+            .expect("The anchor digest preimage must be within the input size of BHP1024")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_anchor_digest() {
+        let mut rng = TestRng::default();
+
+        let mut digests = HashSet::new();
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure equal state paths have equal digests.
+            let digest = state_path.anchor_digest();
+            assert_eq!(digest, state_path.clone().anchor_digest());
+            // Ensure the digest is unique.
+            assert!(digests.insert(digest));
+        }
+        assert_eq!(digests.len(), ITERATIONS);
+    }
+
+    #[test]
+    fn test_anchor_digest_differs_per_anchor_field() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let digest = state_path.anchor_digest();
+
+        // Returns the state path with the given anchor fields.
+        let with_anchor = |global_state_root, transaction_id, transition_leaf| {
+            StatePath::<CurrentNetwork>::from(
+                global_state_root,
+                state_path.block_path().clone(),
+                state_path.block_hash(),
+                state_path.previous_block_hash(),
+                *state_path.header_root(),
+                state_path.header_path().clone(),
+                *state_path.header_leaf(),
+                state_path.transactions_path().clone(),
+                transaction_id,
+                state_path.transaction_path().clone(),
+                *state_path.transaction_leaf(),
+                *state_path.transition_root(),
+                *state_path.tcm(),
+                state_path.transition_path().clone(),
+                transition_leaf,
+            )
+            .unwrap()
+        };
+
+        let transition_leaf = *state_path.transition_leaf();
+        let random_transition_leaf = TransitionLeaf::from(
+            transition_leaf.version(),
+            transition_leaf.index(),
+            transition_leaf.variant(),
+            Field::rand(&mut rng),
+        );
+
+        // Ensure changing any anchor field changes the digest.
+        let candidate =
+            with_anchor(Field::<CurrentNetwork>::rand(&mut rng).into(), *state_path.transaction_id(), transition_leaf);
+        assert_ne!(digest, candidate.anchor_digest());
+        let candidate = with_anchor(
+            state_path.global_state_root(),
+            Field::<CurrentNetwork>::rand(&mut rng).into(),
+            transition_leaf,
+        );
+        assert_ne!(digest, candidate.anchor_digest());
+        let candidate =
+            with_anchor(state_path.global_state_root(), *state_path.transaction_id(), random_transition_leaf);
+        assert_ne!(digest, candidate.anchor_digest());
+    }
+}
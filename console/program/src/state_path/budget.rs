@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when verifying a state path requires more hashes than the given budget.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BudgetExceeded {
+    /// The maximum number of hashes allowed.
+    pub max_hashes: usize,
+}
+
+impl Display for BudgetExceeded {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Verifying the state path exceeded the budget of {} hashes", self.max_hashes)
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+impl<N: Network> StatePath<N> {
+    /// The number of BHP hashes required to verify a valid state path to its global state root,
+    /// which is the smallest budget for which `StatePath::verify_with_budget` succeeds.
+    ///
+    /// Each Merkle path requires one leaf hash and one hash per level, and the transaction leaf ID
+    /// and block hash each require one hash.
+    pub const NUM_VERIFY_HASHES: usize = (1 + TRANSITION_DEPTH as usize)
+        + 1
+        + (1 + TRANSACTION_DEPTH as usize)
+        + (1 + TRANSACTIONS_DEPTH as usize)
+        + (1 + HEADER_DEPTH as usize)
+        + 1
+        + (1 + BLOCKS_DEPTH as usize);
+
+    /// Checks if the state path is valid for its global state root, using at most `max_hashes` hashes.
+    ///
+    /// Each BHP hash is counted as the state path is verified, and the verification aborts with a
+    /// `BudgetExceeded` error once the budget is exhausted.
+    pub fn verify_with_budget(&self, max_hashes: usize) -> Result<()> {
+        // Initialize a hasher, which fails every hash after the budget is exhausted.
+        let hasher = N::bhp_merkle_hasher().with_budget(max_hashes);
+        // Verify the state path, counting each hash as it is computed.
+        let result = self.verify_with_hasher(&hasher, true, Field::zero());
+        // If the budget was exhausted, return the budget error in place of the verification error.
+        match hasher.is_budget_exceeded() {
+            true => Err(BudgetExceeded { max_hashes }.into()),
+            false => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_with_budget() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let required = StatePath::<CurrentNetwork>::NUM_VERIFY_HASHES;
+        assert_eq!(required, 68);
+
+        // Ensure the number of hashes counted during verification is the number required.
+        let hasher = CurrentNetwork::bhp_merkle_hasher().with_budget(usize::MAX);
+        state_path.verify_with_hasher(&hasher, true, Field::zero()).unwrap();
+        assert_eq!(hasher.num_hashes(), required);
+
+        // Ensure a budget that is too small aborts.
+        let error = state_path.verify_with_budget(required - 1).unwrap_err();
+        assert_eq!(error.downcast_ref::<BudgetExceeded>(), Some(&BudgetExceeded { max_hashes: required - 1 }));
+        let error = state_path.verify_with_budget(0).unwrap_err();
+        assert_eq!(error.downcast_ref::<BudgetExceeded>(), Some(&BudgetExceeded { max_hashes: 0 }));
+
+        // Ensure a budget that is large enough succeeds.
+        state_path.verify_with_budget(required).unwrap();
+        state_path.verify_with_budget(usize::MAX).unwrap();
+    }
+
+    #[test]
+    fn test_verify_with_budget_rejects_invalid_path() {
+        let mut rng = TestRng::default();
+
+        // Sample a local state path, which does not verify to a global state root.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure the invalid state path is rejected, and not as a budget error.
+        let error = state_path.verify_with_budget(usize::MAX).unwrap_err();
+        assert!(error.downcast_ref::<BudgetExceeded>().is_none());
+    }
+}
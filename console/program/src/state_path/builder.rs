@@ -0,0 +1,449 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A problem with the fields of a state path, as reported by `StatePathBuilder::validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatePathError<N: Network> {
+    /// The field with the given name is missing.
+    MissingField(&'static str),
+    /// The transition leaf variant is out of range.
+    InvalidTransitionLeafVariant(u8),
+    /// The index of the given leaf is out of bounds for the depth of its Merkle tree.
+    OutOfBoundsLeafIndex { leaf: &'static str, index: u8 },
+    /// The transaction leaf index differs from the leaf index of the transaction path.
+    MismatchedTransactionLeafIndex { leaf_index: u8, path_index: u64 },
+    /// The block hash equals its previous block hash.
+    SelfReferentialBlockHash(N::BlockHash),
+    /// The given segment is invalid.
+    InvalidSegment(VerificationPlan),
+}
+
+impl<N: Network> Display for StatePathError<N> {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingField(name) => write!(f, "The state path field '{name}' is missing"),
+            Self::InvalidTransitionLeafVariant(variant) => {
+                write!(f, "Transition leaf variant '{variant}' is out of range")
+            }
+            Self::OutOfBoundsLeafIndex { leaf, index } => write!(f, "The {leaf} leaf index '{index}' is out of bounds"),
+            Self::MismatchedTransactionLeafIndex { leaf_index, path_index } => write!(
+                f,
+                "Transaction leaf index '{leaf_index}' does not match the transaction path leaf index '{path_index}'"
+            ),
+            Self::SelfReferentialBlockHash(block_hash) => {
+                write!(f, "Block hash '{block_hash}' must not equal its previous block hash")
+            }
+            Self::InvalidSegment(segment) => {
+                let name = match *segment {
+                    VerificationPlan::TRANSITION => "transition",
+                    VerificationPlan::TRANSACTION => "transaction",
+                    VerificationPlan::TRANSACTIONS => "transactions",
+                    VerificationPlan::HEADER => "header",
+                    VerificationPlan::BLOCK_HASH => "block hash",
+                    VerificationPlan::BLOCK_PATH => "block path",
+                    _ => "unknown",
+                };
+                write!(f, "The {name} segment of the state path is invalid")
+            }
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for StatePathError<N> {}
+
+/// A builder for a state path, which assembles the state path one field at a time.
+#[derive(Clone)]
+pub struct StatePathBuilder<N: Network> {
+    /// The global state root.
+    global_state_root: Option<N::StateRoot>,
+    /// The Merkle path for the block hash.
+    block_path: Option<BlockPath<N>>,
+    /// The block hash.
+    block_hash: Option<N::BlockHash>,
+    /// The previous block hash.
+    previous_block_hash: Option<N::BlockHash>,
+    /// The block header root.
+    header_root: Option<Field<N>>,
+    /// The Merkle path for the block header leaf.
+    header_path: Option<HeaderPath<N>>,
+    /// The block header leaf.
+    header_leaf: Option<HeaderLeaf<N>>,
+    /// The Merkle path for the transaction ID.
+    transactions_path: Option<TransactionsPath<N>>,
+    /// The transaction ID.
+    transaction_id: Option<N::TransactionID>,
+    /// The Merkle path for the transaction leaf.
+    transaction_path: Option<TransactionPath<N>>,
+    /// The transaction leaf.
+    transaction_leaf: Option<TransactionLeaf<N>>,
+    /// The transition root.
+    transition_root: Option<Field<N>>,
+    /// The transition commitment.
+    tcm: Option<Field<N>>,
+    /// The Merkle path for the transition leaf.
+    transition_path: Option<TransitionPath<N>>,
+    /// The transition leaf.
+    transition_leaf: Option<TransitionLeaf<N>>,
+}
+
+impl<N: Network> Default for StatePathBuilder<N> {
+    /// Returns a builder with no fields provided.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> StatePathBuilder<N> {
+    /// Initializes a new builder, with no fields provided.
+    pub const fn new() -> Self {
+        Self {
+            global_state_root: None,
+            block_path: None,
+            block_hash: None,
+            previous_block_hash: None,
+            header_root: None,
+            header_path: None,
+            header_leaf: None,
+            transactions_path: None,
+            transaction_id: None,
+            transaction_path: None,
+            transaction_leaf: None,
+            transition_root: None,
+            tcm: None,
+            transition_path: None,
+            transition_leaf: None,
+        }
+    }
+
+    /// Sets the global state root.
+    pub fn global_state_root(mut self, global_state_root: N::StateRoot) -> Self {
+        self.global_state_root = Some(global_state_root);
+        self
+    }
+
+    /// Sets the Merkle path for the block hash.
+    pub fn block_path(mut self, block_path: BlockPath<N>) -> Self {
+        self.block_path = Some(block_path);
+        self
+    }
+
+    /// Sets the block hash.
+    pub fn block_hash(mut self, block_hash: N::BlockHash) -> Self {
+        self.block_hash = Some(block_hash);
+        self
+    }
+
+    /// Sets the previous block hash.
+    pub fn previous_block_hash(mut self, previous_block_hash: N::BlockHash) -> Self {
+        self.previous_block_hash = Some(previous_block_hash);
+        self
+    }
+
+    /// Sets the block header root.
+    pub fn header_root(mut self, header_root: Field<N>) -> Self {
+        self.header_root = Some(header_root);
+        self
+    }
+
+    /// Sets the Merkle path for the block header leaf.
+    pub fn header_path(mut self, header_path: HeaderPath<N>) -> Self {
+        self.header_path = Some(header_path);
+        self
+    }
+
+    /// Sets the block header leaf.
+    pub fn header_leaf(mut self, header_leaf: HeaderLeaf<N>) -> Self {
+        self.header_leaf = Some(header_leaf);
+        self
+    }
+
+    /// Sets the Merkle path for the transaction ID.
+    pub fn transactions_path(mut self, transactions_path: TransactionsPath<N>) -> Self {
+        self.transactions_path = Some(transactions_path);
+        self
+    }
+
+    /// Sets the transaction ID.
+    pub fn transaction_id(mut self, transaction_id: N::TransactionID) -> Self {
+        self.transaction_id = Some(transaction_id);
+        self
+    }
+
+    /// Sets the Merkle path for the transaction leaf.
+    pub fn transaction_path(mut self, transaction_path: TransactionPath<N>) -> Self {
+        self.transaction_path = Some(transaction_path);
+        self
+    }
+
+    /// Sets the transaction leaf.
+    pub fn transaction_leaf(mut self, transaction_leaf: TransactionLeaf<N>) -> Self {
+        self.transaction_leaf = Some(transaction_leaf);
+        self
+    }
+
+    /// Sets the transition root.
+    pub fn transition_root(mut self, transition_root: Field<N>) -> Self {
+        self.transition_root = Some(transition_root);
+        self
+    }
+
+    /// Sets the transition commitment.
+    pub fn tcm(mut self, tcm: Field<N>) -> Self {
+        self.tcm = Some(tcm);
+        self
+    }
+
+    /// Sets the Merkle path for the transition leaf.
+    pub fn transition_path(mut self, transition_path: TransitionPath<N>) -> Self {
+        self.transition_path = Some(transition_path);
+        self
+    }
+
+    /// Sets the transition leaf.
+    pub fn transition_leaf(mut self, transition_leaf: TransitionLeaf<N>) -> Self {
+        self.transition_leaf = Some(transition_leaf);
+        self
+    }
+
+    /// Returns the state path, if every field is provided.
+    ///
+    /// This performs the checks of `StatePath::from`, and does not check the state path.
+    /// Use `StatePathBuilder::validate` to report every problem at once.
+    pub fn build(&self) -> Result<StatePath<N>> {
+        /// Returns the given field, if it is provided.
+        fn required<T: Clone>(field: &Option<T>, name: &str) -> Result<T> {
+            field.clone().ok_or_else(|| anyhow!("The state path field '{name}' is missing"))
+        }
+
+        StatePath::from(
+            required(&self.global_state_root, "global_state_root")?,
+            required(&self.block_path, "block_path")?,
+            required(&self.block_hash, "block_hash")?,
+            required(&self.previous_block_hash, "previous_block_hash")?,
+            required(&self.header_root, "header_root")?,
+            required(&self.header_path, "header_path")?,
+            required(&self.header_leaf, "header_leaf")?,
+            required(&self.transactions_path, "transactions_path")?,
+            required(&self.transaction_id, "transaction_id")?,
+            required(&self.transaction_path, "transaction_path")?,
+            required(&self.transaction_leaf, "transaction_leaf")?,
+            required(&self.transition_root, "transition_root")?,
+            required(&self.tcm, "tcm")?,
+            required(&self.transition_path, "transition_path")?,
+            required(&self.transition_leaf, "transition_leaf")?,
+        )
+    }
+
+    /// Returns every problem with the provided fields, without stopping at the first problem.
+    ///
+    /// This reports each missing field, each inconsistent leaf index, and each segment whose fields are all
+    /// provided and do not verify. A segment with a missing field is not checked. An empty list does not imply
+    /// that the state path is valid, as the links between the segments are only checked by `StatePath::verify`.
+    pub fn validate(&self) -> Vec<StatePathError<N>> {
+        let mut errors = Vec::new();
+
+        // Ensure every field is provided.
+        let provided = [
+            ("global_state_root", self.global_state_root.is_some()),
+            ("block_path", self.block_path.is_some()),
+            ("block_hash", self.block_hash.is_some()),
+            ("previous_block_hash", self.previous_block_hash.is_some()),
+            ("header_root", self.header_root.is_some()),
+            ("header_path", self.header_path.is_some()),
+            ("header_leaf", self.header_leaf.is_some()),
+            ("transactions_path", self.transactions_path.is_some()),
+            ("transaction_id", self.transaction_id.is_some()),
+            ("transaction_path", self.transaction_path.is_some()),
+            ("transaction_leaf", self.transaction_leaf.is_some()),
+            ("transition_root", self.transition_root.is_some()),
+            ("tcm", self.tcm.is_some()),
+            ("transition_path", self.transition_path.is_some()),
+            ("transition_leaf", self.transition_leaf.is_some()),
+        ];
+        errors.extend(
+            provided
+                .into_iter()
+                .filter(|(_, is_provided)| !is_provided)
+                .map(|(name, _)| StatePathError::MissingField(name)),
+        );
+
+        // Ensure the leaves are consistent with their Merkle trees.
+        if let Some(transition_leaf) = &self.transition_leaf {
+            if !transition_leaf.has_valid_variant() {
+                errors.push(StatePathError::InvalidTransitionLeafVariant(transition_leaf.variant()));
+            }
+            if (transition_leaf.index() as u128) >= (1u128 << TRANSITION_DEPTH) {
+                errors
+                    .push(StatePathError::OutOfBoundsLeafIndex { leaf: "transition", index: transition_leaf.index() });
+            }
+        }
+        if let (Some(transaction_leaf), Some(transaction_path)) = (&self.transaction_leaf, &self.transaction_path) {
+            if *transaction_path.leaf_index() != transaction_leaf.index() as u64 {
+                errors.push(StatePathError::MismatchedTransactionLeafIndex {
+                    leaf_index: transaction_leaf.index(),
+                    path_index: *transaction_path.leaf_index(),
+                });
+            }
+        }
+        if let Some(header_leaf) = &self.header_leaf {
+            if (header_leaf.index() as u128) >= (1u128 << HEADER_DEPTH) {
+                errors.push(StatePathError::OutOfBoundsLeafIndex { leaf: "header", index: header_leaf.index() });
+            }
+        }
+        if let (Some(block_hash), Some(previous_block_hash)) = (&self.block_hash, &self.previous_block_hash) {
+            if block_hash == previous_block_hash && **block_hash != Field::zero() {
+                errors.push(StatePathError::SelfReferentialBlockHash(*block_hash));
+            }
+        }
+
+        // Ensure each segment with every field provided is valid.
+        if let (Some(path), Some(root), Some(leaf)) =
+            (&self.transition_path, &self.transition_root, &self.transition_leaf)
+        {
+            if !N::verify_merkle_path_bhp(path, root, &leaf.to_bits_le()) {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::TRANSITION));
+            }
+        }
+        if let (Some(path), Some(transaction_id), Some(leaf)) =
+            (&self.transaction_path, &self.transaction_id, &self.transaction_leaf)
+        {
+            // Ensure the transaction leaf ID is derived from the transition root and tcm, if both are provided.
+            let is_leaf_valid = match (&self.transition_root, &self.tcm) {
+                (Some(transition_root), Some(tcm)) => {
+                    N::hash_bhp512(&(*transition_root, *tcm).to_bits_le()).is_ok_and(|id| *leaf.id() == *id)
+                }
+                _ => true,
+            };
+            if !is_leaf_valid || !N::verify_merkle_path_bhp(path, transaction_id, &leaf.to_bits_le()) {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::TRANSACTION));
+            }
+        }
+        if let (Some(path), Some(transaction_id), Some(header_leaf)) =
+            (&self.transactions_path, &self.transaction_id, &self.header_leaf)
+        {
+            if header_leaf.index_name() != Some(HeaderLeafIndex::TransactionsRoot)
+                || !N::verify_merkle_path_bhp(path, &header_leaf.id(), &transaction_id.to_bits_le())
+            {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::TRANSACTIONS));
+            }
+        }
+        if let (Some(path), Some(root), Some(leaf)) = (&self.header_path, &self.header_root, &self.header_leaf) {
+            if !N::verify_merkle_path_bhp(path, root, &leaf.to_bits_le()) {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::HEADER));
+            }
+        }
+        if let (Some(block_hash), Some(previous_block_hash), Some(header_root)) =
+            (&self.block_hash, &self.previous_block_hash, &self.header_root)
+        {
+            if !ChainedBlockHash::block_hash(previous_block_hash, header_root)
+                .is_ok_and(|expected| *block_hash == expected)
+            {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::BLOCK_HASH));
+            }
+        }
+        if let (Some(path), Some(root), Some(block_hash)) =
+            (&self.block_path, &self.global_state_root, &self.block_hash)
+        {
+            if !N::verify_merkle_path_bhp(path, root, &block_hash.to_bits_le()) {
+                errors.push(StatePathError::InvalidSegment(VerificationPlan::BLOCK_PATH));
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a builder with every field of the given state path.
+    fn builder_for(state_path: &StatePath<CurrentNetwork>) -> StatePathBuilder<CurrentNetwork> {
+        StatePathBuilder::new()
+            .global_state_root(state_path.global_state_root())
+            .block_path(state_path.block_path().clone())
+            .block_hash(state_path.block_hash())
+            .previous_block_hash(state_path.previous_block_hash())
+            .header_root(*state_path.header_root())
+            .header_path(state_path.header_path().clone())
+            .header_leaf(*state_path.header_leaf())
+            .transactions_path(state_path.transactions_path().clone())
+            .transaction_id(*state_path.transaction_id())
+            .transaction_path(state_path.transaction_path().clone())
+            .transaction_leaf(*state_path.transaction_leaf())
+            .transition_root(*state_path.transition_root())
+            .tcm(*state_path.tcm())
+            .transition_path(state_path.transition_path().clone())
+            .transition_leaf(*state_path.transition_leaf())
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a builder with every valid field reports no problems, and builds the state path.
+        let builder = builder_for(&state_path);
+        assert!(builder.validate().is_empty());
+        assert_eq!(builder.build().unwrap(), state_path);
+
+        // Ensure an empty builder reports every field as missing, and does not build.
+        let errors = StatePathBuilder::<CurrentNetwork>::new().validate();
+        assert_eq!(errors.len(), 15);
+        assert!(errors.iter().all(|error| matches!(error, StatePathError::MissingField(_))));
+        let error = StatePathBuilder::<CurrentNetwork>::new().build().unwrap_err();
+        assert!(error.to_string().contains("'global_state_root' is missing"));
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Construct a builder with two independent problems: a missing tcm, and an invalid global state root.
+        let mut builder = builder_for(&state_path).global_state_root(Field::rand(&mut rng).into());
+        builder.tcm = None;
+
+        // Ensure both problems are reported.
+        assert_eq!(
+            builder.validate(),
+            vec![StatePathError::MissingField("tcm"), StatePathError::InvalidSegment(VerificationPlan::BLOCK_PATH)]
+        );
+        assert!(builder.build().is_err());
+
+        // Ensure an invalid header leaf is reported for every segment it belongs to.
+        let header_leaf = state_path.header_leaf().with_id(Field::rand(&mut rng));
+        let builder = builder_for(&state_path).header_leaf(header_leaf);
+        assert_eq!(
+            builder.validate(),
+            vec![
+                StatePathError::InvalidSegment(VerificationPlan::TRANSACTIONS),
+                StatePathError::InvalidSegment(VerificationPlan::HEADER)
+            ]
+        );
+        assert!(builder.build().unwrap().verify(true, Field::zero()).is_err());
+    }
+}
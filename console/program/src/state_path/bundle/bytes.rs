@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// The block prefix of a state path, shared by the state paths for commitments in the same block.
+struct BlockPrefix<N: Network> {
+    global_state_root: N::StateRoot,
+    block_path: BlockPath<N>,
+    block_hash: N::BlockHash,
+    previous_block_hash: N::BlockHash,
+    header_root: Field<N>,
+    header_path: HeaderPath<N>,
+    header_leaf: HeaderLeaf<N>,
+}
+
+impl<N: Network> FromBytes for StatePathBundle<N> {
+    /// Reads the state path bundle from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid state path bundle version"));
+        }
+
+        // Read the block prefixes, without preallocating for the untrusted number of prefixes.
+        let num_blocks = u32::read_le(&mut reader)?;
+        let mut blocks = Vec::new();
+        for _ in 0..num_blocks {
+            blocks.push(BlockPrefix::<N> {
+                global_state_root: N::StateRoot::read_le(&mut reader)?,
+                block_path: BlockPath::read_le(&mut reader)?,
+                block_hash: N::BlockHash::read_le(&mut reader)?,
+                previous_block_hash: N::BlockHash::read_le(&mut reader)?,
+                header_root: Field::read_le(&mut reader)?,
+                header_path: HeaderPath::read_le(&mut reader)?,
+                header_leaf: HeaderLeaf::read_le(&mut reader)?,
+            });
+        }
+
+        // Read the transaction prefixes, without preallocating for the untrusted number of prefixes.
+        let num_transactions = u32::read_le(&mut reader)?;
+        let mut transactions = Vec::new();
+        for _ in 0..num_transactions {
+            let block_index = u32::read_le(&mut reader)? as usize;
+            // Ensure the block prefix exists.
+            if block_index >= blocks.len() {
+                return Err(error(format!("Missing block prefix {block_index} in the state path bundle")));
+            }
+            let transactions_path = TransactionsPath::read_le(&mut reader)?;
+            let transaction_id = N::TransactionID::read_le(&mut reader)?;
+            transactions.push((block_index, transactions_path, transaction_id));
+        }
+
+        // Read the state paths, without preallocating for the untrusted number of state paths.
+        let num_state_paths = u32::read_le(&mut reader)?;
+        let mut state_paths = Vec::new();
+        for _ in 0..num_state_paths {
+            // Retrieve the transaction prefix and block prefix.
+            let transaction_index = u32::read_le(&mut reader)? as usize;
+            let Some((block_index, transactions_path, transaction_id)) = transactions.get(transaction_index) else {
+                return Err(error(format!("Missing transaction prefix {transaction_index} in the state path bundle")));
+            };
+            let block = &blocks[*block_index];
+
+            // Read the remainder of the state path.
+            let transaction_path = TransactionPath::read_le(&mut reader)?;
+            let transaction_leaf = TransactionLeaf::read_le(&mut reader)?;
+            let transition_root = Field::read_le(&mut reader)?;
+            let tcm = Field::read_le(&mut reader)?;
+            let transition_path = TransitionPath::read_le(&mut reader)?;
+            let transition_leaf = TransitionLeaf::read_le(&mut reader)?;
+
+            // Construct the state path.
+            let state_path = StatePath::from(
+                block.global_state_root,
+                block.block_path.clone(),
+                block.block_hash,
+                block.previous_block_hash,
+                block.header_root,
+                block.header_path.clone(),
+                block.header_leaf,
+                transactions_path.clone(),
+                *transaction_id,
+                transaction_path,
+                transaction_leaf,
+                transition_root,
+                tcm,
+                transition_path,
+                transition_leaf,
+            )
+            .map_err(error)?;
+            state_paths.push(state_path);
+        }
+        Ok(Self { state_paths })
+    }
+}
+
+impl<N: Network> ToBytes for StatePathBundle<N> {
+    /// Writes the state path bundle to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Collect the distinct block prefixes and transaction prefixes, in order of first appearance.
+        let mut blocks = IndexSet::new();
+        let mut transactions = IndexSet::new();
+        let mut transaction_indices = Vec::with_capacity(self.state_paths.len());
+        for state_path in &self.state_paths {
+            // Write the block prefix.
+            let mut block = Vec::new();
+            state_path.global_state_root.write_le(&mut block)?;
+            state_path.block_path.write_le(&mut block)?;
+            state_path.block_hash.write_le(&mut block)?;
+            state_path.previous_block_hash.write_le(&mut block)?;
+            state_path.header_root.write_le(&mut block)?;
+            state_path.header_path.write_le(&mut block)?;
+            state_path.header_leaf.write_le(&mut block)?;
+            let (block_index, _) = blocks.insert_full(block);
+
+            // Write the transaction prefix, which refers to its block prefix.
+            let mut transaction = Vec::new();
+            u32::try_from(block_index).map_err(error)?.write_le(&mut transaction)?;
+            state_path.transactions_path.write_le(&mut transaction)?;
+            state_path.transaction_id.write_le(&mut transaction)?;
+            let (transaction_index, _) = transactions.insert_full(transaction);
+            transaction_indices.push(u32::try_from(transaction_index).map_err(error)?);
+        }
+
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block prefixes.
+        u32::try_from(blocks.len()).map_err(error)?.write_le(&mut writer)?;
+        for block in &blocks {
+            writer.write_all(block)?;
+        }
+        // Write the transaction prefixes.
+        u32::try_from(transactions.len()).map_err(error)?.write_le(&mut writer)?;
+        for transaction in &transactions {
+            writer.write_all(transaction)?;
+        }
+
+        // Write the state paths, each referring to its transaction prefix.
+        u32::try_from(self.state_paths.len()).map_err(error)?.write_le(&mut writer)?;
+        for (state_path, transaction_index) in self.state_paths.iter().zip_eq(transaction_indices) {
+            transaction_index.write_le(&mut writer)?;
+            state_path.transaction_path.write_le(&mut writer)?;
+            state_path.transaction_leaf.write_le(&mut writer)?;
+            state_path.transition_root.write_le(&mut writer)?;
+            state_path.tcm.write_le(&mut writer)?;
+            state_path.transition_path.write_le(&mut writer)?;
+            state_path.transition_leaf.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_path::test_helpers::{sample_global_state_path, sample_global_state_paths_in_block};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample state paths in the same block, and state paths in other blocks.
+            let mut state_paths = sample_global_state_paths_in_block::<CurrentNetwork>(4, &mut rng).unwrap();
+            state_paths.push(sample_global_state_path(None, &mut rng).unwrap());
+            state_paths.push(state_paths[0].clone());
+            let expected = StatePathBundle::new(state_paths);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, StatePathBundle::read_le(&expected_bytes[..]).unwrap());
+            assert!(StatePathBundle::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+            // Ensure the bundle is smaller than the state paths encoded separately.
+            let separate_size =
+                expected.state_paths().iter().map(|path| path.to_bytes_le().unwrap().len()).sum::<usize>();
+            assert!(expected_bytes.len() < separate_size);
+        }
+    }
+
+    #[test]
+    fn test_bytes_empty() {
+        // Ensure the empty bundle round-trips.
+        let expected = StatePathBundle::<CurrentNetwork>::new(vec![]);
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, StatePathBundle::read_le(&expected_bytes[..]).unwrap());
+        assert!(StatePathBundle::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_missing_prefix() {
+        // Ensure a transaction prefix that refers to a missing block prefix is rejected.
+        let bytes = [1u8, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert!(StatePathBundle::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a state path that refers to a missing transaction prefix is rejected.
+        let bytes = [1u8, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert!(StatePathBundle::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
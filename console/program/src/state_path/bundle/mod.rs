@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+
+/// A bundle of state paths, whose byte representation stores each shared prefix once.
+///
+/// State paths for commitments in the same block share their block and header paths, and state paths
+/// for commitments in the same transaction additionally share their transactions path. The byte
+/// representation of the bundle stores each distinct block prefix and transaction prefix once,
+/// and only the transaction and transition paths for each state path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathBundle<N: Network> {
+    /// The state paths, in order.
+    state_paths: Vec<StatePath<N>>,
+}
+
+impl<N: Network> StatePathBundle<N> {
+    /// Initializes a new bundle of the given state paths.
+    pub const fn new(state_paths: Vec<StatePath<N>>) -> Self {
+        Self { state_paths }
+    }
+
+    /// Returns the state paths in the bundle.
+    pub fn state_paths(&self) -> &[StatePath<N>] {
+        &self.state_paths
+    }
+
+    /// Returns the state paths in the bundle, consuming the bundle.
+    pub fn into_state_paths(self) -> Vec<StatePath<N>> {
+        self.state_paths
+    }
+
+    /// Returns the number of state paths in the bundle.
+    pub fn len(&self) -> usize {
+        self.state_paths.len()
+    }
+
+    /// Returns `true` if the bundle has no state paths.
+    pub fn is_empty(&self) -> bool {
+        self.state_paths.is_empty()
+    }
+}
+
+impl<N: Network> From<Vec<StatePath<N>>> for StatePathBundle<N> {
+    /// Initializes a new bundle of the given state paths.
+    fn from(state_paths: Vec<StatePath<N>>) -> Self {
+        Self::new(state_paths)
+    }
+}
@@ -44,7 +44,7 @@ impl<N: Network> FromBytes for StatePath<N> {
         let transition_leaf = FromBytes::read_le(&mut reader)?;
 
         // Construct the state path.
-        Ok(Self::from(
+        Self::from(
             global_state_root,
             block_path,
             block_hash,
@@ -60,7 +60,8 @@ impl<N: Network> FromBytes for StatePath<N> {
             tcm,
             transition_path,
             transition_leaf,
-        ))
+        )
+        .map_err(error)
     }
 }
 
@@ -91,6 +92,29 @@ impl<N: Network> ToBytes for StatePath<N> {
     }
 }
 
+impl<N: Network> TryFrom<&[u8]> for StatePath<N> {
+    type Error = Error;
+
+    /// Reads the state path from the given bytes, which must contain exactly one state path.
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the state path.
+        let state_path = Self::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the state path", reader.len());
+        Ok(state_path)
+    }
+}
+
+impl<N: Network> TryFrom<Vec<u8>> for StatePath<N> {
+    type Error = Error;
+
+    /// Reads the state path from the given bytes, which must contain exactly one state path.
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +139,131 @@ mod tests {
             assert!(StatePath::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_try_from_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let expected = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng)?;
+
+        // Ensure the state path round-trips from a byte slice and a byte vector.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, StatePath::try_from(expected_bytes.as_slice())?);
+        assert_eq!(expected, StatePath::try_from(expected_bytes.clone())?);
+
+        // Ensure truncated bytes and trailing bytes are rejected.
+        assert!(StatePath::<CurrentNetwork>::try_from(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        let mut bytes = expected_bytes;
+        bytes.push(0);
+        let error = StatePath::<CurrentNetwork>::try_from(bytes).unwrap_err();
+        assert!(error.to_string().contains("trailing bytes"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_transition_leaf_variant() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a state path with an out-of-range transition leaf variant is rejected at construction.
+        let leaf = state_path.transition_leaf();
+        let invalid_leaf =
+            TransitionLeaf::from(leaf.version(), leaf.index(), MAX_TRANSITION_LEAF_VARIANT + 1, leaf.id());
+        assert!(
+            StatePath::from(
+                state_path.global_state_root(),
+                state_path.block_path().clone(),
+                state_path.block_hash(),
+                state_path.previous_block_hash(),
+                *state_path.header_root(),
+                state_path.header_path().clone(),
+                *state_path.header_leaf(),
+                state_path.transactions_path().clone(),
+                *state_path.transaction_id(),
+                state_path.transaction_path().clone(),
+                *state_path.transaction_leaf(),
+                *state_path.transition_root(),
+                *state_path.tcm(),
+                state_path.transition_path().clone(),
+                invalid_leaf,
+            )
+            .is_err()
+        );
+
+        // Ensure a state path with an out-of-range transition leaf variant is rejected when read.
+        // The transition leaf is written as (version || index || variant || ID), where the ID is 32 bytes.
+        let mut bytes = state_path.to_bytes_le().unwrap();
+        let variant_index = bytes.len() - 33;
+        assert_eq!(bytes[variant_index], leaf.variant());
+        bytes[variant_index] = MAX_TRANSITION_LEAF_VARIANT + 1;
+        assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_out_of_bounds_leaf_indices() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let expected_bytes = state_path.to_bytes_le().unwrap();
+
+        // Ensure an out-of-bounds block path leaf index is rejected.
+        // The block path leaf index follows the version and the global state root.
+        let mut bytes = expected_bytes.clone();
+        bytes[33..41].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure an out-of-bounds header leaf index is rejected.
+        // The header leaf follows the block path, block hash, previous block hash, header root, and header path.
+        let header_leaf_index = 33 + (8 + 32 * BLOCKS_DEPTH as usize) + 32 * 3 + (8 + 32 * HEADER_DEPTH as usize);
+        let mut bytes = expected_bytes.clone();
+        assert_eq!(bytes[header_leaf_index], state_path.header_leaf().index());
+        bytes[header_leaf_index] = 1 << HEADER_DEPTH;
+        assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure an out-of-bounds transition leaf index is rejected.
+        // The transition leaf is written as (version || index || variant || ID), where the ID is 32 bytes.
+        let transition_leaf_index = expected_bytes.len() - 34;
+        let mut bytes = expected_bytes;
+        assert_eq!(bytes[transition_leaf_index], state_path.transition_leaf().index());
+        bytes[transition_leaf_index] = 1 << TRANSITION_DEPTH;
+        assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_read_le_arbitrary_bytes() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let expected_bytes = state_path.to_bytes_le().unwrap();
+
+        for _ in 0..ITERATIONS * 20 {
+            // Ensure reading random bytes of a random length returns, with or without a valid version.
+            let length = rng.gen_range(0..2 * expected_bytes.len());
+            let mut bytes = (0..length).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+            if let Some(version) = bytes.first_mut() {
+                *version = 1;
+            }
+            assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+            // Ensure reading a valid encoding with random bytes corrupted returns.
+            let mut bytes = expected_bytes.clone();
+            for _ in 0..rng.gen_range(1..4) {
+                let index = rng.gen_range(0..bytes.len());
+                bytes[index] = rng.gen();
+            }
+            let _ = StatePath::<CurrentNetwork>::read_le(&bytes[..]);
+
+            // Ensure reading a truncated valid encoding returns an error.
+            let length = rng.gen_range(0..expected_bytes.len());
+            assert!(StatePath::<CurrentNetwork>::read_le(&expected_bytes[..length]).is_err());
+        }
+    }
 }
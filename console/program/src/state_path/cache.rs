@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// A cache of state paths that have been successfully verified to their global state root.
+///
+/// Entries are keyed by the anchor digest and a hash of the state path bytes. As the byte hash
+/// is not collision-resistant, a cache hit additionally requires the cached bytes to match exactly.
+pub struct VerifiedPathCache<N: Network> {
+    /// The maximum number of entries in the cache.
+    capacity: usize,
+    /// The trusted global state root, if one has been set.
+    trusted_state_root: Option<N::StateRoot>,
+    /// The verified state paths, as a map of `(anchor digest, bytes hash)` to bytes, in insertion order.
+    entries: IndexMap<(Field<N>, u64), Vec<u8>>,
+}
+
+impl<N: Network> VerifiedPathCache<N> {
+    /// Initializes a new cache, with the given maximum number of entries.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, trusted_state_root: None, entries: Default::default() }
+    }
+
+    /// Returns the maximum number of entries in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the trusted global state root, if one has been set.
+    pub const fn trusted_state_root(&self) -> Option<N::StateRoot> {
+        self.trusted_state_root
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Sets the trusted global state root, clearing the cache if the root has advanced.
+    pub fn advance_state_root(&mut self, state_root: N::StateRoot) {
+        if self.trusted_state_root != Some(state_root) {
+            self.entries.clear();
+            self.trusted_state_root = Some(state_root);
+        }
+    }
+
+    /// Verifies the given state path to its global state root, using the cache if possible.
+    /// Returns `true` if the result was served from the cache, and `false` if the state path was verified.
+    pub fn verify(&mut self, state_path: &StatePath<N>) -> Result<bool> {
+        // Compute the cache key.
+        let bytes = state_path.to_bytes_le()?;
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+        let key = (state_path.anchor_digest(), hasher.finish());
+
+        // If the state path was previously verified, return early.
+        if self.entries.get(&key) == Some(&bytes) {
+            return Ok(true);
+        }
+
+        // Verify the state path.
+        state_path.verify(true, Field::zero())?;
+
+        // Insert the state path, evicting the oldest entries if the cache is at capacity.
+        if self.capacity > 0 {
+            while self.entries.len() >= self.capacity {
+                self.entries.shift_remove_index(0);
+            }
+            self.entries.insert(key, bytes);
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verified_path_cache() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let first =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let second =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        let mut cache = VerifiedPathCache::new(4);
+
+        // Ensure the second verification of an identical state path is served from the cache.
+        assert!(!cache.verify(&first).unwrap());
+        assert!(cache.verify(&first.clone()).unwrap());
+        // Ensure a differing state path is not served from the cache.
+        assert!(!cache.verify(&second).unwrap());
+        assert_eq!(cache.len(), 2);
+
+        // Ensure advancing the state root clears the cache.
+        cache.advance_state_root(first.global_state_root());
+        assert!(cache.is_empty());
+        assert!(!cache.verify(&first).unwrap());
+        // Ensure setting the same state root does not clear the cache.
+        cache.advance_state_root(first.global_state_root());
+        assert!(cache.verify(&first).unwrap());
+    }
+
+    #[test]
+    fn test_verified_path_cache_rejects_invalid_path() {
+        let mut rng = TestRng::default();
+
+        // Sample a local state path, which does not verify to a global state root.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure the invalid state path is rejected, and not cached.
+        let mut cache = VerifiedPathCache::new(4);
+        assert!(cache.verify(&state_path).is_err());
+        assert!(cache.verify(&state_path).is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_verified_path_cache_evicts_at_capacity() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let state_paths = (0..3)
+            .map(|_| crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let mut cache = VerifiedPathCache::new(2);
+        for state_path in &state_paths {
+            assert!(!cache.verify(state_path).unwrap());
+        }
+        assert_eq!(cache.len(), 2);
+
+        // Ensure the oldest state path was evicted.
+        assert!(cache.verify(&state_paths[2]).unwrap());
+        assert!(!cache.verify(&state_paths[0]).unwrap());
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The version of the compact state path encoding.
+const COMPACT_STATE_PATH_VERSION: u8 = 1;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the compact byte representation of the global state path.
+    ///
+    /// The compact encoding omits the block hash, as it is derivable as
+    /// `Hash( previous_block_hash || header_root )`. As such, this encoding is only
+    /// available for global state paths, whose block hash is derived in this manner.
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>> {
+        // Ensure the block hash is derivable from the previous block hash and block header root.
+        ensure!(
+            self.block_hash == ChainedBlockHash::block_hash(&self.previous_block_hash, &self.header_root)?,
+            "Block hash '{}' is not derivable, and cannot be compactly encoded",
+            self.block_hash
+        );
+
+        let mut bytes = Vec::new();
+        // Write the version.
+        COMPACT_STATE_PATH_VERSION.write_le(&mut bytes)?;
+
+        // Write the state path, without the block hash.
+        self.global_state_root.write_le(&mut bytes)?;
+
+        self.block_path.write_le(&mut bytes)?;
+        self.previous_block_hash.write_le(&mut bytes)?;
+        self.header_root.write_le(&mut bytes)?;
+        self.header_path.write_le(&mut bytes)?;
+        self.header_leaf.write_le(&mut bytes)?;
+        self.transactions_path.write_le(&mut bytes)?;
+
+        self.transaction_id.write_le(&mut bytes)?;
+        self.transaction_path.write_le(&mut bytes)?;
+        self.transaction_leaf.write_le(&mut bytes)?;
+        self.transition_root.write_le(&mut bytes)?;
+        self.tcm.write_le(&mut bytes)?;
+        self.transition_path.write_le(&mut bytes)?;
+        self.transition_leaf.write_le(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Returns the global state path from its compact byte representation.
+    ///
+    /// Decoding is slightly more expensive than `read_le`, as the block hash is recomputed
+    /// and the resulting state path is verified as a global state path.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == COMPACT_STATE_PATH_VERSION, "Invalid compact state path version");
+
+        // Read the state path, without the block hash.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+
+        let block_path = BlockPath::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+        let transactions_path = TransactionsPath::read_le(&mut reader)?;
+
+        let transaction_id = FromBytes::read_le(&mut reader)?;
+        let transaction_path = FromBytes::read_le(&mut reader)?;
+        let transaction_leaf = FromBytes::read_le(&mut reader)?;
+        let transition_root = Field::read_le(&mut reader)?;
+        let tcm = FromBytes::read_le(&mut reader)?;
+        let transition_path = FromBytes::read_le(&mut reader)?;
+        let transition_leaf = FromBytes::read_le(&mut reader)?;
+
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes in the compact state path");
+
+        // Recompute the block hash.
+        let block_hash = ChainedBlockHash::block_hash(&previous_block_hash, &header_root)?;
+
+        // Construct the state path.
+        let state_path = Self::from(
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_root,
+            tcm,
+            transition_path,
+            transition_leaf,
+        )?;
+        // Ensure the state path is a valid global state path.
+        state_path.verify(true, Field::zero())?;
+        // Return the state path.
+        Ok(state_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_compact_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Check the compact byte representation.
+            let compact_bytes = expected.to_compact_bytes().unwrap();
+            assert_eq!(expected, StatePath::from_compact_bytes(&compact_bytes).unwrap());
+            // Ensure the compact representation omits the block hash.
+            assert_eq!(compact_bytes.len() + 32, expected.to_bytes_le().unwrap().len());
+            // Ensure truncated or extended bytes fail.
+            assert!(StatePath::<CurrentNetwork>::from_compact_bytes(&compact_bytes[1..]).is_err());
+            assert!(StatePath::<CurrentNetwork>::from_compact_bytes(&[&compact_bytes[..], &[0u8]].concat()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_compact_bytes_fails_on_local_state_path() {
+        let mut rng = TestRng::default();
+
+        // Sample a local state path, whose block hash is not derivable.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        assert!(state_path.to_compact_bytes().is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+
+impl<N: Network> StatePath<N> {
+    /// The maximum size in bytes of the compact byte representation of a state path.
+    pub const MAX_SIZE: usize = 4096;
+
+    /// Returns the compressed byte representation of the global state path, as
+    /// (uncompressed length || DEFLATE(compact bytes)), where the uncompressed length is a `u32`.
+    ///
+    /// The compression is deterministic, so equal state paths have equal compressed bytes.
+    pub fn to_compressed(&self) -> Result<Vec<u8>> {
+        // Retrieve the compact bytes.
+        let compact_bytes = self.to_compact_bytes()?;
+        // Ensure the compact bytes are within the maximum size.
+        ensure!(compact_bytes.len() <= Self::MAX_SIZE, "The compact state path exceeds the maximum size");
+
+        let mut bytes = Vec::new();
+        // Write the uncompressed length.
+        u32::try_from(compact_bytes.len())?.write_le(&mut bytes)?;
+        // Write the compressed bytes.
+        let mut encoder = DeflateEncoder::new(bytes, Compression::best());
+        encoder.write_all(&compact_bytes)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Returns the global state path from its compressed byte representation.
+    ///
+    /// A frame whose uncompressed length, or decompressed size, exceeds `StatePath::MAX_SIZE` is rejected
+    /// before the compact bytes are decoded.
+    pub fn from_compressed(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the uncompressed length.
+        let length = u32::read_le(&mut reader)? as usize;
+        // Ensure the uncompressed length is within the maximum size.
+        ensure!(length <= Self::MAX_SIZE, "The compressed state path length {length} exceeds the maximum size");
+
+        // Decompress the compact bytes, reading at most one byte beyond the maximum size.
+        let mut compact_bytes = Vec::with_capacity(length);
+        DeflateDecoder::new(reader).take(Self::MAX_SIZE as u64 + 1).read_to_end(&mut compact_bytes)?;
+        // Ensure the decompressed size is within the maximum size.
+        ensure!(compact_bytes.len() <= Self::MAX_SIZE, "The decompressed state path exceeds the maximum size");
+        // Ensure the decompressed size matches the uncompressed length.
+        ensure!(
+            compact_bytes.len() == length,
+            "The decompressed state path is {} bytes, but {length} bytes were expected",
+            compact_bytes.len()
+        );
+
+        // Decode the compact bytes.
+        Self::from_compact_bytes(&compact_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns (uncompressed length || DEFLATE(bytes)) for the given bytes and uncompressed length.
+    fn to_frame(length: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = DeflateEncoder::new(length.to_le_bytes().to_vec(), Compression::best());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure the state path round-trips, and the compression is deterministic.
+            let compressed = expected.to_compressed().unwrap();
+            assert_eq!(compressed, expected.to_compressed().unwrap());
+            assert_eq!(expected, StatePath::from_compressed(&compressed).unwrap());
+
+            // Ensure the compressed bytes are smaller than the compact bytes.
+            let compact_bytes = expected.to_compact_bytes().unwrap();
+            assert!(compact_bytes.len() <= StatePath::<CurrentNetwork>::MAX_SIZE);
+            assert!(compressed.len() < compact_bytes.len());
+        }
+    }
+
+    #[test]
+    fn test_compressed_rejects_oversized_frame() {
+        let oversized = vec![0u8; StatePath::<CurrentNetwork>::MAX_SIZE + 1];
+
+        // Ensure an uncompressed length beyond the maximum size is rejected.
+        let frame = to_frame(oversized.len() as u32, &oversized);
+        let error = StatePath::<CurrentNetwork>::from_compressed(&frame).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum size"));
+
+        // Ensure a decompressed size beyond the maximum size is rejected, even if the uncompressed length is not.
+        let frame = to_frame(1, &oversized);
+        let error = StatePath::<CurrentNetwork>::from_compressed(&frame).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum size"));
+
+        // Ensure a decompressed size that differs from the uncompressed length is rejected.
+        let frame = to_frame(2, &[0u8]);
+        let error = StatePath::<CurrentNetwork>::from_compressed(&frame).unwrap_err();
+        assert!(error.to_string().contains("bytes were expected"));
+    }
+}
@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use snarkvm_console_collections::merkle_tree::MerklePath;
-use snarkvm_console_network::BHPMerkleTree;
+use snarkvm_console_network::{prelude::*, BHPMerkleHasher, BHPMerkleTree};
+use snarkvm_console_types::Field;
 
 /// The depth of the Merkle tree for the blocks.
 pub const BLOCKS_DEPTH: u8 = 32;
@@ -23,6 +24,8 @@ pub const HEADER_DEPTH: u8 = 3;
 pub const FINALIZE_OPERATIONS_DEPTH: u8 = 20;
 /// The depth of the Merkle tree for the ratifications in a block.
 pub const RATIFICATIONS_DEPTH: u8 = 16;
+/// The depth of the Merkle tree for the sorted serial numbers.
+pub const SERIAL_NUMBERS_DEPTH: u8 = 32;
 /// The depth the Merkle tree for the subdag certificates in a block.
 pub const SUBDAG_CERTIFICATES_DEPTH: u8 = 16;
 /// The depth of the Merkle tree for transactions in a block.
@@ -47,6 +50,11 @@ pub type RatificationsTree<N> = BHPMerkleTree<N, RATIFICATIONS_DEPTH>;
 /// The Merkle path for a ratification in a block.
 pub type RatificationsPath<N> = MerklePath<N, RATIFICATIONS_DEPTH>;
 
+/// The Merkle tree for the sorted serial numbers.
+pub type SerialNumbersTree<N> = BHPMerkleTree<N, SERIAL_NUMBERS_DEPTH>;
+/// The Merkle path for a serial number in the sorted serial numbers.
+pub type SerialNumbersPath<N> = MerklePath<N, SERIAL_NUMBERS_DEPTH>;
+
 /// The Merkle tree for transactions in a block.
 pub type TransactionsTree<N> = BHPMerkleTree<N, TRANSACTIONS_DEPTH>;
 /// The Merkle path for a transaction in a block.
@@ -62,6 +70,64 @@ pub type TransitionTree<N> = BHPMerkleTree<N, TRANSITION_DEPTH>;
 /// The Merkle path for an input or output ID in the transition.
 pub type TransitionPath<N> = MerklePath<N, TRANSITION_DEPTH>;
 
+/// Ensures the BHP Merkle tree depths of the given network match the depths of the state path types.
+///
+/// The `*Path` and `*Tree` types in this module are sized by the constants above, as a const generic
+/// cannot be derived from an associated constant of a generic network on stable Rust. A network that
+/// overrides one of these depths is rejected here, instead of silently producing mis-sized paths.
+pub fn ensure_network_depths<N: Network>() -> Result<()> {
+    // Ensures the network depth for the given tree matches the depth of its path type.
+    let check = |name: &str, network_depth: u8, depth: u8| {
+        ensure!(
+            network_depth == depth,
+            "Network '{}' has a {name} depth of {network_depth}, but state paths have a {name} depth of {depth}",
+            N::NAME
+        );
+        Ok(())
+    };
+    check("blocks", N::BLOCKS_DEPTH, BLOCKS_DEPTH)?;
+    check("header", N::HEADER_DEPTH, HEADER_DEPTH)?;
+    check("transactions", N::TRANSACTIONS_DEPTH, TRANSACTIONS_DEPTH)?;
+    check("transaction", N::TRANSACTION_DEPTH, TRANSACTION_DEPTH)?;
+    check("transition", N::TRANSITION_DEPTH, TRANSITION_DEPTH)
+}
+
+/// The derivation of a block hash from its previous block hash and block header root.
+pub trait BlockHashMode<N: Network> {
+    /// Returns the block hash for the given previous block hash and block header root.
+    fn block_hash(previous_block_hash: &N::BlockHash, header_root: &Field<N>) -> Result<N::BlockHash>;
+
+    /// Returns the block hash for the given previous block hash and block header root, using the given hasher.
+    ///
+    /// The default implementation ignores the hasher, and calls `BlockHashMode::block_hash`.
+    fn block_hash_with_hasher(
+        _hasher: &BHPMerkleHasher<N>,
+        previous_block_hash: &N::BlockHash,
+        header_root: &Field<N>,
+    ) -> Result<N::BlockHash> {
+        Self::block_hash(previous_block_hash, header_root)
+    }
+}
+
+/// The default block hash derivation, as `Hash( previous_block_hash || header_root )`.
+pub struct ChainedBlockHash;
+
+impl<N: Network> BlockHashMode<N> for ChainedBlockHash {
+    /// Returns the block hash for the given previous block hash and block header root.
+    fn block_hash(previous_block_hash: &N::BlockHash, header_root: &Field<N>) -> Result<N::BlockHash> {
+        Ok(N::hash_bhp1024(&to_bits_le![(**previous_block_hash), header_root])?.into())
+    }
+
+    /// Returns the block hash for the given previous block hash and block header root, using the given hasher.
+    fn block_hash_with_hasher(
+        hasher: &BHPMerkleHasher<N>,
+        previous_block_hash: &N::BlockHash,
+        header_root: &Field<N>,
+    ) -> Result<N::BlockHash> {
+        Ok(hasher.hash_bhp1024(&to_bits_le![(**previous_block_hash), header_root])?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +150,37 @@ mod tests {
             CurrentNetwork::MAX_INPUTS + CurrentNetwork::MAX_OUTPUTS
         );
     }
+
+    #[test]
+    fn test_network_depths_match() {
+        // Ensure the network depths match the depths of the state path types.
+        ensure_network_depths::<CurrentNetwork>().unwrap();
+        assert_eq!(CurrentNetwork::BLOCKS_DEPTH, BLOCKS_DEPTH);
+        assert_eq!(CurrentNetwork::HEADER_DEPTH, HEADER_DEPTH);
+        assert_eq!(CurrentNetwork::TRANSACTIONS_DEPTH, TRANSACTIONS_DEPTH);
+        assert_eq!(CurrentNetwork::TRANSACTION_DEPTH, TRANSACTION_DEPTH);
+        assert_eq!(CurrentNetwork::TRANSITION_DEPTH, TRANSITION_DEPTH);
+    }
+
+    #[test]
+    fn test_state_path_depths_are_within_bounds() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure each path in the state path is within the depth of its tree.
+        macro_rules! check_depth {
+            ($path:expr, $depth:expr) => {{
+                assert!($path.siblings().len() <= $depth as usize);
+                assert!((*$path.leaf_index() as u128) < (1u128 << $depth));
+            }};
+        }
+        check_depth!(state_path.block_path(), crate::StatePath::<CurrentNetwork>::BLOCKS_DEPTH);
+        check_depth!(state_path.header_path(), crate::StatePath::<CurrentNetwork>::HEADER_DEPTH);
+        check_depth!(state_path.transactions_path(), crate::StatePath::<CurrentNetwork>::TRANSACTIONS_DEPTH);
+        check_depth!(state_path.transaction_path(), crate::StatePath::<CurrentNetwork>::TRANSACTION_DEPTH);
+        check_depth!(state_path.transition_path(), crate::StatePath::<CurrentNetwork>::TRANSITION_DEPTH);
+    }
 }
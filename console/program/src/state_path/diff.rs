@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The changed fields between two state paths, where each unchanged field is `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathDiff<N: Network> {
+    /// The global state root.
+    pub global_state_root: Option<N::StateRoot>,
+    /// The Merkle path for the block hash.
+    pub block_path: Option<BlockPath<N>>,
+    /// The block hash.
+    pub block_hash: Option<N::BlockHash>,
+    /// The previous block hash.
+    pub previous_block_hash: Option<N::BlockHash>,
+    /// The block header root.
+    pub header_root: Option<Field<N>>,
+    /// The Merkle path for the block header leaf.
+    pub header_path: Option<HeaderPath<N>>,
+    /// The block header leaf.
+    pub header_leaf: Option<HeaderLeaf<N>>,
+    /// The Merkle path for the transaction ID.
+    pub transactions_path: Option<TransactionsPath<N>>,
+    /// The transaction ID.
+    pub transaction_id: Option<N::TransactionID>,
+    /// The Merkle path for the transaction leaf.
+    pub transaction_path: Option<TransactionPath<N>>,
+    /// The transaction leaf.
+    pub transaction_leaf: Option<TransactionLeaf<N>>,
+    /// The transition root.
+    pub transition_root: Option<Field<N>>,
+    /// The transition commitment.
+    pub tcm: Option<Field<N>>,
+    /// The Merkle path for the transition leaf.
+    pub transition_path: Option<TransitionPath<N>>,
+    /// The transition leaf.
+    pub transition_leaf: Option<TransitionLeaf<N>>,
+}
+
+/// Invokes the given macro with the name of every field in the state path.
+macro_rules! for_each_field {
+    ($macro:ident) => {
+        $macro!(
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_root,
+            tcm,
+            transition_path,
+            transition_leaf
+        )
+    };
+}
+
+impl<N: Network> StatePathDiff<N> {
+    /// Returns `true` if the diff contains no changed fields.
+    pub fn is_empty(&self) -> bool {
+        macro_rules! is_empty {
+            ($($field:ident),*) => { $(self.$field.is_none())&&* };
+        }
+        for_each_field!(is_empty)
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the diff from this state path to the given newer state path,
+    /// which contains only the fields that changed.
+    pub fn diff(&self, newer: &Self) -> StatePathDiff<N> {
+        macro_rules! diff {
+            ($($field:ident),*) => {
+                StatePathDiff { $($field: (self.$field != newer.$field).then(|| newer.$field.clone()),)* }
+            };
+        }
+        for_each_field!(diff)
+    }
+
+    /// Returns the newer state path, by applying the given diff to this state path.
+    /// The newer state path is constructed with `StatePath::from`, and is verified to its global state root
+    /// before it is returned.
+    pub fn apply(&self, diff: &StatePathDiff<N>) -> Result<Self> {
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                StatePath::from($(diff.$field.clone().unwrap_or_else(|| self.$field.clone())),*)?
+            };
+        }
+        let state_path = for_each_field!(apply);
+
+        // Ensure the newer state path is valid.
+        state_path.verify(true, Field::zero())?;
+        Ok(state_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the given state path, as included in a newer block with the same block header.
+    fn sample_newer_state_path(state_path: &StatePath<CurrentNetwork>, rng: &mut TestRng) -> StatePath<CurrentNetwork> {
+        // Compute the newer block hash.
+        let previous_block_hash = Field::<CurrentNetwork>::rand(rng).into();
+        let block_hash = ChainedBlockHash::block_hash(&previous_block_hash, state_path.header_root()).unwrap();
+        // Compute the newer block path.
+        let block_tree: BlockTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[Field::<CurrentNetwork>::zero().to_bits_le(), block_hash.to_bits_le()])
+                .unwrap();
+        let block_path = block_tree.prove(1, &block_hash.to_bits_le()).unwrap();
+
+        StatePath::from(
+            (*block_tree.root()).into(),
+            block_path,
+            block_hash,
+            previous_block_hash,
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diff_and_apply() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state paths.
+            let older =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+            let newer = sample_newer_state_path(&older, &mut rng);
+            newer.verify(true, Field::zero()).unwrap();
+
+            // Ensure the diff only contains the block prefix.
+            let diff = older.diff(&newer);
+            assert!(!diff.is_empty());
+            assert_eq!(diff.global_state_root, Some(newer.global_state_root()));
+            assert_eq!(diff.block_hash, Some(newer.block_hash()));
+            assert!(diff.header_root.is_none());
+            assert!(diff.transaction_id.is_none());
+            assert!(diff.transition_leaf.is_none());
+
+            // Ensure applying the diff reconstructs the newer state path.
+            assert_eq!(older.apply(&diff).unwrap(), newer);
+            // Ensure the diff from a state path to itself is empty.
+            assert!(older.diff(&older).is_empty());
+            assert_eq!(older.apply(&older.diff(&older)).unwrap(), older);
+        }
+    }
+
+    #[test]
+    fn test_apply_rejects_invalid_diff() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let older =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let newer = sample_newer_state_path(&older, &mut rng);
+
+        // Ensure a diff with a tampered field fails to apply.
+        let mut diff = older.diff(&newer);
+        diff.block_hash = Some(Field::<CurrentNetwork>::rand(&mut rng).into());
+        assert!(older.apply(&diff).is_err());
+
+        // Ensure a partial diff fails to apply.
+        let mut diff = older.diff(&newer);
+        diff.block_path = None;
+        assert!(older.apply(&diff).is_err());
+    }
+
+    #[test]
+    fn test_apply_enforces_construction_invariants() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let older =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let newer = sample_newer_state_path(&older, &mut rng);
+
+        // Ensure a diff to a self-referential block hash is rejected on construction.
+        let mut diff = older.diff(&newer);
+        diff.previous_block_hash = Some(newer.block_hash());
+        let error = older.apply(&diff).unwrap_err();
+        assert!(error.to_string().contains("must not equal its previous block hash"));
+
+        // Ensure a diff to a transaction leaf with a mismatched index is rejected on construction.
+        let mut diff = older.diff(&newer);
+        let transaction_leaf = older.transaction_leaf();
+        diff.transaction_leaf =
+            Some(TransactionLeaf::new_execution(transaction_leaf.index() + 1, transaction_leaf.id()));
+        let error = older.apply(&diff).unwrap_err();
+        assert!(error.to_string().contains("does not match the transaction path leaf index"));
+    }
+}
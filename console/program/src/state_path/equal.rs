@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> PartialEq for StatePath<N> {
+    /// Returns `true` if the state paths are equal.
+    ///
+    /// Only the semantic fields of the state path are compared. Any field that is derived from the
+    /// semantic fields, such as a cached value, must be excluded here, so that logically-identical
+    /// state paths compare equal regardless of their cache state. The destructuring is exhaustive,
+    /// so a new field may not be added without deciding whether it is compared.
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_root,
+            tcm,
+            transition_path,
+            transition_leaf,
+        } = self;
+
+        *global_state_root == other.global_state_root
+            && *block_path == other.block_path
+            && *block_hash == other.block_hash
+            && *previous_block_hash == other.previous_block_hash
+            && *header_root == other.header_root
+            && *header_path == other.header_path
+            && *header_leaf == other.header_leaf
+            && *transactions_path == other.transactions_path
+            && *transaction_id == other.transaction_id
+            && *transaction_path == other.transaction_path
+            && *transaction_leaf == other.transaction_leaf
+            && *transition_root == other.transition_root
+            && *tcm == other.tcm
+            && *transition_path == other.transition_path
+            && *transition_leaf == other.transition_leaf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_eq() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let expected =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a state path rebuilt from the same semantic fields compares equal.
+        let candidate = StatePath::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+        // Ensure a state path decoded from the compact encoding, which recomputes the block hash, compares equal.
+        let candidate = StatePath::from_compact_bytes(&expected.to_compact_bytes().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure a state path that differs in a single semantic field does not compare equal.
+        let candidate = StatePath { tcm: Field::rand(&mut rng), ..expected.clone() };
+        assert_ne!(expected, candidate);
+        let candidate =
+            StatePath { transition_leaf: expected.transition_leaf.with_id(Field::rand(&mut rng)), ..expected.clone() };
+        assert_ne!(expected, candidate);
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_console_collections::merkle_tree::MerklePath;
+use snarkvm_console_types::U64;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the field elements of the state path.
+    ///
+    /// The ordering is stable, and follows the byte representation of the state path:
+    /// ```ignore
+    ///   global_state_root,
+    ///   block_path, block_hash, previous_block_hash, header_root, header_path, header_leaf,
+    ///   transactions_path, transaction_id, transaction_path, transaction_leaf,
+    ///   transition_root, tcm, transition_path, transition_leaf
+    /// ```
+    /// where each Merkle path is encoded as `(leaf_index || siblings)`, and each leaf is encoded
+    /// as its integer components followed by its ID, with each integer as a field element.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        /// Appends the given Merkle path to the fields, as (leaf_index || siblings).
+        fn append_path<N: Network, const DEPTH: u8>(fields: &mut Vec<Field<N>>, path: &MerklePath<N, DEPTH>) {
+            fields.push(Field::from_u64(*path.leaf_index()));
+            fields.extend_from_slice(path.siblings());
+        }
+
+        let mut fields = vec![*self.global_state_root];
+
+        append_path(&mut fields, &self.block_path);
+        fields.push(*self.block_hash);
+        fields.push(*self.previous_block_hash);
+        fields.push(self.header_root);
+        append_path(&mut fields, &self.header_path);
+        fields.push(Field::from_u8(self.header_leaf.index()));
+        fields.push(self.header_leaf.id());
+        append_path(&mut fields, &self.transactions_path);
+
+        fields.push(*self.transaction_id);
+        append_path(&mut fields, &self.transaction_path);
+        fields.push(Field::from_u8(self.transaction_leaf.variant()));
+        fields.push(Field::from_u16(self.transaction_leaf.index()));
+        fields.push(self.transaction_leaf.id());
+        fields.push(self.transition_root);
+        fields.push(self.tcm);
+        append_path(&mut fields, &self.transition_path);
+        fields.push(Field::from_u8(self.transition_leaf.version()));
+        fields.push(Field::from_u8(self.transition_leaf.index()));
+        fields.push(Field::from_u8(self.transition_leaf.variant()));
+        fields.push(self.transition_leaf.id());
+
+        Ok(fields)
+    }
+
+    /// Returns the state path from the given field elements, in the order given by `StatePath::to_fields`.
+    pub fn from_fields(fields: &[Field<N>]) -> Result<Self> {
+        let mut fields = fields.iter();
+
+        // Returns the next field element.
+        let mut next =
+            || fields.next().copied().ok_or_else(|| anyhow!("Found too few field elements for a state path"));
+
+        /// Returns the given field element as an integer, ensuring it is within bounds.
+        fn to_integer<N: Network, I: TryFrom<u64>>(field: Field<N>) -> Result<I> {
+            let bits = field.to_bits_le();
+            ensure!(!bits[64..].contains(&true), "Found an out of bounds integer in the state path fields");
+            let value = bits[..64].iter().rev().fold(0u64, |value, bit| (value << 1) | (*bit as u64));
+            I::try_from(value).map_err(|_| anyhow!("Found an out of bounds integer in the state path fields"))
+        }
+
+        // Returns the next Merkle path, as (leaf_index || siblings).
+        macro_rules! next_path {
+            ($depth:expr) => {{
+                let leaf_index = to_integer(next()?)?;
+                let siblings = (0..$depth).map(|_| next()).collect::<Result<Vec<_>>>()?;
+                MerklePath::try_from((U64::new(leaf_index), siblings))?
+            }};
+        }
+
+        let global_state_root = next()?.into();
+
+        let block_path = next_path!(BLOCKS_DEPTH);
+        let block_hash = next()?.into();
+        let previous_block_hash = next()?.into();
+        let header_root = next()?;
+        let header_path = next_path!(HEADER_DEPTH);
+        let header_leaf = HeaderLeaf::new(to_integer(next()?)?, next()?);
+        let transactions_path = next_path!(TRANSACTIONS_DEPTH);
+
+        let transaction_id = next()?.into();
+        let transaction_path = next_path!(TRANSACTION_DEPTH);
+        let transaction_leaf = TransactionLeaf::from(to_integer(next()?)?, to_integer(next()?)?, next()?);
+        let transition_root = next()?;
+        let tcm = next()?;
+        let transition_path = next_path!(TRANSITION_DEPTH);
+        let transition_leaf =
+            TransitionLeaf::from(to_integer(next()?)?, to_integer(next()?)?, to_integer(next()?)?, next()?);
+
+        // Ensure there are no trailing field elements.
+        ensure!(fields.next().is_none(), "Found too many field elements for a state path");
+
+        Self::from(
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_root,
+            tcm,
+            transition_path,
+            transition_leaf,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_fields() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Check the field representation.
+            let fields = expected.to_fields().unwrap();
+            assert_eq!(fields.len(), 82);
+            assert_eq!(expected, StatePath::from_fields(&fields).unwrap());
+
+            // Ensure too few or too many field elements fail.
+            assert!(StatePath::<CurrentNetwork>::from_fields(&fields[1..]).is_err());
+            assert!(StatePath::<CurrentNetwork>::from_fields(&[&fields[..], &[Field::zero()]].concat()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_fields_rejects_out_of_bounds_integer() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Set the transition leaf variant to a value that does not fit in a `u8`.
+        let mut fields = state_path.to_fields().unwrap();
+        let num_fields = fields.len();
+        fields[num_fields - 2] = Field::from_u16(256);
+        assert!(StatePath::<CurrentNetwork>::from_fields(&fields).is_err());
+    }
+}
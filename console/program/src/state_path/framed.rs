@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The magic prefix of a framed state path.
+pub const STATE_PATH_FRAME_MAGIC: [u8; 4] = *b"SPTH";
+
+/// The error returned when a framed state path is corrupt, before its payload is decoded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FrameError {
+    /// The frame is too short to contain the magic prefix and checksum.
+    Truncated,
+    /// The frame does not start with the magic prefix.
+    InvalidMagic,
+    /// The checksum does not match the payload.
+    ChecksumMismatch { expected: u32, found: u32 },
+}
+
+impl Display for FrameError {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "The state path frame is truncated"),
+            Self::InvalidMagic => write!(f, "The state path frame has an invalid magic prefix"),
+            Self::ChecksumMismatch { expected, found } => {
+                write!(f, "The state path frame checksum is '{found:#010x}', but '{expected:#010x}' was expected")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FrameError {}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the state path as a frame of (magic || payload || CRC32(payload)),
+    /// where the payload is the byte representation of the state path.
+    pub fn to_framed_bytes(&self) -> Result<Vec<u8>> {
+        let payload = self.to_bytes_le()?;
+        let mut frame = Vec::with_capacity(STATE_PATH_FRAME_MAGIC.len() + payload.len() + 4);
+        frame.extend_from_slice(&STATE_PATH_FRAME_MAGIC);
+        frame.extend_from_slice(&payload);
+        frame.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        Ok(frame)
+    }
+
+    /// Returns the state path from a frame of (magic || payload || CRC32(payload)).
+    ///
+    /// The magic prefix and checksum are checked before the payload is decoded, and a failure
+    /// of either returns a `FrameError`. A failure to decode the payload returns the decoding error.
+    pub fn from_framed_bytes(frame: &[u8]) -> Result<Self> {
+        // Ensure the frame contains the magic prefix and checksum.
+        if frame.len() < STATE_PATH_FRAME_MAGIC.len() + 4 {
+            return Err(FrameError::Truncated.into());
+        }
+        // Split the frame.
+        let (magic, rest) = frame.split_at(STATE_PATH_FRAME_MAGIC.len());
+        let (payload, checksum) = rest.split_at(rest.len() - 4);
+
+        // Ensure the magic prefix is correct.
+        if magic != STATE_PATH_FRAME_MAGIC {
+            return Err(FrameError::InvalidMagic.into());
+        }
+        // Ensure the checksum is correct.
+        let expected = crc32fast::hash(payload);
+        let found = u32::from_le_bytes(checksum.try_into()?);
+        if expected != found {
+            return Err(FrameError::ChecksumMismatch { expected, found }.into());
+        }
+
+        // Decode the payload.
+        Self::from_bytes_le(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_framed_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Check the framed byte representation.
+            let frame = expected.to_framed_bytes().unwrap();
+            assert_eq!(frame.len(), expected.to_bytes_le().unwrap().len() + 8);
+            assert_eq!(expected, StatePath::from_framed_bytes(&frame).unwrap());
+
+            // Ensure a single flipped payload byte is caught by the checksum.
+            let mut corrupt = frame.clone();
+            let index = rng.gen_range(STATE_PATH_FRAME_MAGIC.len()..frame.len() - 4);
+            corrupt[index] ^= 1 << rng.gen_range(0..8);
+            let error = StatePath::<CurrentNetwork>::from_framed_bytes(&corrupt).unwrap_err();
+            assert!(matches!(error.downcast_ref::<FrameError>(), Some(FrameError::ChecksumMismatch { .. })));
+        }
+    }
+
+    #[test]
+    fn test_framed_bytes_errors() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let frame = state_path.to_framed_bytes().unwrap();
+
+        // Ensure an invalid magic prefix is rejected.
+        let mut corrupt = frame.clone();
+        corrupt[0] ^= 1;
+        let error = StatePath::<CurrentNetwork>::from_framed_bytes(&corrupt).unwrap_err();
+        assert_eq!(error.downcast_ref::<FrameError>(), Some(&FrameError::InvalidMagic));
+
+        // Ensure a truncated frame is rejected.
+        let error = StatePath::<CurrentNetwork>::from_framed_bytes(&frame[..7]).unwrap_err();
+        assert_eq!(error.downcast_ref::<FrameError>(), Some(&FrameError::Truncated));
+        assert!(StatePath::<CurrentNetwork>::from_framed_bytes(&frame[..frame.len() - 1]).is_err());
+
+        // Ensure a payload with a valid checksum that fails to decode is a decoding error.
+        let payload = &frame[4..frame.len() - 4];
+        let truncated_payload = &payload[..payload.len() - 1];
+        let mut corrupt = STATE_PATH_FRAME_MAGIC.to_vec();
+        corrupt.extend_from_slice(truncated_payload);
+        corrupt.extend_from_slice(&crc32fast::hash(truncated_payload).to_le_bytes());
+        let error = StatePath::<CurrentNetwork>::from_framed_bytes(&corrupt).unwrap_err();
+        assert!(error.downcast_ref::<FrameError>().is_none());
+    }
+}
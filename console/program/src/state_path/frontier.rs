@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The Merkle frontier of the block tree, which is the Merkle path of the latest block hash.
+///
+/// A light node retains the frontier in place of the full block tree, which suffices to check
+/// the inclusion of the latest block in the block tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleFrontier<N: Network> {
+    /// The root of the block tree.
+    root: Field<N>,
+    /// The number of leaves in the block tree.
+    number_of_leaves: u64,
+    /// The Merkle path for the latest block hash.
+    path: BlockPath<N>,
+}
+
+impl<N: Network> MerkleFrontier<N> {
+    /// Initializes the Merkle frontier from the given block tree and its latest block hash.
+    pub fn new(block_tree: &BlockTree<N>, latest_block_hash: &N::BlockHash) -> Result<Self> {
+        // Ensure the block tree is not empty.
+        let number_of_leaves = block_tree.number_of_leaves();
+        ensure!(number_of_leaves > 0, "Cannot construct the Merkle frontier of an empty block tree");
+        // Compute the Merkle path for the latest block hash.
+        let path = block_tree.prove(number_of_leaves - 1, &latest_block_hash.to_bits_le())?;
+        Ok(Self { root: *block_tree.root(), number_of_leaves: u64::try_from(number_of_leaves)?, path })
+    }
+
+    /// Returns the root of the block tree.
+    pub const fn root(&self) -> &Field<N> {
+        &self.root
+    }
+
+    /// Returns the number of leaves in the block tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the Merkle path for the latest block hash.
+    pub const fn path(&self) -> &BlockPath<N> {
+        &self.path
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid for the latest block in the given Merkle frontier.
+    ///
+    /// The block path must be the Merkle path of the latest block hash in the frontier, and the
+    /// global state root must be the root of the frontier. The state path is then verified as a
+    /// global state path.
+    pub fn verify_against_frontier(&self, frontier: &MerkleFrontier<N>) -> Result<()> {
+        // Ensure the global state root matches the frontier root.
+        ensure!(
+            *self.global_state_root == frontier.root,
+            "The global state root '{}' does not match the frontier root '{}'",
+            self.global_state_root,
+            frontier.root
+        );
+        // Ensure the block path is for the latest block hash in the frontier.
+        ensure!(
+            *self.block_path.leaf_index() + 1 == frontier.number_of_leaves,
+            "The block path (at {}) is not for the latest block in the frontier (at {})",
+            *self.block_path.leaf_index(),
+            frontier.number_of_leaves - 1
+        );
+        // Ensure the block path matches the frontier path.
+        ensure!(self.block_path == frontier.path, "The block path does not match the frontier path");
+        // Ensure the frontier path is valid for the block hash.
+        ensure!(
+            N::verify_merkle_path_bhp(&frontier.path, &frontier.root, &self.block_hash.to_bits_le()),
+            "Block hash '{}' is not the latest block in the frontier of the block tree",
+            *self.block_hash
+        );
+        // Verify the state path.
+        self.verify(true, Field::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_against_frontier() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Construct a block tree, and append the block hash as the latest block.
+        let mut leaves = (0..3).map(|_| Field::<CurrentNetwork>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        let mut block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&leaves).unwrap();
+        leaves.push(state_path.block_hash().to_bits_le());
+        block_tree.append(&leaves[3..]).unwrap();
+
+        // Construct the state path for the latest block.
+        let state_path = StatePath {
+            global_state_root: (*block_tree.root()).into(),
+            block_path: block_tree.prove(3, &leaves[3]).unwrap(),
+            ..state_path
+        };
+
+        // Ensure the frontier verification agrees with the full block tree verification.
+        let frontier = MerkleFrontier::new(&block_tree, &state_path.block_hash()).unwrap();
+        assert_eq!(frontier.number_of_leaves(), 4);
+        assert!(block_tree.verify(state_path.block_path(), block_tree.root(), &leaves[3]));
+        state_path.verify(true, Field::zero()).unwrap();
+        state_path.verify_against_frontier(&frontier).unwrap();
+
+        // Ensure a frontier for a different latest block is rejected.
+        let latest_block_hash = Field::<CurrentNetwork>::rand(&mut rng);
+        block_tree.append(&[latest_block_hash.to_bits_le()]).unwrap();
+        let frontier = MerkleFrontier::new(&block_tree, &latest_block_hash.into()).unwrap();
+        assert!(state_path.verify_against_frontier(&frontier).is_err());
+
+        // Ensure the re-rooted state path, which is no longer for the latest block, is rejected.
+        let state_path = state_path.reroot(&block_tree).unwrap();
+        assert!(state_path.verify_against_frontier(&frontier).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_console_collections::merkle_tree::MerklePath;
+
+/// A Merkle inclusion proof for one level of a state path, in a generic form for external tooling.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericMerkleProof {
+    /// The bits of the leaf, in little-endian order.
+    pub leaf: Vec<bool>,
+    /// The sibling hashes from the leaf to the root, as little-endian hex.
+    pub siblings: Vec<String>,
+    /// The direction at each level from the leaf to the root, where `true` if the node is the right child,
+    /// with its sibling on the left.
+    pub directions: Vec<bool>,
+    /// The root, as little-endian hex.
+    pub root: String,
+}
+
+impl Serialize for GenericMerkleProof {
+    /// Serializes the proof into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut proof = serializer.serialize_struct("GenericMerkleProof", 4)?;
+        proof.serialize_field("leaf", &self.leaf)?;
+        proof.serialize_field("siblings", &self.siblings)?;
+        proof.serialize_field("directions", &self.directions)?;
+        proof.serialize_field("root", &self.root)?;
+        proof.end()
+    }
+}
+
+/// The Merkle inclusion proofs for each level of a state path, in a generic form for external tooling.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenericStatePathProofs {
+    /// The proof of the transition leaf in the transition root.
+    pub transition: GenericMerkleProof,
+    /// The proof of the transaction leaf in the transaction ID.
+    pub transaction: GenericMerkleProof,
+    /// The proof of the transaction ID in the header leaf.
+    pub transactions: GenericMerkleProof,
+    /// The proof of the header leaf in the block header root.
+    pub header: GenericMerkleProof,
+    /// The proof of the block hash in the global state root.
+    pub block: GenericMerkleProof,
+}
+
+impl Serialize for GenericStatePathProofs {
+    /// Serializes the proofs into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut proofs = serializer.serialize_struct("GenericStatePathProofs", 5)?;
+        proofs.serialize_field("transition", &self.transition)?;
+        proofs.serialize_field("transaction", &self.transaction)?;
+        proofs.serialize_field("transactions", &self.transactions)?;
+        proofs.serialize_field("header", &self.header)?;
+        proofs.serialize_field("block", &self.block)?;
+        proofs.end()
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the Merkle inclusion proof of each level of the state path, in a generic form.
+    ///
+    /// Each level is a BHP Merkle tree, in which a leaf is hashed as `BHP1024( false || leaf )`,
+    /// and two children are hashed as `BHP512( true || left || right )`.
+    pub fn to_generic_proofs(&self) -> Result<GenericStatePathProofs> {
+        /// Returns the generic proof for the given leaf, Merkle path, and root.
+        fn to_generic_proof<N: Network, const DEPTH: u8>(
+            leaf: Vec<bool>,
+            path: &MerklePath<N, DEPTH>,
+            root: &Field<N>,
+        ) -> Result<GenericMerkleProof> {
+            Ok(GenericMerkleProof {
+                leaf,
+                siblings: path.siblings().iter().map(to_hex).collect::<Result<_>>()?,
+                directions: (0..DEPTH).map(|i| (*path.leaf_index() >> i) & 1 == 1).collect(),
+                root: to_hex(root)?,
+            })
+        }
+
+        /// Returns the given field as little-endian hex.
+        fn to_hex<N: Network>(field: &Field<N>) -> Result<String> {
+            Ok(field.to_bytes_le()?.iter().map(|byte| format!("{byte:02x}")).collect())
+        }
+
+        Ok(GenericStatePathProofs {
+            transition: to_generic_proof(
+                self.transition_leaf.to_bits_le(),
+                &self.transition_path,
+                &self.transition_root,
+            )?,
+            transaction: to_generic_proof(
+                self.transaction_leaf.to_bits_le(),
+                &self.transaction_path,
+                &*self.transaction_id,
+            )?,
+            transactions: to_generic_proof(
+                self.transaction_id.to_bits_le(),
+                &self.transactions_path,
+                &self.header_leaf.id(),
+            )?,
+            header: to_generic_proof(self.header_leaf.to_bits_le(), &self.header_path, &self.header_root)?,
+            block: to_generic_proof(self.block_hash.to_bits_le(), &self.block_path, &*self.global_state_root)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the field for the given little-endian hex.
+    fn from_hex(hex: &str) -> Field<CurrentNetwork> {
+        let bytes =
+            (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect::<Vec<_>>();
+        Field::from_bytes_le(&bytes).unwrap()
+    }
+
+    /// Returns the root computed by walking the given generic proof with the BHP hashes.
+    fn walk(proof: &GenericMerkleProof) -> Field<CurrentNetwork> {
+        // Hash the leaf, prefixed with a `false` bit.
+        let mut current = CurrentNetwork::hash_bhp1024(&[vec![false], proof.leaf.clone()].concat()).unwrap();
+        // Hash the children on each level, prefixed with a `true` bit.
+        for (sibling, is_right) in proof.siblings.iter().zip_eq(&proof.directions) {
+            let sibling = from_hex(sibling);
+            let (left, right) = match *is_right {
+                true => (sibling, current),
+                false => (current, sibling),
+            };
+            current =
+                CurrentNetwork::hash_bhp512(&[vec![true], left.to_bits_le(), right.to_bits_le()].concat()).unwrap();
+        }
+        current
+    }
+
+    #[test]
+    fn test_to_generic_proofs() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let proofs = state_path.to_generic_proofs().unwrap();
+
+        // Ensure each level has one sibling and one direction per level.
+        assert_eq!(proofs.transition.siblings.len(), TRANSITION_DEPTH as usize);
+        assert_eq!(proofs.block.directions.len(), BLOCKS_DEPTH as usize);
+        assert_eq!(from_hex(&proofs.header.root), *state_path.header_root());
+
+        // Ensure the generic proof of each level re-verifies against its root, with a manual BHP walk.
+        for proof in [&proofs.transition, &proofs.transaction, &proofs.transactions, &proofs.header, &proofs.block] {
+            assert_eq!(walk(proof), from_hex(&proof.root));
+        }
+
+        // Ensure a flipped direction does not re-verify.
+        let mut proof = proofs.transactions.clone();
+        proof.directions[0] = !proof.directions[0];
+        assert_ne!(walk(&proof), from_hex(&proof.root));
+
+        // Ensure the proofs serialize each level.
+        let json = serde_json::to_value(&proofs).unwrap();
+        assert_eq!(json["header"]["root"], proofs.header.root);
+        assert_eq!(json["block"]["directions"].as_array().unwrap().len(), BLOCKS_DEPTH as usize);
+    }
+}
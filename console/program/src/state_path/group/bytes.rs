@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for StatePathGroup<N> {
+    /// Reads the state path group from a buffer.
+    ///
+    /// Each state path in the group is reconstructed from the shared prefix and verified,
+    /// such that a group with any inconsistent suffix fails to load.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid state path group version"));
+        }
+
+        // Read the shared prefix.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+        let block_path = BlockPath::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+
+        // Read the number of suffixes.
+        let num_suffixes = u16::read_le(&mut reader)?;
+        // Ensure the group is not empty.
+        if num_suffixes == 0 {
+            return Err(error("A state path group must contain at least one state path"));
+        }
+
+        // Read the suffixes.
+        let suffixes = (0..num_suffixes)
+            .map(|_| {
+                Ok(StatePathSuffix {
+                    transactions_path: FromBytes::read_le(&mut reader)?,
+                    transaction_id: FromBytes::read_le(&mut reader)?,
+                    transaction_path: FromBytes::read_le(&mut reader)?,
+                    transaction_leaf: FromBytes::read_le(&mut reader)?,
+                    transition_root: FromBytes::read_le(&mut reader)?,
+                    tcm: FromBytes::read_le(&mut reader)?,
+                    transition_path: FromBytes::read_le(&mut reader)?,
+                    transition_leaf: FromBytes::read_le(&mut reader)?,
+                })
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        // Construct the state path group.
+        let group = Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            suffixes,
+        };
+
+        // Ensure every state path in the group is valid.
+        for (index, state_path) in group.to_state_paths().map_err(error)?.iter().enumerate() {
+            if let Err(err) = state_path.verify(true, Field::zero()) {
+                return Err(error(format!("State path {index} in the group is invalid: {err}")));
+            }
+        }
+
+        Ok(group)
+    }
+}
+
+impl<N: Network> ToBytes for StatePathGroup<N> {
+    /// Writes the state path group to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the shared prefix.
+        self.global_state_root.write_le(&mut writer)?;
+        self.block_path.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        self.header_path.write_le(&mut writer)?;
+        self.header_leaf.write_le(&mut writer)?;
+
+        // Write the number of suffixes.
+        u16::try_from(self.suffixes.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the suffixes.
+        for suffix in &self.suffixes {
+            suffix.transactions_path.write_le(&mut writer)?;
+            suffix.transaction_id.write_le(&mut writer)?;
+            suffix.transaction_path.write_le(&mut writer)?;
+            suffix.transaction_leaf.write_le(&mut writer)?;
+            suffix.transition_root.write_le(&mut writer)?;
+            suffix.tcm.write_le(&mut writer)?;
+            suffix.transition_path.write_le(&mut writer)?;
+            suffix.transition_leaf.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state paths.
+            let state_paths =
+                crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(3, &mut rng)
+                    .unwrap();
+            // Construct the group.
+            let expected = StatePathGroup::merge(&state_paths).unwrap();
+            assert_eq!(state_paths, expected.to_state_paths().unwrap());
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, StatePathGroup::read_le(&expected_bytes[..]).unwrap());
+            assert!(StatePathGroup::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_merge_rejects_mismatched_prefix() {
+        let mut rng = TestRng::default();
+
+        // Sample state paths from different blocks.
+        let mut state_paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(2, &mut rng).unwrap();
+        state_paths
+            .push(crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap());
+        assert!(StatePathGroup::merge(&state_paths).is_err());
+    }
+
+    #[test]
+    fn test_read_rejects_tampered_suffix() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let state_paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(3, &mut rng).unwrap();
+
+        // Tamper with the tcm of the middle suffix.
+        let mut group = StatePathGroup::merge(&state_paths).unwrap();
+        group.suffixes[1].tcm = Field::rand(&mut rng);
+
+        // Ensure the tampered group fails to load.
+        let bytes = group.to_bytes_le().unwrap();
+        assert!(StatePathGroup::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+}
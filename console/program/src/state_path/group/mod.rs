@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+
+/// The suffix of a state path, from the transactions path down to the transition leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathSuffix<N: Network> {
+    /// The Merkle path for the transaction ID.
+    transactions_path: TransactionsPath<N>,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The Merkle path for the transaction leaf.
+    transaction_path: TransactionPath<N>,
+    /// The transaction leaf.
+    transaction_leaf: TransactionLeaf<N>,
+    /// The transition root.
+    transition_root: Field<N>,
+    /// The transition commitment.
+    tcm: Field<N>,
+    /// The Merkle path for the transition leaf.
+    transition_path: TransitionPath<N>,
+    /// The transition leaf.
+    transition_leaf: TransitionLeaf<N>,
+}
+
+/// A group of state paths in the same block, which stores the shared block and header prefix once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathGroup<N: Network> {
+    /// The global state root (Public).
+    global_state_root: N::StateRoot,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<N>,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<N>,
+    /// The block header leaf.
+    header_leaf: HeaderLeaf<N>,
+    /// The suffixes of the state paths.
+    suffixes: Vec<StatePathSuffix<N>>,
+}
+
+impl<N: Network> StatePathGroup<N> {
+    /// The maximum number of state paths in a group.
+    pub const MAX_STATE_PATHS: usize = u16::MAX as usize;
+
+    /// Initializes a new state path group, by merging the given state paths on their shared prefix.
+    pub fn merge(state_paths: &[StatePath<N>]) -> Result<Self> {
+        // Ensure the number of state paths is within bounds.
+        ensure!(!state_paths.is_empty(), "A state path group must contain at least one state path");
+        ensure!(state_paths.len() <= Self::MAX_STATE_PATHS, "A state path group contains too many state paths");
+
+        // Retrieve the prefix from the first state path.
+        let first = &state_paths[0];
+
+        // Construct the suffixes, ensuring each state path shares the prefix.
+        let suffixes = state_paths
+            .iter()
+            .enumerate()
+            .map(|(index, state_path)| {
+                ensure!(
+                    state_path.global_state_root == first.global_state_root
+                        && state_path.block_path == first.block_path
+                        && state_path.block_hash == first.block_hash
+                        && state_path.previous_block_hash == first.previous_block_hash
+                        && state_path.header_root == first.header_root
+                        && state_path.header_path == first.header_path
+                        && state_path.header_leaf == first.header_leaf,
+                    "State path {index} does not share the prefix of the state path group"
+                );
+                Ok(StatePathSuffix {
+                    transactions_path: state_path.transactions_path.clone(),
+                    transaction_id: state_path.transaction_id,
+                    transaction_path: state_path.transaction_path.clone(),
+                    transaction_leaf: state_path.transaction_leaf,
+                    transition_root: state_path.transition_root,
+                    tcm: state_path.tcm,
+                    transition_path: state_path.transition_path.clone(),
+                    transition_leaf: state_path.transition_leaf,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            global_state_root: first.global_state_root,
+            block_path: first.block_path.clone(),
+            block_hash: first.block_hash,
+            previous_block_hash: first.previous_block_hash,
+            header_root: first.header_root,
+            header_path: first.header_path.clone(),
+            header_leaf: first.header_leaf,
+            suffixes,
+        })
+    }
+
+    /// Returns the number of state paths in the group.
+    pub fn len(&self) -> usize {
+        self.suffixes.len()
+    }
+
+    /// Returns `true` if the group contains no state paths.
+    pub fn is_empty(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
+    /// Returns the state paths in the group.
+    pub fn to_state_paths(&self) -> Result<Vec<StatePath<N>>> {
+        self.suffixes.iter().map(|suffix| self.to_state_path(suffix)).collect()
+    }
+
+    /// Returns the state path for the given suffix.
+    fn to_state_path(&self, suffix: &StatePathSuffix<N>) -> Result<StatePath<N>> {
+        StatePath::from(
+            self.global_state_root,
+            self.block_path.clone(),
+            self.block_hash,
+            self.previous_block_hash,
+            self.header_root,
+            self.header_path.clone(),
+            self.header_leaf,
+            suffix.transactions_path.clone(),
+            suffix.transaction_id,
+            suffix.transaction_path.clone(),
+            suffix.transaction_leaf,
+            suffix.transition_root,
+            suffix.tcm,
+            suffix.transition_path.clone(),
+            suffix.transition_leaf,
+        )
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The header inclusion path proves existence of a block header leaf to a global state root.
+///
+/// This is the header, block hash, and block path segments of a `StatePath`, for any header leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderInclusionPath<N: Network> {
+    /// The global state root (Public).
+    global_state_root: N::StateRoot,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<N>,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<N>,
+    /// The block header leaf.
+    header_leaf: HeaderLeaf<N>,
+}
+
+impl<N: Network> HeaderInclusionPath<N> {
+    /// Initializes a new instance of `HeaderInclusionPath`.
+    ///
+    /// This does not check the header inclusion path; use `HeaderInclusionPath::verify` to check it.
+    /// A header leaf whose index differs from the leaf index of its header path is rejected.
+    pub fn from(
+        global_state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+    ) -> Result<Self> {
+        // Ensure the header leaf index matches the leaf index of the header path.
+        ensure!(
+            *header_path.leaf_index() == header_leaf.index() as u64,
+            "Header leaf index '{}' does not match the header path leaf index '{}'",
+            header_leaf.index(),
+            *header_path.leaf_index()
+        );
+        // Ensure the block does not reference itself.
+        ensure!(previous_block_hash != block_hash, "Block hash '{block_hash}' must not equal its previous block hash");
+        // Return the header inclusion path.
+        Ok(Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+        })
+    }
+
+    /// Returns the global state root.
+    pub const fn global_state_root(&self) -> N::StateRoot {
+        self.global_state_root
+    }
+
+    /// Returns the block path.
+    pub const fn block_path(&self) -> &BlockPath<N> {
+        &self.block_path
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> &Field<N> {
+        &self.header_root
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Checks if the header inclusion path is valid.
+    ///
+    /// This performs the header, block hash, and block path checks of `StatePath::verify`.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            self.header_leaf,
+            self.block_hash
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            self.block_hash == ChainedBlockHash::block_hash(&self.previous_block_hash, &self.header_root)?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        // Ensure the global state root is correct.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le()),
+            "'{}' (a block hash) does not belong to '{}' (a global state root)",
+            self.block_hash,
+            self.global_state_root
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the header inclusion path for the header leaf in the state path.
+    pub fn to_header_inclusion_path(&self) -> HeaderInclusionPath<N> {
+        HeaderInclusionPath {
+            global_state_root: self.global_state_root,
+            block_path: self.block_path.clone(),
+            block_hash: self.block_hash,
+            previous_block_hash: self.previous_block_hash,
+            header_root: self.header_root,
+            header_path: self.header_path.clone(),
+            header_leaf: self.header_leaf,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_header_inclusion_path() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure the header inclusion path of a valid state path is valid.
+            let path = state_path.to_header_inclusion_path();
+            path.verify().unwrap();
+
+            // Ensure the header inclusion path round-trips through its constructor.
+            let candidate = HeaderInclusionPath::from(
+                path.global_state_root(),
+                path.block_path().clone(),
+                path.block_hash(),
+                path.previous_block_hash(),
+                *path.header_root(),
+                path.header_path().clone(),
+                *path.header_leaf(),
+            )
+            .unwrap();
+            assert_eq!(path, candidate);
+
+            // Ensure a header inclusion path with a different header leaf is invalid.
+            let header_leaf = path.header_leaf().with_id(Field::rand(&mut rng));
+            let candidate = HeaderInclusionPath { header_leaf, ..path.clone() };
+            candidate.verify().unwrap_err();
+
+            // Ensure a header inclusion path for a different global state root is invalid.
+            let candidate = HeaderInclusionPath { global_state_root: Field::rand(&mut rng).into(), ..path };
+            candidate.verify().unwrap_err();
+        }
+    }
+}
@@ -20,6 +20,77 @@ mod to_bits;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::Field;
 
+/// The well-known indices of the Merkle leaves in the block header.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HeaderLeafIndex {
+    /// The previous state root.
+    PreviousStateRoot = 0,
+    /// The transactions root.
+    TransactionsRoot = 1,
+    /// The finalize root.
+    FinalizeRoot = 2,
+    /// The ratifications root.
+    RatificationsRoot = 3,
+    /// The solutions root.
+    SolutionsRoot = 4,
+    /// The subdag root.
+    SubdagRoot = 5,
+    /// The metadata hash.
+    MetadataHash = 7,
+}
+
+impl HeaderLeafIndex {
+    /// The well-known indices, in ascending order.
+    pub const ALL: [Self; 7] = [
+        Self::PreviousStateRoot,
+        Self::TransactionsRoot,
+        Self::FinalizeRoot,
+        Self::RatificationsRoot,
+        Self::SolutionsRoot,
+        Self::SubdagRoot,
+        Self::MetadataHash,
+    ];
+
+    /// Returns the well-known index for the given leaf index, if one is defined.
+    pub const fn from_index(index: u8) -> Option<Self> {
+        match index {
+            0 => Some(Self::PreviousStateRoot),
+            1 => Some(Self::TransactionsRoot),
+            2 => Some(Self::FinalizeRoot),
+            3 => Some(Self::RatificationsRoot),
+            4 => Some(Self::SolutionsRoot),
+            5 => Some(Self::SubdagRoot),
+            7 => Some(Self::MetadataHash),
+            _ => None,
+        }
+    }
+
+    /// Returns the leaf index.
+    pub const fn index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the name of the leaf.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::PreviousStateRoot => "previous_state_root",
+            Self::TransactionsRoot => "transactions_root",
+            Self::FinalizeRoot => "finalize_root",
+            Self::RatificationsRoot => "ratifications_root",
+            Self::SolutionsRoot => "solutions_root",
+            Self::SubdagRoot => "subdag_root",
+            Self::MetadataHash => "metadata_hash",
+        }
+    }
+}
+
+impl Display for HeaderLeafIndex {
+    /// Prints the name of the leaf.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 /// The Merkle leaf for the block header.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct HeaderLeaf<N: Network> {
@@ -44,6 +115,16 @@ impl<N: Network> HeaderLeaf<N> {
     pub const fn id(&self) -> Field<N> {
         self.id
     }
+
+    /// Returns the well-known index of the Merkle leaf, if one is defined.
+    pub const fn index_name(&self) -> Option<HeaderLeafIndex> {
+        HeaderLeafIndex::from_index(self.index)
+    }
+
+    /// Returns a copy of the Merkle leaf with the given ID, preserving the index.
+    pub const fn with_id(&self, id: Field<N>) -> Self {
+        Self { id, ..*self }
+    }
 }
 
 #[cfg(test)]
@@ -58,3 +139,59 @@ mod test_helpers {
         HeaderLeaf::new(rng.gen(), Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_index_name() {
+        // Ensure each well-known index maps to its leaf index and name.
+        let expected = [
+            (0, "previous_state_root"),
+            (1, "transactions_root"),
+            (2, "finalize_root"),
+            (3, "ratifications_root"),
+            (4, "solutions_root"),
+            (5, "subdag_root"),
+            (7, "metadata_hash"),
+        ];
+        for (index_name, (index, name)) in HeaderLeafIndex::ALL.into_iter().zip(expected) {
+            assert_eq!(index_name.index(), index);
+            assert_eq!(index_name.to_string(), name);
+            assert_eq!(HeaderLeafIndex::from_index(index), Some(index_name));
+            assert_eq!(HeaderLeaf::<CurrentNetwork>::new(index, Field::zero()).index_name(), Some(index_name));
+        }
+
+        // Ensure every other leaf index has no name.
+        for index in (0..=u8::MAX).filter(|index| !expected.iter().any(|(expected, _)| expected == index)) {
+            assert_eq!(HeaderLeafIndex::from_index(index), None);
+            assert_eq!(HeaderLeaf::<CurrentNetwork>::new(index, Field::zero()).index_name(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_id() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a leaf, and re-key it to a new ID.
+            let leaf = test_helpers::sample_leaf(&mut rng);
+            let id = Field::<CurrentNetwork>::rand(&mut rng);
+            let candidate = leaf.with_id(id);
+            assert_eq!(candidate.id(), id);
+            assert_eq!(candidate.index(), leaf.index());
+
+            // Ensure the bits only differ in the ID region.
+            let (expected_bits, candidate_bits) = (leaf.to_bits_le(), candidate.to_bits_le());
+            assert_eq!(expected_bits.len(), candidate_bits.len());
+            assert_eq!(expected_bits[..8], candidate_bits[..8]);
+            assert_eq!(candidate_bits[8..], id.to_bits_le());
+        }
+    }
+}
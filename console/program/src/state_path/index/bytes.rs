@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for StatePathIndex<N> {
+    /// Reads the state path index from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid state path index version"));
+        }
+
+        // Read the number of entries.
+        let num_entries = u32::read_le(&mut reader)?;
+        // Read the entries, without preallocating for the untrusted number of entries.
+        let mut offsets = IndexMap::new();
+        for _ in 0..num_entries {
+            // Read the commitment.
+            let commitment = Field::read_le(&mut reader)?;
+            // Read the offset.
+            let offset = u64::read_le(&mut reader)?;
+            // Insert the entry, and ensure the index has no duplicate commitments.
+            if offsets.insert(commitment, offset).is_some() {
+                return Err(error(format!("Duplicate commitment '{commitment}' in the state path index")));
+            }
+        }
+        Ok(Self { offsets })
+    }
+}
+
+impl<N: Network> ToBytes for StatePathIndex<N> {
+    /// Writes the state path index to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the number of entries.
+        u32::try_from(self.offsets.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the entries.
+        for (commitment, offset) in &self.offsets {
+            commitment.write_le(&mut writer)?;
+            offset.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for num_entries in [0, 1, 10] {
+            // Sample the index.
+            let mut expected = StatePathIndex::<CurrentNetwork>::new();
+            for _ in 0..num_entries {
+                expected.insert(Field::rand(&mut rng), rng.gen());
+            }
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected_bytes.len(), 1 + 4 + num_entries * 40);
+            assert_eq!(expected, StatePathIndex::read_le(&expected_bytes[..]).unwrap());
+            assert!(StatePathIndex::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bytes_rejects_malformed_index() {
+        let mut rng = TestRng::default();
+
+        // Ensure a duplicate commitment is rejected.
+        let commitment = Field::<CurrentNetwork>::rand(&mut rng);
+        let mut bytes = vec![1u8];
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        for offset in [0u64, 1] {
+            bytes.extend_from_slice(&commitment.to_bytes_le().unwrap());
+            bytes.extend_from_slice(&offset.to_le_bytes());
+        }
+        assert!(StatePathIndex::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a number of entries beyond the buffer is rejected, without allocating for it.
+        let mut bytes = vec![1u8];
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(StatePathIndex::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure reading random bytes returns.
+        for _ in 0..ITERATIONS {
+            let length = rng.gen_range(0..128);
+            let bytes = (0..length).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+            let _ = StatePathIndex::<CurrentNetwork>::read_le(&bytes[..]);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// An index from record commitments to the byte offsets of their stored state paths.
+///
+/// This allows an indexer to persist a file of state paths alongside the index, and to seek directly
+/// to the state path for a commitment without scanning the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathIndex<N: Network> {
+    /// The byte offset of the state path for each commitment.
+    offsets: IndexMap<Field<N>, u64>,
+}
+
+impl<N: Network> StatePathIndex<N> {
+    /// Initializes a new, empty state path index.
+    pub fn new() -> Self {
+        Self { offsets: IndexMap::new() }
+    }
+
+    /// Inserts the byte offset of the state path for the given commitment,
+    /// returning the previous offset, if the commitment was already indexed.
+    pub fn insert(&mut self, commitment: Field<N>, offset: u64) -> Option<u64> {
+        self.offsets.insert(commitment, offset)
+    }
+
+    /// Returns the byte offset of the state path for the given commitment, if it is indexed.
+    pub fn get(&self, commitment: &Field<N>) -> Option<u64> {
+        self.offsets.get(commitment).copied()
+    }
+
+    /// Returns the number of indexed commitments.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if the index contains no commitments.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+impl<N: Network> Default for StatePathIndex<N> {
+    /// Returns an empty state path index.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_state_path_index() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let state_paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(4, &mut rng).unwrap();
+
+        // Write the state paths to a file, and index each state path by its commitment.
+        let mut file = Vec::new();
+        let mut index = StatePathIndex::new();
+        for state_path in &state_paths {
+            let offset = file.len() as u64;
+            file.extend_from_slice(&state_path.to_framed_bytes().unwrap());
+            assert_eq!(index.insert(state_path.transition_leaf().id(), offset), None);
+        }
+        assert_eq!(index.len(), state_paths.len());
+
+        // Ensure each state path is retrieved by its commitment.
+        let frame_size = state_paths[0].to_framed_bytes().unwrap().len();
+        for state_path in &state_paths {
+            let offset = index.get(&state_path.transition_leaf().id()).unwrap() as usize;
+            let candidate = StatePath::from_framed_bytes(&file[offset..offset + frame_size]).unwrap();
+            assert_eq!(&candidate, state_path);
+        }
+
+        // Ensure an unknown commitment is not found.
+        assert_eq!(index.get(&Field::rand(&mut rng)), None);
+
+        // Ensure re-inserting a commitment replaces its offset.
+        let commitment = state_paths[0].transition_leaf().id();
+        assert_eq!(index.insert(commitment, 7), Some(0));
+        assert_eq!(index.get(&commitment), Some(7));
+        assert_eq!(index.len(), state_paths.len());
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+/// A state path, which serializes into a JSON object with camelCase keys.
+///
+/// The human-readable serialization of `StatePath` is its string representation, and this wrapper
+/// is for consumers that expect each field as a separate camelCase key.
+/// The non-human-readable serialization is the same as for `StatePath`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathJson<N: Network>(StatePath<N>);
+
+impl<N: Network> StatePathJson<N> {
+    /// Initializes a new instance of `StatePathJson`.
+    pub const fn new(state_path: StatePath<N>) -> Self {
+        Self(state_path)
+    }
+
+    /// Returns the state path.
+    pub const fn state_path(&self) -> &StatePath<N> {
+        &self.0
+    }
+
+    /// Returns the state path, consuming the wrapper.
+    pub fn into_state_path(self) -> StatePath<N> {
+        self.0
+    }
+}
+
+impl<N: Network> From<StatePath<N>> for StatePathJson<N> {
+    /// Wraps the given state path.
+    fn from(state_path: StatePath<N>) -> Self {
+        Self(state_path)
+    }
+}
+
+impl<N: Network> From<StatePathJson<N>> for StatePath<N> {
+    /// Unwraps the given state path.
+    fn from(state_path: StatePathJson<N>) -> Self {
+        state_path.0
+    }
+}
+
+impl<N: Network> Serialize for StatePathJson<N> {
+    /// Serializes the state path into a camelCase JSON object or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => self.0.serialize_camel_case(serializer),
+            false => self.0.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for StatePathJson<N> {
+    /// Deserializes the state path from a camelCase JSON object or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => Ok(Self(StatePath::deserialize_camel_case(deserializer)?)),
+            false => Ok(Self(StatePath::deserialize(deserializer)?)),
+        }
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Serializes the state path into a JSON object with camelCase keys.
+    pub(super) fn serialize_camel_case<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state_path = serializer.serialize_struct("StatePath", 15)?;
+        state_path.serialize_field("stateRoot", &self.global_state_root)?;
+        state_path.serialize_field("blockPath", &self.block_path)?;
+        state_path.serialize_field("blockHash", &self.block_hash)?;
+        state_path.serialize_field("previousBlockHash", &self.previous_block_hash)?;
+        state_path.serialize_field("headerRoot", &self.header_root)?;
+        state_path.serialize_field("headerPath", &self.header_path)?;
+        state_path.serialize_field("headerLeaf", &self.header_leaf)?;
+        state_path.serialize_field("transactionsPath", &self.transactions_path)?;
+        state_path.serialize_field("transactionId", &self.transaction_id)?;
+        state_path.serialize_field("transactionPath", &self.transaction_path)?;
+        state_path.serialize_field("transactionLeaf", &self.transaction_leaf)?;
+        state_path.serialize_field("transitionRoot", &self.transition_root)?;
+        state_path.serialize_field("tcm", &self.tcm)?;
+        state_path.serialize_field("transitionPath", &self.transition_path)?;
+        state_path.serialize_field("transitionLeaf", &self.transition_leaf)?;
+        state_path.end()
+    }
+
+    /// Deserializes the state path from a JSON object with camelCase keys.
+    pub(super) fn deserialize_camel_case<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the state path from a string into a value.
+        let mut path = serde_json::Value::deserialize(deserializer)?;
+        // Recover the state path.
+        Self::from(
+            DeserializeExt::take_from_value::<D>(&mut path, "stateRoot")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "blockPath")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "blockHash")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "previousBlockHash")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "headerRoot")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "headerPath")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "headerLeaf")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transactionsPath")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transactionId")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transactionPath")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transactionLeaf")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transitionRoot")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "tcm")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transitionPath")?,
+            DeserializeExt::take_from_value::<D>(&mut path, "transitionLeaf")?,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng)?;
+
+            // Serialize
+            let candidate_string = serde_json::to_string(&StatePathJson::new(expected.clone()))?;
+            let candidate_value = serde_json::Value::from_str(&candidate_string)?;
+
+            // Ensure the exact keys are emitted, in order.
+            let keys = candidate_value.as_object().unwrap().keys().map(|key| key.as_str()).collect::<Vec<_>>();
+            assert_eq!(keys, [
+                "stateRoot",
+                "blockPath",
+                "blockHash",
+                "previousBlockHash",
+                "headerRoot",
+                "headerPath",
+                "headerLeaf",
+                "transactionsPath",
+                "transactionId",
+                "transactionPath",
+                "transactionLeaf",
+                "transitionRoot",
+                "tcm",
+                "transitionPath",
+                "transitionLeaf",
+            ]);
+            assert_eq!(candidate_value["blockHash"].as_str().unwrap(), expected.block_hash().to_string());
+            assert_eq!(candidate_value["stateRoot"].as_str().unwrap(), expected.global_state_root().to_string());
+
+            // Deserialize
+            let candidate: StatePathJson<CurrentNetwork> = serde_json::from_str(&candidate_string)?;
+            assert_eq!(expected, candidate.into_state_path());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_state_path_is_unaffected() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let expected = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng)?;
+
+        // Ensure the state path still serializes into its string representation.
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(serde_json::Value::from_str(&candidate_string)?.as_str().unwrap(), expected.to_string());
+        assert_ne!(candidate_string, serde_json::to_string(&StatePathJson::new(expected.clone()))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let expected = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng)?;
+
+        // Ensure the byte serialization is unaffected.
+        let expected_bytes = bincode::serialize(&expected)?;
+        assert_eq!(expected_bytes, bincode::serialize(&StatePathJson::new(expected.clone()))?);
+        assert_eq!(expected, bincode::deserialize::<StatePathJson<CurrentNetwork>>(&expected_bytes)?.into_state_path());
+        Ok(())
+    }
+}
@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "cache")]
+pub use cache::*;
+
 mod configuration;
 pub use configuration::*;
 
+mod group;
+pub use group::*;
+
 mod header_leaf;
 pub use header_leaf::*;
 
@@ -24,16 +32,77 @@ pub use transaction_leaf::*;
 pub mod transition_leaf;
 pub use transition_leaf::*;
 
+mod absence;
+pub use absence::*;
+mod admit;
+mod anchor;
+mod budget;
+pub use budget::*;
+mod builder;
+pub use builder::*;
+mod bundle;
+pub use bundle::*;
 mod bytes;
+mod compact;
+#[cfg(feature = "compression")]
+mod compressed;
+mod diff;
+pub use diff::*;
+mod equal;
+mod fields;
+mod framed;
+pub use framed::*;
+mod frontier;
+pub use frontier::*;
+mod generic;
+pub use generic::*;
+mod header_inclusion;
+pub use header_inclusion::*;
+mod index;
+pub use index::*;
+mod json;
+pub use json::*;
+mod opening;
+pub use opening::*;
 mod parse;
+mod plan;
+pub use plan::*;
+mod position;
+pub use position::*;
+mod preimage;
+mod reroot;
+mod same_block;
+pub use same_block::*;
 mod serialize;
+mod spent;
+pub use spent::*;
+mod stream;
+pub use stream::*;
+mod summary;
+pub use summary::*;
+mod to_bits;
+mod trace;
+mod transaction_state_path;
+pub use transaction_state_path::*;
 mod verify;
+#[cfg(feature = "tokio")]
+mod verify_async;
+mod verify_batch;
+mod verify_verbose;
+pub use verify_verbose::*;
+mod visit;
+pub use visit::*;
+mod well_formed;
+mod window;
+pub use window::*;
+mod witness;
+pub use witness::*;
 
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::Field;
 
 /// The state path proves existence of the transition leaf to either a global or local state root.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq)]
 pub struct StatePath<N: Network> {
     /// The global state root (Public).
     global_state_root: N::StateRoot,
@@ -68,6 +137,17 @@ pub struct StatePath<N: Network> {
 }
 
 impl<N: Network> StatePath<N> {
+    /// The depth of the Merkle tree for the blocks.
+    pub const BLOCKS_DEPTH: u8 = BLOCKS_DEPTH;
+    /// The depth of the Merkle tree for the block header.
+    pub const HEADER_DEPTH: u8 = HEADER_DEPTH;
+    /// The depth of the Merkle tree for transactions in a block.
+    pub const TRANSACTIONS_DEPTH: u8 = TRANSACTIONS_DEPTH;
+    /// The depth of the Merkle tree for the transaction.
+    pub const TRANSACTION_DEPTH: u8 = TRANSACTION_DEPTH;
+    /// The depth of the Merkle tree for the transition.
+    pub const TRANSITION_DEPTH: u8 = TRANSITION_DEPTH;
+
     /// Initializes a new instance of `StatePath`.
     pub fn new_local(
         global_state_root: N::StateRoot,
@@ -100,7 +180,7 @@ impl<N: Network> StatePath<N> {
         let block_path = block_tree.prove(0, &block_hash_bits)?;
 
         // Return the state path.
-        Ok(Self {
+        Self::from(
             global_state_root,
             block_path,
             block_hash,
@@ -109,17 +189,35 @@ impl<N: Network> StatePath<N> {
             header_path,
             header_leaf,
             transactions_path,
-            transaction_id: local_state_root,
+            local_state_root,
             transaction_path,
             transaction_leaf,
             transition_root,
             tcm,
             transition_path,
             transition_leaf,
-        })
+        )
     }
 
     /// Initializes a new instance of `StatePath`.
+    ///
+    /// This does not check the state path; use `StatePath::verify` to check it. Each Merkle path is
+    /// of the correct length with an in-bounds leaf index by construction. A path from a single-leaf
+    /// tree is valid, with the empty hashes as its siblings, while a path to the root of an empty tree
+    /// is accepted here and rejected by `StatePath::verify`, as an empty tree contains no leaves.
+    /// A transition leaf with an out-of-range variant is rejected, as is a transaction leaf whose index
+    /// differs from the leaf index of its transaction path. A header leaf or transition leaf whose index
+    /// is out of bounds for the depth of its Merkle tree is rejected. A state path that is not well-formed,
+    /// as checked by `StatePath::is_well_formed`, is rejected.
+    ///
+    /// A self-referential block, whose block hash equals its previous block hash, is rejected.
+    /// A genesis block is not self-referential, as its previous block hash is the zero sentinel and its
+    /// block hash is not, so the sentinel is accepted for a genesis block. The only exception is a
+    /// block hash and previous block hash that are both zero, which `StatePath::new_local` uses as
+    /// placeholders, as a local state path does not prove a block.
+    ///
+    /// The block hash is not checked against its preimage, as a local state path does not prove a block.
+    /// Use `StatePath::from_with_block_hash` to also check the block hash, for a given block hash derivation.
     #[allow(clippy::too_many_arguments)]
     pub fn from(
         global_state_root: N::StateRoot,
@@ -137,9 +235,93 @@ impl<N: Network> StatePath<N> {
         tcm: Field<N>,
         transition_path: TransitionPath<N>,
         transition_leaf: TransitionLeaf<N>,
-    ) -> Self {
+    ) -> Result<Self> {
+        // Ensure the network depths match the depths of the state path types.
+        ensure_network_depths::<N>()?;
+        // Ensure the transition leaf variant is within range.
+        ensure!(
+            transition_leaf.has_valid_variant(),
+            "Transition leaf variant '{}' is out of range",
+            transition_leaf.variant()
+        );
+        // Ensure the header leaf index is within the header tree depth.
+        ensure!(
+            (header_leaf.index() as u128) < (1u128 << HEADER_DEPTH),
+            "Header leaf index '{}' is out of bounds",
+            header_leaf.index()
+        );
+        // Ensure the transition leaf index is within the transition tree depth.
+        ensure!(
+            (transition_leaf.index() as u128) < (1u128 << TRANSITION_DEPTH),
+            "Transition leaf index '{}' is out of bounds",
+            transition_leaf.index()
+        );
+        // Ensure the transaction leaf index matches the leaf index of the transaction path.
+        ensure!(
+            *transaction_path.leaf_index() == transaction_leaf.index() as u64,
+            "Transaction leaf index '{}' does not match the transaction path leaf index '{}'",
+            transaction_leaf.index(),
+            *transaction_path.leaf_index()
+        );
+        // Ensure the block does not reference itself, unless both block hashes are the zero placeholders.
+        ensure!(
+            previous_block_hash != block_hash || *block_hash == Field::zero(),
+            "Block hash '{block_hash}' must not equal its previous block hash"
+        );
+        // Initialize the state path.
+        let state_path = Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+            transaction_path,
+            transaction_leaf,
+            transition_root,
+            tcm,
+            transition_path,
+            transition_leaf,
+        };
+        // Ensure the state path is well-formed.
+        state_path.ensure_well_formed()?;
         // Return the state path.
-        Self {
+        Ok(state_path)
+    }
+
+    /// Initializes a new instance of `StatePath`, and ensures the block hash is derived from the previous
+    /// block hash and block header root, using the given block hash derivation.
+    ///
+    /// This allows networks with an alternative block hash preimage to construct their state paths.
+    /// Refer to `StatePath::from` for the remaining checks.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_with_block_hash<M: BlockHashMode<N>>(
+        global_state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+        transactions_path: TransactionsPath<N>,
+        transaction_id: N::TransactionID,
+        transaction_path: TransactionPath<N>,
+        transaction_leaf: TransactionLeaf<N>,
+        transition_root: Field<N>,
+        tcm: Field<N>,
+        transition_path: TransitionPath<N>,
+        transition_leaf: TransitionLeaf<N>,
+    ) -> Result<Self> {
+        // Ensure the block hash is derived from the previous block hash and block header root.
+        ensure!(
+            block_hash == M::block_hash(&previous_block_hash, &header_root)?,
+            "Block hash '{block_hash}' is incorrect. Double-check the previous block hash and block header root."
+        );
+        // Return the state path.
+        Self::from(
             global_state_root,
             block_path,
             block_hash,
@@ -155,7 +337,7 @@ impl<N: Network> StatePath<N> {
             tcm,
             transition_path,
             transition_leaf,
-        }
+        )
     }
 
     /// Returns the global state root.
@@ -178,6 +360,16 @@ impl<N: Network> StatePath<N> {
         self.previous_block_hash
     }
 
+    /// Returns a reference to the block hash.
+    pub const fn block_hash_ref(&self) -> &N::BlockHash {
+        &self.block_hash
+    }
+
+    /// Returns a reference to the previous block hash.
+    pub const fn previous_block_hash_ref(&self) -> &N::BlockHash {
+        &self.previous_block_hash
+    }
+
     /// Returns the block header root.
     pub const fn header_root(&self) -> &Field<N> {
         &self.header_root
@@ -288,7 +480,7 @@ pub mod test_helpers {
         let global_state_root = *block_tree.root();
         let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
 
-        Ok(StatePath::<N>::from(
+        StatePath::<N>::from(
             global_state_root.into(),
             block_path,
             block_hash.into(),
@@ -304,7 +496,104 @@ pub mod test_helpers {
             tcm,
             transition_path,
             transition_leaf,
-        ))
+        )
+    }
+
+    /// Randomly sample `num_paths` state paths to a global state root, for distinct transactions in the same block.
+    pub fn sample_global_state_paths_in_block<N: Network>(
+        num_paths: usize,
+        rng: &mut TestRng,
+    ) -> Result<Vec<StatePath<N>>> {
+        // Construct the transition and transaction segments for each transaction.
+        let mut lower_segments = Vec::with_capacity(num_paths);
+        for _ in 0..num_paths {
+            // Prepare the commitment and tcm.
+            let commitment = Field::rand(rng);
+            let tcm = Field::rand(rng);
+
+            // Construct the transition path and transaction leaf.
+            let transition_leaf = TransitionLeaf::new_with_version(0, 3, commitment);
+            let transition_tree: TransitionTree<N> = N::merkle_tree_bhp(&[transition_leaf.to_bits_le()])?;
+            let transition_root = *transition_tree.root();
+            let transition_id = N::hash_bhp512(&(transition_root, tcm).to_bits_le())?;
+            let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+
+            // Construct the transaction path and transaction leaf.
+            let transaction_leaf = TransactionLeaf::new_execution(0, transition_id);
+            let transaction_tree: TransactionTree<N> = N::merkle_tree_bhp(&[transaction_leaf.to_bits_le()])?;
+            let transaction_id = *transaction_tree.root();
+            let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+
+            lower_segments.push((
+                transaction_id,
+                transaction_path,
+                transaction_leaf,
+                transition_root,
+                tcm,
+                transition_path,
+                transition_leaf,
+            ));
+        }
+
+        // Construct the transactions tree.
+        let transaction_ids = lower_segments.iter().map(|(id, ..)| id.to_bits_le()).collect::<Vec<_>>();
+        let transactions_tree: TransactionsTree<N> = N::merkle_tree_bhp(&transaction_ids)?;
+        let transactions_root = transactions_tree.root();
+
+        // Construct the block header path.
+        let header_leaf = HeaderLeaf::<N>::new(1, *transactions_root);
+        let header_tree: HeaderTree<N> =
+            N::merkle_tree_bhp(&[Field::<N>::zero().to_bits_le(), header_leaf.to_bits_le()])?;
+        let header_root = header_tree.root();
+        let header_path = header_tree.prove(1, &header_leaf.to_bits_le())?;
+
+        let previous_block_hash: N::BlockHash = Field::<N>::rand(rng).into();
+        let preimage = (*previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le().into_iter());
+        let block_hash = N::hash_bhp1024(&preimage.collect::<Vec<_>>())?;
+
+        // Construct the global state root and block path.
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        let global_state_root = *block_tree.root();
+        let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
+
+        // Construct the state paths.
+        lower_segments
+            .into_iter()
+            .enumerate()
+            .map(
+                |(
+                    index,
+                    (
+                        transaction_id,
+                        transaction_path,
+                        transaction_leaf,
+                        transition_root,
+                        tcm,
+                        transition_path,
+                        transition_leaf,
+                    ),
+                )| {
+                    let transactions_path = transactions_tree.prove(index, &transaction_id.to_bits_le())?;
+                    StatePath::<N>::from(
+                        global_state_root.into(),
+                        block_path.clone(),
+                        block_hash.into(),
+                        previous_block_hash,
+                        *header_root,
+                        header_path.clone(),
+                        header_leaf,
+                        transactions_path,
+                        transaction_id.into(),
+                        transaction_path,
+                        transaction_leaf,
+                        transition_root,
+                        tcm,
+                        transition_path,
+                        transition_leaf,
+                    )
+                },
+            )
+            .collect()
     }
 
     /// Randomly sample a state path to a local state root.
@@ -359,7 +648,7 @@ pub mod test_helpers {
         let global_state_root = *block_tree.root();
         let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
 
-        Ok(StatePath::<N>::from(
+        StatePath::<N>::from(
             global_state_root.into(),
             block_path,
             block_hash,
@@ -375,6 +664,116 @@ pub mod test_helpers {
             tcm,
             transition_path,
             transition_leaf,
-        ))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given state path, with the given block hashes and a block path for the block hash.
+    fn with_block_hashes(
+        state_path: &StatePath<CurrentNetwork>,
+        block_hash: <CurrentNetwork as Network>::BlockHash,
+        previous_block_hash: <CurrentNetwork as Network>::BlockHash,
+    ) -> Result<StatePath<CurrentNetwork>> {
+        let block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
+        StatePath::from(
+            (*block_tree.root()).into(),
+            block_path,
+            block_hash,
+            previous_block_hash,
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+    }
+
+    #[test]
+    fn test_rejects_mismatched_transaction_leaf_index() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        assert_eq!(*state_path.transaction_path().leaf_index(), state_path.transaction_leaf().index() as u64);
+
+        // Returns the state path, with the given transaction leaf.
+        let with_transaction_leaf = |transaction_leaf| {
+            StatePath::from(
+                state_path.global_state_root(),
+                state_path.block_path().clone(),
+                state_path.block_hash(),
+                state_path.previous_block_hash(),
+                *state_path.header_root(),
+                state_path.header_path().clone(),
+                *state_path.header_leaf(),
+                state_path.transactions_path().clone(),
+                *state_path.transaction_id(),
+                state_path.transaction_path().clone(),
+                transaction_leaf,
+                *state_path.transition_root(),
+                *state_path.tcm(),
+                state_path.transition_path().clone(),
+                *state_path.transition_leaf(),
+            )
+        };
+
+        // Ensure a consistent transaction leaf is accepted and valid.
+        let transaction_leaf = *state_path.transaction_leaf();
+        with_transaction_leaf(transaction_leaf).unwrap().verify(true, Field::zero()).unwrap();
+
+        // Ensure a transaction leaf with a mismatched index is rejected.
+        let transaction_leaf = TransactionLeaf::new_execution(transaction_leaf.index() + 1, transaction_leaf.id());
+        let error = with_transaction_leaf(transaction_leaf).unwrap_err();
+        assert!(error.to_string().contains("does not match the transaction path leaf index"));
+    }
+
+    #[test]
+    fn test_rejects_self_referential_block_hash() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a block hash that equals its previous block hash is rejected.
+        let block_hash = state_path.block_hash();
+        let error = with_block_hashes(&state_path, block_hash, block_hash).unwrap_err();
+        assert!(error.to_string().contains("must not equal its previous block hash"));
+    }
+
+    #[test]
+    fn test_genesis_block_hash() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a genesis block, with a previous block hash of zero, is accepted and valid.
+        let previous_block_hash = Field::<CurrentNetwork>::zero().into();
+        let block_hash = ChainedBlockHash::block_hash(&previous_block_hash, state_path.header_root()).unwrap();
+        let genesis_state_path = with_block_hashes(&state_path, block_hash, previous_block_hash).unwrap();
+        genesis_state_path.verify(true, Field::zero()).unwrap();
+
+        // Ensure the zero placeholders of a local state path are accepted.
+        let local_state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        assert_eq!(local_state_path.block_hash(), local_state_path.previous_block_hash());
+        with_block_hashes(&state_path, local_state_path.block_hash(), local_state_path.previous_block_hash()).unwrap();
     }
 }
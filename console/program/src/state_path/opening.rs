@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A level of the state path, from the transition leaf up to the global state root.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatePathLevel {
+    /// The transition leaf, in the transition tree.
+    Transition,
+    /// The transaction leaf, in the transaction tree.
+    Transaction,
+    /// The transaction ID, in the transactions tree.
+    Transactions,
+    /// The block header leaf, in the block header tree.
+    Header,
+    /// The block hash, in the block tree.
+    Block,
+}
+
+impl StatePathLevel {
+    /// The levels of the state path, from the transition leaf up to the global state root.
+    pub const ALL: [Self; 5] = [Self::Transition, Self::Transaction, Self::Transactions, Self::Header, Self::Block];
+}
+
+/// The Merkle opening of a single level of a state path, as the leaf bits, Merkle path, and root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MerkleOpening<N: Network> {
+    /// The opening of the transition leaf to the transition root.
+    Transition { leaf: Vec<bool>, path: TransitionPath<N>, root: Field<N> },
+    /// The opening of the transaction leaf to the transaction ID.
+    Transaction { leaf: Vec<bool>, path: TransactionPath<N>, root: Field<N> },
+    /// The opening of the transaction ID to the header leaf ID.
+    Transactions { leaf: Vec<bool>, path: TransactionsPath<N>, root: Field<N> },
+    /// The opening of the block header leaf to the block header root.
+    Header { leaf: Vec<bool>, path: HeaderPath<N>, root: Field<N> },
+    /// The opening of the block hash to the global state root.
+    Block { leaf: Vec<bool>, path: BlockPath<N>, root: Field<N> },
+}
+
+impl<N: Network> MerkleOpening<N> {
+    /// Returns the level of the opening.
+    pub const fn level(&self) -> StatePathLevel {
+        match self {
+            Self::Transition { .. } => StatePathLevel::Transition,
+            Self::Transaction { .. } => StatePathLevel::Transaction,
+            Self::Transactions { .. } => StatePathLevel::Transactions,
+            Self::Header { .. } => StatePathLevel::Header,
+            Self::Block { .. } => StatePathLevel::Block,
+        }
+    }
+
+    /// Returns the leaf bits of the opening.
+    pub fn leaf(&self) -> &[bool] {
+        match self {
+            Self::Transition { leaf, .. }
+            | Self::Transaction { leaf, .. }
+            | Self::Transactions { leaf, .. }
+            | Self::Header { leaf, .. }
+            | Self::Block { leaf, .. } => leaf.as_slice(),
+        }
+    }
+
+    /// Returns the root of the opening.
+    pub const fn root(&self) -> &Field<N> {
+        match self {
+            Self::Transition { root, .. }
+            | Self::Transaction { root, .. }
+            | Self::Transactions { root, .. }
+            | Self::Header { root, .. }
+            | Self::Block { root, .. } => root,
+        }
+    }
+
+    /// Returns `true` if the Merkle path of the opening is valid for its root and leaf.
+    pub fn verify(&self) -> bool {
+        match self {
+            Self::Transition { leaf, path, root } => N::verify_merkle_path_bhp(path, root, leaf),
+            Self::Transaction { leaf, path, root } => N::verify_merkle_path_bhp(path, root, leaf),
+            Self::Transactions { leaf, path, root } => N::verify_merkle_path_bhp(path, root, leaf),
+            Self::Header { leaf, path, root } => N::verify_merkle_path_bhp(path, root, leaf),
+            Self::Block { leaf, path, root } => N::verify_merkle_path_bhp(path, root, leaf),
+        }
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the Merkle opening of the given level of the state path.
+    ///
+    /// The opening does not check the state path, and may be verified on its own with `MerkleOpening::verify`.
+    pub fn opening_for(&self, level: StatePathLevel) -> MerkleOpening<N> {
+        match level {
+            StatePathLevel::Transition => MerkleOpening::Transition {
+                leaf: self.transition_leaf.to_bits_le(),
+                path: self.transition_path.clone(),
+                root: self.transition_root,
+            },
+            StatePathLevel::Transaction => MerkleOpening::Transaction {
+                leaf: self.transaction_leaf.to_bits_le(),
+                path: self.transaction_path.clone(),
+                root: *self.transaction_id,
+            },
+            StatePathLevel::Transactions => MerkleOpening::Transactions {
+                leaf: self.transaction_id.to_bits_le(),
+                path: self.transactions_path.clone(),
+                root: self.header_leaf.id(),
+            },
+            StatePathLevel::Header => MerkleOpening::Header {
+                leaf: self.header_leaf.to_bits_le(),
+                path: self.header_path.clone(),
+                root: self.header_root,
+            },
+            StatePathLevel::Block => MerkleOpening::Block {
+                leaf: self.block_hash.to_bits_le(),
+                path: self.block_path.clone(),
+                root: *self.global_state_root,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_opening_for() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            for level in StatePathLevel::ALL {
+                // Ensure each opening verifies on its own.
+                let opening = state_path.opening_for(level);
+                assert_eq!(opening.level(), level);
+                assert!(opening.verify());
+
+                // Ensure the opening does not verify for a different root.
+                let candidate = match opening {
+                    MerkleOpening::Transition { leaf, path, .. } => {
+                        MerkleOpening::Transition { leaf, path, root: Field::rand(&mut rng) }
+                    }
+                    MerkleOpening::Transaction { leaf, path, .. } => {
+                        MerkleOpening::Transaction { leaf, path, root: Field::rand(&mut rng) }
+                    }
+                    MerkleOpening::Transactions { leaf, path, .. } => {
+                        MerkleOpening::Transactions { leaf, path, root: Field::rand(&mut rng) }
+                    }
+                    MerkleOpening::Header { leaf, path, .. } => {
+                        MerkleOpening::Header { leaf, path, root: Field::rand(&mut rng) }
+                    }
+                    MerkleOpening::Block { leaf, path, .. } => {
+                        MerkleOpening::Block { leaf, path, root: Field::rand(&mut rng) }
+                    }
+                };
+                assert!(!candidate.verify());
+            }
+        }
+    }
+}
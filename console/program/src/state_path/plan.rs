@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A selection of the segments of a state path to verify, from the transition leaf up to the global state root.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationPlan(u8);
+
+impl VerificationPlan {
+    /// Verifies the transition leaf belongs to the transition root.
+    pub const TRANSITION: Self = Self(1 << 0);
+    /// Verifies the transaction leaf is derived from the transition root, and belongs to the transaction ID.
+    pub const TRANSACTION: Self = Self(1 << 1);
+    /// Verifies the transaction ID belongs to the header leaf.
+    pub const TRANSACTIONS: Self = Self(1 << 2);
+    /// Verifies the header leaf belongs to the block header root.
+    pub const HEADER: Self = Self(1 << 3);
+    /// Verifies the block hash is derived from the previous block hash and block header root.
+    pub const BLOCK_HASH: Self = Self(1 << 4);
+    /// Verifies the block hash belongs to the global state root.
+    pub const BLOCK_PATH: Self = Self(1 << 5);
+    /// Verifies every segment.
+    pub const ALL: Self = Self((1 << 6) - 1);
+
+    /// Returns `true` if every segment of the given plan is selected.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no segment is selected.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Ensures at least one segment is selected, and the selected segments are adjacent.
+    ///
+    /// A gap between selected segments would leave the root of the lower segment unlinked from
+    /// the leaf of the upper segment, so such a plan is rejected.
+    pub(super) fn ensure_valid(&self) -> Result<()> {
+        ensure!(!self.is_empty(), "The verification plan does not select any segment");
+        ensure!(self.0 & !Self::ALL.0 == 0, "The verification plan selects an unknown segment");
+        // Ensure the selected segments are adjacent, as a contiguous run of bits.
+        let bits = self.0 >> self.0.trailing_zeros();
+        ensure!(bits & (bits + 1) == 0, "The verification plan skips a segment between two selected segments");
+        Ok(())
+    }
+}
+
+impl BitOr for VerificationPlan {
+    type Output = Self;
+
+    /// Returns the union of the two plans.
+    fn bitor(self, other: Self) -> Self::Output {
+        Self(self.0 | other.0)
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the selected segments of the state path are valid, along with the links between them.
+    ///
+    /// The selected segments must be adjacent. `StatePath::verify` to a global state root is
+    /// equivalent to `verify_plan(VerificationPlan::ALL)`.
+    pub fn verify_plan(&self, plan: VerificationPlan) -> Result<()> {
+        self.verify_plan_with::<ChainedBlockHash>(&N::bhp_merkle_hasher(), plan)
+    }
+
+    /// Returns the segments the state path carries the data to verify, without verifying them.
+    ///
+    /// A local state path from `StatePath::new_local` is not anchored to a block, and carries placeholders
+    /// above the transaction segment, with a zero block hash and previous block hash. Only its transition
+    /// and transaction segments are proven. Every other state path carries every segment.
+    pub fn proven_levels(&self) -> VerificationPlan {
+        match *self.block_hash == Field::zero() && *self.previous_block_hash == Field::zero() {
+            true => VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION,
+            false => VerificationPlan::ALL,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given state path, with the given global state root and tcm.
+    fn with_root_and_tcm(
+        state_path: &StatePath<CurrentNetwork>,
+        global_state_root: <CurrentNetwork as Network>::StateRoot,
+        tcm: Field<CurrentNetwork>,
+    ) -> StatePath<CurrentNetwork> {
+        StatePath::from(
+            global_state_root,
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            tcm,
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_plan() {
+        let mut rng = TestRng::default();
+
+        let lower = VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION | VerificationPlan::TRANSACTIONS;
+        let upper = VerificationPlan::HEADER | VerificationPlan::BLOCK_HASH | VerificationPlan::BLOCK_PATH;
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure every plan is valid for a valid state path.
+        state_path.verify_plan(VerificationPlan::ALL).unwrap();
+        state_path.verify_plan(lower).unwrap();
+        state_path.verify_plan(upper).unwrap();
+        state_path.verify_plan(VerificationPlan::TRANSITION).unwrap();
+        assert_eq!(lower | upper, VerificationPlan::ALL);
+
+        // Ensure an invalid global state root is only rejected by plans that select the block path.
+        let candidate = with_root_and_tcm(&state_path, Field::rand(&mut rng).into(), *state_path.tcm());
+        candidate.verify_plan(VerificationPlan::ALL).unwrap_err();
+        candidate.verify_plan(upper).unwrap_err();
+        candidate.verify_plan(lower).unwrap();
+        candidate.verify_plan(VerificationPlan::HEADER | VerificationPlan::BLOCK_HASH).unwrap();
+
+        // Ensure an invalid tcm is only rejected by plans that select the transaction segment.
+        let candidate = with_root_and_tcm(&state_path, state_path.global_state_root(), Field::rand(&mut rng));
+        candidate.verify_plan(VerificationPlan::ALL).unwrap_err();
+        candidate.verify_plan(lower).unwrap_err();
+        candidate.verify_plan(upper).unwrap();
+        candidate.verify_plan(VerificationPlan::TRANSITION).unwrap();
+    }
+
+    #[test]
+    fn test_verify_plan_rejects_invalid_plan() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a plan that skips the segments linking the transition to the block hash is rejected.
+        let plan = VerificationPlan::TRANSITION | VerificationPlan::BLOCK_PATH;
+        let error = state_path.verify_plan(plan).unwrap_err();
+        assert!(error.to_string().contains("skips a segment"));
+
+        // Ensure a plan that skips the header segment is rejected.
+        let plan = VerificationPlan::TRANSACTIONS | VerificationPlan::BLOCK_HASH;
+        assert!(state_path.verify_plan(plan).is_err());
+
+        // Ensure an empty plan is rejected.
+        assert!(state_path.verify_plan(VerificationPlan(0)).is_err());
+    }
+
+    #[test]
+    fn test_proven_levels() {
+        let mut rng = TestRng::default();
+
+        // Ensure a full state path proves every segment.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        assert_eq!(state_path.proven_levels(), VerificationPlan::ALL);
+        state_path.verify_plan(state_path.proven_levels()).unwrap();
+
+        // Ensure a local state path proves only the transition and transaction segments.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let state_path = StatePath::new_local(
+            state_path.global_state_root(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+        .unwrap();
+        let proven_levels = state_path.proven_levels();
+        assert_eq!(proven_levels, VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION);
+        assert!(!proven_levels.contains(VerificationPlan::BLOCK_PATH));
+        state_path.verify_plan(proven_levels).unwrap();
+        state_path.verify_plan(VerificationPlan::ALL).unwrap_err();
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The position of the proven leaf in its block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LeafPosition {
+    /// The index of the transaction in the block.
+    pub transaction_index: u64,
+    /// The index of the transition in the transaction.
+    pub transition_index: u16,
+    /// The index of the input or output in the transition.
+    pub io_index: u8,
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the position of the proven leaf in its block.
+    ///
+    /// The transaction index is the leaf index of the transactions path, while the transition index
+    /// and input or output index are decoded from the transaction leaf and transition leaf.
+    pub fn leaf_position(&self) -> LeafPosition {
+        LeafPosition {
+            transaction_index: *self.transactions_path.leaf_index(),
+            transition_index: self.transaction_leaf.index(),
+            io_index: self.transition_leaf.index(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_leaf_position() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths, where the transaction index is the position in the block.
+        let state_paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(3, &mut rng).unwrap();
+
+        // Ensure the position matches the indices used to build the state paths.
+        for (index, state_path) in state_paths.iter().enumerate() {
+            let position = state_path.leaf_position();
+            assert_eq!(position, LeafPosition { transaction_index: index as u64, transition_index: 0, io_index: 0 });
+            assert_eq!(position.transition_index, state_path.transaction_leaf().index());
+            assert_eq!(position.io_index, state_path.transition_leaf().index());
+        }
+    }
+}
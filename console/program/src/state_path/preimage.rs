@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the preimage of the block hash, as `( previous_block_hash || header_root )` in little-endian bits.
+    ///
+    /// Hashing the preimage with the BHP hash of 1024-bits reproduces the block hash of a valid state path.
+    pub fn block_hash_preimage(&self) -> Vec<bool> {
+        to_bits_le![(*self.previous_block_hash), self.header_root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_block_hash_preimage() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure hashing the preimage reproduces the block hash.
+            let preimage = state_path.block_hash_preimage();
+            assert_eq!(CurrentNetwork::hash_bhp1024(&preimage).unwrap(), *state_path.block_hash());
+
+            // Ensure the preimage matches the default block hash derivation.
+            let expected =
+                ChainedBlockHash::block_hash(&state_path.previous_block_hash(), state_path.header_root()).unwrap();
+            assert_eq!(CurrentNetwork::hash_bhp1024(&preimage).unwrap(), *expected);
+        }
+    }
+}
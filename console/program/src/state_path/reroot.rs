@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the state path, re-rooted to the given block tree.
+    ///
+    /// The block tree is append-only, so the block hash is expected at the same leaf index as in
+    /// the current block path. The global state root and block path are recomputed from the given
+    /// block tree, while all fields from the block hash downward are reused. The re-rooted state path
+    /// is verified before it is returned.
+    pub fn reroot(&self, new_block_tree: &BlockTree<N>) -> Result<Self> {
+        // Retrieve the leaf index of the block hash.
+        let leaf_index = usize::try_from(*self.block_path.leaf_index())?;
+        // Compute the block path in the new block tree.
+        let block_path = match new_block_tree.prove(leaf_index, &self.block_hash.to_bits_le()) {
+            Ok(block_path) => block_path,
+            Err(error) => bail!("Block hash '{}' is not present in the new block tree: {error}", self.block_hash),
+        };
+
+        // Construct the re-rooted state path.
+        self.with_state_root((*new_block_tree.root()).into(), block_path)
+    }
+
+    /// Returns the state path, re-anchored to the given global state root and block path.
+    ///
+    /// All fields from the block hash downward are reused. The given block path must authenticate
+    /// the block hash under the given global state root, and the re-anchored state path is verified
+    /// before it is returned.
+    pub fn with_state_root(&self, state_root: N::StateRoot, block_path: BlockPath<N>) -> Result<Self> {
+        // Ensure the block path authenticates the block hash under the global state root.
+        ensure!(
+            N::verify_merkle_path_bhp(&block_path, &state_root, &self.block_hash.to_bits_le()),
+            "Block hash '{}' does not belong to '{state_root}' (the new global state root)",
+            self.block_hash
+        );
+
+        // Construct the re-anchored state path.
+        let state_path = Self { global_state_root: state_root, block_path, ..self.clone() };
+        // Ensure the re-anchored state path is valid.
+        state_path.verify(true, Field::zero())?;
+        Ok(state_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reroot() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path, in a block tree with a single block.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let block_hash_bits = state_path.block_hash().to_bits_le();
+        let mut block_tree: BlockTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[block_hash_bits.clone()]).unwrap();
+        assert_eq!(*block_tree.root(), *state_path.global_state_root());
+
+        // Append new blocks to the block tree.
+        let new_block_hashes = (0..3).map(|_| Field::<CurrentNetwork>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        block_tree.append(&new_block_hashes).unwrap();
+
+        // Ensure the stale state path is no longer valid for the new root.
+        assert_ne!(*block_tree.root(), *state_path.global_state_root());
+
+        // Ensure the re-rooted state path is valid for the new root, and reuses the lower fields.
+        let candidate = state_path.reroot(&block_tree).unwrap();
+        assert_eq!(*candidate.global_state_root(), *block_tree.root());
+        assert_eq!(candidate.block_path(), &block_tree.prove(0, &block_hash_bits).unwrap());
+        assert_eq!(candidate.block_hash(), state_path.block_hash());
+        assert_eq!(candidate.transition_leaf(), state_path.transition_leaf());
+        candidate.verify(true, Field::zero()).unwrap();
+    }
+
+    #[test]
+    fn test_reroot_rejects_missing_block_hash() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Construct a block tree without the block hash.
+        let block_tree: BlockTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[Field::<CurrentNetwork>::rand(&mut rng).to_bits_le()]).unwrap();
+        let error = state_path.reroot(&block_tree).unwrap_err();
+        assert!(error.to_string().contains("is not present in the new block tree"));
+
+        // Construct an empty block tree.
+        let block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[]).unwrap();
+        assert!(state_path.reroot(&block_tree).is_err());
+    }
+
+    #[test]
+    fn test_with_state_root() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Construct a recomputed block tree, with the block hash at a different leaf index.
+        let block_hash_bits = state_path.block_hash().to_bits_le();
+        let mut leaves = (0..5).map(|_| Field::<CurrentNetwork>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        leaves[3] = block_hash_bits.clone();
+        let block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&leaves).unwrap();
+        let state_root = (*block_tree.root()).into();
+        let block_path = block_tree.prove(3, &block_hash_bits).unwrap();
+
+        // Ensure the re-anchored state path is valid for the new root, and reuses the lower fields.
+        let candidate = state_path.with_state_root(state_root, block_path.clone()).unwrap();
+        assert_eq!(candidate.global_state_root(), state_root);
+        assert_eq!(candidate.block_path(), &block_path);
+        assert_eq!(candidate.block_hash(), state_path.block_hash());
+        assert_eq!(candidate.transition_leaf(), state_path.transition_leaf());
+        candidate.verify(true, Field::zero()).unwrap();
+
+        // Ensure a block path that does not authenticate the block hash under the new root is rejected.
+        let error = state_path.with_state_root(state_root, state_path.block_path().clone()).unwrap_err();
+        assert!(error.to_string().contains("the new global state root"));
+        let error = state_path.with_state_root(state_path.global_state_root(), block_path).unwrap_err();
+        assert!(error.to_string().contains("the new global state root"));
+        let other_path = block_tree.prove(0, &leaves[0]).unwrap();
+        assert!(state_path.with_state_root(state_root, other_path).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when a state path is not in the same block as the first state path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DivergentBlock {
+    /// The index of the first state path that is not in the same block.
+    pub index: usize,
+}
+
+impl Display for DivergentBlock {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "State path {} is not in the same block as state path 0", self.index)
+    }
+}
+
+impl std::error::Error for DivergentBlock {}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if every given state path is valid for its global state root, and all state paths are in the
+    /// same block under the same global state root.
+    ///
+    /// Each state path must share the global state root, block hash, previous block hash, and block header root
+    /// of the first state path. Otherwise, this returns a `DivergentBlock` error with the index of the first
+    /// state path that does not.
+    pub fn assert_same_block(paths: &[Self]) -> Result<()> {
+        // Retrieve the first state path, if one exists.
+        let Some(first) = paths.first() else {
+            return Ok(());
+        };
+
+        for (index, path) in paths.iter().enumerate() {
+            // Ensure the state path is valid.
+            path.verify(true, Field::zero()).map_err(|error| anyhow!("State path {index} is invalid: {error}"))?;
+            // Ensure the state path is in the same block as the first state path.
+            if path.global_state_root != first.global_state_root
+                || path.block_hash != first.block_hash
+                || path.previous_block_hash != first.previous_block_hash
+                || path.header_root != first.header_root
+            {
+                return Err(DivergentBlock { index }.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_assert_same_block() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths in the same block, and a state path in another block.
+        let paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(3, &mut rng).unwrap();
+        let off_block =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure state paths in the same block are accepted.
+        StatePath::assert_same_block(&paths).unwrap();
+        StatePath::assert_same_block(&paths[..1]).unwrap();
+        StatePath::<CurrentNetwork>::assert_same_block(&[]).unwrap();
+
+        // Ensure the index of the first state path in another block is returned.
+        for index in 1..=paths.len() {
+            let mut candidate = paths.clone();
+            candidate.insert(index, off_block.clone());
+            candidate.push(off_block.clone());
+            let error = StatePath::assert_same_block(&candidate).unwrap_err();
+            assert_eq!(error.downcast_ref::<DivergentBlock>(), Some(&DivergentBlock { index }));
+        }
+
+        // Ensure an invalid state path is rejected, even in the same block.
+        let local = crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let error = StatePath::assert_same_block(&[local.clone(), local]).unwrap_err();
+        assert!(error.downcast_ref::<DivergentBlock>().is_none());
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+use std::collections::HashSet;
+
+/// A set of spent records, keyed by the ID in the transition leaf of their state path.
+pub trait SpentRecords<N: Network> {
+    /// Returns `true` if the given ID is marked as spent.
+    fn contains(&self, id: &Field<N>) -> bool;
+
+    /// Marks the given ID as spent.
+    fn insert(&mut self, id: Field<N>) -> Result<()>;
+
+    /// Unmarks the given ID as spent.
+    fn remove(&mut self, id: &Field<N>);
+}
+
+impl<N: Network> SpentRecords<N> for HashSet<Field<N>> {
+    /// Returns `true` if the given ID is marked as spent.
+    fn contains(&self, id: &Field<N>) -> bool {
+        HashSet::contains(self, id)
+    }
+
+    /// Marks the given ID as spent.
+    fn insert(&mut self, id: Field<N>) -> Result<()> {
+        HashSet::insert(self, id);
+        Ok(())
+    }
+
+    /// Unmarks the given ID as spent.
+    fn remove(&mut self, id: &Field<N>) {
+        HashSet::remove(self, id);
+    }
+}
+
+impl<N: Network> SpentRecords<N> for IndexSet<Field<N>> {
+    /// Returns `true` if the given ID is marked as spent.
+    fn contains(&self, id: &Field<N>) -> bool {
+        IndexSet::contains(self, id)
+    }
+
+    /// Marks the given ID as spent.
+    fn insert(&mut self, id: Field<N>) -> Result<()> {
+        IndexSet::insert(self, id);
+        Ok(())
+    }
+
+    /// Unmarks the given ID as spent.
+    fn remove(&mut self, id: &Field<N>) {
+        IndexSet::shift_remove(self, id);
+    }
+}
+
+/// The error returned when the record of a state path is already marked as spent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AlreadySpent<N: Network> {
+    /// The ID in the transition leaf of the state path.
+    pub id: Field<N>,
+}
+
+impl<N: Network> Display for AlreadySpent<N> {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The record '{}' is already marked as spent", self.id)
+    }
+}
+
+impl<N: Network> std::error::Error for AlreadySpent<N> {}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid for its global state root, and marks its record as spent.
+    ///
+    /// The record is keyed by the ID in the transition leaf. If the record is already marked as spent,
+    /// this returns an `AlreadySpent` error. If marking the record fails, the spent set is left unchanged.
+    pub fn verify_and_record(&self, spent_set: &mut impl SpentRecords<N>) -> Result<()> {
+        // Ensure the state path is valid.
+        self.verify(true, Field::zero())?;
+
+        // Ensure the record is not already marked as spent.
+        let id = self.transition_leaf.id();
+        if spent_set.contains(&id) {
+            return Err(AlreadySpent { id }.into());
+        }
+        // Mark the record as spent, rolling back on failure.
+        if let Err(error) = spent_set.insert(id) {
+            spent_set.remove(&id);
+            return Err(error);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A spent set that marks the ID as spent, and then fails.
+    #[derive(Default)]
+    struct FailingSpentSet(HashSet<Field<CurrentNetwork>>);
+
+    impl SpentRecords<CurrentNetwork> for FailingSpentSet {
+        fn contains(&self, id: &Field<CurrentNetwork>) -> bool {
+            self.0.contains(id)
+        }
+
+        fn insert(&mut self, id: Field<CurrentNetwork>) -> Result<()> {
+            self.0.insert(id);
+            bail!("Failed to persist the spent record")
+        }
+
+        fn remove(&mut self, id: &Field<CurrentNetwork>) {
+            self.0.remove(id);
+        }
+    }
+
+    #[test]
+    fn test_verify_and_record() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let id = state_path.transition_leaf().id();
+
+        // Ensure the record is marked as spent.
+        let mut spent_set = HashSet::new();
+        state_path.verify_and_record(&mut spent_set).unwrap();
+        assert!(spent_set.contains(&id));
+
+        // Ensure a double spend is rejected.
+        let error = state_path.verify_and_record(&mut spent_set).unwrap_err();
+        assert_eq!(error.downcast_ref::<AlreadySpent<CurrentNetwork>>(), Some(&AlreadySpent { id }));
+        assert_eq!(spent_set.len(), 1);
+    }
+
+    #[test]
+    fn test_verify_and_record_rejects_invalid_path() {
+        let mut rng = TestRng::default();
+
+        // Ensure an invalid state path is not marked as spent.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let mut spent_set = IndexSet::new();
+        let error = state_path.verify_and_record(&mut spent_set).unwrap_err();
+        assert!(error.downcast_ref::<AlreadySpent<CurrentNetwork>>().is_none());
+        assert!(spent_set.is_empty());
+    }
+
+    #[test]
+    fn test_verify_and_record_rolls_back() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a failed insertion is rolled back.
+        let mut spent_set = FailingSpentSet::default();
+        state_path.verify_and_record(&mut spent_set).unwrap_err();
+        assert!(spent_set.0.is_empty());
+    }
+}
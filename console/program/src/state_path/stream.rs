@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// A reader over a stream of concatenated state paths, which verifies each state path as it is read.
+///
+/// Only a single state path is buffered at a time. The reader stops after the first state path
+/// that fails to decode or verify, and reports the index of the failing state path.
+pub struct StreamingStatePathReader<R: Read, N: Network> {
+    /// The underlying reader.
+    reader: R,
+    /// A boolean indicating whether the state paths are verified to a global or local state root.
+    is_global: bool,
+    /// The local state root, if the state paths are verified to a local state root.
+    local_state_root: Field<N>,
+    /// The index of the next state path.
+    index: usize,
+    /// A boolean indicating whether the stream has ended.
+    is_done: bool,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<R: Read, N: Network> StreamingStatePathReader<R, N> {
+    /// Initializes a new streaming reader, which verifies each state path to its global state root.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            is_global: true,
+            local_state_root: Field::zero(),
+            index: 0,
+            is_done: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Initializes a new streaming reader, which verifies each state path to the given local state root.
+    pub fn new_local(reader: R, local_state_root: Field<N>) -> Self {
+        Self { reader, is_global: false, local_state_root, index: 0, is_done: false, _phantom: PhantomData }
+    }
+
+    /// Returns the next state path from the stream, or `None` if the stream has ended.
+    fn read_next(&mut self) -> Result<Option<StatePath<N>>> {
+        // Read the first byte, to determine if the stream has ended.
+        let mut first_byte = [0u8; 1];
+        if self.reader.read(&mut first_byte)? == 0 {
+            return Ok(None);
+        }
+        // Read the state path.
+        let state_path = StatePath::read_le((&first_byte[..]).chain(&mut self.reader))?;
+        // Ensure the state path is valid.
+        state_path.verify(self.is_global, self.local_state_root)?;
+        Ok(Some(state_path))
+    }
+}
+
+impl<R: Read, N: Network> Iterator for StreamingStatePathReader<R, N> {
+    type Item = Result<StatePath<N>>;
+
+    /// Returns the next verified state path from the stream.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.read_next() {
+            Ok(Some(state_path)) => {
+                self.index += 1;
+                Some(Ok(state_path))
+            }
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(error) => {
+                self.is_done = true;
+                Some(Err(anyhow!("State path {} in the stream is invalid: {error}", self.index)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_streaming_reader() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let state_paths = (0..3)
+            .map(|_| crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        // Construct the stream.
+        let stream = state_paths.iter().flat_map(|state_path| state_path.to_bytes_le().unwrap()).collect::<Vec<_>>();
+
+        // Ensure every state path is read and verified.
+        let candidates = StreamingStatePathReader::<_, CurrentNetwork>::new(&stream[..]).collect::<Result<Vec<_>>>();
+        assert_eq!(state_paths, candidates.unwrap());
+    }
+
+    #[test]
+    fn test_streaming_reader_aborts_on_corrupt_path() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let mut state_paths = (0..3)
+            .map(|_| crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        // Corrupt the tcm of the middle state path.
+        let path = &state_paths[1];
+        state_paths[1] = StatePath::from(
+            path.global_state_root(),
+            path.block_path().clone(),
+            path.block_hash(),
+            path.previous_block_hash(),
+            *path.header_root(),
+            path.header_path().clone(),
+            *path.header_leaf(),
+            path.transactions_path().clone(),
+            *path.transaction_id(),
+            path.transaction_path().clone(),
+            *path.transaction_leaf(),
+            *path.transition_root(),
+            Field::rand(&mut rng),
+            path.transition_path().clone(),
+            *path.transition_leaf(),
+        )
+        .unwrap();
+        // Construct the stream.
+        let stream = state_paths.iter().flat_map(|state_path| state_path.to_bytes_le().unwrap()).collect::<Vec<_>>();
+
+        // Ensure the first state path is read, and the stream aborts on the second state path.
+        let mut reader = StreamingStatePathReader::<_, CurrentNetwork>::new(&stream[..]);
+        assert_eq!(state_paths[0], reader.next().unwrap().unwrap());
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(error.to_string().starts_with("State path 1 in the stream is invalid"));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_streaming_reader_aborts_on_truncated_path() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let bytes = state_path.to_bytes_le().unwrap();
+
+        // Ensure a truncated stream aborts.
+        let mut reader = StreamingStatePathReader::<_, CurrentNetwork>::new(&bytes[..bytes.len() - 1]);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
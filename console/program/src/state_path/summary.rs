@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A summary of a verified state path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StatePathSummary<N: Network> {
+    /// The global state root.
+    pub global_state_root: N::StateRoot,
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The variant of the transition leaf, which is the kind of input or output.
+    pub transition_leaf_variant: u8,
+    /// The ID in the transition leaf.
+    pub transition_leaf_id: Field<N>,
+}
+
+impl<N: Network> Serialize for StatePathSummary<N> {
+    /// Serializes the summary into a struct.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut summary = serializer.serialize_struct("StatePathSummary", 4)?;
+        summary.serialize_field("global_state_root", &self.global_state_root)?;
+        summary.serialize_field("transaction_id", &self.transaction_id)?;
+        summary.serialize_field("transition_leaf_variant", &self.transition_leaf_variant)?;
+        summary.serialize_field("transition_leaf_id", &self.transition_leaf_id)?;
+        summary.end()
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid for its global state root, and returns a summary on success.
+    pub fn verify_summary(&self) -> Result<StatePathSummary<N>> {
+        self.verify(true, Field::zero())?;
+        Ok(StatePathSummary {
+            global_state_root: self.global_state_root,
+            transaction_id: self.transaction_id,
+            transition_leaf_variant: self.transition_leaf.variant(),
+            transition_leaf_id: self.transition_leaf.id(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_summary() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure the summary matches the state path.
+        let summary = state_path.verify_summary().unwrap();
+        assert_eq!(summary.global_state_root, state_path.global_state_root());
+        assert_eq!(summary.transaction_id, *state_path.transaction_id());
+        assert_eq!(summary.transition_leaf_variant, state_path.transition_leaf().variant());
+        assert_eq!(summary.transition_leaf_id, state_path.transition_leaf().id());
+
+        // Ensure the summary serializes each field.
+        let json = serde_json::to_value(summary).unwrap();
+        assert_eq!(json["global_state_root"], serde_json::to_value(summary.global_state_root).unwrap());
+        assert_eq!(json["transaction_id"], serde_json::to_value(summary.transaction_id).unwrap());
+        assert_eq!(json["transition_leaf_variant"], summary.transition_leaf_variant);
+        assert_eq!(json["transition_leaf_id"], serde_json::to_value(summary.transition_leaf_id).unwrap());
+
+        // Ensure an invalid state path does not return a summary.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        state_path.verify_summary().unwrap_err();
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Note: `ToBits` is infallible, so the byte serialization below is unwrapped. This cannot fail, as
+/// `to_bytes_le` writes into an in-memory buffer, which never returns an I/O error, and every field of
+/// the state path (the roots, hashes, Merkle paths, and leaves) is written without a fallible conversion.
+impl<N: Network> ToBits for StatePath<N> {
+    /// Returns the little-endian bits of the state path, as the little-endian bits of each byte of `to_bytes_le`.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        let bytes = self.to_bytes_le().expect("Serializing the state path into bytes is infallible");
+        vec.extend(Self::bytes_to_bits_le(&bytes));
+    }
+
+    /// Returns the big-endian bits of the state path, as the big-endian bits of each byte of `to_bytes_le`.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        let bytes = self.to_bytes_le().expect("Serializing the state path into bytes is infallible");
+        bytes.write_bits_be(vec);
+    }
+}
+
+impl<N: Network> FromBits for StatePath<N> {
+    /// Reads the state path from the little-endian bits of each byte of its byte representation.
+    fn from_bits_le(bits: &[bool]) -> Result<Self> {
+        Self::try_from(Self::bits_to_bytes_le(bits)?)
+    }
+
+    /// Reads the state path from the big-endian bits of each byte of its byte representation.
+    fn from_bits_be(bits: &[bool]) -> Result<Self> {
+        // Ensure the bits are byte-aligned.
+        ensure!(bits.len() % 8 == 0, "The state path bits are not byte-aligned");
+        Self::try_from(Vec::<u8>::from_bits_be(bits)?)
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the little-endian bits of each of the given bytes, which is the bit representation
+    /// of the state path with the given byte representation.
+    pub fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| byte.to_bits_le()).collect()
+    }
+
+    /// Returns the bytes packed from the given little-endian bits, which is the byte representation
+    /// of the state path with the given bit representation.
+    pub fn bits_to_bytes_le(bits: &[bool]) -> Result<Vec<u8>> {
+        // Ensure the bits are byte-aligned.
+        ensure!(bits.len() % 8 == 0, "The state path bits are not byte-aligned");
+        Vec::<u8>::from_bits_le(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_bits_and_bytes_parity() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let expected =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+            let bytes = expected.to_bytes_le().unwrap();
+            let bits = expected.to_bits_le();
+
+            // Ensure the bits are the packed bytes.
+            assert_eq!(bits.len(), bytes.len() * 8);
+            assert_eq!(bits, StatePath::<CurrentNetwork>::bytes_to_bits_le(&bytes));
+            assert_eq!(bytes, StatePath::<CurrentNetwork>::bits_to_bytes_le(&bits).unwrap());
+
+            // Ensure all of the round trips recover the state path.
+            assert_eq!(expected, StatePath::from_bytes_le(&bytes).unwrap());
+            assert_eq!(expected, StatePath::from_bits_le(&bits).unwrap());
+            assert_eq!(
+                expected,
+                StatePath::from_bytes_le(&StatePath::<CurrentNetwork>::bits_to_bytes_le(&bits).unwrap()).unwrap()
+            );
+            assert_eq!(
+                expected,
+                StatePath::from_bits_le(&StatePath::<CurrentNetwork>::bytes_to_bits_le(&bytes)).unwrap()
+            );
+            assert_eq!(expected, StatePath::from_bits_be(&expected.to_bits_be()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_bits_rejects_unaligned_bits() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure bits that are not byte-aligned are rejected.
+        let mut bits = state_path.to_bits_le();
+        bits.push(false);
+        assert!(StatePath::<CurrentNetwork>::from_bits_le(&bits).is_err());
+        assert!(StatePath::<CurrentNetwork>::from_bits_be(&bits).is_err());
+    }
+}
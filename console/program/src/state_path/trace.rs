@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> StatePath<N> {
+    /// Returns the hashes computed while verifying the transition path, from the transition leaf hash
+    /// up to the transition root.
+    pub fn transition_path_trace(&self) -> Result<Vec<Field<N>>> {
+        N::bhp_merkle_hasher().trace_merkle_path_bhp(&self.transition_path, &self.transition_leaf.to_bits_le())
+    }
+
+    /// Returns the hashes computed while verifying the transaction path, from the transaction leaf hash
+    /// up to the transaction ID.
+    pub fn transaction_path_trace(&self) -> Result<Vec<Field<N>>> {
+        N::bhp_merkle_hasher().trace_merkle_path_bhp(&self.transaction_path, &self.transaction_leaf.to_bits_le())
+    }
+
+    /// Returns the hashes computed while verifying the transactions path, from the transaction ID hash
+    /// up to the header leaf ID.
+    pub fn transactions_path_trace(&self) -> Result<Vec<Field<N>>> {
+        N::bhp_merkle_hasher().trace_merkle_path_bhp(&self.transactions_path, &self.transaction_id.to_bits_le())
+    }
+
+    /// Returns the hashes computed while verifying the header path, from the header leaf hash
+    /// up to the block header root.
+    pub fn header_path_trace(&self) -> Result<Vec<Field<N>>> {
+        N::bhp_merkle_hasher().trace_merkle_path_bhp(&self.header_path, &self.header_leaf.to_bits_le())
+    }
+
+    /// Returns the hashes computed while verifying the block path, from the block hash leaf hash
+    /// up to the global state root.
+    pub fn block_path_trace(&self) -> Result<Vec<Field<N>>> {
+        N::bhp_merkle_hasher().trace_merkle_path_bhp(&self.block_path, &self.block_hash.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_path_traces() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure each trace has one hash per level, and ends in the root of its level.
+            for (trace, depth, root) in [
+                (state_path.transition_path_trace(), TRANSITION_DEPTH, *state_path.transition_root()),
+                (state_path.transaction_path_trace(), TRANSACTION_DEPTH, **state_path.transaction_id()),
+                (state_path.transactions_path_trace(), TRANSACTIONS_DEPTH, state_path.header_leaf().id()),
+                (state_path.header_path_trace(), HEADER_DEPTH, *state_path.header_root()),
+                (state_path.block_path_trace(), BLOCKS_DEPTH, *state_path.global_state_root()),
+            ] {
+                let trace = trace.unwrap();
+                assert_eq!(trace.len(), depth as usize + 1);
+                assert_eq!(trace.last(), Some(&root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_trace_pinpoints_mismatch() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure the trace for a different transition leaf differs at every level, and does not end in the root.
+        let transition_leaf = state_path.transition_leaf().with_id(Field::rand(&mut rng));
+        let candidate = CurrentNetwork::bhp_merkle_hasher()
+            .trace_merkle_path_bhp(state_path.transition_path(), &transition_leaf.to_bits_le())
+            .unwrap();
+        let expected = state_path.transition_path_trace().unwrap();
+        assert!(candidate.iter().zip_eq(&expected).all(|(candidate, expected)| candidate != expected));
+        assert_ne!(candidate.last(), Some(state_path.transition_root()));
+    }
+}
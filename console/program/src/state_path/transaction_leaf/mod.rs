@@ -66,6 +66,11 @@ impl<N: Network> TransactionLeaf<N> {
     pub const fn id(&self) -> Field<N> {
         self.id
     }
+
+    /// Returns a copy of the Merkle leaf with the given ID, preserving the variant and index.
+    pub const fn with_id(&self, id: Field<N>) -> Self {
+        Self { id, ..*self }
+    }
 }
 
 #[cfg(test)]
@@ -80,3 +85,34 @@ mod test_helpers {
         TransactionLeaf::from(rng.gen(), rng.gen(), Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_with_id() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a leaf, and re-key it to a new ID.
+            let leaf = test_helpers::sample_leaf(&mut rng);
+            let id = Field::<CurrentNetwork>::rand(&mut rng);
+            let candidate = leaf.with_id(id);
+            assert_eq!(candidate.id(), id);
+            assert_eq!(candidate.variant(), leaf.variant());
+            assert_eq!(candidate.index(), leaf.index());
+
+            // Ensure the bits only differ in the ID region.
+            let (expected_bits, candidate_bits) = (leaf.to_bits_le(), candidate.to_bits_le());
+            assert_eq!(expected_bits.len(), candidate_bits.len());
+            assert_eq!(expected_bits[..24], candidate_bits[..24]);
+            assert_eq!(candidate_bits[24..], id.to_bits_le());
+        }
+    }
+}
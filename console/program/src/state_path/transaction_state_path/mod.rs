@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+
+/// The transaction state path proves existence of a transaction ID to a global state root,
+/// without naming an input or output of the transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionStatePath<N: Network> {
+    /// The global state root (Public).
+    global_state_root: N::StateRoot,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<N>,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<N>,
+    /// The block header leaf.
+    header_leaf: HeaderLeaf<N>,
+    /// The Merkle path for the transaction ID.
+    transactions_path: TransactionsPath<N>,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+}
+
+impl<N: Network> TransactionStatePath<N> {
+    /// Initializes a new instance of `TransactionStatePath`.
+    ///
+    /// This does not check the transaction state path; use `TransactionStatePath::verify` to check it.
+    /// A header leaf whose index differs from the leaf index of its header path is rejected,
+    /// as is a self-referential block, whose block hash equals its previous block hash.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from(
+        global_state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+        transactions_path: TransactionsPath<N>,
+        transaction_id: N::TransactionID,
+    ) -> Result<Self> {
+        // Ensure the header leaf index matches the leaf index of the header path.
+        ensure!(
+            *header_path.leaf_index() == header_leaf.index() as u64,
+            "Header leaf index '{}' does not match the header path leaf index '{}'",
+            header_leaf.index(),
+            *header_path.leaf_index()
+        );
+        // Ensure the block does not reference itself.
+        ensure!(previous_block_hash != block_hash, "Block hash '{block_hash}' must not equal its previous block hash");
+        // Return the transaction state path.
+        Ok(Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+        })
+    }
+
+    /// Returns the global state root.
+    pub const fn global_state_root(&self) -> N::StateRoot {
+        self.global_state_root
+    }
+
+    /// Returns the block path.
+    pub const fn block_path(&self) -> &BlockPath<N> {
+        &self.block_path
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> &Field<N> {
+        &self.header_root
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Returns the transactions path.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Checks if the transaction state path is valid for its global state root.
+    ///
+    /// This performs the transactions, header, block hash, and block path checks of `StatePath::verify`.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the header leaf is the transactions root.
+        ensure!(
+            self.header_leaf.index_name() == Some(HeaderLeafIndex::TransactionsRoot),
+            "Header leaf index must be {} ({}), found {}",
+            HeaderLeafIndex::TransactionsRoot.index(),
+            HeaderLeafIndex::TransactionsRoot,
+            self.header_leaf.index()
+        );
+        // Ensure the transactions path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                &self.transactions_path,
+                &self.header_leaf.id(),
+                &self.transaction_id.to_bits_le()
+            ),
+            "Transaction '{}' does not belong to '{}' (a header leaf)",
+            self.transaction_id,
+            self.header_leaf
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            self.header_leaf,
+            self.block_hash
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            self.block_hash == ChainedBlockHash::block_hash(&self.previous_block_hash, &self.header_root)?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        // Ensure the global state root is correct.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le()),
+            "'{}' (a block hash) does not belong to '{}' (a global state root)",
+            self.block_hash,
+            self.global_state_root
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the transaction state path for the transaction in the state path.
+    pub fn to_transaction_state_path(&self) -> TransactionStatePath<N> {
+        TransactionStatePath {
+            global_state_root: self.global_state_root,
+            block_path: self.block_path.clone(),
+            block_hash: self.block_hash,
+            previous_block_hash: self.previous_block_hash,
+            header_root: self.header_root,
+            header_path: self.header_path.clone(),
+            header_leaf: self.header_leaf,
+            transactions_path: self.transactions_path.clone(),
+            transaction_id: self.transaction_id,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// Randomly sample a transaction state path to a global state root.
+    pub fn sample_transaction_state_path<N: Network>(rng: &mut TestRng) -> Result<TransactionStatePath<N>> {
+        Ok(crate::state_path::test_helpers::sample_global_state_path::<N>(None, rng)?.to_transaction_state_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_verify() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Ensure the transaction state path of a valid state path is valid.
+            let path = state_path.to_transaction_state_path();
+            path.verify().unwrap();
+            assert_eq!(path.transaction_id(), state_path.transaction_id());
+
+            // Ensure the transaction state path round-trips through its constructor.
+            let candidate = TransactionStatePath::from(
+                path.global_state_root(),
+                path.block_path().clone(),
+                path.block_hash(),
+                path.previous_block_hash(),
+                *path.header_root(),
+                path.header_path().clone(),
+                *path.header_leaf(),
+                path.transactions_path().clone(),
+                *path.transaction_id(),
+            )
+            .unwrap();
+            assert_eq!(path, candidate);
+
+            // Ensure a transaction state path for a different transaction ID is invalid.
+            let candidate = TransactionStatePath { transaction_id: Field::rand(&mut rng).into(), ..path.clone() };
+            candidate.verify().unwrap_err();
+
+            // Ensure a transaction state path for a different global state root is invalid.
+            let candidate = TransactionStatePath { global_state_root: Field::rand(&mut rng).into(), ..path };
+            candidate.verify().unwrap_err();
+        }
+    }
+}
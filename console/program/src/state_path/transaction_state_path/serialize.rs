@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for TransactionStatePath<N> {
+    /// Serializes the transaction state path into bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransactionStatePath<N> {
+    /// Deserializes the transaction state path from bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction state path")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the transaction state path.
+        let expected = test_helpers::sample_transaction_state_path::<CurrentNetwork>(&mut rng)?;
+
+        // Ensure the transaction state path round-trips through JSON.
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the transaction state path.
+        let expected = test_helpers::sample_transaction_state_path::<CurrentNetwork>(&mut rng)?;
+
+        // Ensure the transaction state path round-trips through bincode.
+        let candidate_bytes = bincode::serialize(&expected)?;
+        assert_eq!(expected, bincode::deserialize(&candidate_bytes[..])?);
+        Ok(())
+    }
+}
@@ -21,7 +21,9 @@ use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::Field;
 
 /// The transition leaf version.
-const TRANSITION_LEAF_VERSION: u8 = 1u8;
+pub(crate) const TRANSITION_LEAF_VERSION: u8 = 1u8;
+/// The maximum transition leaf variant, as defined by the input and output variants of a transition.
+pub const MAX_TRANSITION_LEAF_VARIANT: u8 = 5u8;
 
 /// The Merkle leaf for an input or output ID in the transition.
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -37,6 +39,13 @@ pub struct TransitionLeaf<N: Network> {
 }
 
 impl<N: Network> TransitionLeaf<N> {
+    /// Initializes a new instance of `TransitionLeaf`, ensuring the variant is within range.
+    pub fn new(index: u8, variant: u8, id: Field<N>) -> Result<Self> {
+        let leaf = Self::new_with_version(index, variant, id);
+        ensure!(leaf.has_valid_variant(), "Transition leaf variant '{variant}' is out of range");
+        Ok(leaf)
+    }
+
     /// Initializes a new instance of `TransitionLeaf`.
     pub const fn new_with_version(index: u8, variant: u8, id: Field<N>) -> Self {
         Self { version: TRANSITION_LEAF_VERSION, index, variant, id }
@@ -66,6 +75,16 @@ impl<N: Network> TransitionLeaf<N> {
     pub const fn id(&self) -> Field<N> {
         self.id
     }
+
+    /// Returns `true` if the variant is within the range of input and output variants.
+    pub const fn has_valid_variant(&self) -> bool {
+        self.variant <= MAX_TRANSITION_LEAF_VARIANT
+    }
+
+    /// Returns a copy of the Merkle leaf with the given ID, preserving the version, index, and variant.
+    pub const fn with_id(&self, id: Field<N>) -> Self {
+        Self { id, ..*self }
+    }
 }
 
 #[cfg(test)]
@@ -80,3 +99,48 @@ mod test_helpers {
         TransitionLeaf::new_with_version(rng.gen(), rng.gen(), Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_with_id() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a leaf, and re-key it to a new ID.
+            let leaf = test_helpers::sample_leaf(&mut rng);
+            let id = Field::<CurrentNetwork>::rand(&mut rng);
+            let candidate = leaf.with_id(id);
+            assert_eq!(candidate.id(), id);
+            assert_eq!(candidate.version(), leaf.version());
+            assert_eq!(candidate.index(), leaf.index());
+            assert_eq!(candidate.variant(), leaf.variant());
+
+            // Ensure the bits only differ in the ID region.
+            let (expected_bits, candidate_bits) = (leaf.to_bits_le(), candidate.to_bits_le());
+            assert_eq!(expected_bits.len(), candidate_bits.len());
+            assert_eq!(expected_bits[..24], candidate_bits[..24]);
+            assert_eq!(candidate_bits[24..], id.to_bits_le());
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_out_of_range_variant() {
+        let mut rng = TestRng::default();
+
+        for variant in 0..=u8::MAX {
+            let id = Field::<CurrentNetwork>::rand(&mut rng);
+            match variant <= MAX_TRANSITION_LEAF_VARIANT {
+                true => assert_eq!(TransitionLeaf::new(0, variant, id).unwrap().variant(), variant),
+                false => assert!(TransitionLeaf::new(0, variant, id).is_err()),
+            }
+        }
+    }
+}
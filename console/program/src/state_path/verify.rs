@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use snarkvm_console_network::BHPMerkleHasher;
 
 impl<N: Network> StatePath<N> {
     /// Checks if the state path is valid.
@@ -52,84 +53,211 @@ impl<N: Network> StatePath<N> {
     ///                                                                          transition_leaf
     /// ```
     pub fn verify(&self, is_global: bool, local_state_root: Field<N>) -> Result<()> {
-        // Ensure the transition leaf variant is 3 (Input::Record).
-        ensure!(self.transition_leaf.variant() == 3, "Transition leaf variant must be 3 (Input::Record)");
-        // Ensure the transition path is valid.
-        ensure!(
-            N::verify_merkle_path_bhp(&self.transition_path, &self.transition_root, &self.transition_leaf.to_bits_le()),
-            "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
-            self.transition_leaf.id(),
-            self.transaction_leaf.id()
-        );
+        self.verify_with_block_hash::<ChainedBlockHash>(is_global, local_state_root)
+    }
 
-        // Ensure the transaction leaf is correct.
-        ensure!(
-            *self.transaction_leaf.id() == *N::hash_bhp512(&(*self.transition_root, self.tcm).to_bits_le())?,
-            "Transaction leaf id '{}' is incorrect. Double-check the tcm and transition root.",
-            self.transaction_leaf.id()
-        );
+    /// Checks if the state path is valid, using the given BHP hasher.
+    ///
+    /// This allows a batch verifier to share a single hasher across many state paths.
+    /// Refer to `StatePath::verify` for the checks.
+    pub fn verify_with_hasher(
+        &self,
+        hasher: &BHPMerkleHasher<N>,
+        is_global: bool,
+        local_state_root: Field<N>,
+    ) -> Result<()> {
+        self.verify_with_block_hash_and_hasher::<ChainedBlockHash>(hasher, is_global, local_state_root)
+    }
+
+    /// Checks if the state path is valid for its global state root, and returns the global state root on success.
+    pub fn verify_into_root(&self) -> Result<N::StateRoot> {
+        self.verify(true, Field::zero())?;
+        Ok(self.global_state_root)
+    }
+
+    /// Checks if the state path is valid for its global state root, and returns the commitment on success.
+    pub fn verify_into_commitment(&self) -> Result<Field<N>> {
+        self.verify(true, Field::zero())?;
+        Ok(self.transition_leaf.id())
+    }
 
-        // Ensure the transaction leaf variant is 1 (Transaction::Execution).
-        ensure!(self.transaction_leaf.variant() == 1, "Transaction leaf variant must be 1 (Transaction::Execution)");
-        // Ensure the transaction path is valid.
+    /// Checks if the state path proves a transition in the given transaction,
+    /// without checking the block and block header portion of the state path.
+    pub fn verify_within_transaction(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Ensure the transaction ID matches the expected transaction ID.
         ensure!(
-            N::verify_merkle_path_bhp(
-                &self.transaction_path,
-                &self.transaction_id,
-                &self.transaction_leaf.to_bits_le()
-            ),
-            "'{}' (a function or transition) does not belong to transaction '{}'",
-            self.transaction_leaf.id(),
+            self.transaction_id == *transaction_id,
+            "State path is for transaction '{}', but transaction '{transaction_id}' was expected",
             self.transaction_id
         );
+        // Ensure the transition and transaction portion of the state path is valid.
+        self.verify(false, **transaction_id)
+    }
 
-        if is_global {
-            // Ensure the header leaf index is 1 (Header::transactions_root).
-            ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
-            // Ensure the transactions path is valid.
+    /// Checks if the state path is valid, using the given block hash derivation.
+    ///
+    /// This allows networks with an alternative block hash preimage to verify their state paths.
+    /// Refer to `StatePath::verify` for the remaining checks.
+    pub fn verify_with_block_hash<M: BlockHashMode<N>>(
+        &self,
+        is_global: bool,
+        local_state_root: Field<N>,
+    ) -> Result<()> {
+        self.verify_with_block_hash_and_hasher::<M>(&N::bhp_merkle_hasher(), is_global, local_state_root)
+    }
+
+    /// Checks if the state path is valid, using the given block hash derivation and BHP hasher.
+    fn verify_with_block_hash_and_hasher<M: BlockHashMode<N>>(
+        &self,
+        hasher: &BHPMerkleHasher<N>,
+        is_global: bool,
+        local_state_root: Field<N>,
+    ) -> Result<()> {
+        match is_global {
+            // Ensure every segment of the state path is valid.
+            true => self.verify_plan_with::<M>(hasher, VerificationPlan::ALL),
+            false => {
+                // Ensure the transition and transaction segments of the state path are valid.
+                self.verify_plan_with::<M>(hasher, VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION)?;
+                // Ensure the local state root is correct.
+                ensure!(
+                    *self.transaction_id == local_state_root,
+                    "'{}' (a decoded transaction ID) does not match the '{local_state_root}' (a local state root)",
+                    *self.transaction_id
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks if the selected segments of the state path are valid, using the given block hash derivation
+    /// and BHP hasher.
+    pub(super) fn verify_plan_with<M: BlockHashMode<N>>(
+        &self,
+        hasher: &BHPMerkleHasher<N>,
+        plan: VerificationPlan,
+    ) -> Result<()> {
+        // Ensure the plan is valid.
+        plan.ensure_valid()?;
+        // Ensure the state path is well-formed, before computing any hashes.
+        self.ensure_well_formed()?;
+
+        // If the transition and transaction segments are both selected, verify their paths in one batch, as they
+        // share the same depth. If the batch is invalid, the individual checks below report which path is invalid.
+        let transition_leaf_bits = self.transition_leaf.to_bits_le();
+        let transaction_leaf_bits = self.transaction_leaf.to_bits_le();
+        let is_batch_valid = plan.contains(VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION)
+            && hasher.verify_merkle_paths_bhp::<TRANSITION_DEPTH>(&[
+                (&self.transition_path, &self.transition_root, &transition_leaf_bits),
+                (&self.transaction_path, &*self.transaction_id, &transaction_leaf_bits),
+            ]);
+
+        if plan.contains(VerificationPlan::TRANSITION) {
+            // Ensure the transition path is valid.
+            if !is_batch_valid
+                && !hasher.verify_merkle_path_bhp(&self.transition_path, &self.transition_root, &transition_leaf_bits)
+            {
+                ensure_nonempty_tree::<N, TRANSITION_DEPTH>(&self.transition_root, "transition")?;
+                bail!(
+                    "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
+                    self.transition_leaf.id(),
+                    self.transaction_leaf.id()
+                )
+            }
+        }
+
+        if plan.contains(VerificationPlan::TRANSACTION) {
+            // Ensure the transaction leaf is correct.
             ensure!(
-                N::verify_merkle_path_bhp(
-                    &self.transactions_path,
-                    &self.header_leaf.id(),
-                    &self.transaction_id.to_bits_le()
-                ),
-                "Transaction '{}' does not belong to '{}' (a header leaf)",
-                self.transaction_id,
-                self.header_leaf
+                *self.transaction_leaf.id() == *hasher.hash_bhp512(&(*self.transition_root, self.tcm).to_bits_le())?,
+                "Transaction leaf id '{}' is incorrect. Double-check the tcm and transition root.",
+                self.transaction_leaf.id()
             );
-            // Ensure the header path is valid.
+            // Ensure the transaction path is valid.
+            if !is_batch_valid
+                && !hasher.verify_merkle_path_bhp(&self.transaction_path, &self.transaction_id, &transaction_leaf_bits)
+            {
+                ensure_nonempty_tree::<N, TRANSACTION_DEPTH>(&self.transaction_id, "transaction")?;
+                bail!(
+                    "'{}' (a function or transition) does not belong to transaction '{}'",
+                    self.transaction_leaf.id(),
+                    self.transaction_id
+                )
+            }
+        }
+
+        if plan.contains(VerificationPlan::TRANSACTIONS) {
+            // Ensure the header leaf is the transactions root.
+            let expected = HeaderLeafIndex::TransactionsRoot;
             ensure!(
-                N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
-                "'{}' (a header leaf) does not belong to '{}' (a block header)",
-                self.header_leaf,
-                self.block_hash
+                self.header_leaf.index_name() == Some(expected),
+                "Header leaf index must be {} ({expected}), found {}",
+                expected.index(),
+                match self.header_leaf.index_name() {
+                    Some(index_name) => format!("{} ({index_name})", self.header_leaf.index()),
+                    None => self.header_leaf.index().to_string(),
+                }
             );
-            // Ensure the block hash is correct.
+            // Ensure the transactions path is valid.
+            if !hasher.verify_merkle_path_bhp(
+                &self.transactions_path,
+                &self.header_leaf.id(),
+                &self.transaction_id.to_bits_le(),
+            ) {
+                ensure_nonempty_tree::<N, TRANSACTIONS_DEPTH>(&self.header_leaf.id(), "transactions")?;
+                bail!("Transaction '{}' does not belong to '{}' (a header leaf)", self.transaction_id, self.header_leaf)
+            }
+        }
+
+        if plan.contains(VerificationPlan::HEADER) {
+            // Ensure the header path is valid.
+            if !hasher.verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()) {
+                ensure_nonempty_tree::<N, HEADER_DEPTH>(&self.header_root, "header")?;
+                bail!(
+                    "'{}' (a header leaf) does not belong to '{}' (a block header)",
+                    self.header_leaf,
+                    self.block_hash
+                )
+            }
+        }
+
+        if plan.contains(VerificationPlan::BLOCK_HASH) {
+            // Ensure the block hash is correct. The block header root in the preimage is the same value the
+            // header path is verified against, so the block hash commits to the root that contains the header leaf.
             ensure!(
-                *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+                self.block_hash == M::block_hash_with_hasher(hasher, &self.previous_block_hash, &self.header_root)?,
                 "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
                 self.block_hash
             );
+        }
+
+        if plan.contains(VerificationPlan::BLOCK_PATH) {
             // Ensure the global state root is correct.
-            ensure!(
-                N::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le()),
-                "'{}' (a block hash) does not belong to '{}' (a global state root)",
-                self.block_hash,
-                self.global_state_root
-            );
-        } else {
-            // Ensure the local state root is correct.
-            ensure!(
-                *self.transaction_id == local_state_root,
-                "'{}' (a decoded transaction ID) does not match the '{local_state_root}' (a local state root)",
-                *self.transaction_id
-            );
+            if !hasher.verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le())
+            {
+                ensure_nonempty_tree::<N, BLOCKS_DEPTH>(&self.global_state_root, "block")?;
+                bail!(
+                    "'{}' (a block hash) does not belong to '{}' (a global state root)",
+                    self.block_hash,
+                    self.global_state_root
+                )
+            }
         }
 
         Ok(())
     }
 }
 
+/// Ensures the given root is not the root of an empty Merkle tree, for which no Merkle path exists.
+///
+/// This check is only performed once a Merkle path has failed to verify, to report a clearer error
+/// without adding the cost of computing the empty root to every successful verification.
+fn ensure_nonempty_tree<N: Network, const DEPTH: u8>(root: &Field<N>, name: &str) -> Result<()> {
+    let empty_tree = N::merkle_tree_bhp::<DEPTH>(&[])?;
+    ensure!(root != empty_tree.root(), "Found the root of an empty {name} tree, which has no Merkle path to any leaf");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +285,148 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_verify_into_root_and_commitment() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the commitment and state path.
+            let commitment = Field::rand(rng);
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(Some(commitment), rng)
+                    .unwrap();
+
+            // Ensure the verified root and commitment are returned.
+            assert_eq!(state_path.verify_into_root().unwrap(), state_path.global_state_root());
+            assert_eq!(state_path.verify_into_commitment().unwrap(), commitment);
+
+            // Ensure an invalid state path returns an error.
+            let state_path =
+                crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(Some(commitment), rng)
+                    .unwrap();
+            state_path.verify_into_root().unwrap_err();
+            state_path.verify_into_commitment().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn test_verify_within_transaction() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+            // Ensure the state path is valid for its transaction ID.
+            state_path.verify_within_transaction(state_path.transaction_id()).unwrap();
+
+            // Ensure the state path is *not* valid for a different transaction ID.
+            let transaction_id = Field::<CurrentNetwork>::rand(rng).into();
+            let error = state_path.verify_within_transaction(&transaction_id).unwrap_err();
+            assert!(error.to_string().contains("was expected"));
+        }
+    }
+
+    #[test]
+    fn test_verify_with_hasher() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the hasher once, for all of the state paths.
+        let hasher = CurrentNetwork::bhp_merkle_hasher();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state paths.
+            let global_state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+            let local_state_path =
+                crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, rng).unwrap();
+            let local_state_root = **local_state_path.transaction_id();
+
+            // Ensure the hasher agrees with `verify` for valid and invalid state paths.
+            for (state_path, is_global, local_state_root) in [
+                (&global_state_path, true, Field::zero()),
+                (&global_state_path, false, Field::rand(rng)),
+                (&local_state_path, false, local_state_root),
+                (&local_state_path, false, Field::rand(rng)),
+                (&local_state_path, true, local_state_root),
+            ] {
+                let expected = state_path.verify(is_global, local_state_root);
+                let candidate = state_path.verify_with_hasher(&hasher, is_global, local_state_root);
+                assert_eq!(expected.is_ok(), candidate.is_ok());
+                if let (Err(expected), Err(candidate)) = (expected, candidate) {
+                    assert_eq!(expected.to_string(), candidate.to_string());
+                }
+            }
+        }
+    }
+
+    /// A block hash derivation that only hashes the block header root.
+    struct HeaderOnlyBlockHash;
+
+    impl<N: Network> BlockHashMode<N> for HeaderOnlyBlockHash {
+        fn block_hash(_previous_block_hash: &N::BlockHash, header_root: &Field<N>) -> Result<N::BlockHash> {
+            Ok(N::hash_bhp1024(&header_root.to_bits_le())?.into())
+        }
+    }
+
+    /// Returns the given state path, with the given block hash, constructed under the given block hash derivation.
+    fn with_block_hash<M: BlockHashMode<CurrentNetwork>>(
+        state_path: &StatePath<CurrentNetwork>,
+        block_hash: <CurrentNetwork as Network>::BlockHash,
+    ) -> Result<StatePath<CurrentNetwork>> {
+        let block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
+        StatePath::from_with_block_hash::<M>(
+            (*block_tree.root()).into(),
+            block_path,
+            block_hash,
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+    }
+
+    #[test]
+    fn test_verify_with_block_hash() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+            // Ensure the default state path is *not* valid under the header-only derivation.
+            state_path.verify_with_block_hash::<HeaderOnlyBlockHash>(true, Field::zero()).unwrap_err();
+
+            // Ensure the default state path is constructed under the default derivation only.
+            with_block_hash::<ChainedBlockHash>(&state_path, state_path.block_hash()).unwrap();
+            let error = with_block_hash::<HeaderOnlyBlockHash>(&state_path, state_path.block_hash()).unwrap_err();
+            assert!(error.to_string().contains("Double-check the previous block hash and block header root"));
+
+            // Construct the state path under the header-only derivation.
+            let block_hash =
+                HeaderOnlyBlockHash::block_hash(&state_path.previous_block_hash(), state_path.header_root()).unwrap();
+            let candidate = with_block_hash::<HeaderOnlyBlockHash>(&state_path, block_hash).unwrap();
+            // Ensure the state path is *not* constructed under the default derivation.
+            with_block_hash::<ChainedBlockHash>(&state_path, block_hash).unwrap_err();
+
+            // Ensure the state path is valid under the header-only derivation.
+            candidate.verify_with_block_hash::<HeaderOnlyBlockHash>(true, Field::zero()).unwrap();
+            // Ensure the state path is *not* valid under the default derivation.
+            candidate.verify(true, Field::zero()).unwrap_err();
+        }
+    }
+
     #[test]
     fn test_verify_local() {
         let rng = &mut TestRng::default();
@@ -214,4 +484,147 @@ mod tests {
             new_local_state_path.verify(true, Field::rand(rng)).unwrap_err();
         }
     }
+
+    #[test]
+    fn test_verify_single_leaf_trees() {
+        let rng = &mut TestRng::default();
+
+        // Sample the state path, in which the transition, transaction, and block trees each contain a single leaf.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+        // Ensure each single-leaf path is at index 0, with the empty hashes as its siblings.
+        let empty_tree: TransitionTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[]).unwrap();
+        let single_leaf_tree: TransitionTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[state_path.transition_leaf().to_bits_le()]).unwrap();
+        assert_eq!(*state_path.transition_path().leaf_index(), 0);
+        assert_eq!(single_leaf_tree.root(), state_path.transition_root());
+        assert_ne!(empty_tree.root(), single_leaf_tree.root());
+
+        // Ensure the state path is valid.
+        state_path.verify(true, Field::zero()).unwrap();
+    }
+
+    #[test]
+    fn test_verify_rejects_empty_trees() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an empty tree does not produce a path.
+        let empty_tree: TransitionTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[]).unwrap();
+        assert!(empty_tree.prove(0, &Field::<CurrentNetwork>::rand(rng).to_bits_le()).is_err());
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+        // Ensure a state path with an empty transition tree is rejected.
+        let candidate = StatePath::from(
+            state_path.global_state_root(),
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *empty_tree.root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+        .unwrap();
+        let error = candidate.verify(true, Field::zero()).unwrap_err();
+        assert!(error.to_string().contains("empty transition tree"));
+
+        // Ensure a state path with an empty block tree is rejected.
+        let empty_block_tree: BlockTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&[]).unwrap();
+        let candidate = StatePath::from(
+            (*empty_block_tree.root()).into(),
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+        .unwrap();
+        let error = candidate.verify(true, Field::zero()).unwrap_err();
+        assert!(error.to_string().contains("empty block tree"));
+    }
+
+    #[test]
+    fn test_verify_rejects_inconsistent_header_root() {
+        let rng = &mut TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+        // Returns the state path with the given block and header segments.
+        let with_header = |block_hash: <CurrentNetwork as Network>::BlockHash, header_root, header_path| {
+            let block_tree: BlockTree<CurrentNetwork> =
+                CurrentNetwork::merkle_tree_bhp(&[block_hash.to_bits_le()]).unwrap();
+            StatePath::from(
+                (*block_tree.root()).into(),
+                block_tree.prove(0, &block_hash.to_bits_le()).unwrap(),
+                block_hash,
+                state_path.previous_block_hash(),
+                header_root,
+                header_path,
+                *state_path.header_leaf(),
+                state_path.transactions_path().clone(),
+                *state_path.transaction_id(),
+                state_path.transaction_path().clone(),
+                *state_path.transaction_leaf(),
+                *state_path.transition_root(),
+                *state_path.tcm(),
+                state_path.transition_path().clone(),
+                *state_path.transition_leaf(),
+            )
+            .unwrap()
+        };
+
+        // Construct another block header tree, which also contains the header leaf.
+        let header_leaf = state_path.header_leaf();
+        let header_leaves = (0..1u8 << HEADER_DEPTH)
+            .map(|index| match index == header_leaf.index() {
+                true => header_leaf.to_bits_le(),
+                false => HeaderLeaf::<CurrentNetwork>::new(index, Field::rand(rng)).to_bits_le(),
+            })
+            .collect::<Vec<_>>();
+        let header_tree: HeaderTree<CurrentNetwork> = CurrentNetwork::merkle_tree_bhp(&header_leaves).unwrap();
+        let other_header_root = *header_tree.root();
+        let other_header_path = header_tree.prove(header_leaf.index() as usize, &header_leaf.to_bits_le()).unwrap();
+        assert_ne!(other_header_root, *state_path.header_root());
+
+        // Ensure a block hash over the original header root, with a header path to the other header root, is rejected.
+        let candidate = with_header(state_path.block_hash(), other_header_root, other_header_path.clone());
+        let error = candidate.verify(true, Field::zero()).unwrap_err();
+        assert!(error.to_string().contains("Double-check the previous block hash and block header root"));
+        let candidate = with_header(state_path.block_hash(), *state_path.header_root(), other_header_path.clone());
+        let error = candidate.verify(true, Field::zero()).unwrap_err();
+        assert!(error.to_string().contains("(a header leaf) does not belong to"));
+
+        // Ensure a block hash over the other header root, with a header path to the original header root, is rejected.
+        let other_block_hash =
+            ChainedBlockHash::block_hash(&state_path.previous_block_hash(), &other_header_root).unwrap();
+        let candidate = with_header(other_block_hash, *state_path.header_root(), state_path.header_path().clone());
+        let error = candidate.verify(true, Field::zero()).unwrap_err();
+        assert!(error.to_string().contains("Double-check the previous block hash and block header root"));
+
+        // Ensure the block hash and header path are valid when both use the other header root.
+        let candidate = with_header(other_block_hash, other_header_root, other_header_path);
+        candidate.verify(true, Field::zero()).unwrap();
+    }
 }
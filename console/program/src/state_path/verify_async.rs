@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use tokio::task;
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid, on a blocking thread of the Tokio runtime.
+    ///
+    /// The result matches `StatePath::verify`. Refer to `StatePath::verify` for the checks.
+    pub async fn verify_async(&self, is_global: bool, local_state_root: Field<N>) -> Result<()> {
+        let state_path = self.clone();
+        task::spawn_blocking(move || state_path.verify(is_global, local_state_root)).await?
+    }
+
+    /// Checks if every given state path is valid, on a blocking thread of the Tokio runtime.
+    ///
+    /// The state paths are checked in order, and the first error is returned.
+    pub async fn batch_verify_async(state_paths: &[Self], is_global: bool, local_state_root: Field<N>) -> Result<()> {
+        let state_paths = state_paths.to_vec();
+        task::spawn_blocking(move || {
+            state_paths.iter().try_for_each(|state_path| state_path.verify(is_global, local_state_root))
+        })
+        .await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Runs the given future to completion, on a single-threaded Tokio runtime.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+    }
+
+    #[test]
+    fn test_verify_async() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let global_state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let local_state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure the result matches `verify` for a valid and an invalid state path.
+        block_on(global_state_path.verify_async(true, Field::zero())).unwrap();
+        let expected = local_state_path.verify(true, Field::zero()).unwrap_err();
+        let candidate = block_on(local_state_path.verify_async(true, Field::zero())).unwrap_err();
+        assert_eq!(expected.to_string(), candidate.to_string());
+
+        // Ensure a batch is valid if and only if every state path is valid.
+        let state_paths = vec![global_state_path.clone(), global_state_path.clone()];
+        block_on(StatePath::batch_verify_async(&state_paths, true, Field::zero())).unwrap();
+        let state_paths = vec![global_state_path, local_state_path];
+        let candidate = block_on(StatePath::batch_verify_async(&state_paths, true, Field::zero())).unwrap_err();
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
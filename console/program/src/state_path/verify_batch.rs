@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> StatePath<N> {
+    /// Checks if every given state path is valid for the same global state root.
+    ///
+    /// The header, block hash, and block path segments are verified once for each distinct block header leaf,
+    /// and the result is reused for every state path in the same block. Refer to `StatePath::verify` for the checks.
+    pub fn verify_batch(state_paths: &[Self]) -> Result<()> {
+        // Retrieve the first state path, if one exists.
+        let Some(first) = state_paths.first() else {
+            return Ok(());
+        };
+
+        let hasher = N::bhp_merkle_hasher();
+        let lower = VerificationPlan::TRANSITION | VerificationPlan::TRANSACTION | VerificationPlan::TRANSACTIONS;
+        let upper = VerificationPlan::HEADER | VerificationPlan::BLOCK_HASH | VerificationPlan::BLOCK_PATH;
+
+        // The state paths whose header, block hash, and block path segments are verified.
+        let mut verified_upper_segments: Vec<&Self> = Vec::new();
+        for (index, state_path) in state_paths.iter().enumerate() {
+            // Ensure the state path is for the same global state root.
+            ensure!(
+                state_path.global_state_root == first.global_state_root,
+                "State path {index} is for global state root '{}', but '{}' was expected",
+                state_path.global_state_root,
+                first.global_state_root
+            );
+            // Ensure the transition, transaction, and transactions segments are valid.
+            if let Err(error) = state_path.verify_plan_with::<ChainedBlockHash>(&hasher, lower) {
+                bail!("State path {index} is invalid: {error}")
+            }
+            // Ensure the header, block hash, and block path segments are valid, unless an identical set was verified.
+            let is_verified = verified_upper_segments.iter().any(|other| {
+                state_path.block_path == other.block_path
+                    && state_path.block_hash == other.block_hash
+                    && state_path.previous_block_hash == other.previous_block_hash
+                    && state_path.header_root == other.header_root
+                    && state_path.header_path == other.header_path
+                    && state_path.header_leaf == other.header_leaf
+            });
+            if !is_verified {
+                if let Err(error) = state_path.verify_plan_with::<ChainedBlockHash>(&hasher, upper) {
+                    bail!("State path {index} is invalid: {error}")
+                }
+                verified_upper_segments.push(state_path);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_batch() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths in the same block.
+        let state_paths =
+            crate::state_path::test_helpers::sample_global_state_paths_in_block::<CurrentNetwork>(4, &mut rng).unwrap();
+
+        // Ensure the state paths are valid as a batch, and individually.
+        StatePath::verify_batch(&state_paths).unwrap();
+        StatePath::<CurrentNetwork>::verify_batch(&[]).unwrap();
+        for state_path in &state_paths {
+            state_path.verify(true, Field::zero()).unwrap();
+        }
+
+        // Ensure a state path for another global state root is rejected.
+        let other =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let mut candidate = state_paths.clone();
+        candidate.push(other);
+        let error = StatePath::verify_batch(&candidate).unwrap_err();
+        assert!(error.to_string().contains("State path 4 is for global state root"));
+
+        // Ensure a state path with an invalid lower segment is rejected, with its index.
+        let mut candidate = state_paths.clone();
+        candidate[2] = StatePath { tcm: Field::rand(&mut rng), ..candidate[2].clone() };
+        let error = StatePath::verify_batch(&candidate).unwrap_err();
+        assert!(error.to_string().contains("State path 2 is invalid"));
+
+        // Ensure a state path with an invalid upper segment is rejected, even after the block was verified.
+        let mut candidate = state_paths;
+        candidate[3] = StatePath { previous_block_hash: Field::rand(&mut rng).into(), ..candidate[3].clone() };
+        let error = StatePath::verify_batch(&candidate).unwrap_err();
+        assert!(error.to_string().contains("State path 3 is invalid"));
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when a state path is invalid, identifying the state path by its chain of IDs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidStatePath<N: Network> {
+    /// The global state root.
+    pub global_state_root: N::StateRoot,
+    /// The block hash.
+    pub block_hash: N::BlockHash,
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The ID in the transition leaf.
+    pub transition_leaf_id: Field<N>,
+    /// The reason the state path is invalid, which names the failing segment.
+    pub reason: String,
+}
+
+impl<N: Network> Display for InvalidStatePath<N> {
+    /// Prints the error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid state path (global state root '{}', block hash '{}', transaction '{}', transition leaf '{}'): {}",
+            self.global_state_root, self.block_hash, self.transaction_id, self.transition_leaf_id, self.reason
+        )
+    }
+}
+
+impl<N: Network> std::error::Error for InvalidStatePath<N> {}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid, and on failure returns an `InvalidStatePath` error that identifies
+    /// the state path by its global state root, block hash, transaction ID, and transition leaf ID.
+    ///
+    /// Refer to `StatePath::verify` for the checks.
+    pub fn verify_verbose(&self, is_global: bool, local_state_root: Field<N>) -> Result<()> {
+        self.verify(is_global, local_state_root).map_err(|error| {
+            InvalidStatePath {
+                global_state_root: self.global_state_root,
+                block_hash: self.block_hash,
+                transaction_id: self.transaction_id,
+                transition_leaf_id: self.transition_leaf.id(),
+                reason: error.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_verbose() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        // Ensure a valid state path is accepted.
+        state_path.verify_verbose(true, Field::zero()).unwrap();
+
+        // Ensure an invalid state path is rejected with every identifier, and the reason from `verify`.
+        let local_state_root = Field::rand(&mut rng);
+        let error = state_path.verify_verbose(false, local_state_root).unwrap_err();
+        let expected = state_path.verify(false, local_state_root).unwrap_err().to_string();
+        assert_eq!(
+            error.downcast_ref::<InvalidStatePath<CurrentNetwork>>(),
+            Some(&InvalidStatePath {
+                global_state_root: state_path.global_state_root(),
+                block_hash: state_path.block_hash(),
+                transaction_id: *state_path.transaction_id(),
+                transition_leaf_id: state_path.transition_leaf().id(),
+                reason: expected.clone(),
+            })
+        );
+
+        // Ensure the message contains every identifier, and the reason from `verify`.
+        let message = error.to_string();
+        assert!(message.contains(&state_path.global_state_root().to_string()));
+        assert!(message.contains(&state_path.block_hash().to_string()));
+        assert!(message.contains(&state_path.transaction_id().to_string()));
+        assert!(message.contains(&state_path.transition_leaf().id().to_string()));
+        assert!(message.ends_with(&expected));
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A visitor over the levels of a state path, from the transition leaf up to the global state root.
+///
+/// Each method receives the leaf, root, and Merkle path of one level, and does nothing by default.
+pub trait StatePathVisitor<N: Network> {
+    /// Visits the transition leaf, with the transition root and transition path.
+    fn visit_transition(&mut self, _leaf: &TransitionLeaf<N>, _root: &Field<N>, _path: &TransitionPath<N>) {}
+
+    /// Visits the transaction leaf, with the transaction ID and transaction path.
+    fn visit_transaction(&mut self, _leaf: &TransactionLeaf<N>, _root: &N::TransactionID, _path: &TransactionPath<N>) {}
+
+    /// Visits the transaction ID, with the header leaf and transactions path.
+    fn visit_transactions(&mut self, _leaf: &N::TransactionID, _root: &HeaderLeaf<N>, _path: &TransactionsPath<N>) {}
+
+    /// Visits the header leaf, with the block header root and header path.
+    fn visit_header(&mut self, _leaf: &HeaderLeaf<N>, _root: &Field<N>, _path: &HeaderPath<N>) {}
+
+    /// Visits the block hash, with the global state root and block path.
+    fn visit_block(&mut self, _leaf: &N::BlockHash, _root: &N::StateRoot, _path: &BlockPath<N>) {}
+}
+
+impl<N: Network> StatePath<N> {
+    /// Walks the levels of the state path with the given visitor, from the transition leaf up to the global state root.
+    ///
+    /// Every level is visited, including the block and block header levels of a local state path.
+    pub fn walk<V: StatePathVisitor<N>>(&self, visitor: &mut V) {
+        visitor.visit_transition(&self.transition_leaf, &self.transition_root, &self.transition_path);
+        visitor.visit_transaction(&self.transaction_leaf, &self.transaction_id, &self.transaction_path);
+        visitor.visit_transactions(&self.transaction_id, &self.header_leaf, &self.transactions_path);
+        visitor.visit_header(&self.header_leaf, &self.header_root, &self.header_path);
+        visitor.visit_block(&self.block_hash, &self.global_state_root, &self.block_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A visitor that collects the leaf ID of each level.
+    #[derive(Default)]
+    struct LeafIdCollector {
+        ids: Vec<Field<CurrentNetwork>>,
+    }
+
+    impl StatePathVisitor<CurrentNetwork> for LeafIdCollector {
+        fn visit_transition(
+            &mut self,
+            leaf: &TransitionLeaf<CurrentNetwork>,
+            _root: &Field<CurrentNetwork>,
+            _path: &TransitionPath<CurrentNetwork>,
+        ) {
+            self.ids.push(leaf.id());
+        }
+
+        fn visit_transaction(
+            &mut self,
+            leaf: &TransactionLeaf<CurrentNetwork>,
+            _root: &<CurrentNetwork as Network>::TransactionID,
+            _path: &TransactionPath<CurrentNetwork>,
+        ) {
+            self.ids.push(leaf.id());
+        }
+
+        fn visit_transactions(
+            &mut self,
+            leaf: &<CurrentNetwork as Network>::TransactionID,
+            _root: &HeaderLeaf<CurrentNetwork>,
+            _path: &TransactionsPath<CurrentNetwork>,
+        ) {
+            self.ids.push(**leaf);
+        }
+
+        fn visit_header(
+            &mut self,
+            leaf: &HeaderLeaf<CurrentNetwork>,
+            _root: &Field<CurrentNetwork>,
+            _path: &HeaderPath<CurrentNetwork>,
+        ) {
+            self.ids.push(leaf.id());
+        }
+
+        fn visit_block(
+            &mut self,
+            leaf: &<CurrentNetwork as Network>::BlockHash,
+            _root: &<CurrentNetwork as Network>::StateRoot,
+            _path: &BlockPath<CurrentNetwork>,
+        ) {
+            self.ids.push(**leaf);
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Collect the leaf ID of each level.
+        let mut visitor = LeafIdCollector::default();
+        state_path.walk(&mut visitor);
+
+        // Ensure the levels are visited in order, from the transition leaf up to the block hash.
+        assert_eq!(
+            visitor.ids,
+            vec![
+                state_path.transition_leaf().id(),
+                state_path.transaction_leaf().id(),
+                **state_path.transaction_id(),
+                state_path.header_leaf().id(),
+                *state_path.block_hash(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_console_collections::merkle_tree::MerklePath;
+
+impl<N: Network> StatePath<N> {
+    /// Returns `true` if the state path is structurally well-formed.
+    ///
+    /// This performs only non-cryptographic checks, and is a cheap pre-filter for `StatePath::verify`.
+    /// A well-formed state path is not necessarily valid.
+    pub fn is_well_formed(&self) -> bool {
+        self.ensure_well_formed().is_ok()
+    }
+
+    /// Ensures the state path is structurally well-formed, without computing any hashes.
+    pub(super) fn ensure_well_formed(&self) -> Result<()> {
+        /// Ensures the given Merkle path has the given depth, with an in-bounds leaf index.
+        fn ensure_path<N: Network, const DEPTH: u8>(path: &MerklePath<N, DEPTH>, name: &str) -> Result<()> {
+            ensure!(path.siblings().len() == DEPTH as usize, "The {name} path has an incorrect length");
+            ensure!((*path.leaf_index() as u128) < (1u128 << DEPTH), "The {name} path has an out of bounds leaf index");
+            Ok(())
+        }
+
+        // Ensure each Merkle path is well-formed.
+        ensure_path(&self.block_path, "block")?;
+        ensure_path(&self.header_path, "header")?;
+        ensure_path(&self.transactions_path, "transactions")?;
+        ensure_path(&self.transaction_path, "transaction")?;
+        ensure_path(&self.transition_path, "transition")?;
+
+        // Ensure the transition leaf version is correct.
+        ensure!(self.transition_leaf.version() == TRANSITION_LEAF_VERSION, "Transition leaf version is incorrect");
+        // Ensure the transition leaf variant is 3 (Input::Record).
+        ensure!(self.transition_leaf.variant() == 3, "Transition leaf variant must be 3 (Input::Record)");
+        // Ensure the transaction leaf variant is 1 (Transaction::Execution).
+        ensure!(self.transaction_leaf.variant() == 1, "Transaction leaf variant must be 1 (Transaction::Execution)");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the given state path, with the given leaves, without the checks of `StatePath::from`.
+    fn with_leaves(
+        state_path: &StatePath<CurrentNetwork>,
+        transaction_leaf: TransactionLeaf<CurrentNetwork>,
+        transition_leaf: TransitionLeaf<CurrentNetwork>,
+    ) -> StatePath<CurrentNetwork> {
+        StatePath { transaction_leaf, transition_leaf, ..state_path.clone() }
+    }
+
+    /// Returns the given state path, with the given leaves, constructed with `StatePath::from`.
+    fn from_leaves(
+        state_path: &StatePath<CurrentNetwork>,
+        transaction_leaf: TransactionLeaf<CurrentNetwork>,
+        transition_leaf: TransitionLeaf<CurrentNetwork>,
+    ) -> Result<StatePath<CurrentNetwork>> {
+        StatePath::from(
+            state_path.global_state_root(),
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            transaction_leaf,
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            transition_leaf,
+        )
+    }
+
+    #[test]
+    fn test_is_well_formed() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure sampled state paths are well-formed.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+            assert!(state_path.is_well_formed());
+            let state_path =
+                crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+            assert!(state_path.is_well_formed());
+        }
+    }
+
+    #[test]
+    fn test_from_is_well_formed() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Ensure a well-formed state path is constructed.
+        let candidate = from_leaves(&state_path, *state_path.transaction_leaf(), *state_path.transition_leaf());
+        assert_eq!(candidate.unwrap(), state_path);
+    }
+
+    #[test]
+    fn test_is_well_formed_rejects_malformed_paths() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let (transaction_leaf, transition_leaf) = (*state_path.transaction_leaf(), *state_path.transition_leaf());
+
+        // Ensure a transition leaf with an incorrect version is rejected.
+        let leaf = TransitionLeaf::from(2, transition_leaf.index(), transition_leaf.variant(), transition_leaf.id());
+        let candidate = with_leaves(&state_path, transaction_leaf, leaf);
+        assert!(!candidate.is_well_formed());
+        assert!(candidate.verify(true, Field::zero()).is_err());
+        assert!(from_leaves(&state_path, *candidate.transaction_leaf(), *candidate.transition_leaf()).is_err());
+
+        // Ensure a transition leaf with a non-record variant is rejected.
+        let leaf = TransitionLeaf::new_with_version(transition_leaf.index(), 1, transition_leaf.id());
+        let candidate = with_leaves(&state_path, transaction_leaf, leaf);
+        assert!(!candidate.is_well_formed());
+        assert!(candidate.verify(true, Field::zero()).is_err());
+        assert!(from_leaves(&state_path, *candidate.transaction_leaf(), *candidate.transition_leaf()).is_err());
+
+        // Ensure a transaction leaf with a deployment variant is rejected.
+        let leaf = TransactionLeaf::new_deployment(transaction_leaf.index(), transaction_leaf.id());
+        let candidate = with_leaves(&state_path, leaf, transition_leaf);
+        assert!(!candidate.is_well_formed());
+        assert!(candidate.verify(true, Field::zero()).is_err());
+        assert!(from_leaves(&state_path, *candidate.transaction_leaf(), *candidate.transition_leaf()).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::VecDeque;
+
+/// A bounded history of the most recent global state roots, from oldest to latest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateRootWindow<N: Network> {
+    /// The maximum number of state roots in the window.
+    capacity: usize,
+    /// The state roots in the window.
+    roots: VecDeque<N::StateRoot>,
+}
+
+impl<N: Network> StateRootWindow<N> {
+    /// Initializes a new, empty window of the given capacity.
+    pub fn new(capacity: usize) -> Result<Self> {
+        ensure!(capacity > 0, "The state root window must have a capacity of at least 1");
+        Ok(Self { capacity, roots: VecDeque::with_capacity(capacity) })
+    }
+
+    /// Returns the maximum number of state roots in the window.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of state roots in the window.
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Returns the latest state root, if one exists.
+    pub fn latest(&self) -> Option<&N::StateRoot> {
+        self.roots.back()
+    }
+
+    /// Returns an iterator over the state roots, from oldest to latest.
+    pub fn roots(&self) -> impl '_ + DoubleEndedIterator<Item = &N::StateRoot> {
+        self.roots.iter()
+    }
+
+    /// Returns `true` if the given state root is in the window.
+    pub fn contains(&self, state_root: &N::StateRoot) -> bool {
+        self.roots.contains(state_root)
+    }
+
+    /// Appends the given state root as the latest, evicting the oldest state root if the window is full.
+    pub fn push(&mut self, state_root: N::StateRoot) {
+        if self.roots.len() == self.capacity {
+            self.roots.pop_front();
+        }
+        self.roots.push_back(state_root);
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Checks if the state path is valid, and if it is for a global root, that its global state root
+    /// is one of the recent state roots in the given window.
+    ///
+    /// This allows a state path to remain valid while the ledger advances by fewer blocks than the window.
+    pub fn verify_in_window(
+        &self,
+        is_global: bool,
+        local_state_root: Field<N>,
+        window: &StateRootWindow<N>,
+    ) -> Result<()> {
+        // Ensure the global state root is recent.
+        if is_global && !window.contains(&self.global_state_root) {
+            bail!("The global state root '{}' is not in the recent state root window", self.global_state_root)
+        }
+        // Ensure the state path is valid.
+        self.verify(is_global, local_state_root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_window_evicts_oldest() {
+        let mut rng = TestRng::default();
+
+        // Ensure an empty window is rejected.
+        assert!(StateRootWindow::<CurrentNetwork>::new(0).is_err());
+
+        let mut window = StateRootWindow::<CurrentNetwork>::new(3).unwrap();
+        assert!(window.is_empty());
+        assert_eq!(window.latest(), None);
+
+        // Sample the state roots.
+        let roots: Vec<<CurrentNetwork as Network>::StateRoot> =
+            (0..5).map(|_| Field::<CurrentNetwork>::rand(&mut rng).into()).collect();
+
+        for (i, root) in roots.iter().enumerate() {
+            window.push(*root);
+            assert_eq!(window.len(), (i + 1).min(3));
+            assert_eq!(window.latest(), Some(root));
+        }
+
+        // Ensure only the latest three state roots remain, from oldest to latest.
+        assert_eq!(window.roots().copied().collect::<Vec<_>>(), roots[2..]);
+        assert!(!window.contains(&roots[0]));
+        assert!(!window.contains(&roots[1]));
+        assert!(roots[2..].iter().all(|root| window.contains(root)));
+    }
+
+    #[test]
+    fn test_verify_in_window() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+        // Add the global state root, followed by newer state roots.
+        let mut window = StateRootWindow::<CurrentNetwork>::new(3).unwrap();
+        window.push(state_path.global_state_root());
+        for _ in 0..2 {
+            window.push(Field::<CurrentNetwork>::rand(&mut rng).into());
+            // Ensure the state path is valid, as its global state root is still recent.
+            state_path.verify_in_window(true, Field::zero(), &window).unwrap();
+        }
+
+        // Advance the window past the global state root.
+        window.push(Field::<CurrentNetwork>::rand(&mut rng).into());
+        // Ensure the state path is now stale.
+        assert!(state_path.verify_in_window(true, Field::zero(), &window).is_err());
+    }
+
+    #[test]
+    fn test_verify_in_window_local() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let local_state_root = **state_path.transaction_id();
+
+        // Ensure a local state path does not depend on the window.
+        let window = StateRootWindow::<CurrentNetwork>::new(1).unwrap();
+        state_path.verify_in_window(false, local_state_root, &window).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_console_collections::merkle_tree::MerklePath;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The flattened witness of a state path, in the order the state path circuit allocates its variables.
+///
+/// Roots, hashes, IDs, and siblings are allocated as field elements, while leaf indices and
+/// leaf metadata (versions, indices, variants) are allocated as little-endian bits,
+/// where each bit is encoded as a field element of `0` or `1`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatePathWitness<N: Network> {
+    /// The public inputs of the state path (i.e. the global state root).
+    public_inputs: Vec<Field<N>>,
+    /// The private inputs of the state path.
+    private_inputs: Vec<Field<N>>,
+}
+
+impl<N: Network> StatePathWitness<N> {
+    /// Returns the public inputs of the state path.
+    pub fn public_inputs(&self) -> &[Field<N>] {
+        &self.public_inputs
+    }
+
+    /// Returns the private inputs of the state path.
+    pub fn private_inputs(&self) -> &[Field<N>] {
+        &self.private_inputs
+    }
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the flattened witness of the state path, for use by the prover.
+    pub fn to_witness(&self) -> StatePathWitness<N> {
+        /// Appends the given bits to the witness, as field elements.
+        fn append_bits<N: Network>(witness: &mut Vec<Field<N>>, bits: &[bool]) {
+            witness.extend(bits.iter().map(|bit| match bit {
+                true => Field::one(),
+                false => Field::zero(),
+            }));
+        }
+
+        /// Appends the given Merkle path to the witness, as (leaf_index || siblings).
+        fn append_path<N: Network, const DEPTH: u8>(witness: &mut Vec<Field<N>>, path: &MerklePath<N, DEPTH>) {
+            append_bits(witness, &path.leaf_index().to_bits_le());
+            witness.extend_from_slice(path.siblings());
+        }
+
+        let mut witness = Vec::new();
+
+        // Append the block segment.
+        append_path(&mut witness, &self.block_path);
+        witness.push(*self.block_hash);
+        witness.push(*self.previous_block_hash);
+        witness.push(self.header_root);
+
+        // Append the header segment.
+        append_path(&mut witness, &self.header_path);
+        append_bits(&mut witness, &self.header_leaf.index().to_bits_le());
+        witness.push(self.header_leaf.id());
+
+        // Append the transactions segment.
+        append_path(&mut witness, &self.transactions_path);
+        witness.push(*self.transaction_id);
+
+        // Append the transaction segment.
+        append_path(&mut witness, &self.transaction_path);
+        append_bits(&mut witness, &self.transaction_leaf.variant().to_bits_le());
+        append_bits(&mut witness, &self.transaction_leaf.index().to_bits_le());
+        witness.push(self.transaction_leaf.id());
+        witness.push(self.transition_root);
+        witness.push(self.tcm);
+
+        // Append the transition segment.
+        append_path(&mut witness, &self.transition_path);
+        append_bits(&mut witness, &self.transition_leaf.version().to_bits_le());
+        append_bits(&mut witness, &self.transition_leaf.index().to_bits_le());
+        append_bits(&mut witness, &self.transition_leaf.variant().to_bits_le());
+        witness.push(self.transition_leaf.id());
+
+        StatePathWitness { public_inputs: vec![*self.global_state_root], private_inputs: witness }
+    }
+
+    /// Returns the flattened witness of each of the given state paths in parallel, in the same order.
+    #[cfg(feature = "parallel")]
+    pub fn to_witnesses_parallel(paths: &[Self]) -> Vec<StatePathWitness<N>> {
+        paths.par_iter().map(Self::to_witness).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// The number of private inputs in the state path witness, derived from its layout.
+    const NUM_PRIVATE_INPUTS: usize = {
+        // Each Merkle path is allocated as a 64-bit leaf index, followed by its siblings.
+        let num_paths =
+            5 * 64 + (BLOCKS_DEPTH + HEADER_DEPTH + TRANSACTIONS_DEPTH + TRANSACTION_DEPTH + TRANSITION_DEPTH) as usize;
+        // The hashes, roots, and IDs of the block, header, transactions, transaction, and transition segments.
+        let num_fields = 3 + 1 + 1 + 3 + 1;
+        // The header leaf index, the transaction leaf variant and index, and the transition leaf metadata.
+        let num_bits = 8 + (8 + 16) + (8 + 8 + 8);
+        num_paths + num_fields + num_bits
+    };
+
+    #[test]
+    fn test_to_witness() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+
+            // Compute the witness.
+            let witness = state_path.to_witness();
+            assert_eq!(witness.public_inputs(), &[*state_path.global_state_root()]);
+            assert_eq!(witness.private_inputs().len(), NUM_PRIVATE_INPUTS);
+            // Ensure the witness is deterministic.
+            assert_eq!(witness, state_path.to_witness());
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_to_witnesses_parallel() {
+        let mut rng = TestRng::default();
+
+        // Sample the state paths.
+        let state_paths = (0..ITERATIONS)
+            .map(|_| crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        // Ensure the parallel witnesses match the serial witnesses, in order.
+        let expected = state_paths.iter().map(StatePath::to_witness).collect::<Vec<_>>();
+        assert_eq!(StatePath::to_witnesses_parallel(&state_paths), expected);
+        assert!(StatePath::<CurrentNetwork>::to_witnesses_parallel(&[]).is_empty());
+    }
+}
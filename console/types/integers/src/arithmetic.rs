@@ -95,6 +95,23 @@ impl<E: Environment, I: IntegerType> AddWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> AddSaturating<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `sum` of `self` and `other`, bounded to `MIN` or `MAX` if an overflow occurs.
+    #[inline]
+    fn add_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_add(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            // An overflow can only occur in the direction of `other`.
+            None => match other.integer < I::zero() {
+                true => Integer::MIN,
+                false => Integer::MAX,
+            },
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> AddAssign<Integer<E, I>> for Integer<E, I> {
     /// Adds `other` to `self`.
     #[inline]
@@ -153,6 +170,23 @@ impl<E: Environment, I: IntegerType> SubWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> SubSaturating<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `difference` of `self` and `other`, bounded to `MIN` or `MAX` if an overflow occurs.
+    #[inline]
+    fn sub_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_sub(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            // An overflow can only occur in the opposite direction of `other`.
+            None => match other.integer < I::zero() {
+                true => Integer::MAX,
+                false => Integer::MIN,
+            },
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> SubAssign<Integer<E, I>> for Integer<E, I> {
     /// Subtracts `other` from `self`.
     #[inline]
@@ -211,6 +245,23 @@ impl<E: Environment, I: IntegerType> MulWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> MulSaturating<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `product` of `self` and `other`, bounded to `MIN` or `MAX` if an overflow occurs.
+    #[inline]
+    fn mul_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_mul(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            // An overflow is negative if and only if the operands have different signs.
+            None => match (self.integer < I::zero()) != (other.integer < I::zero()) {
+                true => Integer::MIN,
+                false => Integer::MAX,
+            },
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> MulAssign<Integer<E, I>> for Integer<E, I> {
     /// Multiplies `self` by `other`.
     #[inline]
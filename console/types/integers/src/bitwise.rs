@@ -277,6 +277,28 @@ impl<E: Environment, I: IntegerType, M: Magnitude> ShrAssign<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateLeft<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates the bits of `self` to the left by `n` bits, wrapping `n` to the number of bits in `self`.
+    #[inline]
+    fn rotate_left(&self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        Integer::new(I::rotate_left(self.integer, (n.integer.to_u64().unwrap() % I::BITS) as u32))
+    }
+}
+
+impl<E: Environment, I: IntegerType, M: Magnitude> RotateRight<Integer<E, M>> for Integer<E, I> {
+    type Output = Self;
+
+    /// Rotates the bits of `self` to the right by `n` bits, wrapping `n` to the number of bits in `self`.
+    #[inline]
+    fn rotate_right(&self, n: &Integer<E, M>) -> Self::Output {
+        // Unwrap is safe as we only cast up.
+        Integer::new(I::rotate_right(self.integer, (n.integer.to_u64().unwrap() % I::BITS) as u32))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Ternary for Integer<E, I> {
     type Boolean = Boolean<E>;
     type Output = Self;
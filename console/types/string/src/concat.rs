@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Result<Self> {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.string.len() + other.string.len();
+        ensure!(
+            num_bytes <= E::MAX_STRING_BYTES as usize,
+            "Concatenated string exceeds maximum length of {} bytes",
+            E::MAX_STRING_BYTES
+        );
+        // Return the concatenated string.
+        Ok(Self::new(&format!("{}{}", self.string, other.string)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1_000;
+
+    #[test]
+    fn test_concat() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two new strings.
+            let first = StringType::<CurrentEnvironment>::rand(&mut rng);
+            let second = StringType::<CurrentEnvironment>::rand(&mut rng);
+
+            let candidate = first.concat(&second)?;
+            assert_eq!(format!("{}{}", *first, *second), *candidate);
+            assert_eq!(first.len() + second.len(), candidate.len());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_exceeds_capacity() {
+        let half = "a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize / 2 + 1);
+        let string = StringType::<CurrentEnvironment>::new(&half);
+        assert!(string.concat(&string).is_err());
+
+        // Ensure a string at capacity can be concatenated with the empty string.
+        let full = StringType::<CurrentEnvironment>::new(&"a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize));
+        assert_eq!(full, full.concat(&StringType::new("")).unwrap());
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `length` bytes, starting at the byte index `start`.
+    /// Fails if the range is out of bounds, or if it does not lie on character boundaries.
+    pub fn slice(&self, start: u32, length: u32) -> Result<Self> {
+        // Compute the end of the range.
+        let (start, end, num_bytes) = (start as usize, start as usize + length as usize, self.string.len());
+        // Ensure the range is within the string.
+        ensure!(end <= num_bytes, "Slice [{start}..{end}] is out of bounds for a string of {num_bytes} bytes");
+        // Retrieve the substring, which must lie on character boundaries.
+        match self.string.get(start..end) {
+            Some(substring) => Ok(Self::new(substring)),
+            None => bail!("Slice [{start}..{end}] does not lie on character boundaries"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1_000;
+
+    #[test]
+    fn test_slice() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new string.
+            let string = StringType::<CurrentEnvironment>::rand(&mut rng);
+
+            // Slice the string at a random character boundary.
+            let boundaries = string.char_indices().map(|(index, _)| index).chain([string.len()]).collect::<Vec<_>>();
+            let start = boundaries[rng.gen_range(0..boundaries.len())];
+            let end = boundaries[rng.gen_range(0..boundaries.len())].max(start);
+
+            let candidate = string.slice(start as u32, (end - start) as u32)?;
+            assert_eq!(&string[start..end], &*candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_fails() {
+        let string = StringType::<CurrentEnvironment>::new("héllo");
+        assert_eq!("h", &*string.slice(0, 1).unwrap());
+        assert_eq!("llo", &*string.slice(3, 3).unwrap());
+        assert_eq!("", &*string.slice(6, 0).unwrap());
+        // Ensure the range must be within the string.
+        assert!(string.slice(4, 3).is_err());
+        assert!(string.slice(7, 0).is_err());
+        assert!(string.slice(u32::MAX, u32::MAX).is_err());
+        // Ensure the range must lie on character boundaries.
+        assert!(string.slice(1, 1).is_err());
+        assert!(string.slice(2, 1).is_err());
+    }
+}
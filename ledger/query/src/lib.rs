@@ -23,5 +23,8 @@ mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+mod local;
+pub use local::*;
+
 mod traits;
 pub use traits::*;
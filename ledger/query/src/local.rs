@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{
+    network::prelude::*,
+    program::{
+        BlockTree,
+        HeaderLeaf,
+        HeaderTree,
+        StatePath,
+        TransactionLeaf,
+        TransactionTree,
+        TransactionsTree,
+        TransitionLeaf,
+        TransitionTree,
+    },
+    types::Field,
+};
+
+use std::collections::HashMap;
+
+/// A query over a local state, which places each of the given record commitments in its own transaction,
+/// in a single synthetic block. It lets a program be executed end-to-end without a ledger.
+///
+/// Note: The local state root does not exist in any ledger, so an execution proven against it
+/// is valid, but can never be accepted by the network.
+#[derive(Clone)]
+pub struct LocalQuery<N: Network> {
+    /// The local state root.
+    state_root: N::StateRoot,
+    /// The state paths of the record commitments.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> LocalQuery<N> {
+    /// Initializes a new local query for the given record commitments.
+    pub fn new(commitments: impl IntoIterator<Item = Field<N>>) -> Result<Self> {
+        // Construct the transition and transaction segments for each commitment.
+        let mut lower_segments = Vec::new();
+        for commitment in commitments {
+            // Construct the transition path, with the commitment as the first input record.
+            let tcm = Field::zero();
+            let transition_leaf = TransitionLeaf::new_with_version(0, 3, commitment);
+            let transition_tree: TransitionTree<N> = N::merkle_tree_bhp(&[transition_leaf.to_bits_le()])?;
+            let transition_root = *transition_tree.root();
+            let transition_id = N::hash_bhp512(&(transition_root, tcm).to_bits_le())?;
+            let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+
+            // Construct the transaction path.
+            let transaction_leaf = TransactionLeaf::new_execution(0, transition_id);
+            let transaction_tree: TransactionTree<N> = N::merkle_tree_bhp(&[transaction_leaf.to_bits_le()])?;
+            let transaction_id = *transaction_tree.root();
+            let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+
+            lower_segments.push((
+                commitment,
+                transaction_id,
+                transaction_path,
+                transaction_leaf,
+                transition_root,
+                tcm,
+                transition_path,
+                transition_leaf,
+            ));
+        }
+
+        // Construct the transactions tree.
+        let transaction_ids = lower_segments.iter().map(|(_, id, ..)| id.to_bits_le()).collect::<Vec<_>>();
+        let transactions_tree: TransactionsTree<N> = N::merkle_tree_bhp(&transaction_ids)?;
+
+        // Construct the block header path.
+        let header_leaf = HeaderLeaf::<N>::new(1, *transactions_tree.root());
+        let header_tree: HeaderTree<N> =
+            N::merkle_tree_bhp(&[Field::<N>::zero().to_bits_le(), header_leaf.to_bits_le()])?;
+        let header_root = *header_tree.root();
+        let header_path = header_tree.prove(1, &header_leaf.to_bits_le())?;
+
+        // Construct the block hash, as a genesis block.
+        let previous_block_hash: N::BlockHash = Field::<N>::zero().into();
+        let preimage = (*previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le());
+        let block_hash = N::hash_bhp1024(&preimage.collect::<Vec<_>>())?;
+
+        // Construct the local state root and block path.
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        let state_root: N::StateRoot = (*block_tree.root()).into();
+        let block_path = block_tree.prove(0, &block_hash.to_bits_le())?;
+
+        // Construct the state paths.
+        let mut state_paths = HashMap::with_capacity(lower_segments.len());
+        for (index, segment) in lower_segments.into_iter().enumerate() {
+            let (
+                commitment,
+                transaction_id,
+                transaction_path,
+                transaction_leaf,
+                transition_root,
+                tcm,
+                transition_path,
+                transition_leaf,
+            ) = segment;
+            let transactions_path = transactions_tree.prove(index, &transaction_id.to_bits_le())?;
+            let state_path = StatePath::from(
+                state_root,
+                block_path.clone(),
+                block_hash.into(),
+                previous_block_hash,
+                header_root,
+                header_path.clone(),
+                header_leaf,
+                transactions_path,
+                transaction_id.into(),
+                transaction_path,
+                transaction_leaf,
+                transition_root,
+                tcm,
+                transition_path,
+                transition_leaf,
+            )?;
+            state_paths.insert(commitment, state_path);
+        }
+
+        Ok(Self { state_root, state_paths })
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for LocalQuery<N> {
+    /// Returns the local state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the local state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`, to the local state root.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        match self.state_paths.get(commitment) {
+            Some(state_path) => Ok(state_path.clone()),
+            None => bail!("Missing record commitment '{commitment}' in the local state"),
+        }
+    }
+
+    /// Returns a state path for the given `commitment`, to the local state root.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_local_query() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a local query for a few commitments.
+        let commitments = (0..3).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        let query = LocalQuery::new(commitments.clone()).unwrap();
+        let state_root = query.current_state_root().unwrap();
+        assert_ne!(*state_root, Field::zero());
+
+        // Ensure each state path is valid, and is to the local state root.
+        for commitment in &commitments {
+            let state_path = query.get_state_path_for_commitment(commitment).unwrap();
+            assert_eq!(state_path.global_state_root(), state_root);
+            assert_eq!(state_path.transition_leaf().id(), *commitment);
+            state_path.verify(true, Field::zero()).unwrap();
+        }
+
+        // Ensure an unknown commitment is rejected.
+        assert!(query.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+
+        // Ensure a local query without commitments still has a state root.
+        let query = LocalQuery::<CurrentNetwork>::new([]).unwrap();
+        assert_ne!(*query.current_state_root().unwrap(), Field::zero());
+    }
+}
@@ -92,6 +92,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Drop the write lock on the current block.
         drop(current_block);
 
+        // Add the new state root to the recent state roots.
+        self.recent_state_roots.write().push(self.vm.block_store().current_state_root());
+
+        // If the ledger is in pruning mode, prune the blocks beyond the pruning depth.
+        if let Some(depth) = self.pruning_depth() {
+            if let Err(error) = self.prune_to_height(block.height().saturating_sub(depth)) {
+                warn!("Failed to prune the ledger to depth {depth} - {error}");
+            }
+        }
+
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
             *self.current_committee.write() = Some(current_committee);
@@ -105,6 +115,36 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Reverts the ledger to the given block height, atomically removing the blocks above it,
+    /// along with their transactions, serial numbers, commitments, and finalize operations.
+    ///
+    /// Note: The mappings are recomputed by replaying every block from genesis up to the given height
+    /// into an in-memory scratch VM, so this operation takes time and memory linear in the chain length,
+    /// and fails if the ledger is pruned. It is intended for development networks and short chains,
+    /// and is unsuitable for reorgs on a long chain such as mainnet.
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Revert the VM.
+        self.vm.revert_to_height(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Reload the recent state roots from storage.
+        let capacity = self.recent_state_roots.read().capacity();
+        *self.recent_state_roots.write() = self.load_state_root_window(capacity)?;
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = Some(self.vm.finalize_store().committee_store().current_committee()?);
+
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(height).ok());
+
+        Ok(())
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
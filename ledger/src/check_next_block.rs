@@ -14,9 +14,34 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::join;
+
+/// Runs the given closures sequentially, in place of `rayon::join`.
+#[cfg(feature = "serial")]
+fn join<A: FnOnce() -> RA, B: FnOnce() -> RB, RA, RB>(a: A, b: B) -> (RA, RB) {
+    (a(), b())
+}
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
+    ///
+    /// The block is checked in three stages. The header checks and the transaction verification
+    /// are independent of each other, and run concurrently on the rayon thread pool. Once both succeed,
+    /// the finalize replay checks the block against the latest state of the ledger.
     pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Check the block header, and verify the transactions, concurrently.
+        let (header_result, transactions_result) =
+            join(|| self.check_next_block_header(block), || self.check_next_block_transactions(block));
+        header_result?;
+        transactions_result?;
+
+        // Replay the finalize operations, and check the block against the latest state.
+        self.check_next_block_finalize(block)
+    }
+
+    /// Checks the block hash, height, and solutions of the given block are not already in the ledger.
+    fn check_next_block_header(&self, block: &Block<N>) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -38,14 +63,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
+        Ok(())
+    }
+
+    /// Checks each transaction in the given block is well-formed and unique, verifying their proofs in parallel.
+    ///
+    /// Note: The proofs are not batched across transactions. Each transaction carries its own Varuna proof,
+    /// and Varuna only batches the circuits within a single proof, so each proof is verified on its own.
+    fn check_next_block_transactions(&self, block: &Block<N>) -> Result<()> {
         // Ensure each transaction is well-formed and unique.
         // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
         let transactions = block.transactions().iter().collect::<Vec<_>>();
         cfg_iter!(transactions).try_for_each(|transaction| {
             self.check_transaction_basic(*transaction, transaction.to_rejected_id()?)
                 .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        })
+    }
 
+    /// Checks the speculation over the transactions in the given block, and the block itself, against the latest state.
+    fn check_next_block_finalize(&self, block: &Block<N>) -> Result<()> {
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
             // // Retrieve the latest total supply.
@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{BlockTree, BLOCKS_DEPTH};
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// The version of the ledger checkpoint format.
+const CHECKPOINT_VERSION: u8 = 1;
+
+/// The mappings of a ledger checkpoint, as `(program ID, mapping name, entries)` triples.
+type CheckpointMappings<N> = Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports a checkpoint of the ledger at the given height to the given path.
+    ///
+    /// The checkpoint contains every block up to the given height, the committee for each block,
+    /// and the confirmed mappings, along with the state root and the finalize checksum at the given height,
+    /// so that `Ledger::import_checkpoint` can verify it. As the mappings are only stored for the latest block,
+    /// the given height must be the latest block height.
+    pub fn export_checkpoint<P: AsRef<Path>>(&self, path: P, height: u32) -> Result<()> {
+        let timer = timer!("Ledger::export_checkpoint");
+
+        // Acquire the read lock on the current block, so no block is added while exporting.
+        let current_block = self.current_block.read();
+        // Ensure the height is the latest block height.
+        let latest_height = current_block.height();
+        ensure!(
+            height == latest_height,
+            "Cannot export a checkpoint at height {height}, as mappings are only stored at height {latest_height}"
+        );
+
+        // Retrieve the state root.
+        let state_root = match self.get_state_root(height)? {
+            Some(state_root) => state_root,
+            None => bail!("Missing state root for block {height}"),
+        };
+        // Retrieve the finalize checksum.
+        let checksum = self.vm.finalize_store().get_checksum_confirmed()?;
+
+        // Write the header.
+        let mut writer = BufWriter::new(File::create(path)?);
+        CHECKPOINT_VERSION.write_le(&mut writer)?;
+        height.write_le(&mut writer)?;
+        state_root.write_le(&mut writer)?;
+        checksum.write_le(&mut writer)?;
+
+        // Write the blocks.
+        for block_height in 0..=height {
+            self.get_block(block_height)?.write_le(&mut writer)?;
+        }
+        lap!(timer, "Write the blocks");
+
+        // Write the committees.
+        for block_height in 0..=height {
+            match self.get_committee(block_height)? {
+                Some(committee) => committee.write_le(&mut writer)?,
+                None => bail!("Missing committee for block {block_height}"),
+            }
+        }
+        lap!(timer, "Write the committees");
+
+        // Collect the mappings.
+        let finalize_store = self.vm.finalize_store();
+        let mut mappings = Vec::new();
+        for program_id in finalize_store.program_ids_confirmed() {
+            let program_id = *program_id;
+            for mapping_name in finalize_store.get_mapping_names_confirmed(&program_id)?.unwrap_or_default() {
+                mappings.push((program_id, mapping_name));
+            }
+        }
+        // Write the mappings.
+        u32::try_from(mappings.len())?.write_le(&mut writer)?;
+        for (program_id, mapping_name) in mappings {
+            let entries = finalize_store.get_mapping_confirmed(program_id, mapping_name)?;
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            u32::try_from(entries.len())?.write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        lap!(timer, "Write the mappings");
+
+        // Flush the checkpoint.
+        writer.flush()?;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Imports a checkpoint exported by `Ledger::export_checkpoint` into empty storage, and loads the ledger.
+    ///
+    /// The blocks are verified to form a chain whose block tree root is the state root of the checkpoint,
+    /// and the imported mappings are verified against the finalize checksum of the checkpoint.
+    /// The blocks are not re-executed, so the checkpoint must come from a trusted source,
+    /// or its state root must be checked against a trusted one. If the import fails after writing to storage,
+    /// the storage must be cleared before retrying.
+    pub fn import_checkpoint<P: AsRef<Path>>(path: P, dev: Option<u16>) -> Result<Self> {
+        let timer = timer!("Ledger::import_checkpoint");
+
+        // Read the header.
+        let mut reader = BufReader::new(File::open(path)?);
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == CHECKPOINT_VERSION, "Unsupported checkpoint version {version}");
+        let height = u32::read_le(&mut reader)?;
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        let checksum = Field::<N>::read_le(&mut reader)?;
+
+        // Read and verify the blocks.
+        let mut blocks: Vec<Block<N>> = Vec::new();
+        let mut tree: BlockTree<N> = N::merkle_tree_bhp::<BLOCKS_DEPTH>(&[])?;
+        for block_height in 0..=height {
+            let block = Block::<N>::read_le(&mut reader)?;
+            // Ensure the block is at the expected height.
+            ensure!(block.height() == block_height, "Expected block {block_height} in the checkpoint");
+            // Ensure the block extends the previous block.
+            if let Some(previous_block) = blocks.last() {
+                ensure!(
+                    block.previous_hash() == previous_block.hash(),
+                    "Block {block_height} has an invalid previous hash"
+                );
+                ensure!(
+                    *block.previous_state_root() == *tree.root(),
+                    "Block {block_height} has an invalid previous state root"
+                );
+            }
+            tree.append_in_place(&[block.hash().to_bits_le()])?;
+            blocks.push(block);
+        }
+        // Ensure the block tree root is the state root of the checkpoint.
+        ensure!(*tree.root() == *state_root, "The blocks in the checkpoint do not match its state root");
+        lap!(timer, "Read and verify the blocks");
+
+        // Read the committees.
+        let committees = (0..=height).map(|_| Committee::<N>::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        lap!(timer, "Read the committees");
+
+        // Read the mappings.
+        let mut mappings: CheckpointMappings<N> = Vec::new();
+        for _ in 0..u32::read_le(&mut reader)? {
+            let program_id = ProgramID::read_le(&mut reader)?;
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            let mut entries = Vec::new();
+            for _ in 0..u32::read_le(&mut reader)? {
+                entries.push((Plaintext::read_le(&mut reader)?, Value::read_le(&mut reader)?));
+            }
+            mappings.push((program_id, mapping_name, entries));
+        }
+        // Ensure there is no trailing data in the checkpoint.
+        ensure!(reader.read(&mut [0u8])? == 0, "Found trailing data in the checkpoint");
+        lap!(timer, "Read the mappings");
+
+        // Initialize the consensus store, and ensure it is empty.
+        let store = ConsensusStore::<N, C>::open(dev)?;
+        ensure!(store.block_store().heights().max().is_none(), "Cannot import a checkpoint into a non-empty ledger");
+
+        // Insert the blocks and their committees.
+        for (block, committee) in blocks.iter().zip_eq(committees) {
+            store.block_store().insert(block)?;
+            store.finalize_store().committee_store().insert(block.height(), committee)?;
+        }
+        lap!(timer, "Insert the blocks");
+
+        // Insert the mappings.
+        let finalize_store = store.finalize_store();
+        for (program_id, mapping_name, entries) in mappings {
+            finalize_store.initialize_mapping(program_id, mapping_name)?;
+            finalize_store.replace_mapping(program_id, mapping_name, entries)?;
+        }
+        // Ensure the mappings match the finalize checksum of the checkpoint.
+        ensure!(
+            finalize_store.get_checksum_confirmed()? == checksum,
+            "The mappings in the checkpoint do not match its finalize checksum"
+        );
+        lap!(timer, "Insert the mappings");
+
+        // Initialize the ledger.
+        let genesis_block = blocks.into_iter().next().ok_or_else(|| anyhow!("Missing genesis block"))?;
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+}
@@ -141,4 +141,41 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             })
         })
     }
+
+    /// Returns the records in the given range of block heights that belong to the given view key,
+    /// along with their commitments and state paths, in block order.
+    ///
+    /// The records are trial-decrypted with the view key in parallel. The records of pruned blocks are not found,
+    /// as their record ciphertexts are discarded.
+    pub fn find_records_in_range(
+        &self,
+        view_key: &ViewKey<N>,
+        block_range: Range<u32>,
+    ) -> Result<Vec<(Field<N>, Record<N, Plaintext<N>>, StatePath<N>)>> {
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        // Collect the record ciphertexts in the block range.
+        let mut records = Vec::new();
+        for transaction in self.transactions_in_range(block_range) {
+            let transaction = transaction?;
+            let transaction_records = transaction.transaction().records();
+            records.extend(transaction_records.map(|(commitment, record)| (*commitment, record.clone())));
+        }
+
+        // Decrypt the records that belong to the view key.
+        let records = cfg_into_iter!(records)
+            .filter_map(|(commitment, record)| {
+                match record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                    true => Some(record.decrypt(view_key).map(|record| (commitment, record))),
+                    false => None,
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the state path for each record.
+        cfg_into_iter!(records)
+            .map(|(commitment, record)| Ok((commitment, record, self.get_state_path_for_commitment(&commitment)?)))
+            .collect()
+    }
 }
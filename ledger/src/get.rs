@@ -35,6 +35,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns an absence proof for the given serial number, over all serial numbers in the ledger.
+    ///
+    /// This builds the serial numbers tree from every serial number, so its cost is linear in their number.
+    pub fn get_absence_proof(&self, serial_number: &Field<N>) -> Result<AbsenceProof<N>> {
+        AbsenceProof::prove(self.serial_numbers().map(|serial_number| serial_number.into_owned()), serial_number)
+    }
+
     /// Returns the epoch challenge for the given block height.
     pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
         // Compute the epoch number from the current block height.
@@ -199,6 +206,20 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the IDs of the transitions that call the given program ID.
+    pub fn get_transition_ids_for_program(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransitionID>> {
+        self.vm.transition_store().get_transition_ids_for_program(program_id)
+    }
+
+    /// Returns the IDs of the transitions that call the given function of the given program ID.
+    pub fn get_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransitionID>> {
+        self.vm.transition_store().get_transition_ids_for_function(program_id, function_name)
+    }
+
     /// Returns the block solutions for the given block height.
     pub fn get_solutions(&self, height: u32) -> Result<Option<CoinbaseSolution<N>>> {
         // If the height is 0, return the genesis block solutions.
@@ -25,6 +25,19 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().puzzle_commitments()
     }
 
+    /// Returns an iterator over the blocks, for the given range of block heights.
+    pub fn blocks_in_range(&self, range: Range<u32>) -> impl '_ + DoubleEndedIterator<Item = Result<Block<N>>> {
+        self.vm.block_store().blocks_in_range(range)
+    }
+
+    /// Returns an iterator over the confirmed transactions, for the given range of block heights.
+    pub fn transactions_in_range(
+        &self,
+        range: Range<u32>,
+    ) -> impl '_ + DoubleEndedIterator<Item = Result<ConfirmedTransaction<N>>> {
+        self.vm.block_store().transactions_in_range(range)
+    }
+
     /* Transaction */
 
     /// Returns an iterator over the program IDs, for all transactions in `self`.
@@ -37,6 +37,7 @@ pub use helpers::*;
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
+mod checkpoint;
 mod contains;
 mod find;
 mod get;
@@ -48,7 +49,19 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{
+        AbsenceProof,
+        Ciphertext,
+        Entry,
+        Identifier,
+        Literal,
+        Plaintext,
+        ProgramID,
+        Record,
+        StatePath,
+        StateRootWindow,
+        Value,
+    },
     types::{Field, Group},
 };
 use ledger_authority::Authority;
@@ -77,6 +90,9 @@ use rayon::prelude::*;
 
 pub type RecordMap<N> = IndexMap<Field<N>, Record<N, Plaintext<N>>>;
 
+/// The default number of recent state roots that a state path may be verified against.
+pub const DEFAULT_STATE_ROOT_WINDOW: usize = 64;
+
 #[derive(Copy, Clone, Debug)]
 pub enum RecordsFilter<N: Network> {
     /// Returns all records associated with the account.
@@ -105,6 +121,10 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The recent state roots.
+    recent_state_roots: Arc<RwLock<StateRootWindow<N>>>,
+    /// The number of latest blocks that are kept unpruned, if the ledger is in pruning mode.
+    pruning_depth: Arc<RwLock<Option<u32>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -150,12 +170,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, without performing integrity checks.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
 
         // Retrieve the current committee.
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
+        // Retrieve the pruning depth.
+        let pruning_depth = vm.block_store().pruning_depth()?;
 
         // Initialize the ledger.
         let mut ledger = Self {
@@ -165,6 +198,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            recent_state_roots: Arc::new(RwLock::new(StateRootWindow::new(DEFAULT_STATE_ROOT_WINDOW)?)),
+            pruning_depth: Arc::new(RwLock::new(pruning_depth)),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -188,8 +223,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         ledger.current_committee = Arc::new(RwLock::new(Some(ledger.latest_committee()?)));
         // Set the current epoch challenge.
         ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
+        // Set the recent state roots.
+        ledger.recent_state_roots = Arc::new(RwLock::new(ledger.load_state_root_window(DEFAULT_STATE_ROOT_WINDOW)?));
 
-        finish!(timer, "Initialize ledger");
+        finish!(timer, "Load the latest block");
         Ok(ledger)
     }
 
@@ -216,6 +253,60 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().current_state_root()
     }
 
+    /// Returns the recent state roots, from oldest to latest.
+    pub fn recent_state_roots(&self) -> StateRootWindow<N> {
+        self.recent_state_roots.read().clone()
+    }
+
+    /// Sets the number of recent state roots that a state path may be verified against.
+    pub fn set_state_root_window(&self, capacity: usize) -> Result<()> {
+        // Acquire the write lock on the recent state roots.
+        let mut recent_state_roots = self.recent_state_roots.write();
+        // Reload the recent state roots from storage.
+        *recent_state_roots = self.load_state_root_window(capacity)?;
+        Ok(())
+    }
+
+    /// Checks if the given state path is valid, and if it is for a global root, that its global state root
+    /// is one of the recent state roots.
+    pub fn verify_state_path(
+        &self,
+        state_path: &StatePath<N>,
+        is_global: bool,
+        local_state_root: Field<N>,
+    ) -> Result<()> {
+        state_path.verify_in_window(is_global, local_state_root, &self.recent_state_roots.read())
+    }
+
+    /// Returns the number of latest blocks that are kept unpruned, if the ledger is in pruning mode.
+    pub fn pruning_depth(&self) -> Option<u32> {
+        *self.pruning_depth.read()
+    }
+
+    /// Sets the number of latest blocks that are kept unpruned, or disables pruning mode if `None`.
+    ///
+    /// In pruning mode, the ledger prunes the blocks beyond the given depth as it advances.
+    /// The depth is persisted in storage, so the ledger stays in pruning mode after it is reloaded.
+    pub fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // Persist the pruning depth.
+        self.vm.block_store().set_pruning_depth(depth)?;
+        *self.pruning_depth.write() = depth;
+        // Prune the blocks beyond the depth.
+        match depth {
+            Some(depth) => self.prune_to_height(self.latest_height().saturating_sub(depth)),
+            None => Ok(()),
+        }
+    }
+
+    /// Discards the transition data of the blocks below the given height, and reclaims the freed storage space.
+    ///
+    /// The pruned blocks keep their headers, transaction IDs, serial numbers, and commitments,
+    /// so new blocks are validated and state paths are served as before. However, their record ciphertexts
+    /// and input and output values are discarded, so their records can no longer be found with a view key.
+    pub fn prune_to_height(&self, height: u32) -> Result<()> {
+        self.vm.block_store().prune_to_height(height)
+    }
+
     /// Returns the latest epoch number.
     pub fn latest_epoch_number(&self) -> u32 {
         self.current_block.read().height() / N::NUM_BLOCKS_PER_EPOCH
@@ -298,6 +389,24 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn latest_transactions(&self) -> Transactions<N> {
         self.current_block.read().transactions().clone()
     }
+
+    /// Returns a window of the given capacity, containing the state roots of the latest blocks in storage.
+    fn load_state_root_window(&self, capacity: usize) -> Result<StateRootWindow<N>> {
+        // Initialize the window.
+        let mut window = StateRootWindow::new(capacity)?;
+        // Retrieve the latest height.
+        let latest_height = self.current_block.read().height();
+        // Retrieve the earliest height in the window.
+        let start_height = latest_height.saturating_sub(u32::try_from(capacity - 1).unwrap_or(u32::MAX));
+        // Add the state roots, from oldest to latest.
+        for height in start_height..=latest_height {
+            match self.get_state_root(height)? {
+                Some(state_root) => window.push(state_root),
+                None => bail!("Missing state root for block {height} in the ledger"),
+            }
+        }
+        Ok(window)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
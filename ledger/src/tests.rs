@@ -14,12 +14,14 @@
 
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
+    Ledger,
     RecordsFilter,
 };
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
@@ -80,6 +82,253 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_absence_proof() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+
+    // Ensure an unspent serial number has a valid absence proof.
+    let serial_number = Field::rand(rng);
+    let absence_proof = ledger.get_absence_proof(&serial_number).unwrap();
+    absence_proof.verify().unwrap();
+    assert_eq!(*absence_proof.serial_number(), serial_number);
+
+    // Ensure every spent serial number has no absence proof.
+    for serial_number in ledger.serial_numbers() {
+        assert!(ledger.get_absence_proof(&serial_number).is_err());
+    }
+}
+
+#[test]
+fn test_state_root_window() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve a state path for a record in the genesis block.
+    let (commitment, _) = ledger.find_records(&view_key, RecordsFilter::All).unwrap().next().unwrap();
+    let state_path = ledger.get_state_path_for_commitment(&commitment).unwrap();
+
+    // Ensure the recent state roots are loaded from storage.
+    assert_eq!(ledger.recent_state_roots().latest(), Some(&ledger.latest_state_root()));
+    assert!(ledger.contains_recent_state_root(&state_path.global_state_root()));
+    ledger.verify_state_path(&state_path, true, Field::zero()).unwrap();
+
+    // Shrink the window to two state roots.
+    ledger.set_state_root_window(2).unwrap();
+    assert_eq!(ledger.recent_state_roots().capacity(), 2);
+
+    // Advance by one block, and ensure the state path is still recent.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.recent_state_roots().latest(), Some(&ledger.latest_state_root()));
+    ledger.verify_state_path(&state_path, true, Field::zero()).unwrap();
+
+    // Advance by another block, and ensure the state path is now stale.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(!ledger.contains_recent_state_root(&state_path.global_state_root()));
+    assert!(ledger.verify_state_path(&state_path, true, Field::zero()).is_err());
+
+    // Widen the window, and ensure the state path is recent again.
+    ledger.set_state_root_window(3).unwrap();
+    assert_eq!(ledger.recent_state_roots().len(), 3);
+    ledger.verify_state_path(&state_path, true, Field::zero()).unwrap();
+
+    // Ensure an empty window is rejected.
+    assert!(ledger.set_state_root_window(0).is_err());
+}
+
+#[test]
+fn test_pruning_mode() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the state paths for the records in the genesis block.
+    let commitments = ledger.find_records(&view_key, RecordsFilter::All).unwrap().map(|(commitment, _)| commitment);
+    let commitments = commitments.collect::<Vec<_>>();
+    assert!(!commitments.is_empty());
+
+    // Enable pruning mode, which keeps the genesis block as it is the latest block.
+    ledger.set_pruning_depth(Some(1)).unwrap();
+    assert_eq!(ledger.pruning_depth(), Some(1));
+    assert_eq!(ledger.find_records(&view_key, RecordsFilter::All).unwrap().count(), commitments.len());
+
+    // Advance by one block, and ensure the genesis block is still unpruned.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.find_records(&view_key, RecordsFilter::All).unwrap().count(), commitments.len());
+
+    // Advance by another block, and ensure the genesis block is pruned.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.vm().block_store().pruned_height(), 1);
+    assert_eq!(ledger.find_records(&view_key, RecordsFilter::All).unwrap().count(), 0);
+
+    // Ensure the genesis block and the state paths for its records are still available.
+    assert_eq!(ledger.get_block(0).unwrap().hash(), ledger.genesis_block.hash());
+    for commitment in &commitments {
+        let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+        ledger.verify_state_path(&state_path, true, Field::zero()).unwrap();
+    }
+
+    // Ensure the latest block cannot be pruned.
+    assert!(ledger.prune_to_height(ledger.latest_height() + 1).is_err());
+}
+
+#[test]
+fn test_find_records_in_range() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure the records in the genesis block are found, with valid state paths.
+    let records = ledger.find_records_in_range(&view_key, 0..1).unwrap();
+    let expected = ledger.find_records(&view_key, RecordsFilter::All).unwrap().collect::<Vec<_>>();
+    assert!(!records.is_empty());
+    assert_eq!(records.len(), expected.len());
+    for (commitment, record, state_path) in records {
+        assert!(expected.contains(&(commitment, record)));
+        ledger.verify_state_path(&state_path, true, Field::zero()).unwrap();
+    }
+
+    // Ensure the records do not belong to another view key.
+    let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(ledger.find_records_in_range(&other_view_key, 0..1).unwrap().is_empty());
+
+    // Ensure an empty range has no records, and a range beyond the latest block is an error.
+    assert!(ledger.find_records_in_range(&view_key, 0..0).unwrap().is_empty());
+    assert!(ledger.find_records_in_range(&view_key, 0..2).is_err());
+
+    // Advance by one block without transactions, and ensure it has no records.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(ledger.find_records_in_range(&view_key, 1..2).unwrap().is_empty());
+}
+
+#[test]
+fn test_checkpoint_export_import() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance by one block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure a checkpoint can only be exported at the latest height.
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("checkpoint");
+    assert!(ledger.export_checkpoint(&path, 0).is_err());
+
+    // Export and import the checkpoint.
+    ledger.export_checkpoint(&path, ledger.latest_height()).unwrap();
+    let imported = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::import_checkpoint(&path, None).unwrap();
+
+    // Ensure the imported ledger matches the ledger.
+    assert_eq!(imported.latest_height(), ledger.latest_height());
+    assert_eq!(imported.latest_hash(), ledger.latest_hash());
+    assert_eq!(imported.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(imported.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        imported.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+    assert_eq!(
+        imported.find_records(&view_key, RecordsFilter::All).unwrap().count(),
+        ledger.find_records(&view_key, RecordsFilter::All).unwrap().count()
+    );
+
+    // Ensure the imported ledger can advance.
+    let block = imported.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    imported.advance_to_next_block(&block).unwrap();
+
+    // Ensure a tampered checkpoint is rejected.
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+    assert!(Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::import_checkpoint(&path, None).is_err());
+}
+
+#[test]
+fn test_check_next_block() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure a valid next block passes every stage.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure a block that is already in the ledger fails the header checks.
+    let error = ledger.check_next_block(&block).unwrap_err();
+    assert!(error.to_string().contains("already exists in the ledger"));
+}
+
+#[test]
+fn test_revert_to_height() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Advance by one block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Record the state of the ledger.
+    let height = ledger.latest_height();
+    let hash = ledger.latest_hash();
+    let state_root = ledger.latest_state_root();
+    let committee = ledger.latest_committee().unwrap();
+    let checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+    let num_records = ledger.find_records(&view_key, RecordsFilter::All).unwrap().count();
+
+    // Advance by a block with a public transfer, which updates the mappings.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_ne!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), checksum);
+
+    // Ensure the ledger cannot be reverted to a future height.
+    assert!(ledger.revert_to_height(ledger.latest_height() + 1).is_err());
+
+    // Revert the ledger.
+    ledger.revert_to_height(height).unwrap();
+
+    // Ensure the ledger matches its earlier state.
+    assert_eq!(ledger.latest_height(), height);
+    assert_eq!(ledger.latest_hash(), hash);
+    assert_eq!(ledger.latest_state_root(), state_root);
+    assert_eq!(ledger.latest_committee().unwrap(), committee);
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), checksum);
+    assert_eq!(ledger.find_records(&view_key, RecordsFilter::All).unwrap().count(), num_records);
+    assert!(!ledger.contains_transaction_id(&transaction_id).unwrap());
+    assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+
+    // Ensure the reverted ledger can advance.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), height + 1);
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
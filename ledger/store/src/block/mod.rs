@@ -24,7 +24,16 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{
+        BlockTree,
+        HeaderInclusionPath,
+        HeaderLeaf,
+        HeaderLeafIndex,
+        ProgramID,
+        StatePath,
+        TransitionLeaf,
+        BLOCKS_DEPTH,
+    },
     types::Field,
 };
 use ledger_authority::Authority;
@@ -43,6 +52,7 @@ use ledger_narwhal_batch_certificate::BatchCertificate;
 use synthesizer_program::Program;
 
 use anyhow::Result;
+use core::ops::Range;
 use parking_lot::RwLock;
 use std::{borrow::Cow, io::Cursor, sync::Arc};
 
@@ -167,6 +177,70 @@ fn to_confirmed_transaction<N: Network>(
     }
 }
 
+/// Returns a state path for the given `commitment` in the given block store, to the global state root
+/// of the given block tree.
+///
+/// The block tree must contain every block in the block store, and its root must be a global state root
+/// in the block store.
+pub fn new_commitment<N: Network, B: BlockStorage<N>>(
+    block_tree: &BlockTree<N>,
+    store: &BlockStore<N, B>,
+    commitment: &Field<N>,
+) -> Result<StatePath<N>> {
+    store.storage.get_state_path_for_commitment(commitment, block_tree)
+}
+
+/// Returns a header inclusion path for the given header leaf of the given block, to the global state root
+/// of the given block tree.
+///
+/// Unlike a state path, which proves the transactions root of a block, this proves any leaf in the block header.
+pub fn new_header_leaf<N: Network, B: BlockStorage<N>>(
+    block_tree: &BlockTree<N>,
+    store: &B,
+    block_hash: &N::BlockHash,
+    header_index: HeaderLeafIndex,
+) -> Result<HeaderInclusionPath<N>> {
+    // Retrieve the block.
+    let block = match store.get_block(block_hash)? {
+        Some(block) => block,
+        None => bail!("The block '{block_hash}' is missing in storage"),
+    };
+
+    // Construct the global state root and block path.
+    let global_state_root = *block_tree.root();
+    let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+
+    // Ensure the global state root exists in storage.
+    if !store.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
+        bail!("The global state root '{global_state_root}' for block '{block_hash}' is missing in storage");
+    }
+
+    // Construct the block header path.
+    let block_header = block.header();
+    let header_root = block_header.to_root()?;
+    let header_leaf_id = match header_index {
+        HeaderLeafIndex::PreviousStateRoot => *block_header.previous_state_root(),
+        HeaderLeafIndex::TransactionsRoot => block_header.transactions_root(),
+        HeaderLeafIndex::FinalizeRoot => block_header.finalize_root(),
+        HeaderLeafIndex::RatificationsRoot => block_header.ratifications_root(),
+        HeaderLeafIndex::SolutionsRoot => block_header.solutions_root(),
+        HeaderLeafIndex::SubdagRoot => block_header.subdag_root(),
+        HeaderLeafIndex::MetadataHash => block_header.metadata().to_hash()?,
+    };
+    let header_leaf = HeaderLeaf::<N>::new(header_index.index(), header_leaf_id);
+    let header_path = block_header.to_path(&header_leaf)?;
+
+    HeaderInclusionPath::from(
+        global_state_root.into(),
+        block_path,
+        block.hash(),
+        block.previous_hash(),
+        header_root,
+        header_path,
+        header_leaf,
+    )
+}
+
 /// A trait for block storage.
 pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `block height` to `state root`.
@@ -252,6 +326,48 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transition_store().dev()
     }
 
+    /// Returns the latest checkpoint of the Merkle tree with the given ID, if it exists.
+    ///
+    /// Note: By default, the storage does not persist tree checkpoints, so the block tree is recomputed on open.
+    fn get_tree_checkpoint(&self, _tree_id: u8) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    /// Stores the latest checkpoint of the Merkle tree with the given ID.
+    ///
+    /// Note: By default, the storage does not persist tree checkpoints, so this returns an error.
+    fn insert_tree_checkpoint(&self, tree_id: u8, _checkpoint: Vec<u8>) -> Result<()> {
+        bail!("The block storage does not persist a checkpoint for tree {tree_id}")
+    }
+
+    /// Returns the height below which blocks have been pruned.
+    ///
+    /// Note: By default, the storage does not persist the pruned height, so the blocks are pruned again on open.
+    fn get_pruned_height(&self) -> Result<u32> {
+        Ok(0)
+    }
+
+    /// Stores the height below which blocks have been pruned.
+    ///
+    /// Note: By default, the storage does not persist the pruned height, so this is a no-op.
+    fn set_pruned_height(&self, _height: u32) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns the number of latest blocks that are kept unpruned, if the ledger is in pruning mode.
+    ///
+    /// Note: By default, the storage does not persist the pruning depth, so pruning mode is disabled on open.
+    fn get_pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(None)
+    }
+
+    /// Stores the number of latest blocks that are kept unpruned, or removes it if pruning mode is disabled.
+    ///
+    /// Note: By default, the storage does not persist the pruning depth, so this is a no-op.
+    fn set_pruning_depth(&self, _depth: Option<u32>) -> Result<()> {
+        Ok(())
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.state_root_map().start_atomic();
@@ -599,6 +715,29 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Discards the transition data of the given block, keeping everything needed to validate new blocks,
+    /// to reconstruct the block, and to serve state paths.
+    fn prune(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // Retrieve the transactions.
+        let transactions = match self.get_block_transactions(block_hash)? {
+            Some(transactions) => transactions,
+            None => bail!("Failed to prune block: missing transactions for block '{block_hash}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transitions.
+            for transition_id in transactions.transition_ids() {
+                self.transition_store().prune(transition_id)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    fn reclaim(&self) -> Result<()> {
+        self.transition_store().reclaim()
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -714,7 +853,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
         let header_path = block_header.to_path(&header_leaf)?;
 
-        Ok(StatePath::from(
+        StatePath::from(
             global_state_root.into(),
             block_path,
             block.hash(),
@@ -730,7 +869,85 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             *transition.tcm(),
             transition_path,
             transition_leaf,
-        ))
+        )
+    }
+
+    /// Returns a state path for every record commitment in the given block, along with its commitment.
+    /// The block, block header, and transactions portion of the state paths is computed once for the block,
+    /// and the state paths are ordered by transaction index, then transition index, then output index.
+    fn all_commitment_paths(
+        &self,
+        block_hash: &N::BlockHash,
+        block_tree: &BlockTree<N>,
+    ) -> Result<Vec<(Field<N>, StatePath<N>)>> {
+        // Retrieve the block.
+        let block = match self.get_block(block_hash)? {
+            Some(block) => block,
+            None => bail!("The block '{block_hash}' is missing in storage"),
+        };
+
+        // Construct the global state root and block path.
+        let global_state_root = *block_tree.root();
+        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+
+        // Ensure the global state root exists in storage.
+        if !self.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
+            bail!("The global state root '{global_state_root}' for block '{block_hash}' is missing in storage");
+        }
+
+        // Construct the block header path.
+        let block_header = block.header();
+        let header_root = block_header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+        let header_path = block_header.to_path(&header_leaf)?;
+        // Retrieve the previous block hash, which is the zero block hash sentinel for the genesis block.
+        let previous_block_hash = block.previous_hash();
+
+        let transactions = block.transactions();
+        let mut state_paths = Vec::new();
+        for transaction in transactions.iter() {
+            // Skip transactions without record commitments.
+            if transaction.commitments().next().is_none() {
+                continue;
+            }
+
+            // Construct the transactions path.
+            let transaction_id = transaction.id();
+            let transactions_path = transactions.to_path(transaction_id)?;
+
+            for transition in transaction.transitions() {
+                // Construct the transition root, and the transaction path and transaction leaf.
+                let transition_root = transition.to_root()?;
+                let transaction_leaf = transaction.to_leaf(transition.id())?;
+                let transaction_path = transaction.to_path(&transaction_leaf)?;
+
+                for commitment in transition.commitments() {
+                    // Construct the transition path and transition leaf.
+                    let transition_leaf = transition.to_leaf(commitment, false)?;
+                    let transition_path = transition.to_path(&transition_leaf)?;
+
+                    let state_path = StatePath::from(
+                        global_state_root.into(),
+                        block_path.clone(),
+                        block.hash(),
+                        previous_block_hash,
+                        header_root,
+                        header_path.clone(),
+                        header_leaf,
+                        transactions_path.clone(),
+                        transaction_id,
+                        transaction_path.clone(),
+                        transaction_leaf,
+                        transition_root,
+                        *transition.tcm(),
+                        transition_path,
+                        transition_leaf,
+                    )?;
+                    state_paths.push((*commitment, state_path));
+                }
+            }
+        }
+        Ok(state_paths)
     }
 
     /// Returns the previous block hash of the given `block height`.
@@ -981,6 +1198,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// The ID of the block tree in the tree checkpoints map.
+const BLOCK_TREE_CHECKPOINT_ID: u8 = 0;
+
+/// The key of the pruned height in the pruning map.
+pub(crate) const PRUNED_HEIGHT_KEY: u8 = 0;
+/// The key of the pruning depth in the pruning map.
+pub(crate) const PRUNING_DEPTH_KEY: u8 = 1;
+
 /// The block store.
 #[derive(Clone)]
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
@@ -988,34 +1213,107 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     storage: B,
     /// The block tree.
     tree: Arc<RwLock<BlockTree<N>>>,
+    /// The block tree before blocks were removed in the atomic batch in progress, which is restored if it is aborted.
+    tree_before_removal: Arc<RwLock<Option<BlockTree<N>>>>,
+    /// The height below which blocks have been pruned.
+    pruned_height: Arc<RwLock<u32>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     /// Initializes the block store.
     pub fn open(dev: Option<u16>) -> Result<Self> {
-        // Initialize the block storage.
-        let storage = B::open(dev)?;
-
-        // Compute the block tree.
-        let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
-                    })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+        Self::from(B::open(dev)?)
+    }
+
+    /// Initializes a block store from storage.
+    pub fn from(storage: B) -> Result<Self> {
+        // Restore the block tree from its checkpoint, or compute it from every block hash.
+        let tree = match Self::restore_tree(&storage)? {
+            Some(tree) => Arc::new(RwLock::new(tree)),
+            None => {
+                // Prepare an iterator over the block heights.
+                let heights = storage.id_map().keys_confirmed();
+                // Prepare the leaves of the block tree.
+                let hashes = match heights.max() {
+                    Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
+                        .map(|height| match storage.get_block_hash(height)? {
+                            Some(hash) => Ok(hash.to_bits_le()),
+                            None => bail!("Missing block hash for block {height}"),
+                        })
+                        .collect::<Result<Vec<Vec<bool>>>>()?,
+                    None => vec![],
+                };
+                // Construct the block tree.
+                Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+            }
         };
 
+        // Load the pruned height.
+        let pruned_height = Arc::new(RwLock::new(storage.get_pruned_height()?));
+
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree, tree_before_removal: Default::default(), pruned_height })
+    }
+
+    /// Restores the block tree from its checkpoint in storage, appending the block hashes after the checkpoint.
+    ///
+    /// Returns `None` if there is no checkpoint, or if the checkpoint is malformed, is ahead of storage,
+    /// or does not match the stored state roots.
+    fn restore_tree(storage: &B) -> Result<Option<BlockTree<N>>> {
+        // Retrieve the checkpoint.
+        let Some(checkpoint) = storage.get_tree_checkpoint(BLOCK_TREE_CHECKPOINT_ID)? else { return Ok(None) };
+        // Restore the block tree.
+        let mut tree = match N::merkle_tree_bhp_from_checkpoint::<BLOCKS_DEPTH>(&checkpoint) {
+            Ok(tree) => tree,
+            Err(_) => return Ok(None),
+        };
+
+        // Returns `true` if the given root is the stored state root for the given block height.
+        let is_state_root = |height: u32, root: &Field<N>| -> Result<bool> {
+            Ok(storage.get_state_root(height)? == Some((*root).into()))
+        };
+
+        // Determine the number of blocks in the checkpoint and in storage.
+        let num_checkpoint_blocks = u32::try_from(tree.number_of_leaves())?;
+        let num_blocks = match storage.id_map().keys_confirmed().max() {
+            Some(height) => cow_to_copied!(height).saturating_add(1),
+            None => 0,
+        };
+        // Ensure the checkpoint is not ahead of storage, as blocks may have been removed since.
+        if num_checkpoint_blocks > num_blocks {
+            return Ok(None);
+        }
+        // Ensure the checkpoint root is the stored state root of its last block.
+        if num_checkpoint_blocks > 0 && !is_state_root(num_checkpoint_blocks - 1, tree.root())? {
+            return Ok(None);
+        }
+
+        // Append the block hashes after the checkpoint.
+        let hashes = cfg_into_iter!(num_checkpoint_blocks..num_blocks)
+            .map(|height| match storage.get_block_hash(height)? {
+                Some(hash) => Ok(hash.to_bits_le()),
+                None => bail!("Missing block hash for block {height}"),
+            })
+            .collect::<Result<Vec<Vec<bool>>>>()?;
+        tree.append(&hashes)?;
+
+        // Ensure the restored root is the latest stored state root.
+        if num_blocks > 0 && !is_state_root(num_blocks - 1, tree.root())? {
+            return Ok(None);
+        }
+        Ok(Some(tree))
+    }
+
+    /// Writes a checkpoint of the block tree to storage, so that reopening the store
+    /// restores the block tree instead of re-hashing every block hash.
+    pub fn checkpoint_tree(&self) -> Result<()> {
+        // Acquire the read lock on the block tree, so no block is inserted while checkpointing.
+        let tree = self.tree.read();
+        // Write the checkpoint.
+        let mut checkpoint = Vec::new();
+        tree.write_checkpoint(&mut checkpoint)?;
+        // Store the checkpoint.
+        self.storage.insert_tree_checkpoint(BLOCK_TREE_CHECKPOINT_ID, checkpoint)
     }
 
     /// Stores the given block into storage.
@@ -1072,6 +1370,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Prepare an updated Merkle tree removing the last 'n' block hashes.
         let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
 
+        // If the blocks are removed as part of an atomic batch in progress, keep the current block tree,
+        // so it is restored if the batch is aborted after the block tree is updated.
+        if self.is_atomic_in_progress() {
+            self.tree_before_removal.write().get_or_insert_with(|| tree.clone());
+        }
+
         atomic_batch_scope!(self, {
             // Remove the blocks, in descending order.
             for block_hash in hashes.iter().rev() {
@@ -1086,6 +1390,59 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Discards the transition data of the blocks below the given height, and reclaims the freed storage space.
+    ///
+    /// The record ciphertexts and the constant, public, and private input and output values are discarded,
+    /// while the IDs, serial numbers, commitments, and block headers are kept. As the ledger cannot determine
+    /// which records are spent, the record ciphertexts are discarded for every record in these blocks.
+    /// Blocks below the persisted pruned height are skipped, and as values that are already discarded
+    /// are not rewritten, it is safe to prune the same blocks again if the storage does not persist it.
+    pub fn prune_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the pruned height.
+        let mut pruned_height = self.pruned_height.write();
+
+        // Ensure the blocks to prune are below the latest height.
+        let latest_height = match self.storage.id_map().keys_confirmed().max() {
+            Some(height) => cow_to_copied!(height),
+            None => bail!("Failed to prune to block {height}: no blocks in storage"),
+        };
+        ensure!(height <= latest_height, "Failed to prune to block {height}: the latest block is {latest_height}");
+
+        // If the blocks are already pruned, return early.
+        if height <= *pruned_height {
+            return Ok(());
+        }
+
+        // Prune the blocks that are not yet pruned, in ascending order.
+        for block_height in *pruned_height..height {
+            match self.storage.get_block_hash(block_height)? {
+                Some(block_hash) => self.storage.prune(&block_hash)?,
+                None => bail!("Failed to prune to block {height}: missing block hash for block {block_height}"),
+            }
+            // Update the pruned height.
+            self.storage.set_pruned_height(block_height + 1)?;
+            *pruned_height = block_height + 1;
+        }
+
+        // Reclaim the freed storage space.
+        self.storage.reclaim()
+    }
+
+    /// Returns the height below which blocks have been pruned.
+    pub fn pruned_height(&self) -> u32 {
+        *self.pruned_height.read()
+    }
+
+    /// Returns the number of latest blocks that are kept unpruned, if the ledger is in pruning mode.
+    pub fn pruning_depth(&self) -> Result<Option<u32>> {
+        self.storage.get_pruning_depth()
+    }
+
+    /// Stores the number of latest blocks that are kept unpruned, or `None` if pruning mode is disabled.
+    pub fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        self.storage.set_pruning_depth(depth)
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1122,13 +1479,42 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 
     /// Aborts an atomic batch write operation.
+    ///
+    /// As the block tree is updated when a block is inserted or removed, the block tree from before
+    /// the blocks were removed in the aborted batch is restored, and the block hashes of the blocks
+    /// that were inserted in the aborted batch are removed from the block tree.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Restore the block tree from before the blocks were removed, if any.
+        if let Some(tree_before_removal) = self.tree_before_removal.write().take() {
+            *tree = tree_before_removal;
+        }
+        // Determine the number of blocks in storage.
+        let num_blocks = match self.storage.id_map().keys_confirmed().max() {
+            Some(height) => cow_to_copied!(height) as usize + 1,
+            None => 0,
+        };
+        // Remove the block hashes of the aborted blocks from the block tree.
+        let num_aborted_blocks = tree.number_of_leaves().saturating_sub(num_blocks);
+        if num_aborted_blocks > 0 {
+            // Note: This does not fail, as the block tree contains the aborted blocks.
+            if let Ok(updated_tree) = tree.prepare_remove_last_n(num_aborted_blocks) {
+                *tree = updated_tree;
+            }
+        }
     }
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        self.storage.finish_atomic()?;
+        // Discard the block tree from before the blocks were removed, as the removal is committed.
+        if !self.is_atomic_in_progress() {
+            *self.tree_before_removal.write() = None;
+        }
+        Ok(())
     }
 
     /// Returns the optional development ID.
@@ -1173,6 +1559,27 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns a state path for every record commitment in the given block, along with its commitment.
+    pub fn all_commitment_paths(&self, block_hash: &N::BlockHash) -> Result<Vec<(Field<N>, StatePath<N>)>> {
+        self.storage.all_commitment_paths(block_hash, &self.tree.read())
+    }
+
+    /// Returns a header inclusion path for the given header leaf of the given `block hash`.
+    pub fn get_header_inclusion_path(
+        &self,
+        block_hash: &N::BlockHash,
+        header_index: HeaderLeafIndex,
+    ) -> Result<HeaderInclusionPath<N>> {
+        new_header_leaf(&self.tree.read(), &self.storage, block_hash, header_index)
+    }
+
+    /// Returns a state path for the given `commitment`, along with the transition leaf it proves.
+    pub fn prove_commitment(&self, commitment: &Field<N>) -> Result<(StatePath<N>, TransitionLeaf<N>)> {
+        let state_path = self.get_state_path_for_commitment(commitment)?;
+        let transition_leaf = *state_path.transition_leaf();
+        Ok((state_path, transition_leaf))
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -1322,6 +1729,54 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn puzzle_commitments(&self) -> impl '_ + Iterator<Item = Cow<'_, PuzzleCommitment<N>>> {
         self.storage.puzzle_commitments_map().keys_confirmed()
     }
+
+    /// Returns an iterator over the blocks, for the given range of block heights.
+    ///
+    /// Each block is loaded from storage only once it is reached,
+    /// and the iterator may be reversed to stream the blocks from the highest block height.
+    pub fn blocks_in_range(&self, range: Range<u32>) -> impl '_ + DoubleEndedIterator<Item = Result<Block<N>>> {
+        range.map(move |height| {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.get_block_hash(height)? else {
+                bail!("Missing block hash for block {height}")
+            };
+            // Retrieve the block.
+            match self.get_block(&block_hash)? {
+                Some(block) => Ok(block),
+                None => bail!("Missing block {height} in block storage"),
+            }
+        })
+    }
+
+    /// Returns an iterator over the confirmed transactions, for the given range of block heights.
+    ///
+    /// Each transaction is loaded from storage only once it is reached, without loading its block,
+    /// and the iterator may be reversed to stream the transactions from the highest block height.
+    pub fn transactions_in_range(
+        &self,
+        range: Range<u32>,
+    ) -> impl '_ + DoubleEndedIterator<Item = Result<ConfirmedTransaction<N>>> {
+        // Returns the transaction IDs for the given block height.
+        let get_transaction_ids = move |height: u32| -> Result<Vec<N::TransactionID>> {
+            let Some(block_hash) = self.get_block_hash(height)? else {
+                bail!("Missing block hash for block {height}")
+            };
+            match self.storage.transactions_map().get_confirmed(&block_hash)? {
+                Some(transaction_ids) => Ok(cow_to_cloned!(transaction_ids)),
+                None => bail!("Missing transaction IDs for block {height}"),
+            }
+        };
+
+        range
+            .flat_map(move |height| match get_transaction_ids(height) {
+                Ok(transaction_ids) => transaction_ids.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(error) => vec![Err(error)],
+            })
+            .map(move |transaction_id| match self.get_confirmed_transaction(&transaction_id?)? {
+                Some(transaction) => Ok(transaction),
+                None => bail!("Missing confirmed transaction in block storage"),
+            })
+    }
 }
 
 #[cfg(test)]
@@ -1361,6 +1816,280 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_restore_tree() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Restores the block tree from the checkpoint in the block store.
+        let restore = || BlockStore::<CurrentNetwork, BlockMemory<_>>::restore_tree(&block_store.storage).unwrap();
+
+        // Ensure there is nothing to restore without a checkpoint.
+        assert!(restore().is_none());
+
+        // Checkpoint the empty block tree, and insert the block.
+        block_store.checkpoint_tree().unwrap();
+        block_store.insert(&block).unwrap();
+        // Ensure the block tree is restored from the stale checkpoint, by appending the block hash.
+        assert_eq!(restore().unwrap().root(), block_store.tree.read().root());
+
+        // Ensure the block tree is restored from an up-to-date checkpoint.
+        block_store.checkpoint_tree().unwrap();
+        assert_eq!(restore().unwrap().root(), block_store.tree.read().root());
+
+        // Ensure a checkpoint that is ahead of storage is not restored.
+        block_store.remove_last_n(1).unwrap();
+        assert!(restore().is_none());
+
+        // Ensure a malformed checkpoint is not restored.
+        block_store.storage.insert_tree_checkpoint(BLOCK_TREE_CHECKPOINT_ID, vec![1, 2, 3]).unwrap();
+        assert!(restore().is_none());
+    }
+
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+        assert!(block.transactions().records().next().is_some());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure pruning requires blocks in storage.
+        assert!(block_store.prune_to_height(0).is_err());
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the latest block cannot be pruned.
+        assert!(block_store.prune_to_height(1).is_err());
+        block_store.prune_to_height(0).unwrap();
+        assert_eq!(block_store.pruned_height(), 0);
+
+        // Prune the block, twice.
+        for _ in 0..2 {
+            block_store.storage.prune(&block_hash).unwrap();
+
+            // Ensure the block is reconstructed without its record ciphertexts.
+            let pruned = block_store.get_block(&block_hash).unwrap().unwrap();
+            assert_eq!(pruned.hash(), block_hash);
+            assert!(pruned.transactions().records().next().is_none());
+            assert!(pruned.transactions().commitments().eq(block.transactions().commitments()));
+            assert!(pruned.transactions().transaction_ids().eq(block.transactions().transaction_ids()));
+
+            // Ensure the state paths are still served.
+            for commitment in block.transactions().commitments() {
+                let state_path = block_store.get_state_path_for_commitment(commitment).unwrap();
+                state_path.verify(true, Field::zero()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_open_loads_pruning_state() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new block store, and insert the genesis block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&ledger_test_helpers::sample_genesis_block(rng)).unwrap();
+        assert_eq!(block_store.pruned_height(), 0);
+        assert_eq!(block_store.pruning_depth().unwrap(), None);
+
+        // Persist the pruned height and the pruning depth.
+        block_store.storage.set_pruned_height(1).unwrap();
+        block_store.set_pruning_depth(Some(10)).unwrap();
+
+        // Ensure they are loaded when the block store is opened from its storage.
+        let reopened = BlockStore::<CurrentNetwork, BlockMemory<_>>::from(block_store.storage.clone()).unwrap();
+        assert_eq!(reopened.pruned_height(), 1);
+        assert_eq!(reopened.pruning_depth().unwrap(), Some(10));
+
+        // Ensure disabling pruning mode is persisted.
+        reopened.set_pruning_depth(None).unwrap();
+        assert_eq!(block_store.pruning_depth().unwrap(), None);
+    }
+
+    #[test]
+    fn test_blocks_and_transactions_in_range() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the range is empty.
+        assert_eq!(block_store.blocks_in_range(0..0).count(), 0);
+        assert!(block_store.blocks_in_range(0..1).next().unwrap().is_err());
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the blocks are iterated, forwards and in reverse.
+        let blocks = block_store.blocks_in_range(0..1).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blocks, vec![block.clone()]);
+        let blocks = block_store.blocks_in_range(0..1).rev().collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(blocks, vec![block.clone()]);
+        // Ensure a missing block height is an error.
+        assert!(block_store.blocks_in_range(0..2).nth(1).unwrap().is_err());
+
+        // Ensure the transactions are iterated, forwards and in reverse.
+        let transactions = block_store.transactions_in_range(0..1).collect::<Result<Vec<_>>>().unwrap();
+        assert!(transactions.iter().eq(block.transactions().iter()));
+        let transactions = block_store.transactions_in_range(0..1).rev().collect::<Result<Vec<_>>>().unwrap();
+        assert!(transactions.iter().eq(block.transactions().iter().rev()));
+        // Ensure a missing block height is an error.
+        assert!(block_store.transactions_in_range(1..2).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_prove_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        for commitment in block.transactions().commitments() {
+            // Ensure the returned leaf is the transition leaf of the state path.
+            let (state_path, transition_leaf) = block_store.prove_commitment(commitment).unwrap();
+            assert_eq!(transition_leaf, *state_path.transition_leaf());
+            assert_eq!(transition_leaf.id(), *commitment);
+            assert_eq!(state_path, block_store.get_state_path_for_commitment(commitment).unwrap());
+            state_path.verify(true, Field::zero()).unwrap();
+        }
+
+        // Ensure an unknown commitment is rejected.
+        assert!(block_store.prove_commitment(&Field::rand(rng)).is_err());
+    }
+
+    #[test]
+    fn test_all_commitment_paths() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure a state path is produced for each commitment, in order.
+        let state_paths = block_store.all_commitment_paths(&block.hash()).unwrap();
+        let commitments = block.transactions().commitments().copied().collect::<Vec<_>>();
+        assert_eq!(state_paths.iter().map(|(commitment, _)| *commitment).collect::<Vec<_>>(), commitments);
+
+        for (commitment, state_path) in state_paths {
+            // Ensure each state path verifies, and matches the state path for the commitment.
+            state_path.verify(true, Field::zero()).unwrap();
+            assert_eq!(state_path, block_store.get_state_path_for_commitment(&commitment).unwrap());
+        }
+
+        // Ensure an unknown block hash is rejected.
+        assert!(block_store.all_commitment_paths(&Field::<CurrentNetwork>::rand(rng).into()).is_err());
+    }
+
+    #[test]
+    fn test_genesis_state_path() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        assert_eq!(block.height(), 0);
+        // Ensure the genesis block is constructed with the sentinel previous block hash.
+        assert_eq!(block.previous_hash(), <CurrentNetwork as Network>::BlockHash::default());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        for commitment in block.transactions().commitments() {
+            // Ensure the state path is anchored to the genesis block, with the sentinel previous block hash.
+            let state_path = block_store.get_state_path_for_commitment(commitment).unwrap();
+            assert_eq!(state_path.block_hash(), block.hash());
+            assert_eq!(state_path.previous_block_hash(), <CurrentNetwork as Network>::BlockHash::default());
+            // Ensure the state path is valid.
+            state_path.verify(true, Field::zero()).unwrap();
+            assert_eq!(state_path.verify_into_root().unwrap(), block_store.current_state_root());
+        }
+    }
+
+    #[test]
+    fn test_new_commitment() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Construct the block tree of the block store.
+        let mut block_tree: BlockTree<CurrentNetwork> =
+            CurrentNetwork::merkle_tree_bhp(&[block.hash().to_bits_le()]).unwrap();
+        assert_eq!(*block_tree.root(), *block_store.current_state_root());
+
+        for commitment in block.transactions().commitments() {
+            // Ensure the state path matches the one from the block store, and is valid.
+            let state_path = new_commitment(&block_tree, &block_store, commitment).unwrap();
+            assert_eq!(state_path, block_store.get_state_path_for_commitment(commitment).unwrap());
+            state_path.verify(true, Field::zero()).unwrap();
+        }
+
+        // Ensure an unknown commitment is rejected.
+        assert!(new_commitment(&block_tree, &block_store, &Field::rand(rng)).is_err());
+
+        // Ensure a block tree whose root is not in the block store is rejected.
+        block_tree.append(&[Field::<CurrentNetwork>::rand(rng).to_bits_le()]).unwrap();
+        let commitment = block.transactions().commitments().next().unwrap();
+        let error = new_commitment(&block_tree, &block_store, commitment).unwrap_err();
+        assert!(error.to_string().contains("is missing in storage"));
+    }
+
+    #[test]
+    fn test_header_inclusion_path() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the header inclusion path for the transactions root matches the one in a state path.
+        let commitment = block.transactions().commitments().next().unwrap();
+        let state_path = block_store.get_state_path_for_commitment(commitment).unwrap();
+        let header_inclusion_path =
+            block_store.get_header_inclusion_path(&block.hash(), HeaderLeafIndex::TransactionsRoot).unwrap();
+        assert_eq!(header_inclusion_path, state_path.to_header_inclusion_path());
+
+        // Ensure the header inclusion path for every header leaf is valid.
+        for header_index in HeaderLeafIndex::ALL {
+            let header_inclusion_path = block_store.get_header_inclusion_path(&block.hash(), header_index).unwrap();
+            assert_eq!(header_inclusion_path.header_leaf().index(), header_index.index());
+            assert_eq!(header_inclusion_path.global_state_root(), block_store.current_state_root());
+            header_inclusion_path.verify().unwrap();
+        }
+
+        // Ensure an unknown block hash is rejected.
+        let block_hash = Field::<CurrentNetwork>::rand(rng).into();
+        assert!(block_store.get_header_inclusion_path(&block_hash, HeaderLeafIndex::RatificationsRoot).is_err());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
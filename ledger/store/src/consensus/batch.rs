@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An atomic write batch spanning the finalize, block, transaction, and transition stores.
+///
+/// The writes to these stores while the batch is open are queued, and are either committed together
+/// with `AtomicBatch::commit`, or discarded together with `AtomicBatch::rollback`. A batch that is dropped
+/// before it is committed, such as on an error or a panic midway, is rolled back, so that storage is left unchanged.
+#[must_use = "An atomic batch is rolled back unless it is committed"]
+pub struct AtomicBatch<'a, N: Network, C: ConsensusStorage<N>> {
+    /// The consensus storage.
+    storage: &'a C,
+    /// A flag indicating if the batch is committed or rolled back.
+    is_finished: bool,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> AtomicBatch<'a, N, C> {
+    /// Starts an atomic batch over the given consensus storage.
+    pub(super) fn start(storage: &'a C) -> Result<Self> {
+        // Ensure that there is no atomic batch in progress.
+        ensure!(!storage.is_atomic_in_progress(), "Cannot start an atomic batch while another one is in progress");
+        // Start the atomic batch.
+        storage.start_atomic();
+        Ok(Self { storage, is_finished: false, _phantom: PhantomData })
+    }
+
+    /// Returns the finalize store, whose writes are queued in this batch.
+    pub fn finalize_store(&self) -> &FinalizeStore<N, C::FinalizeStorage> {
+        self.storage.finalize_store()
+    }
+
+    /// Commits the queued writes to every store. If the commit fails, the batch is rolled back.
+    ///
+    /// Note: The RocksDB backend commits the queued writes of every store in a single database write.
+    pub fn commit(mut self) -> Result<()> {
+        self.is_finished = true;
+        // Commit the atomic batch.
+        self.storage.finish_atomic().map_err(|error| {
+            // Roll back the queued writes that were not committed.
+            self.storage.abort_atomic();
+            error
+        })
+    }
+
+    /// Discards the queued writes to every store.
+    pub fn rollback(mut self) {
+        self.is_finished = true;
+        self.storage.abort_atomic();
+    }
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> Drop for AtomicBatch<'a, N, C> {
+    /// Rolls back the batch, if it was not committed or rolled back.
+    fn drop(&mut self) {
+        if !self.is_finished {
+            self.storage.abort_atomic();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{atomic_finalize, atomic_finalize_in_batch, helpers::memory::ConsensusMemory, FinalizeMode};
+    use console::program::{Identifier, ProgramID};
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_commit_and_rollback() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        let state_root = store.block_store().current_state_root();
+
+        // Insert the block, and roll back the batch.
+        let batch = store.atomic_batch().unwrap();
+        // Ensure a second batch cannot be started.
+        assert!(store.atomic_batch().is_err());
+        store.block_store().insert(&block).unwrap();
+        batch.rollback();
+        // Ensure the block and the block tree are rolled back.
+        assert!(!store.is_atomic_in_progress());
+        assert!(!store.block_store().contains_block_hash(&block.hash()).unwrap());
+        assert_eq!(store.block_store().current_state_root(), state_root);
+
+        // Insert the block, and commit the batch.
+        let batch = store.atomic_batch().unwrap();
+        store.block_store().insert(&block).unwrap();
+        // Ensure the block is not committed before the batch is.
+        assert!(!store.block_store().contains_block_hash(&block.hash()).unwrap());
+        batch.commit().unwrap();
+        // Ensure the block is committed.
+        assert!(!store.is_atomic_in_progress());
+        assert_eq!(store.block_store().get_block(&block.hash()).unwrap(), Some(block));
+        assert_ne!(store.block_store().current_state_root(), state_root);
+    }
+
+    #[test]
+    fn test_crash_recovery() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        let state_root = store.block_store().current_state_root();
+
+        // Insert the block and the mapping, and panic before the batch is committed.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _batch = store.atomic_batch().unwrap();
+            store.block_store().insert(&block).unwrap();
+            store.finalize_store().initialize_mapping(program_id, mapping_name).unwrap();
+            panic!("Crashed before the commit");
+        }));
+        assert!(result.is_err());
+
+        // Ensure no store retains the writes.
+        assert!(!store.is_atomic_in_progress());
+        assert!(!store.block_store().contains_block_hash(&block.hash()).unwrap());
+        assert!(!store.transaction_store().contains_transaction_id(block.transaction_ids().next().unwrap()).unwrap());
+        assert!(!store.finalize_store().contains_program_confirmed(&program_id).unwrap());
+        assert_eq!(store.block_store().current_state_root(), state_root);
+
+        // Ensure the writes are committed when retried.
+        let batch = store.atomic_batch().unwrap();
+        store.block_store().insert(&block).unwrap();
+        store.finalize_store().initialize_mapping(program_id, mapping_name).unwrap();
+        batch.commit().unwrap();
+        assert!(store.block_store().contains_block_hash(&block.hash()).unwrap());
+        assert!(store.finalize_store().contains_program_confirmed(&program_id).unwrap());
+    }
+
+    #[test]
+    fn test_rollback_after_remove_last_n() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        // Initialize a new consensus store, and insert the block.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        store.block_store().insert(&block).unwrap();
+        let state_root = store.block_store().current_state_root();
+
+        // Remove the block, and fail before the batch is committed.
+        let result = || -> Result<()> {
+            let _batch = store.atomic_batch()?;
+            store.block_store().remove_last_n(1)?;
+            bail!("Failed after removing the block")
+        };
+        assert!(result().is_err());
+
+        // Ensure the block and the block tree are rolled back.
+        assert!(!store.is_atomic_in_progress());
+        assert!(store.block_store().contains_block_hash(&block.hash()).unwrap());
+        assert_eq!(store.block_store().current_state_root(), state_root);
+
+        // Ensure the removal is committed when retried.
+        let batch = store.atomic_batch().unwrap();
+        store.block_store().remove_last_n(1).unwrap();
+        batch.commit().unwrap();
+        assert!(!store.block_store().contains_block_hash(&block.hash()).unwrap());
+        assert_ne!(store.block_store().current_state_root(), state_root);
+
+        // Ensure the block tree is not restored by a later rollback.
+        let batch = store.atomic_batch().unwrap();
+        let state_root = store.block_store().current_state_root();
+        batch.rollback();
+        assert_eq!(store.block_store().current_state_root(), state_root);
+    }
+
+    #[test]
+    fn test_atomic_finalize_in_batch() {
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        let batch = store.atomic_batch().unwrap();
+        // Ensure a top-level finalize operation does not join the batch.
+        let outcome = || -> Result<()> { atomic_finalize!(store.finalize_store(), FinalizeMode::RealRun, { Ok(()) }) };
+        assert!(outcome().is_err());
+
+        // Ensure a failed finalize operation only rewinds its own writes, and leaves the batch open.
+        let outcome: Result<()> = atomic_finalize_in_batch!(batch, FinalizeMode::RealRun, {
+            batch.finalize_store().initialize_mapping(program_id, mapping_name).map_err(|e| e.to_string())?;
+            Err("Failed to finalize".to_string())
+        });
+        assert!(outcome.is_err());
+        assert!(store.is_atomic_in_progress());
+
+        // Ensure a successful finalize operation is only committed with the batch.
+        let outcome: Result<()> = atomic_finalize_in_batch!(batch, FinalizeMode::RealRun, {
+            batch.finalize_store().initialize_mapping(program_id, mapping_name).map_err(|e| e.to_string())
+        });
+        outcome.unwrap();
+        assert!(!store.finalize_store().contains_program_confirmed(&program_id).unwrap());
+        batch.commit().unwrap();
+        assert!(store.finalize_store().contains_program_confirmed(&program_id).unwrap());
+    }
+}
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod batch;
+pub use batch::*;
+
+#[cfg(feature = "rocks")]
+use crate::helpers::rocksdb::{ConsensusDB, StorageStats};
 use crate::{
     BlockStorage,
     BlockStore,
@@ -28,6 +33,11 @@ use anyhow::Result;
 use core::marker::PhantomData;
 
 /// A trait for consensus storage.
+///
+/// A storage backend implements the storage traits (e.g. `BlockStorage` and `FinalizeStorage`) by choosing a
+/// `Map` and `NestedMap` implementation for each of their maps, as done in `helpers::memory` and `helpers::rocksdb`.
+/// A `Map` implementation for a new backend should pass the conformance suite in `helpers::test_helpers`
+/// (enabled with the `test` feature), and `helpers::file::FileMap` serves as a reference implementation.
 pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The finalize storage.
     type FinalizeStorage: FinalizeStorage<N>;
@@ -180,8 +190,27 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.finish_atomic()
     }
 
+    /// Starts an atomic batch spanning every store, which is rolled back unless it is committed.
+    pub fn atomic_batch(&self) -> Result<AtomicBatch<'_, N, C>> {
+        AtomicBatch::start(&self.storage)
+    }
+
     /// Returns the optional development ID.
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
 }
+
+#[cfg(feature = "rocks")]
+impl<N: Network> ConsensusStore<N, ConsensusDB<N>> {
+    /// Returns the per-map key counts and sizes, along with the disk usage of the database.
+    pub fn stats(&self) -> Result<StorageStats> {
+        self.storage.stats()
+    }
+
+    /// Compacts the database, reclaiming the space of its removed and overwritten entries,
+    /// e.g. after pruning.
+    pub fn compact(&self) -> Result<()> {
+        self.storage.compact()
+    }
+}
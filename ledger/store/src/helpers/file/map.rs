@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use crate::helpers::{memory::MemoryMap, Map, MapRead};
+use console::network::prelude::*;
+
+use core::{borrow::Borrow, hash::Hash};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// A file-backed map, serving as a reference implementation of the `Map` trait for other storage backends.
+///
+/// The entries are held in memory, and every committed write rewrites the file with all confirmed entries.
+/// The file is replaced atomically, so it always holds the entries before or after a committed write.
+#[derive(Clone)]
+pub struct FileMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The in-memory map, holding the confirmed entries and the atomic batch.
+    map: MemoryMap<K, V>,
+    /// The path of the file holding the confirmed entries.
+    path: Arc<PathBuf>,
+    /// The lock held while writing the file.
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> FileMap<K, V>
+{
+    /// Opens the map stored at the given path, or a new, empty map if the file does not exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        // Load the confirmed entries from the file, if it exists.
+        let map = match path.exists() {
+            true => {
+                let entries: Vec<(K, V)> = bincode::deserialize(&std::fs::read(&path)?)?;
+                entries.into_iter().collect()
+            }
+            false => MemoryMap::default(),
+        };
+        Ok(Self { map, path: Arc::new(path), write_lock: Default::default() })
+    }
+
+    /// Returns the path of the file holding the confirmed entries.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the confirmed entries to the file.
+    fn persist<'a>(&'a self) -> Result<()>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        // Acquire the write lock.
+        let _lock = self.write_lock.lock();
+        // Collect the confirmed entries.
+        let entries: Vec<(K, V)> =
+            self.map.iter_confirmed().map(|(key, value)| (key.into_owned(), value.into_owned())).collect();
+        // Write the entries to a temporary file, and move it into place.
+        let temp_path = self.path.with_extension("tmp");
+        std::fs::write(&temp_path, bincode::serialize(&entries)?)?;
+        std::fs::rename(&temp_path, &*self.path)?;
+        Ok(())
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for FileMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        self.map.insert(key, value)?;
+        // If no batch is in progress, the write is committed.
+        match self.map.is_atomic_in_progress() {
+            true => Ok(()),
+            false => self.persist(),
+        }
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        self.map.remove(key)?;
+        // If no batch is in progress, the write is committed.
+        match self.map.is_atomic_in_progress() {
+            true => Ok(()),
+            false => self.persist(),
+        }
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        self.map.start_atomic()
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.map.is_atomic_in_progress()
+    }
+
+    ///
+    /// Saves the current list of pending operations, so that if `atomic_rewind` is called,
+    /// we roll back all future operations, and return to the start of this checkpoint.
+    ///
+    fn atomic_checkpoint(&self) {
+        self.map.atomic_checkpoint()
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        self.map.clear_latest_checkpoint()
+    }
+
+    ///
+    /// Removes all pending operations to the last `atomic_checkpoint`
+    /// (or to `start_atomic` if no checkpoints have been created).
+    ///
+    fn atomic_rewind(&self) {
+        self.map.atomic_rewind()
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        self.map.abort_atomic()
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        self.map.finish_atomic()?;
+        self.persist()
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for FileMap<K, V>
+{
+    type Iterator = <MemoryMap<K, V> as MapRead<'a, K, V>>::Iterator;
+    type Keys = <MemoryMap<K, V> as MapRead<'a, K, V>>::Keys;
+    type PendingIterator = <MemoryMap<K, V> as MapRead<'a, K, V>>::PendingIterator;
+    type Values = <MemoryMap<K, V> as MapRead<'a, K, V>>::Values;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key_confirmed<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.map.contains_key_confirmed(key)
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    /// This method first checks the atomic batch, and if it does not exist, then checks the map.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.map.contains_key_speculative(key)
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get_confirmed<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.map.get_confirmed(key)
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_pending<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        self.map.get_pending(key)
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the atomic batch.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        self.map.iter_pending()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter_confirmed(&'a self) -> Self::Iterator {
+        self.map.iter_confirmed()
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys_confirmed(&'a self) -> Self::Keys {
+        self.map.keys_confirmed()
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values_confirmed(&'a self) -> Self::Values {
+        self.map.values_confirmed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_conformance() {
+        let directory = tempfile::tempdir().unwrap();
+        let counter = AtomicUsize::new(0);
+
+        // Run the conformance suite, each on a new file.
+        crate::helpers::test_helpers::map::check_map(|| {
+            let path = directory.path().join(counter.fetch_add(1, Ordering::SeqCst).to_string());
+            FileMap::<usize, String>::open(path).unwrap()
+        });
+    }
+
+    #[test]
+    fn test_reopen() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("map");
+
+        // Insert entries, directly and in an atomic batch.
+        let map = FileMap::<usize, String>::open(&path).unwrap();
+        map.insert(0, "0".to_string()).unwrap();
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+        map.remove(&0).unwrap();
+        map.finish_atomic().unwrap();
+
+        // Queue an entry in an aborted batch.
+        map.start_atomic();
+        map.insert(2, "2".to_string()).unwrap();
+        map.abort_atomic();
+
+        // Queue an entry in a batch that is never finished.
+        map.start_atomic();
+        map.insert(3, "3".to_string()).unwrap();
+
+        // Ensure only the committed entries are restored.
+        let reopened = FileMap::<usize, String>::open(&path).unwrap();
+        let entries: Vec<_> = reopened.iter_confirmed().map(|(k, v)| (*k, v.into_owned())).collect();
+        assert_eq!(entries, vec![(1, "1".to_string())]);
+        assert!(!reopened.is_atomic_in_progress());
+    }
+
+    #[test]
+    fn test_open_corrupt_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("map");
+
+        // Ensure a corrupt file is rejected.
+        std::fs::write(&path, [0xFFu8; 3]).unwrap();
+        assert!(FileMap::<usize, String>::open(&path).is_err());
+    }
+}
@@ -334,6 +334,19 @@ impl<
         Ok(key_values)
     }
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, after skipping the first `start` keys.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Serialize 'm'.
+        let m = bincode::serialize(map)?;
+        // Retrieve the keys in the range, which are ordered by their serialized bytes.
+        match self.map.read().get(&m) {
+            Some(keys) => keys.iter().skip(start).take(limit).map(|k| Ok(bincode::deserialize(k)?)).collect(),
+            None => Ok(Default::default()),
+        }
+    }
+
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
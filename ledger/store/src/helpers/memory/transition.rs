@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
@@ -36,6 +43,8 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The function index map.
+    function_index_map: NestedMemoryMap<ProgramID<N>, (Identifier<N>, N::TransitionID), ()>,
 }
 
 #[rustfmt::skip]
@@ -47,6 +56,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+    type FunctionIndexMap = NestedMemoryMap<ProgramID<N>, (Identifier<N>, N::TransitionID), ()>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -58,6 +68,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            function_index_map: NestedMemoryMap::default(),
         })
     }
 
@@ -95,6 +106,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
 }
 
 /// An in-memory transition input storage.
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod file;
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
-#[cfg(test)]
-pub(crate) mod test_helpers;
+/// The conformance suite for `Map` and `NestedMap` implementations.
+#[cfg(any(test, feature = "test"))]
+pub mod test_helpers;
 
 mod traits;
 pub use traits::*;
@@ -109,3 +111,45 @@ macro_rules! atomic_finalize {
         }
     }};
 }
+
+/// A helper macro to perform the finalize operation on a list of transactions, as part of the given `AtomicBatch`.
+///
+/// The finalize operation is performed on the finalize store of the batch, and is only committed once the batch is.
+/// A failed real run or a dry run only rewinds the operations of this finalize operation, and leaves the batch open.
+#[macro_export]
+macro_rules! atomic_finalize_in_batch {
+    ($batch:expr, $finalize_mode:expr, $ops:block) => {{
+        // Retrieve the finalize store of the atomic batch.
+        let store = $batch.finalize_store();
+
+        // Checkpoint the atomic batch.
+        store.atomic_checkpoint();
+
+        // Run the atomic operations.
+        //
+        // Wrap the operations that should be batched in a closure to be able to rewind the atomic batch
+        // to the checkpoint if any of them fails.
+        match ($finalize_mode, || -> Result<_, String> { $ops }()) {
+            // If this is a successful real run, keep the operations in the atomic batch.
+            (FinalizeMode::RealRun, Ok(result)) => {
+                store.clear_latest_checkpoint();
+                Ok(result)
+            }
+            // If this is a failed real run, rewind the atomic batch to the checkpoint.
+            (FinalizeMode::RealRun, Err(error_msg)) => {
+                store.atomic_rewind();
+                Err(anyhow!("Failed to finalize transactions - {error_msg}"))
+            }
+            // If this is a successful dry run, rewind the atomic batch to the checkpoint.
+            (FinalizeMode::DryRun, Ok(result)) => {
+                store.atomic_rewind();
+                Ok(result)
+            }
+            // If this is a failed dry run, rewind the atomic batch to the checkpoint.
+            (FinalizeMode::DryRun, Err(error_msg)) => {
+                store.atomic_rewind();
+                Err(anyhow!("Failed to speculate on transactions - {error_msg}"))
+            }
+        }
+    }};
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    block::{PRUNED_HEIGHT_KEY, PRUNING_DEPTH_KEY},
     helpers::rocksdb::{
         internal::{self, DataMap, Database},
         BlockMap,
@@ -20,6 +21,7 @@ use crate::{
         TransactionDB,
         TransitionDB,
     },
+    helpers::{Map, MapRead},
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
@@ -63,6 +65,10 @@ pub struct BlockDB<N: Network> {
     confirmed_transactions_map: DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The tree checkpoints map.
+    tree_checkpoints_map: DataMap<u8, Vec<u8>>,
+    /// The pruning map.
+    pruning_map: DataMap<u8, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -110,6 +116,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ConfirmedTransactions))?,
             rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            tree_checkpoints_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::TreeCheckpoints))?,
+            pruning_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Pruning))?,
             transaction_store,
         })
     }
@@ -193,4 +201,37 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Returns the latest checkpoint of the Merkle tree with the given ID, if it exists.
+    fn get_tree_checkpoint(&self, tree_id: u8) -> Result<Option<Vec<u8>>> {
+        Ok(self.tree_checkpoints_map.get_confirmed(&tree_id)?.map(|checkpoint| checkpoint.into_owned()))
+    }
+
+    /// Stores the latest checkpoint of the Merkle tree with the given ID.
+    fn insert_tree_checkpoint(&self, tree_id: u8, checkpoint: Vec<u8>) -> Result<()> {
+        self.tree_checkpoints_map.insert(tree_id, checkpoint)
+    }
+
+    /// Returns the height below which blocks have been pruned.
+    fn get_pruned_height(&self) -> Result<u32> {
+        Ok(self.pruning_map.get_confirmed(&PRUNED_HEIGHT_KEY)?.map_or(0, |height| *height))
+    }
+
+    /// Stores the height below which blocks have been pruned.
+    fn set_pruned_height(&self, height: u32) -> Result<()> {
+        self.pruning_map.insert(PRUNED_HEIGHT_KEY, height)
+    }
+
+    /// Returns the number of latest blocks that are kept unpruned, if the ledger is in pruning mode.
+    fn get_pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(self.pruning_map.get_confirmed(&PRUNING_DEPTH_KEY)?.map(|depth| *depth))
+    }
+
+    /// Stores the number of latest blocks that are kept unpruned, or removes it if pruning mode is disabled.
+    fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        match depth {
+            Some(depth) => self.pruning_map.insert(PRUNING_DEPTH_KEY, depth),
+            None => self.pruning_map.remove(&PRUNING_DEPTH_KEY),
+        }
+    }
 }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::rocksdb::{BlockDB, Database, FinalizeDB, RocksDB, StorageStats, TransactionDB, TransitionDB},
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -59,3 +59,16 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
         &self.block_store
     }
 }
+
+impl<N: Network> ConsensusDB<N> {
+    /// Returns the per-map key counts and sizes, along with the disk usage of the database.
+    pub fn stats(&self) -> Result<StorageStats> {
+        RocksDB::open(N::ID, self.dev())?.stats()
+    }
+
+    /// Compacts the database, reclaiming the space of its removed and overwritten entries.
+    pub fn compact(&self) -> Result<()> {
+        RocksDB::open(N::ID, self.dev())?.compact();
+        Ok(())
+    }
+}
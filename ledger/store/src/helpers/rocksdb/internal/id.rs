@@ -71,6 +71,8 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    TreeCheckpoints = DataID::BlockTreeCheckpointsMap as u16,
+    Pruning = DataID::BlockPruningMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -173,6 +175,7 @@ pub enum TransitionMap {
     ReverseTPK = DataID::TransitionReverseTPKMap as u16,
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
+    FunctionIndex = DataID::TransitionFunctionIndexMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -205,7 +208,7 @@ pub enum TestMap {
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u16)]
-enum DataID {
+pub(super) enum DataID {
     // Block
     BlockStateRootMap,
     BlockReverseStateRootMap,
@@ -273,6 +276,9 @@ enum DataID {
 
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
+    BlockTreeCheckpointsMap,
+    TransitionFunctionIndexMap,
+    BlockPruningMap,
 
     // Testing
     #[cfg(test)]
@@ -286,3 +292,68 @@ enum DataID {
     #[cfg(test)]
     Test5,
 }
+
+impl DataID {
+    /// The data IDs in storage, ordered by their value.
+    // Note: any new variant MUST also be added to the end of this list.
+    pub(super) const ALL: [DataID; 58] = [
+        DataID::BlockStateRootMap,
+        DataID::BlockReverseStateRootMap,
+        DataID::BlockIDMap,
+        DataID::BlockReverseIDMap,
+        DataID::BlockHeaderMap,
+        DataID::BlockAuthorityMap,
+        DataID::BlockCertificateMap,
+        DataID::BlockRatificationsMap,
+        DataID::BlockSolutionsMap,
+        DataID::BlockPuzzleCommitmentsMap,
+        DataID::BlockTransactionsMap,
+        DataID::BlockAbortedTransactionIDsMap,
+        DataID::BlockRejectedOrAbortedTransactionIDMap,
+        DataID::BlockConfirmedTransactionsMap,
+        DataID::CurrentRoundMap,
+        DataID::RoundToHeightMap,
+        DataID::CommitteeMap,
+        DataID::DeploymentIDMap,
+        DataID::DeploymentEditionMap,
+        DataID::DeploymentReverseIDMap,
+        DataID::DeploymentOwnerMap,
+        DataID::DeploymentProgramMap,
+        DataID::DeploymentVerifyingKeyMap,
+        DataID::DeploymentCertificateMap,
+        DataID::ExecutionIDMap,
+        DataID::ExecutionReverseIDMap,
+        DataID::ExecutionInclusionMap,
+        DataID::FeeFeeMap,
+        DataID::FeeReverseFeeMap,
+        DataID::InputIDMap,
+        DataID::InputReverseIDMap,
+        DataID::InputConstantMap,
+        DataID::InputPublicMap,
+        DataID::InputPrivateMap,
+        DataID::InputRecordMap,
+        DataID::InputRecordTagMap,
+        DataID::InputExternalRecordMap,
+        DataID::OutputIDMap,
+        DataID::OutputReverseIDMap,
+        DataID::OutputConstantMap,
+        DataID::OutputPublicMap,
+        DataID::OutputPrivateMap,
+        DataID::OutputRecordMap,
+        DataID::OutputRecordNonceMap,
+        DataID::OutputExternalRecordMap,
+        DataID::OutputFutureMap,
+        DataID::TransactionIDMap,
+        DataID::TransitionLocatorMap,
+        DataID::TransitionTPKMap,
+        DataID::TransitionReverseTPKMap,
+        DataID::TransitionTCMMap,
+        DataID::TransitionReverseTCMMap,
+        DataID::ProgramIDMap,
+        DataID::KeyValueMap,
+        DataID::BlockRejectedDeploymentOrExecutionMap,
+        DataID::BlockTreeCheckpointsMap,
+        DataID::TransitionFunctionIndexMap,
+        DataID::BlockPruningMap,
+    ];
+}
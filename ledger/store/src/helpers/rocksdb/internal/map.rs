@@ -470,6 +470,20 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
 }
 
 impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+    /// Compacts the entries of the map, reclaiming the space of its removed and overwritten entries.
+    pub fn compact(&self) {
+        // Compute the first key after the map prefix, by incrementing the prefix.
+        let mut end = self.context.clone();
+        while let Some(byte) = end.pop() {
+            if byte < u8::MAX {
+                end.push(byte + 1);
+                break;
+            }
+        }
+        // Compact the entries with the map prefix.
+        self.database.compact_range(Some(&self.context), (!end.is_empty()).then_some(&end));
+    }
+
     #[inline]
     fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
     where
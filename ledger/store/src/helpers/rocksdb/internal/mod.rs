@@ -21,6 +21,9 @@ pub use map::*;
 mod nested_map;
 pub use nested_map::*;
 
+mod stats;
+pub use stats::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -194,6 +197,15 @@ impl RocksDB {
     pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
         use console::prelude::{Rng, TestRng};
 
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        Self::open_testing_at(temp_dir, dev)
+    }
+
+    /// Opens the test database in the given directory, which is reused if it already contains one.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing_at(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
         let database = {
             // Customize database options.
             let mut options = rocksdb::Options::default();
@@ -203,9 +215,6 @@ impl RocksDB {
             let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
             options.set_prefix_extractor(prefix_extractor);
 
-            // Ensure the `temp_dir` is unique.
-            let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
-
             // Construct the directory for the test database.
             let primary = match dev {
                 Some(dev) => temp_dir.join(dev.to_string()),
@@ -272,6 +281,30 @@ impl RocksDB {
         })))
     }
 
+    /// Opens the test map in the given directory, which is reused if it already contains a test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing_at<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<DataMap<K, V>> {
+        // Open the RocksDB test database.
+        let database = Self::open_testing_at(temp_dir, dev)?;
+
+        // Combine contexts to create a new scope.
+        let mut context = database.network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&(map_id.into()).to_le_bytes());
+
+        // Return the DataMap.
+        Ok(DataMap(Arc::new(InnerDataMap {
+            database,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        })))
+    }
+
     /// Opens the test nested map.
     #[cfg(any(test, feature = "test"))]
     pub fn open_nested_map_testing<
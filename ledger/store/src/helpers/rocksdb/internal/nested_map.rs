@@ -433,6 +433,56 @@ impl<
         Ok(entries)
     }
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, after skipping the first `start` keys.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Serialize the map.
+        let serialized_map = bincode::serialize(map)?;
+
+        // Initialize a vector for the keys.
+        let mut keys = Vec::new();
+        // Initialize a counter for the skipped keys.
+        let mut num_skipped = 0;
+
+        // Construct an iterator over the DB with the specified prefix.
+        let iterator = self
+            .database
+            .iterator(rocksdb::IteratorMode::From(&self.create_prefixed_map(map)?, rocksdb::Direction::Forward));
+
+        // Iterate over the keys in the DB with the specified prefix, until the end of the range.
+        for entry in iterator {
+            // If the range is complete, stop reading from the DB.
+            if keys.len() == limit {
+                break;
+            }
+
+            let (map_key, _) = entry?;
+
+            // Extract the bytes belonging to the map and the key.
+            let Ok((entry_map, entry_key)) = get_map_and_key(&map_key) else {
+                break;
+            };
+
+            // If the 'entry_map' no longer matches the 'serialized_map',
+            // we've moved past the relevant keys and can break the loop.
+            if entry_map != serialized_map {
+                break;
+            }
+
+            // Skip the keys before the start of the range.
+            if num_skipped < start {
+                num_skipped += 1;
+                continue;
+            }
+
+            // Deserialize the key, and push it to the vector.
+            keys.push(bincode::deserialize(entry_key)?);
+        }
+
+        Ok(keys)
+    }
+
     ///
     /// Returns the speculative key-value pairs for the given map, if it exists.
     ///
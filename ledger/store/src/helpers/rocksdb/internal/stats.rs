@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DataID, RocksDB};
+
+use anyhow::Result;
+use indexmap::IndexMap;
+
+/// The statistics of a single map in storage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MapStats {
+    /// The number of keys in the map.
+    pub num_keys: u64,
+    /// The total size of the keys and values in the map, in bytes, before compression.
+    pub size_in_bytes: u64,
+}
+
+/// The statistics of the storage of a network.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageStats {
+    /// The statistics of each non-empty map, by map name.
+    pub maps: IndexMap<String, MapStats>,
+    /// The total size of the SST files of the database on disk, in bytes.
+    pub total_sst_files_size: u64,
+    /// The estimated size of the live data of the database on disk, in bytes.
+    pub estimated_live_data_size: u64,
+}
+
+impl RocksDB {
+    /// Returns the statistics of the maps of this network, along with the disk usage of the database.
+    ///
+    /// Note: The maps are scanned in full, so this operation is linear in the size of the storage.
+    pub fn stats(&self) -> Result<StorageStats> {
+        let mut maps = IndexMap::new();
+        for data_id in DataID::ALL {
+            // Compute the prefix of the map.
+            let mut prefix = self.network_id.to_le_bytes().to_vec();
+            prefix.extend_from_slice(&(data_id as u16).to_le_bytes());
+
+            // Count the keys and their sizes.
+            let mut stats = MapStats::default();
+            for entry in self.prefix_iterator(&prefix) {
+                let (key, value) = entry?;
+                stats.num_keys += 1;
+                stats.size_in_bytes += (key.len() + value.len()) as u64;
+            }
+            // Skip the empty maps.
+            if stats.num_keys > 0 {
+                maps.insert(format!("{data_id:?}"), stats);
+            }
+        }
+
+        Ok(StorageStats {
+            maps,
+            total_sst_files_size: self.property_int_value("rocksdb.total-sst-files-size")?.unwrap_or_default(),
+            estimated_live_data_size: self.property_int_value("rocksdb.estimate-live-data-size")?.unwrap_or_default(),
+        })
+    }
+
+    /// Compacts the entries of this network, reclaiming the space of its removed and overwritten entries.
+    pub fn compact(&self) {
+        // Compute the first key after the network prefix, by incrementing the prefix.
+        let start = self.network_id.to_le_bytes().to_vec();
+        let mut end = start.clone();
+        while let Some(byte) = end.pop() {
+            if byte < u8::MAX {
+                end.push(byte + 1);
+                break;
+            }
+        }
+        // Compact the entries with the network prefix.
+        self.compact_range(Some(&start), (!end.is_empty()).then_some(&end));
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::helpers::{
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{internal::DataID, BlockMap, MapID, RocksDB, TestMap as TestMapID},
     Map,
     MapRead,
 };
@@ -214,3 +214,64 @@ fn test_iterator_ordering() {
         assert_eq!(&*v1, v2);
     }
 }
+
+#[test]
+fn test_data_ids_are_ordered() {
+    // Ensure the list of data IDs matches their values, which the storage statistics rely on.
+    for (index, data_id) in DataID::ALL.into_iter().enumerate() {
+        assert_eq!(data_id as usize, index);
+    }
+}
+
+#[test]
+#[serial]
+fn test_reopen_pruning_map() {
+    use crate::{PRUNED_HEIGHT_KEY, PRUNING_DEPTH_KEY};
+
+    // Prepare a directory that outlives the test database.
+    let directory = temp_dir();
+    // Opens the block pruning map in the directory.
+    let open = || {
+        RocksDB::open_map_testing_at::<u8, u32, _>(directory.clone(), None, MapID::Block(BlockMap::Pruning))
+            .expect("Failed to open data map")
+    };
+
+    // Persist the pruned height and the pruning depth, and close the database.
+    {
+        let map = open();
+        map.insert(PRUNED_HEIGHT_KEY, 5).expect("Failed to insert");
+        map.insert(PRUNING_DEPTH_KEY, 10).expect("Failed to insert");
+    }
+
+    // Ensure they are loaded after the database is reopened.
+    let map = open();
+    assert_eq!(map.get_confirmed(&PRUNED_HEIGHT_KEY).expect("Failed to get").map(|height| *height), Some(5));
+    assert_eq!(map.get_confirmed(&PRUNING_DEPTH_KEY).expect("Failed to get").map(|depth| *depth), Some(10));
+}
+
+#[test]
+#[serial]
+fn test_stats_and_compact() {
+    let map = RocksDB::open_map_testing(temp_dir(), None, MapID::Block(BlockMap::ID)).expect("Failed to open data map");
+
+    // Ensure the map is not in the statistics while it is empty.
+    let stats = map.database.stats().expect("Failed to get the storage statistics");
+    assert!(!stats.maps.contains_key("BlockIDMap"));
+
+    // Insert values into the map.
+    for i in 0u32..100 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+    let stats = map.database.stats().expect("Failed to get the storage statistics");
+    assert_eq!(stats.maps["BlockIDMap"].num_keys, 100);
+    assert!(stats.maps["BlockIDMap"].size_in_bytes > 0);
+
+    // Remove half of the values, and compact the database.
+    for i in 0u32..50 {
+        map.remove(&i).expect("Failed to remove");
+    }
+    map.database.compact();
+    let stats = map.database.stats().expect("Failed to get the storage statistics");
+    assert_eq!(stats.maps["BlockIDMap"].num_keys, 50);
+    assert_eq!(map.get_confirmed(&75).expect("Failed to get").map(|v| v.to_string()), Some("75".to_string()));
+}
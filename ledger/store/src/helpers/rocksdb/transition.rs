@@ -13,7 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::rocksdb::{
+        self,
+        DataMap,
+        Database,
+        MapID,
+        NestedDataMap,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
@@ -43,6 +52,8 @@ pub struct TransitionDB<N: Network> {
     tcm_map: DataMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
+    /// The function index map.
+    function_index_map: NestedDataMap<ProgramID<N>, (Identifier<N>, N::TransitionID), ()>,
 }
 
 #[rustfmt::skip]
@@ -54,6 +65,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type ReverseTPKMap = DataMap<Group<N>, N::TransitionID>;
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
+    type FunctionIndexMap = NestedDataMap<ProgramID<N>, (Identifier<N>, N::TransitionID), ()>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -65,6 +77,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             reverse_tpk_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::ReverseTPK))?,
             tcm_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, dev,  MapID::Transition(TransitionMap::ReverseTCM))?,
+            function_index_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Transition(TransitionMap::FunctionIndex))?,
         })
     }
 
@@ -102,6 +115,11 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
 }
 
 /// An database transition input storage.
@@ -197,6 +215,14 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
     fn dev(&self) -> Option<u16> {
         self.dev
     }
+
+    /// Reclaims the storage space freed by pruning, by compacting the input value maps.
+    fn reclaim(&self) -> Result<()> {
+        self.constant.compact();
+        self.public.compact();
+        self.private.compact();
+        Ok(())
+    }
 }
 
 /// A database transition output storage.
@@ -302,4 +328,13 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     fn dev(&self) -> Option<u16> {
         self.dev
     }
+
+    /// Reclaims the storage space freed by pruning, by compacting the output value maps.
+    fn reclaim(&self) -> Result<()> {
+        self.constant.compact();
+        self.public.compact();
+        self.private.compact();
+        self.record.compact();
+        Ok(())
+    }
 }
@@ -33,6 +33,17 @@ pub use check_iterators_match::*;
 mod check_remove_and_get_value_speculative;
 pub use check_remove_and_get_value_speculative::*;
 
+/// Runs every `NestedMap` conformance check, each on a new, empty map from the given constructor.
+pub fn check_nested_map<M: for<'a> crate::helpers::NestedMap<'a, usize, usize, String>>(new_map: impl Fn() -> M) {
+    check_insert_and_get_value_speculative(new_map());
+    check_remove_and_get_value_speculative(new_map());
+    check_contains_key(new_map());
+    check_get_map(new_map());
+    check_iterators_match(new_map());
+    check_atomic_writes_are_batched(new_map());
+    check_atomic_writes_can_be_aborted(new_map());
+}
+
 fn ensure_map_is_empty(map: &impl for<'a> crate::helpers::NestedMap<'a, usize, usize, String>) {
     // Sanity check.
     assert!(map.iter_pending().next().is_none());
@@ -15,7 +15,10 @@
 use console::network::prelude::{Deserialize, Result, Serialize};
 
 use core::hash::Hash;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
 
 /// A trait representing 'nested map'-like storage operations with read-write capabilities.
 pub trait NestedMap<
@@ -115,6 +118,70 @@ pub trait NestedMapRead<
     ///
     fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>>;
 
+    ///
+    /// Returns up to `limit` confirmed keys for the given map, after skipping the first `start` keys.
+    /// The keys are in the order of their serialized bytes, which is the order of the keys in storage,
+    /// so only the keys up to the end of the range are read.
+    ///
+    fn get_keys_confirmed(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>>;
+
+    ///
+    /// Returns up to `limit` speculative keys for the given map, after skipping the first `start` keys.
+    /// The keys are in the order of their serialized bytes, with the atomic batch applied over the confirmed keys.
+    ///
+    fn get_keys_speculative(&'a self, map: &M, start: usize, limit: usize) -> Result<Vec<K>> {
+        // Collect the pending changes to the map, by their serialized keys.
+        let mut is_cleared = false;
+        let mut inserted = BTreeMap::new();
+        let mut removed = BTreeSet::new();
+        for (m, k, v) in self.iter_pending() {
+            // If the map does not match the given map, then continue.
+            if *m != *map {
+                continue;
+            }
+            match (k, v) {
+                // Insert or update the key.
+                (Some(k), Some(_)) => {
+                    let bytes = bincode::serialize(&*k)?;
+                    removed.remove(&bytes);
+                    inserted.insert(bytes, k.into_owned());
+                }
+                // Remove the key.
+                (Some(k), None) => {
+                    let bytes = bincode::serialize(&*k)?;
+                    inserted.remove(&bytes);
+                    removed.insert(bytes);
+                }
+                // Clear the map.
+                (None, None) => {
+                    is_cleared = true;
+                    inserted.clear();
+                    removed.clear();
+                }
+                (None, Some(_)) => unreachable!("Cannot remove a key-value pair from a map without a key."),
+            }
+        }
+
+        // Retrieve the confirmed keys up to the end of the range.
+        // Note: Each removed key moves the end of the range by at most one confirmed key.
+        let end = start.saturating_add(limit).saturating_add(removed.len());
+        let confirmed = match is_cleared {
+            true => Vec::new(),
+            false => self.get_keys_confirmed(map, 0, end)?,
+        };
+
+        // Merge the confirmed keys that are not removed into the inserted keys, in the order of their bytes.
+        let mut keys = inserted;
+        for key in confirmed {
+            let bytes = bincode::serialize(&key)?;
+            if !removed.contains(&bytes) {
+                keys.entry(bytes).or_insert(key);
+            }
+        }
+        // Return the requested range of keys.
+        Ok(keys.into_values().skip(start).take(limit).collect())
+    }
+
     ///
     /// Returns the value for the given key from the map, if it exists.
     ///
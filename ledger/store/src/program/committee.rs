@@ -234,6 +234,40 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Removes the committees above the given `height`, along with their round to height entries,
+    /// restoring the storage to its state right after the committee for the given `height` was inserted.
+    fn remove_above(&self, height: u32) -> Result<()> {
+        // Retrieve the committee for the given height.
+        let Some(committee) = self.get_committee(height)? else {
+            bail!("Committee not found for height {height} in committee storage");
+        };
+        // Retrieve the round for the given height, which becomes the current round.
+        let committee_round = committee.starting_round();
+
+        // Determine the heights of the committees to remove.
+        let heights = self.committee_map().keys_confirmed().map(|h| cow_to_copied!(h)).filter(|h| *h > height);
+        let heights = heights.collect::<Vec<_>>();
+        // Determine the rounds to remove, which includes the rounds that were caught up to the removed committees.
+        let rounds = self.round_to_height_map().keys_confirmed().map(|r| cow_to_copied!(r));
+        let rounds = rounds.filter(|r| *r > committee_round).collect::<Vec<_>>();
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Update the current round.
+            self.current_round_map().insert(ROUND_KEY, committee_round)?;
+            // Remove the round to height mappings.
+            for round in rounds {
+                self.round_to_height_map().remove(&round)?;
+            }
+            // Remove the committees.
+            for height in heights {
+                self.committee_map().remove(&height)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the current round.
     fn current_round(&self) -> Result<u64> {
         match self.current_round_map().get_confirmed(&ROUND_KEY)? {
@@ -373,6 +407,12 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn remove(&self, height: u32) -> Result<()> {
         self.storage.remove(height)
     }
+
+    /// Removes the committees above the given `height`, along with their round to height entries,
+    /// restoring the storage to its state right after the committee for the given `height` was inserted.
+    pub fn remove_above(&self, height: u32) -> Result<()> {
+        self.storage.remove_above(height)
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
@@ -535,6 +575,49 @@ mod tests {
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
 
+    #[test]
+    fn test_remove_above() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(8, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        assert!(store.remove_above(0).is_err());
+
+        // Insert the committees.
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1.clone()).unwrap();
+        store.insert(2, committee_2).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+
+        // Remove the committees above height 1.
+        store.remove_above(1).unwrap();
+        assert_eq!(store.current_round().unwrap(), 5);
+        assert_eq!(store.current_height().unwrap(), 1);
+        assert_eq!(store.current_committee().unwrap(), committee_1);
+        assert_eq!(store.get_height_for_round(5).unwrap(), Some(1));
+        assert_eq!(store.get_height_for_round(6).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+
+        // Remove the committees above height 0.
+        store.remove_above(0).unwrap();
+        assert_eq!(store.current_round().unwrap(), 0);
+        assert_eq!(store.current_height().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+        assert_eq!(store.get_height_for_round(0).unwrap(), Some(0));
+        assert_eq!(store.get_height_for_round(1).unwrap(), None);
+        assert_eq!(store.get_committee(1).unwrap(), None);
+
+        // Ensure the committees can be inserted again.
+        store.insert(1, committee_1.clone()).unwrap();
+        assert_eq!(store.current_committee().unwrap(), committee_1);
+        assert_eq!(store.get_height_for_round(4).unwrap(), Some(0));
+    }
+
     #[test]
     fn test_remove_hole() {
         let rng = &mut TestRng::default();
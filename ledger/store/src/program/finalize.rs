@@ -28,7 +28,8 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use std::borrow::Cow;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -450,6 +451,24 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, starting at `start`.
+    /// The keys are ordered by their serialized bytes, which is the order of the keys in every storage,
+    /// so only the keys up to the end of the range are read.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get keys (S).")
+        }
+        // Retrieve the keys in the range.
+        self.key_value_map().get_keys_speculative(&(program_id, mapping_name), start, limit)
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -620,6 +639,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, starting at `start`.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>> {
+        self.storage.get_keys_speculative(program_id, mapping_name, start, limit)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -715,6 +745,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an iterator over the confirmed program IDs.
+    pub fn program_ids_confirmed(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys_confirmed()
+    }
+
     /// Returns the confirmed mapping names for the given `program ID`.
     pub fn get_mapping_names_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
         self.storage.get_mapping_names_confirmed(program_id)
@@ -738,6 +773,23 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_speculative(program_id, mapping_name)
     }
 
+    /// Returns the key-value changes in the atomic batch in progress,
+    /// as (`program ID`, `mapping name`, `key`, `value`), where a `None` value indicates the key is removed.
+    /// If a key changes more than once, only its latest value is returned.
+    pub fn get_pending_key_values(&self) -> Vec<(ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>)> {
+        let mut changes = IndexMap::new();
+        for (map, key, value) in self.storage.key_value_map().iter_pending() {
+            // Note: The removals of entire mappings are not included.
+            if let Some(key) = key {
+                changes.insert((cow_to_copied!(map), cow_to_cloned!(key)), value.map(|value| cow_to_cloned!(value)));
+            }
+        }
+        changes
+            .into_iter()
+            .map(|(((program_id, mapping_name), key), value)| (program_id, mapping_name, key, value))
+            .collect()
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value_confirmed(
         &self,
@@ -1076,6 +1128,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_keys_speculative() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure retrieving the keys of an un-initialized mapping fails.
+        assert!(finalize_store.get_keys_speculative(program_id, mapping_name, 0, 10).is_err());
+
+        // Initialize the mapping, and insert the keys in reverse order.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert!(finalize_store.get_keys_speculative(program_id, mapping_name, 0, 10).unwrap().is_empty());
+        for item in (0..10u8).rev() {
+            let key = Plaintext::from_str(&format!("{item}u8")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        // Ensure the keys are returned in order, within the requested range.
+        let keys = |start, limit| {
+            let keys = finalize_store.get_keys_speculative(program_id, mapping_name, start, limit).unwrap();
+            keys.iter().map(|key| key.to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(keys(0, 3), ["0u8", "1u8", "2u8"]);
+        assert_eq!(keys(8, 3), ["8u8", "9u8"]);
+        assert!(keys(10, 3).is_empty());
+    }
+
+    #[test]
+    fn test_get_keys_speculative_in_atomic_batch() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Initialize the mapping, and insert the keys.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for item in 0..10u8 {
+            let key = Plaintext::from_str(&format!("{item}u8")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        let keys = |start, limit| {
+            let keys = finalize_store.get_keys_speculative(program_id, mapping_name, start, limit).unwrap();
+            keys.iter().map(|key| key.to_string()).collect::<Vec<_>>()
+        };
+
+        // Remove and insert keys in an atomic batch.
+        finalize_store.start_atomic();
+        for item in ["0u8", "5u8"] {
+            let key = Plaintext::from_str(item).unwrap();
+            assert!(finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap().is_some());
+        }
+        let key = Plaintext::from_str("10u8").unwrap();
+        let value = Value::from_str("10u64").unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+
+        // Ensure the pending keys are merged with the confirmed keys, in order.
+        assert_eq!(keys(0, 3), ["1u8", "2u8", "3u8"]);
+        assert_eq!(keys(3, 3), ["4u8", "6u8", "7u8"]);
+        assert_eq!(keys(6, 10), ["8u8", "9u8", "10u8"]);
+        assert!(keys(9, 3).is_empty());
+
+        // Ensure aborting the atomic batch restores the confirmed keys.
+        finalize_store.abort_atomic();
+        assert_eq!(keys(0, 3), ["0u8", "1u8", "2u8"]);
+        assert_eq!(keys(4, 2), ["4u8", "5u8"]);
+    }
+
     #[test]
     fn test_remove_mapping() {
         // Initialize a program ID and mapping name.
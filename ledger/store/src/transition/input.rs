@@ -216,6 +216,41 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Discards the input values for the given `transition ID`, keeping the input IDs.
+    ///
+    /// The constant, public, and private inputs are set to `None`,
+    /// which leaves the transition ID and the record serial numbers and tags unchanged.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Discard the input values that are not already discarded.
+            for input_id in input_ids {
+                if let Some(Some(_)) = self.constant_map().get_confirmed(&input_id)?.as_deref() {
+                    self.constant_map().insert(input_id, None)?;
+                }
+                if let Some(Some(_)) = self.public_map().get_confirmed(&input_id)?.as_deref() {
+                    self.public_map().insert(input_id, None)?;
+                }
+                if let Some(Some(_)) = self.private_map().get_confirmed(&input_id)?.as_deref() {
+                    self.private_map().insert(input_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    fn reclaim(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -343,6 +378,16 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Discards the input values for the given `transition ID`, keeping the input IDs.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    pub fn reclaim(&self) -> Result<()> {
+        self.storage.reclaim()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -22,7 +22,7 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
 };
 use console::{
     network::prelude::*,
@@ -50,6 +50,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type TCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
     /// The mapping of `transition commitment` to `transition ID`.
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
+    /// The mapping of `program ID` to `(function name, transition ID)` entries.
+    type FunctionIndexMap: for<'a> NestedMap<'a, ProgramID<N>, (Identifier<N>, N::TransitionID), ()>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -68,6 +70,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -84,6 +88,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().start_atomic();
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
+        self.function_index_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -95,6 +100,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.reverse_tpk_map().is_atomic_in_progress()
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
+            || self.function_index_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -106,6 +112,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_checkpoint();
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
+        self.function_index_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -117,6 +124,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().clear_latest_checkpoint();
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
+        self.function_index_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +136,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_rewind();
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
+        self.function_index_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -139,6 +148,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().abort_atomic();
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
+        self.function_index_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -149,7 +159,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        self.reverse_tcm_map().finish_atomic()?;
+        self.function_index_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -171,6 +182,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().insert(transition_id, *transition.tcm())?;
             // Store the reverse `tcm` entry.
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
+            // Store the function index entry.
+            self.function_index_map().insert(
+                *transition.program_id(),
+                (*transition.function_name(), transition_id),
+                (),
+            )?;
 
             Ok(())
         })
@@ -178,6 +195,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the input for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the program ID and function name.
+        let (program_id, function_name) = match self.locator_map().get_confirmed(transition_id)? {
+            Some(locator) => cow_to_cloned!(locator),
+            None => return Ok(()),
+        };
         // Retrieve the `tpk`.
         let tpk = match self.tpk_map().get_confirmed(transition_id)? {
             Some(tpk) => cow_to_copied!(tpk),
@@ -204,11 +226,51 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
             self.reverse_tcm_map().remove(&tcm)?;
+            // Remove the function index entry.
+            self.function_index_map().remove_key(&program_id, &(function_name, *transition_id))?;
+
+            Ok(())
+        })
+    }
+
+    /// Discards the input and output values for the given `transition ID`, keeping the transition ID.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
 
             Ok(())
         })
     }
 
+    /// Reclaims the storage space freed by pruning.
+    fn reclaim(&self) -> Result<()> {
+        self.input_store().reclaim()?;
+        self.output_store().reclaim()
+    }
+
+    /// Returns the transition IDs for the given `program ID`.
+    fn get_transition_ids_for_program(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransitionID>> {
+        let entries = self.function_index_map().get_map_confirmed(program_id)?;
+        Ok(entries.into_iter().map(|((_, transition_id), _)| transition_id).collect())
+    }
+
+    /// Returns the transition IDs for the given `program ID` and `function name`.
+    fn get_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransitionID>> {
+        let entries = self.function_index_map().get_map_confirmed(program_id)?;
+        Ok(entries
+            .into_iter()
+            .filter(|((name, _), _)| name == function_name)
+            .map(|((_, transition_id), _)| transition_id)
+            .collect())
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -310,6 +372,16 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Discards the input and output values for the given `transition ID`, keeping the transition ID.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    pub fn reclaim(&self) -> Result<()> {
+        self.storage.reclaim()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -381,6 +453,20 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         }))
     }
 
+    /// Returns the transition IDs for the given `program ID`.
+    pub fn get_transition_ids_for_program(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransitionID>> {
+        self.storage.get_transition_ids_for_program(program_id)
+    }
+
+    /// Returns the transition IDs for the given `program ID` and `function name`.
+    pub fn get_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransitionID>> {
+        self.storage.get_transition_ids_for_function(program_id, function_name)
+    }
+
     /// Returns the function name for the given `transition ID`.
     pub fn get_function_name(&self, transition_id: &N::TransitionID) -> Result<Option<Identifier<N>>> {
         Ok(self.locator.get_confirmed(transition_id)?.map(|locator| match locator {
@@ -621,6 +707,10 @@ mod tests {
     use super::*;
     use crate::helpers::memory::TransitionMemory;
 
+    use std::collections::HashSet;
+
+    type CurrentNetwork = console::network::Testnet3;
+
     #[test]
     fn test_insert_get_remove() {
         let rng = &mut TestRng::default();
@@ -705,4 +795,49 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_function_index() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transitions = transaction.transitions().cloned().collect::<Vec<_>>();
+
+        // Returns the transition IDs for the given program ID and optional function name.
+        let expected = |program_id: &ProgramID<CurrentNetwork>, function_name: Option<&Identifier<CurrentNetwork>>| {
+            transitions
+                .iter()
+                .filter(|t| t.program_id() == program_id && function_name.map_or(true, |f| t.function_name() == f))
+                .map(|t| *t.id())
+                .collect::<HashSet<_>>()
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<CurrentNetwork, TransitionMemory<_>>::open(None).unwrap();
+        // Insert every transition.
+        for transition in transitions.iter() {
+            transition_store.insert(transition).unwrap();
+        }
+
+        // Ensure the transitions are indexed by program ID and function name.
+        for transition in transitions.iter() {
+            let (program_id, function_name) = (transition.program_id(), transition.function_name());
+            let transition_ids = transition_store.get_transition_ids_for_program(program_id).unwrap();
+            assert_eq!(transition_ids.into_iter().collect::<HashSet<_>>(), expected(program_id, None));
+            let transition_ids = transition_store.get_transition_ids_for_function(program_id, function_name).unwrap();
+            assert_eq!(transition_ids.into_iter().collect::<HashSet<_>>(), expected(program_id, Some(function_name)));
+        }
+        // Ensure an unknown function name has no transitions.
+        let (program_id, function_name) = (transitions[0].program_id(), Identifier::from_str("unknown").unwrap());
+        assert!(transition_store.get_transition_ids_for_function(program_id, &function_name).unwrap().is_empty());
+
+        // Remove every transition, and ensure the index is empty.
+        for transition in transitions.iter() {
+            transition_store.remove(transition.id()).unwrap();
+        }
+        for transition in transitions.iter() {
+            assert!(transition_store.get_transition_ids_for_program(transition.program_id()).unwrap().is_empty());
+        }
+    }
 }
@@ -233,6 +233,44 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Discards the output values for the given `transition ID`, keeping the output IDs.
+    ///
+    /// The constant, public, and private outputs and the record ciphertexts are set to `None`,
+    /// which leaves the transition ID and the record commitments and nonces unchanged.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Discard the output values that are not already discarded.
+            for output_id in output_ids {
+                if let Some(Some(_)) = self.constant_map().get_confirmed(&output_id)?.as_deref() {
+                    self.constant_map().insert(output_id, None)?;
+                }
+                if let Some(Some(_)) = self.public_map().get_confirmed(&output_id)?.as_deref() {
+                    self.public_map().insert(output_id, None)?;
+                }
+                if let Some(Some(_)) = self.private_map().get_confirmed(&output_id)?.as_deref() {
+                    self.private_map().insert(output_id, None)?;
+                }
+                if let Some((checksum, Some(_))) = self.record_map().get_confirmed(&output_id)?.as_deref() {
+                    self.record_map().insert(output_id, (*checksum, None))?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    fn reclaim(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -366,6 +404,16 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Discards the output values for the given `transition ID`, keeping the output IDs.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
+    /// Reclaims the storage space freed by pruning.
+    pub fn reclaim(&self) -> Result<()> {
+        self.storage.reclaim()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{LiteralType, PlaintextType},
+};
+use synthesizer_program::{Command, Instruction};
+
+/// Returns the number of microcredits required to run the given finalize command.
+pub fn command_cost_in_microcredits<N: Network>(command: &Command<N>) -> Result<u64> {
+    match command {
+        Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AbsWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Add(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AddSaturating(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AddWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::And(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AssertEq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AssertNeq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Async(_)) => bail!("`async` is not supported in finalize."),
+        Command::Instruction(Instruction::Call(_)) => bail!("`call` is not supported in finalize."),
+        Command::Instruction(Instruction::Cast(_)) => Ok(2_000),
+        Command::Instruction(Instruction::CastLossy(_)) => Ok(2_000),
+        Command::Instruction(Instruction::CommitBHP256(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP512(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP768(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP1024(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitPED64(_)) => Ok(100_000),
+        Command::Instruction(Instruction::CommitPED128(_)) => Ok(100_000),
+        Command::Instruction(Instruction::Div(_)) => Ok(10_000),
+        Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::EcdsaVerify(_)) => Ok(2_000_000),
+        Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
+        Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashBHP512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashBHP768(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashBHP1024(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashKeccak256(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashKeccak384(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashKeccak512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashPED64(_)) => Ok(20_000),
+        Command::Instruction(Instruction::HashPED128(_)) => Ok(30_000),
+        Command::Instruction(Instruction::HashPSD2(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(600_000),
+            PlaintextType::Literal(..) => Ok(60_000),
+            plaintext_type => bail!("`hash.psd2` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashPSD4(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(700_000),
+            PlaintextType::Literal(..) => Ok(100_000),
+            plaintext_type => bail!("`hash.psd4` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashPSD8(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(800_000),
+            PlaintextType::Literal(..) => Ok(200_000),
+            plaintext_type => bail!("`hash.psd8` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashSha3_256(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashSha3_384(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashSha3_512(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashManyPSD2(_)) => {
+            bail!("`hash_many.psd2` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::HashManyPSD4(_)) => {
+            bail!("`hash_many.psd4` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::HashManyPSD8(_)) => {
+            bail!("`hash_many.psd8` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::HashKeccak256Native(_)) => Ok(100_000),
+        Command::Instruction(Instruction::HashSha3_256Native(_)) => Ok(100_000),
+        Command::Instruction(Instruction::Inv(_)) => Ok(10_000),
+        Command::Instruction(Instruction::IsEq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::LessThan(_)) => Ok(2_000),
+        Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Modulo(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Mul(_)) => Ok(150_000),
+        Command::Instruction(Instruction::MulSaturating(_)) => Ok(2_000),
+        Command::Instruction(Instruction::MulWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Nand(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Neg(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Nor(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Not(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Or(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Pow(_)) => Ok(20_000),
+        Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Rol(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Ror(_)) => Ok(2_000),
+        Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
+        Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Shr(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ShrWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Square(_)) => Ok(2_000),
+        Command::Instruction(Instruction::SquareRoot(_)) => Ok(120_000),
+        Command::Instruction(Instruction::StrConcat(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StrLen(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StrSlice(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
+        Command::Instruction(Instruction::SubSaturating(_)) => Ok(2_000),
+        Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecGet(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecLen(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecSet(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
+        // TODO: The following 'finalize' commands are currently priced higher than expected.
+        //  Expect these numbers to change as their usage is stabilized.
+        Command::Await(_) => Ok(2_000),
+        Command::Contains(_) => Ok(12_500),
+        Command::Get(_) => Ok(25_000),
+        Command::GetOrUse(_) => Ok(25_000),
+        // Note: The keys skipped to reach the start index are charged by the `FinalizeMeter`, once the start is known.
+        Command::Iter(iter) => Ok(50_000u64.saturating_add(25_000u64.saturating_mul(**iter.limit() as u64))),
+        Command::RandChaCha(_) => Ok(25_000),
+        Command::Remove(_) => Ok(10_000),
+        Command::Set(_) => Ok(100_000),
+        Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
+        Command::Position(_) => Ok(1_000),
+    }
+}
+
+/// A meter that charges the cost of each finalize command that is run against a budget, in microcredits.
+///
+/// As the costs depend only on the command and its operands, a finalize that exceeds its budget
+/// fails at the same command on every node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeMeter {
+    /// The budget in microcredits, or `None` if the finalize is not metered.
+    budget: Option<u64>,
+    /// The microcredits used so far.
+    used: u64,
+}
+
+impl FinalizeMeter {
+    /// Initializes a new meter with the given budget, in microcredits.
+    pub const fn new(budget: u64) -> Self {
+        Self { budget: Some(budget), used: 0 }
+    }
+
+    /// Initializes a new meter without a budget, which only tracks the microcredits used.
+    pub const fn unmetered() -> Self {
+        Self { budget: None, used: 0 }
+    }
+
+    /// Returns the budget in microcredits, or `None` if the finalize is not metered.
+    pub const fn budget(&self) -> Option<u64> {
+        self.budget
+    }
+
+    /// Returns the microcredits used so far.
+    pub const fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Charges the cost of the given command, and returns an error if the budget is exhausted.
+    pub fn charge<N: Network>(&mut self, command: &Command<N>) -> Result<()> {
+        self.charge_microcredits(command_cost_in_microcredits(command)?, command)
+    }
+
+    /// Charges the cost of skipping the keys before the given start index of the given `iter` command,
+    /// and returns an error if the budget is exhausted.
+    pub fn charge_iter_start<N: Network>(&mut self, command: &Command<N>, start: u32) -> Result<()> {
+        ensure!(matches!(command, Command::Iter(_)), "Expected an 'iter' command, found '{command}'");
+        self.charge_microcredits(1_000u64.saturating_mul(start as u64), command)
+    }
+
+    /// Charges the given microcredits for the given command, and returns an error if the budget is exhausted.
+    fn charge_microcredits<N: Network>(&mut self, microcredits: u64, command: &Command<N>) -> Result<()> {
+        // Compute the microcredits used after the command.
+        let used = self.used.checked_add(microcredits).ok_or_else(|| anyhow!("The finalize cost overflowed"))?;
+        // Ensure the budget is not exhausted.
+        if let Some(budget) = self.budget {
+            ensure!(used <= budget, "The finalize exhausted its budget of {budget} microcredits at '{command}'");
+        }
+        self.used = used;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_finalize_meter() {
+        let add = Command::<CurrentNetwork>::from_str("add r0 r1 into r2;").unwrap();
+        let set = Command::<CurrentNetwork>::from_str("set r0 into account[r1];").unwrap();
+        let add_cost = command_cost_in_microcredits(&add).unwrap();
+        let set_cost = command_cost_in_microcredits(&set).unwrap();
+
+        // Ensure the commands within the budget are charged.
+        let mut meter = FinalizeMeter::new(add_cost + set_cost);
+        meter.charge(&add).unwrap();
+        meter.charge(&set).unwrap();
+        assert_eq!(meter.used(), add_cost + set_cost);
+        // Ensure the budget can not be exceeded.
+        assert!(meter.charge(&add).is_err());
+        assert_eq!(meter.used(), add_cost + set_cost);
+
+        // Ensure an unmetered finalize is never exhausted.
+        let mut meter = FinalizeMeter::unmetered();
+        for _ in 0..100 {
+            meter.charge(&set).unwrap();
+        }
+        assert_eq!(meter.used(), 100 * set_cost);
+        assert_eq!(meter.budget(), None);
+    }
+
+    #[test]
+    fn test_iter_cost() {
+        let iter_1 = Command::<CurrentNetwork>::from_str("iter account r0 1u32 into r1;").unwrap();
+        let iter_8 = Command::<CurrentNetwork>::from_str("iter account r0 8u32 into r1;").unwrap();
+        let iter_1_cost = command_cost_in_microcredits(&iter_1).unwrap();
+        let iter_8_cost = command_cost_in_microcredits(&iter_8).unwrap();
+
+        // Ensure the cost scales with the limit.
+        assert_eq!(iter_1_cost, 75_000);
+        assert_eq!(iter_8_cost, 250_000);
+
+        // Ensure the skipped keys are charged.
+        let mut meter = FinalizeMeter::unmetered();
+        meter.charge(&iter_8).unwrap();
+        meter.charge_iter_start(&iter_8, 100).unwrap();
+        assert_eq!(meter.used(), iter_8_cost + 100_000);
+        // Ensure a far start index exhausts the budget.
+        let mut meter = FinalizeMeter::new(iter_8_cost + 100_000);
+        meter.charge(&iter_8).unwrap();
+        assert!(meter.charge_iter_start(&iter_8, 101).is_err());
+        assert_eq!(meter.used(), iter_8_cost);
+
+        // Ensure only an `iter` command may be charged for its start index.
+        let add = Command::<CurrentNetwork>::from_str("add r0 r1 into r2;").unwrap();
+        assert!(FinalizeMeter::unmetered().charge_iter_start(&add, 1).is_err());
+    }
+}
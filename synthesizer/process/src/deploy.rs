@@ -37,6 +37,36 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Certifies the next function of the given deployment builder, and returns its name,
+    /// or `None` if every function in the deployment builder is already certified.
+    #[inline]
+    pub fn continue_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        builder: &mut DeploymentBuilder<N>,
+        rng: &mut R,
+    ) -> Result<Option<Identifier<N>>> {
+        let timer = timer!("Process::continue_deployment");
+
+        // Retrieve the next function to certify.
+        let function_name = match builder.next_function() {
+            Some(function_name) => *function_name,
+            None => return Ok(None),
+        };
+
+        // Compute the stack.
+        let stack = Stack::new(self, builder.program())?;
+        lap!(timer, "Compute the stack");
+
+        // Certify the function.
+        let (verifying_key, certificate) = stack.certify_function::<A, R>(&function_name, rng)?;
+        builder.insert(function_name, verifying_key, certificate)?;
+        lap!(timer, "Certify the circuit for {function_name}");
+
+        finish!(timer);
+
+        Ok(Some(function_name))
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Identifier};
+use ledger_block::Deployment;
+use synthesizer_program::Program;
+use synthesizer_snark::{Certificate, VerifyingKey};
+
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// The version of the deployment builder format.
+const DEPLOYMENT_BUILDER_VERSION: u8 = 1;
+
+/// A deployment whose functions are certified one at a time, so that its progress can be persisted,
+/// and the synthesis can continue after an interruption. See `Process::continue_deployment`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DeploymentBuilder<N: Network> {
+    /// The program being deployed.
+    program: Program<N>,
+    /// The verifying keys and certificates of the certified functions, in the order of the program.
+    verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
+}
+
+impl<N: Network> DeploymentBuilder<N> {
+    /// Initializes a new deployment builder for the given program.
+    pub fn new(program: Program<N>) -> Result<Self> {
+        // Ensure the program contains functions.
+        ensure!(!program.functions().is_empty(), "Program '{}' has no functions", program.id());
+        Ok(Self { program, verifying_keys: IndexMap::new() })
+    }
+
+    /// Returns the program being deployed.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the names of the certified functions.
+    pub fn certified_functions(&self) -> impl '_ + ExactSizeIterator<Item = &Identifier<N>> {
+        self.verifying_keys.keys()
+    }
+
+    /// Returns the verifying keys and certificates of the certified functions.
+    pub const fn verifying_keys(&self) -> &IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)> {
+        &self.verifying_keys
+    }
+
+    /// Returns the name of the next function to certify, or `None` if every function is certified.
+    pub fn next_function(&self) -> Option<&Identifier<N>> {
+        self.program.functions().keys().nth(self.verifying_keys.len())
+    }
+
+    /// Returns `true` if every function of the program is certified.
+    pub fn is_complete(&self) -> bool {
+        self.verifying_keys.len() == self.program.functions().len()
+    }
+
+    /// Adds the verifying key and certificate of the given function, which must be the next function to certify.
+    pub fn insert(
+        &mut self,
+        function_name: Identifier<N>,
+        verifying_key: VerifyingKey<N>,
+        certificate: Certificate<N>,
+    ) -> Result<()> {
+        match self.next_function() {
+            Some(next_function) => ensure!(
+                *next_function == function_name,
+                "Expected to certify '{next_function}' in '{}', found '{function_name}'",
+                self.program.id()
+            ),
+            None => bail!("Every function in '{}' is already certified", self.program.id()),
+        }
+        self.verifying_keys.insert(function_name, (verifying_key, certificate));
+        Ok(())
+    }
+
+    /// Returns the deployment, if every function of the program is certified.
+    pub fn build(&self) -> Result<Deployment<N>> {
+        if let Some(function_name) = self.next_function() {
+            bail!("Function '{function_name}' in '{}' is not certified", self.program.id())
+        }
+        let verifying_keys = self.verifying_keys.iter().map(|(name, keys)| (*name, keys.clone())).collect();
+        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+    }
+
+    /// Writes the deployment builder to the given path.
+    ///
+    /// The builder is written to a temporary file first and then moved into place,
+    /// so an interrupted write never replaces the previous progress.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, self.to_bytes_le()?)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Reads a deployment builder from the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_bytes_le(&std::fs::read(path)?)
+    }
+}
+
+impl<N: Network> FromBytes for DeploymentBuilder<N> {
+    /// Reads the deployment builder from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != DEPLOYMENT_BUILDER_VERSION {
+            return Err(error("Invalid deployment builder version"));
+        }
+
+        // Read the program.
+        let program = Program::read_le(&mut reader)?;
+        let mut builder = Self::new(program).map_err(|err| error(format!("{err}")))?;
+
+        // Read the number of certified functions.
+        let num_entries = u16::read_le(&mut reader)?;
+        // Read the verifying keys and certificates.
+        for _ in 0..num_entries {
+            let function_name = Identifier::<N>::read_le(&mut reader)?;
+            let verifying_key = VerifyingKey::<N>::read_le(&mut reader)?;
+            let certificate = Certificate::<N>::read_le(&mut reader)?;
+            builder.insert(function_name, verifying_key, certificate).map_err(|err| error(format!("{err}")))?;
+        }
+        Ok(builder)
+    }
+}
+
+impl<N: Network> ToBytes for DeploymentBuilder<N> {
+    /// Writes the deployment builder to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        DEPLOYMENT_BUILDER_VERSION.write_le(&mut writer)?;
+        // Write the program.
+        self.program.write_le(&mut writer)?;
+        // Write the number of certified functions.
+        (u16::try_from(self.verifying_keys.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        // Write the verifying keys and certificates.
+        for (function_name, (verifying_key, certificate)) in &self.verifying_keys {
+            function_name.write_le(&mut writer)?;
+            verifying_key.write_le(&mut writer)?;
+            certificate.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_resume_deployment() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program resume.aleo;
+
+function first:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;
+
+function second:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Initialize a temporary file for the progress.
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("resume.deployment");
+
+        // Certify the first function, and persist the progress.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let mut builder = DeploymentBuilder::new(program.clone()).unwrap();
+        assert!(builder.build().is_err());
+        let function_name = process.continue_deployment::<CurrentAleo, _>(&mut builder, rng).unwrap();
+        assert_eq!(function_name, Some(Identifier::from_str("first").unwrap()));
+        assert!(!builder.is_complete());
+        builder.save(&path).unwrap();
+
+        // Resume the deployment in a new process, as if after a crash.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let mut builder = DeploymentBuilder::<CurrentNetwork>::load(&path).unwrap();
+        assert_eq!(builder.certified_functions().len(), 1);
+        assert_eq!(builder.next_function(), Some(&Identifier::from_str("second").unwrap()));
+        let function_name = process.continue_deployment::<CurrentAleo, _>(&mut builder, rng).unwrap();
+        assert_eq!(function_name, Some(Identifier::from_str("second").unwrap()));
+        assert!(builder.is_complete());
+        // Ensure there is nothing left to certify.
+        assert_eq!(process.continue_deployment::<CurrentAleo, _>(&mut builder, rng).unwrap(), None);
+
+        // Ensure the deployment is valid.
+        let deployment = builder.build().unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    }
+
+    #[test]
+    fn test_insert_out_of_order() {
+        let rng = &mut TestRng::default();
+
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program order.aleo;
+
+function first:
+    input r0 as u32.public;
+    output r0 as u32.public;
+
+function second:
+    input r0 as u32.public;
+    output r0 as u32.public;",
+        )
+        .unwrap();
+
+        // Certify the second function.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let mut builder = DeploymentBuilder::new(program).unwrap();
+        let second = Identifier::from_str("second").unwrap();
+        let stack = crate::Stack::new(&process, builder.program()).unwrap();
+        let (verifying_key, certificate) = stack.certify_function::<CurrentAleo, _>(&second, rng).unwrap();
+
+        // Ensure the second function cannot be added before the first.
+        assert!(builder.insert(second, verifying_key, certificate).is_err());
+        assert_eq!(builder.certified_functions().len(), 0);
+    }
+}
@@ -51,6 +51,46 @@ impl<N: Network> Process<N> {
         finish!(timer);
         Ok((response, trace))
     }
+
+    /// Executes the given authorization against a local state, and returns the response and a local execution.
+    ///
+    /// The input records do not need to exist in a ledger, as they are placed in a synthetic block,
+    /// which lets program logic be tested end-to-end without a ledger. The local execution is proven,
+    /// but is **not broadcastable**, as its state root does not exist in any ledger.
+    #[inline]
+    pub fn execute_local<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, LocalExecution<N>)> {
+        let timer = timer!("Process::execute_local");
+
+        // Construct the locator of the main function.
+        let locator = {
+            let request = authorization.peek_next()?;
+            Locator::new(*request.program_id(), *request.function_name()).to_string()
+        };
+
+        // Execute the call.
+        let (response, mut trace) = self.execute::<A>(authorization)?;
+        lap!(timer, "Execute the call");
+
+        // Prepare the assignments, against a local state for the input records.
+        trace.prepare_local()?;
+        lap!(timer, "Prepare the assignments");
+
+        // Compute the proof and construct the execution.
+        let execution = trace.prove_execution::<A, R>(&locator, rng)?;
+        finish!(timer, "Compute the proof");
+
+        Ok((response, LocalExecution::new(execution)))
+    }
+
+    /// Verifies the given local execution is valid, against its own local state root.
+    #[inline]
+    pub fn verify_local_execution(&self, local_execution: &LocalExecution<N>) -> Result<()> {
+        self.verify_execution(local_execution.execution())
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +153,41 @@ mod tests {
         assert!(transition.is_fee_private(), "Transition must be for 'credits.aleo/fee_private'");
     }
 
+    #[test]
+    fn test_execute_local() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(private_key).unwrap();
+
+        // Sample a credits record, which does not exist in any ledger.
+        let credits = Record::<CurrentNetwork, Plaintext<_>>::from_str(&format!(
+            "{{ owner: {owner}.private, microcredits: 1000u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Authorize a private transfer of the record.
+        let inputs =
+            [Value::Record(credits), Value::from_str(&owner.to_string()).unwrap(), Value::from_str("400u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_private", inputs.iter(), rng)
+            .unwrap();
+
+        // Execute the authorization locally.
+        let (response, local_execution) = process.execute_local::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs().len(), 2);
+        assert_eq!(local_execution.len(), 1);
+        assert!(local_execution.proof().is_some());
+        assert_ne!(*local_execution.local_state_root(), Field::zero());
+
+        // Ensure the local execution is valid.
+        process.verify_local_execution(&local_execution).unwrap();
+    }
+
     #[test]
     fn test_execute_fee_public() {
         let rng = &mut TestRng::default();
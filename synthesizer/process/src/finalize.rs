@@ -102,11 +102,19 @@ impl<N: Network> Process<N> {
         // Construct the call graph.
         let call_graph = self.construct_call_graph(execution)?;
 
+        // Initialize the finalize meter, with the base fee that remains after the storage cost of the execution.
+        // Note: An execution without a fee is not metered, as the VM only accepts such executions without finalize.
+        let mut meter = match fee {
+            Some(fee) => FinalizeMeter::new((*fee.base_amount()?).saturating_sub(execution.size_in_bytes()?)),
+            None => FinalizeMeter::unmetered(),
+        };
+
         atomic_batch_scope!(store, {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph, &mut meter)?;
+            lap!(timer, "Finalize the execution, using {} microcredits", meter.used());
 
             /* Finalize the fee. */
 
@@ -161,7 +169,8 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    // Note: The fee is not metered, as its finalize cost is not included in the base fee.
+    match finalize_transition(state, store, stack, fee, call_graph, &mut FinalizeMeter::unmetered()) {
         // If the evaluation succeeds, return the finalize operations.
         Ok(finalize_operations) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
@@ -176,6 +185,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    meter: &mut FinalizeMeter,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
@@ -221,6 +231,14 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Charge the cost of the command, and abort if the budget is exhausted.
+            meter.charge(command)?;
+            // Charge the keys skipped by an `iter` command, which depend on its start index.
+            if let Command::Iter(iter) = command {
+                if let Literal::U32(start) = registers.load_literal(stack, iter.start())? {
+                    meter.charge_iter_start(command, *start)?;
+                }
+            }
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -0,0 +1,384 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use synthesizer_snark::{ProvingKey, VerifyingKey};
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// The default maximum number of proving keys, and of verifying keys, that are kept in memory.
+///
+/// Note: Verifying keys are only evicted once a verifying key loader is set, as they cannot be reloaded otherwise.
+pub const DEFAULT_KEY_CACHE_CAPACITY: usize = 1024;
+
+/// A loader for the verifying key of the given `(program ID, function name)`, e.g. from the deployments in storage.
+pub type VerifyingKeyLoader<N> =
+    Arc<dyn Fn(&ProgramID<N>, &Identifier<N>) -> Result<Option<VerifyingKey<N>>> + Send + Sync>;
+
+/// The metrics of a key cache.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyCacheMetrics {
+    /// The number of keys in the cache.
+    pub num_keys: usize,
+    /// The number of lookups that found the key in the cache.
+    pub hits: u64,
+    /// The number of lookups that did not find the key in the cache.
+    pub misses: u64,
+    /// The number of keys that were evicted from the cache.
+    pub evictions: u64,
+}
+
+impl KeyCacheMetrics {
+    /// Returns the fraction of lookups that found the key in the cache, or `0` if there were no lookups.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+/// A bounded cache of keys, which evicts the least-recently used key when it is full.
+struct LruCache<N: Network, K: Clone> {
+    /// The maximum number of keys.
+    capacity: usize,
+    /// A flag indicating if keys are evicted when the cache is over capacity.
+    is_bounded: bool,
+    /// The mapping of `(program ID, function name)` to the key, ordered from the least to the most recently used.
+    entries: IndexMap<(ProgramID<N>, Identifier<N>), K>,
+    /// The number of hits.
+    hits: u64,
+    /// The number of misses.
+    misses: u64,
+    /// The number of evictions.
+    evictions: u64,
+}
+
+impl<N: Network, K: Clone> LruCache<N, K> {
+    /// Initializes a new cache with the given capacity, which only evicts keys if it is bounded.
+    fn new(capacity: usize, is_bounded: bool) -> Self {
+        Self { capacity, is_bounded, entries: IndexMap::new(), hits: 0, misses: 0, evictions: 0 }
+    }
+
+    /// Returns `true` if the cache contains the key.
+    fn contains(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        self.entries.contains_key(&(*program_id, *function_name))
+    }
+
+    /// Returns the key, if it is in the cache, and marks it as the most recently used.
+    fn get(&mut self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Option<K> {
+        match self.entries.get_index_of(&(*program_id, *function_name)) {
+            Some(index) => {
+                // Move the key to the back, as the most recently used.
+                self.entries.move_index(index, self.entries.len() - 1);
+                self.hits += 1;
+                self.entries.last().map(|(_, key)| key.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Inserts the key as the most recently used, evicting the least-recently used keys if the cache is full.
+    fn insert(&mut self, program_id: ProgramID<N>, function_name: Identifier<N>, key: K) {
+        // Remove the previous key, so the key is inserted at the back.
+        self.entries.shift_remove(&(program_id, function_name));
+        self.entries.insert((program_id, function_name), key);
+        self.shrink_to_capacity();
+    }
+
+    /// Removes the key.
+    fn remove(&mut self, program_id: &ProgramID<N>, function_name: &Identifier<N>) {
+        self.entries.shift_remove(&(*program_id, *function_name));
+    }
+
+    /// Removes the keys of the given program.
+    fn remove_program(&mut self, program_id: &ProgramID<N>) {
+        self.entries.retain(|(key_program_id, _), _| key_program_id != program_id);
+    }
+
+    /// Sets the capacity, evicting the least-recently used keys if the cache is over capacity.
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.shrink_to_capacity();
+    }
+
+    /// Sets whether keys are evicted, evicting the least-recently used keys if the cache is over capacity.
+    fn set_bounded(&mut self, is_bounded: bool) {
+        self.is_bounded = is_bounded;
+        self.shrink_to_capacity();
+    }
+
+    /// Evicts the least-recently used keys until the cache is within capacity, if the cache is bounded.
+    fn shrink_to_capacity(&mut self) {
+        if !self.is_bounded || self.entries.len() <= self.capacity {
+            return;
+        }
+        // Evict the least-recently used keys, from the front.
+        let num_evicted = self.entries.len() - self.capacity;
+        self.entries.drain(..num_evicted);
+        self.evictions += num_evicted as u64;
+    }
+
+    /// Returns the metrics of the cache.
+    fn metrics(&self) -> KeyCacheMetrics {
+        KeyCacheMetrics {
+            num_keys: self.entries.len(),
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+}
+
+/// The bounded caches of the proving keys and verifying keys of a process.
+///
+/// An evicted proving key is re-synthesized when the function is executed next, and an evicted verifying key
+/// is reloaded with the verifying key loader. As verifying keys cannot be recomputed, they are only evicted
+/// once a verifying key loader is set. The 'credits.aleo' keys are reloaded from resources.
+pub struct KeyCache<N: Network> {
+    /// The cache of proving keys.
+    proving_keys: RwLock<LruCache<N, ProvingKey<N>>>,
+    /// The cache of verifying keys.
+    verifying_keys: RwLock<LruCache<N, VerifyingKey<N>>>,
+    /// The loader of evicted verifying keys.
+    verifying_key_loader: RwLock<Option<VerifyingKeyLoader<N>>>,
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Initializes a new key cache, with the given capacity for the proving keys, and for the verifying keys.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            proving_keys: RwLock::new(LruCache::new(capacity, true)),
+            verifying_keys: RwLock::new(LruCache::new(capacity, false)),
+            verifying_key_loader: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the proving key for the given `(program ID, function name)` is in the cache.
+    pub fn contains_proving_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        self.proving_keys.read().contains(program_id, function_name)
+    }
+
+    /// Returns `true` if the verifying key for the given `(program ID, function name)` is in the cache.
+    pub fn contains_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> bool {
+        self.verifying_keys.read().contains(program_id, function_name)
+    }
+
+    /// Returns the proving key for the given `(program ID, function name)`, if it is in the cache.
+    pub fn get_proving_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Option<ProvingKey<N>> {
+        self.proving_keys.write().get(program_id, function_name)
+    }
+
+    /// Returns the verifying key for the given `(program ID, function name)`.
+    /// If the verifying key is not in the cache, it is reloaded with the verifying key loader, if one is set.
+    pub fn get_verifying_key(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Option<VerifyingKey<N>>> {
+        // Return the verifying key, if it is in the cache.
+        if let Some(verifying_key) = self.verifying_keys.write().get(program_id, function_name) {
+            return Ok(Some(verifying_key));
+        }
+        // Otherwise, reload the verifying key.
+        let loader = self.verifying_key_loader.read().clone();
+        match loader {
+            Some(loader) => match loader(program_id, function_name)? {
+                Some(verifying_key) => {
+                    self.insert_verifying_key(*program_id, *function_name, verifying_key.clone());
+                    Ok(Some(verifying_key))
+                }
+                None => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Inserts the proving key for the given `(program ID, function name)`.
+    pub fn insert_proving_key(&self, program_id: ProgramID<N>, function_name: Identifier<N>, key: ProvingKey<N>) {
+        self.proving_keys.write().insert(program_id, function_name, key);
+    }
+
+    /// Inserts the verifying key for the given `(program ID, function name)`.
+    pub fn insert_verifying_key(&self, program_id: ProgramID<N>, function_name: Identifier<N>, key: VerifyingKey<N>) {
+        self.verifying_keys.write().insert(program_id, function_name, key);
+    }
+
+    /// Removes the proving key for the given `(program ID, function name)`.
+    pub fn remove_proving_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) {
+        self.proving_keys.write().remove(program_id, function_name);
+    }
+
+    /// Removes the verifying key for the given `(program ID, function name)`.
+    pub fn remove_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) {
+        self.verifying_keys.write().remove(program_id, function_name);
+    }
+
+    /// Removes the proving keys and verifying keys of the given program.
+    pub fn remove_program(&self, program_id: &ProgramID<N>) {
+        self.proving_keys.write().remove_program(program_id);
+        self.verifying_keys.write().remove_program(program_id);
+    }
+
+    /// Sets the capacity for the proving keys, and for the verifying keys, evicting keys as needed.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.proving_keys.write().set_capacity(capacity);
+        self.verifying_keys.write().set_capacity(capacity);
+    }
+
+    /// Sets the loader of evicted verifying keys, which enables the eviction of verifying keys.
+    pub fn set_verifying_key_loader(&self, loader: VerifyingKeyLoader<N>) {
+        *self.verifying_key_loader.write() = Some(loader);
+        self.verifying_keys.write().set_bounded(true);
+    }
+
+    /// Returns the metrics of the proving key cache.
+    pub fn proving_key_metrics(&self) -> KeyCacheMetrics {
+        self.proving_keys.read().metrics()
+    }
+
+    /// Returns the metrics of the verifying key cache.
+    pub fn verifying_key_metrics(&self) -> KeyCacheMetrics {
+        self.verifying_keys.read().metrics()
+    }
+}
+
+impl<N: Network> Default for KeyCache<N> {
+    /// Initializes a new key cache, with the default capacity.
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the `(program ID, function name)` for the given function name in 'test.aleo'.
+    fn sample_locator(function_name: &str) -> (ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>) {
+        (ProgramID::from_str("test.aleo").unwrap(), Identifier::from_str(function_name).unwrap())
+    }
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::<CurrentNetwork, u8>::new(2, true);
+        let (program_id, a) = sample_locator("a");
+        let (_, b) = sample_locator("b");
+        let (_, c) = sample_locator("c");
+
+        // Fill the cache.
+        cache.insert(program_id, a, 0);
+        cache.insert(program_id, b, 1);
+        // Use 'a', so that 'b' is the least-recently used.
+        assert_eq!(cache.get(&program_id, &a), Some(0));
+        // Insert 'c', which evicts 'b'.
+        cache.insert(program_id, c, 2);
+        assert!(cache.contains(&program_id, &a));
+        assert!(!cache.contains(&program_id, &b));
+        assert!(cache.contains(&program_id, &c));
+        assert_eq!(cache.get(&program_id, &b), None);
+
+        // Check the metrics.
+        let metrics = cache.metrics();
+        assert_eq!(metrics, KeyCacheMetrics { num_keys: 2, hits: 1, misses: 1, evictions: 1 });
+        assert_eq!(metrics.hit_rate(), 0.5);
+
+        // Shrink the cache, which evicts 'a'.
+        cache.set_capacity(1);
+        assert!(!cache.contains(&program_id, &a));
+        assert!(cache.contains(&program_id, &c));
+        assert_eq!(cache.metrics().evictions, 2);
+
+        // Ensure a removed key is not counted as evicted.
+        cache.remove(&program_id, &c);
+        assert_eq!(cache.metrics(), KeyCacheMetrics { num_keys: 0, hits: 1, misses: 1, evictions: 2 });
+
+        // Ensure an unbounded cache does not evict keys, until it is bounded.
+        cache.set_bounded(false);
+        cache.insert(program_id, a, 0);
+        cache.insert(program_id, b, 1);
+        assert_eq!(cache.metrics().num_keys, 2);
+        cache.set_bounded(true);
+        assert!(!cache.contains(&program_id, &a));
+        assert!(cache.contains(&program_id, &b));
+        assert_eq!(cache.metrics().evictions, 3);
+    }
+
+    #[test]
+    fn test_verifying_key_loader() {
+        let cache = KeyCache::<CurrentNetwork>::new(1);
+        let (program_id, a) = sample_locator("a");
+        let (_, b) = sample_locator("b");
+
+        // Load the 'credits.aleo/transfer_public' verifying key, as a sample key.
+        let verifying_key = VerifyingKey::<CurrentNetwork>::new(
+            CurrentNetwork::get_credits_verifying_key("transfer_public".into()).unwrap().clone(),
+        );
+
+        // Ensure a missing key is not found, without a loader.
+        assert!(cache.get_verifying_key(&program_id, &a).unwrap().is_none());
+
+        // Insert two keys, and ensure neither is evicted without a loader.
+        cache.insert_verifying_key(program_id, a, verifying_key.clone());
+        cache.insert_verifying_key(program_id, b, verifying_key.clone());
+        assert!(cache.contains_verifying_key(&program_id, &a));
+        assert!(cache.contains_verifying_key(&program_id, &b));
+
+        // Set a loader that only knows 'a', which evicts the least-recently used key 'a'.
+        let loaded_key = verifying_key.clone();
+        cache.set_verifying_key_loader(Arc::new(move |_, function_name| {
+            Ok((function_name == &a).then(|| loaded_key.clone()))
+        }));
+        assert!(!cache.contains_verifying_key(&program_id, &a));
+
+        // Ensure the evicted key is reloaded, and cached.
+        assert_eq!(cache.get_verifying_key(&program_id, &a).unwrap(), Some(verifying_key));
+        assert!(cache.contains_verifying_key(&program_id, &a));
+        // Ensure 'b' was evicted, and can no longer be loaded.
+        assert!(cache.get_verifying_key(&program_id, &b).unwrap().is_none());
+        assert_eq!(cache.verifying_key_metrics().evictions, 2);
+    }
+
+    #[test]
+    fn test_process_reloads_credits_verifying_keys() {
+        // Initialize the process, and only keep one key of each kind in memory.
+        let process = crate::Process::<CurrentNetwork>::load().unwrap();
+        process.key_cache().set_capacity(1);
+        // Set a loader that knows no keys, so that the verifying keys are evicted.
+        process.key_cache().set_verifying_key_loader(Arc::new(|_, _| Ok(None)));
+
+        // Retrieve the 'credits.aleo' stack.
+        let stack = process.get_stack("credits.aleo").unwrap();
+        let transfer_public = Identifier::from_str("transfer_public").unwrap();
+        let transfer_private = Identifier::from_str("transfer_private").unwrap();
+
+        // Ensure the evicted verifying keys are reloaded.
+        let verifying_key = stack.get_verifying_key(&transfer_public).unwrap();
+        stack.get_verifying_key(&transfer_private).unwrap();
+        assert!(!stack.contains_verifying_key(&transfer_public));
+        assert_eq!(stack.get_verifying_key(&transfer_public).unwrap(), verifying_key);
+        assert_eq!(process.key_cache().verifying_key_metrics().num_keys, 1);
+    }
+}
@@ -18,9 +18,21 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod cost;
+pub use cost::*;
+
+mod deployment_builder;
+pub use deployment_builder::*;
+
 mod stack;
 pub use stack::*;
 
+mod key_cache;
+pub use key_cache::*;
+
+mod local_execution;
+pub use local_execution::*;
+
 mod trace;
 pub use trace::*;
 
@@ -76,6 +88,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The cache of proving keys and verifying keys, shared by the stacks.
+    key_cache: Arc<KeyCache<N>>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +99,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            key_cache: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -124,6 +142,20 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Adds a new program to the process, whose functions are optimized before circuit generation.
+    /// Note: The circuit keys of an optimized program differ from those of the program, so they must be
+    /// synthesized from this process, and do not verify the executions of the unoptimized program.
+    #[inline]
+    pub fn add_program_with_optimization(&mut self, program: &Program<N>) -> Result<()> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Ensure the program is not 'credits.aleo', as its circuit keys are fixed.
+        ensure!(program.id() != &credits_program_id, "Cannot optimize the 'credits.aleo' program");
+        // Compute the optimized program stack, and add it to the process.
+        self.add_stack(Stack::new_optimized(self, program)?);
+        Ok(())
+    }
+
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -140,7 +172,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            key_cache: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +209,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            key_cache: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -194,6 +234,12 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the cache of proving keys and verifying keys.
+    #[inline]
+    pub const fn key_cache(&self) -> &Arc<KeyCache<N>> {
+        &self.key_cache
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -278,6 +324,18 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Returns the circuit profiles for the given program ID and function name, on sampled inputs.
+    #[inline]
+    pub fn profile_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<Vec<CircuitProfile<N>>> {
+        // Profile the circuit of the function.
+        self.get_stack(program_id)?.profile_function::<A, R>(function_name, rng)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{Execution, Transition};
+use synthesizer_snark::Proof;
+
+/// An execution proven against a local state, in which the input records are placed in a synthetic block,
+/// instead of being looked up in a ledger. See `Process::execute_local`.
+///
+/// A local execution is **not broadcastable**: its state root does not exist in any ledger,
+/// so it cannot be converted into a transaction. It is intended for testing program logic end-to-end.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LocalExecution<N: Network> {
+    /// The execution, proven against the local state root.
+    execution: Execution<N>,
+}
+
+impl<N: Network> LocalExecution<N> {
+    /// Initializes a new local execution, for an execution proven against a local state root.
+    pub(crate) const fn new(execution: Execution<N>) -> Self {
+        Self { execution }
+    }
+
+    /// Returns the local state root.
+    pub fn local_state_root(&self) -> N::StateRoot {
+        self.execution.global_state_root()
+    }
+
+    /// Returns the proof.
+    pub fn proof(&self) -> Option<&Proof<N>> {
+        self.execution.proof()
+    }
+
+    /// Returns the number of transitions.
+    pub fn len(&self) -> usize {
+        self.execution.len()
+    }
+
+    /// Returns `true` if there are no transitions.
+    pub fn is_empty(&self) -> bool {
+        self.execution.is_empty()
+    }
+
+    /// Returns an iterator over the transitions.
+    pub fn transitions(&self) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = &Transition<N>> {
+        self.execution.transitions()
+    }
+
+    /// Returns the underlying execution.
+    pub(crate) const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+}
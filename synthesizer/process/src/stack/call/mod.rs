@@ -230,7 +230,9 @@ impl<N: Network> CallTrait<N> for Call<N> {
                         // Return the request and response.
                         (request, response)
                     }
-                    CallStack::CheckDeployment(_, private_key, ..) | CallStack::PackageRun(_, private_key, ..) => {
+                    CallStack::CheckDeployment(_, private_key, ..)
+                    | CallStack::PackageRun(_, private_key, ..)
+                    | CallStack::Profile(_, private_key, ..) => {
                         // Compute the request.
                         let request = Request::sign(
                             &private_key,
@@ -27,19 +27,9 @@ impl<N: Network> Stack<N> {
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
 
         for function_name in self.program.functions().keys() {
-            // Synthesize the proving and verifying key.
-            self.synthesize_key::<A, R>(function_name, rng)?;
-            lap!(timer, "Synthesize key for {function_name}");
-
-            // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function_name)?;
-            // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(function_name)?;
-            lap!(timer, "Retrieve the keys for {function_name}");
-
-            // Certify the circuit.
-            let certificate = Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)?;
-            lap!(timer, "Certify the circuit");
+            // Synthesize and certify the circuit.
+            let (verifying_key, certificate) = self.certify_function::<A, R>(function_name, rng)?;
+            lap!(timer, "Certify the circuit for {function_name}");
 
             // Add the verifying key and certificate to the bundle.
             verifying_keys.push((*function_name, (verifying_key, certificate)));
@@ -51,6 +41,26 @@ impl<N: Network> Stack<N> {
         Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
     }
 
+    /// Synthesizes the circuit keys for the given function name, and returns its verifying key and certificate.
+    #[inline]
+    pub fn certify_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(VerifyingKey<N>, Certificate<N>)> {
+        // Synthesize the proving and verifying key.
+        self.synthesize_key::<A, R>(function_name, rng)?;
+
+        // Retrieve the proving key.
+        let proving_key = self.get_proving_key(function_name)?;
+        // Retrieve the verifying key.
+        let verifying_key = self.get_verifying_key(function_name)?;
+
+        // Certify the circuit.
+        let certificate = Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)?;
+        Ok((verifying_key, certificate))
+    }
+
     /// Checks each function in the program on the given verifying key and certificate.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
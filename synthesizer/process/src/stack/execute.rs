@@ -43,6 +43,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the number of public variables in the circuit.
         let num_public = A::num_public();
 
+        // Determine if the circuit is in `Profile` mode.
+        let is_profile = matches!(call_stack, CallStack::Profile(..));
+
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition signer, as a circuit.
@@ -75,8 +78,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            // Execute the instruction. If the circuit is in `Profile` mode, then execute it in its own scope.
+            match is_profile {
+                true => A::scope(scope_name(instruction), || instruction.execute(self, &mut registers))?,
+                false => instruction.execute(self, &mut registers)?,
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -190,8 +196,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         ensure!(console_request.verify(&input_types), "Request is invalid");
         lap!(timer, "Verify the console request");
 
+        // Retrieve the optimization of the function, if the stack is optimized.
+        let optimization = self.get_optimization(function.name());
+
         // Initialize the registers.
-        let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        let register_types = self.get_register_types(function.name())?.clone();
+        let mut registers = match optimization {
+            Some(..) => Registers::new_optimized(call_stack, register_types),
+            None => Registers::new(call_stack, register_types),
+        };
 
         use circuit::{Eject, Inject};
 
@@ -237,6 +250,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the number of public variables in the circuit.
         let num_public = A::num_public();
 
+        // Determine if the circuit is in `Profile` mode.
+        let is_profile = matches!(registers.call_stack(), CallStack::Profile(..));
+        // Retrieve the count for verifying the request in the circuit.
+        let request_count = CircuitCount::current::<A>();
+        // Initialize the profiles of the instructions.
+        let mut instruction_profiles = Vec::new();
+
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(request.inputs()).try_for_each(|(register, input)| {
             // If the circuit is in execute mode, then store the console input.
@@ -252,8 +272,37 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
-        // Execute the instructions.
-        for instruction in function.instructions() {
+        // Execute the instructions, or if the function is optimized, the steps in place of its instructions.
+        let num_steps = optimization.map_or(function.instructions().len(), |optimization| optimization.steps().len());
+        for index in 0..num_steps {
+            let instruction = match optimization.map(|optimization| &optimization.steps()[index]) {
+                // If the function is not optimized, execute its instruction.
+                None => &function.instructions()[index],
+                Some(Step::Instruction(instruction)) => instruction,
+                // Assign the folded value to the register, as a constant.
+                Some(Step::Constant(register, value)) => {
+                    // If the circuit is in execute mode, then assign the console value.
+                    if let CallStack::Execute(..) = registers.call_stack() {
+                        registers.store(self, register, value.clone())?;
+                    }
+                    let value = circuit::Value::new(circuit::Mode::Constant, value.clone());
+                    registers.store_circuit(self, register, value)?;
+                    continue;
+                }
+                // Assign the value of the source register to the destination register.
+                Some(Step::Alias(source, destination)) => {
+                    let source = Operand::Register(source.clone());
+                    // If the circuit is in execute mode, then assign the console value.
+                    if let CallStack::Execute(..) = registers.call_stack() {
+                        let value = registers.load(self, &source)?;
+                        registers.store(self, destination, value)?;
+                    }
+                    let value = registers.load_circuit(self, &source)?;
+                    registers.store_circuit(self, destination, value)?;
+                    continue;
+                }
+            };
+
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
@@ -269,18 +318,38 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
+            // Retrieve the count of the circuit, and the number of exited scopes, prior to executing the instruction.
+            let (instruction_start, scopes_start) = (CircuitCount::current::<A>(), A::num_exited_scopes());
+
             // Execute the instruction.
-            let result = match instruction {
+            let mut execute = || match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
+            // If the circuit is in `Profile` mode, then execute the instruction in its own scope.
+            let result = match is_profile {
+                true => A::scope(scope_name(instruction), execute),
+                false => execute(),
+            };
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
 
+            // If the circuit is in `Profile` mode, then save the count of the instruction, and its nested scopes.
+            if is_profile {
+                let count = CircuitCount::current::<A>().since(&instruction_start);
+                // Retrieve the exited scopes, where the last one is the scope of the instruction.
+                let exited_scopes = A::exited_scopes(scopes_start);
+                let scopes = match exited_scopes.last() {
+                    Some((scope, _)) => ScopeProfile::from_exited_scopes(scope, &exited_scopes),
+                    None => Vec::new(),
+                };
+                instruction_profiles.push(InstructionProfile { instruction: instruction.to_string(), count, scopes });
+            }
+
             // If the instruction was a function call, then set the tracker to `true`.
             if let Instruction::Call(call) = instruction {
                 // Check if the call is a function call.
@@ -291,6 +360,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         }
         lap!(timer, "Execute the instructions");
 
+        // Retrieve the count of the circuit, prior to loading the outputs and constructing the response.
+        let response_start = CircuitCount::current::<A>();
+
         // Load the outputs.
         let output_operands = &function.outputs().iter().map(|output| output.operand()).collect::<Vec<_>>();
         let outputs = output_operands
@@ -368,6 +440,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let num_response_constraints =
             A::num_constraints().saturating_sub(num_request_constraints).saturating_sub(num_function_constraints);
 
+        // Retrieve the count of the complete circuit.
+        let total_count = CircuitCount::current::<A>();
+
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Complete");
 
@@ -471,6 +546,21 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             assignments.write().push((assignment, metrics));
             lap!(timer, "Save the circuit assignment");
         }
+        // If the circuit is in `Profile` mode, then save the circuit profile.
+        else if let CallStack::Profile(_, _, ref profiles) = registers.call_stack() {
+            // Construct the circuit profile.
+            let profile = CircuitProfile {
+                program_id: *self.program_id(),
+                function_name: *function.name(),
+                request: request_count,
+                instructions: instruction_profiles,
+                response: total_count.since(&response_start),
+                total: total_count,
+            };
+            // Add the profile to the profiles.
+            profiles.write().push(profile);
+            lap!(timer, "Save the circuit profile");
+        }
 
         finish!(timer);
 
@@ -498,3 +588,8 @@ impl<N: Network> Stack<N> {
         );
     }
 }
+
+/// Returns the name of the circuit scope for the given instruction, as a scope name can not contain periods.
+fn scope_name<N: Network>(instruction: &Instruction<N>) -> String {
+    instruction.opcode().to_string().replace('.', "_")
+}
@@ -21,6 +21,7 @@ use synthesizer_program::{
     Contains,
     Get,
     GetOrUse,
+    Iter,
     MappingLocator,
     RandChaCha,
     Remove,
@@ -149,6 +150,9 @@ impl<N: Network> FinalizeTypes<N> {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
+            FinalizeType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                RegisterTypes::check_vector(stack, vector_type)?
+            }
             FinalizeType::Future(..) => (),
         };
 
@@ -177,6 +181,7 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Contains(contains) => self.check_contains(stack, finalize.name(), contains)?,
             Command::Get(get) => self.check_get(stack, get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, get_or_use)?,
+            Command::Iter(iter) => self.check_iter(stack, finalize.name(), iter)?,
             Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
             Command::Remove(remove) => self.check_remove(stack, finalize.name(), remove)?,
             Command::Set(set) => self.check_set(stack, finalize.name(), set)?,
@@ -429,6 +434,50 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `iter` command is well-formed.
+    #[inline]
+    fn check_iter(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        iter: &Iter<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `iter` is defined in the program.
+        if !stack.program().contains_mapping(iter.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", iter.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(iter.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the start index.
+        let start_type = match self.get_type_from_operand(stack, iter.start())? {
+            // If the register is a plaintext type, return it.
+            FinalizeType::Plaintext(plaintext_type) => plaintext_type,
+            // If the register is a future, throw an error.
+            FinalizeType::Future(..) => bail!("A future cannot be used as the start index in an `iter` command"),
+        };
+        // Check that the start index is a `u32`.
+        if start_type != PlaintextType::Literal(LiteralType::U32) {
+            bail!("Start index in `iter` must be a 'u32', found '{start_type}'.")
+        }
+        // Ensure the keys can be padded to the limit, which is not supported for signatures.
+        if *mapping_key_type == PlaintextType::Literal(LiteralType::Signature) {
+            bail!("Cannot iterate over the keys of '{}', as signature keys are not supported.", iter.mapping_name())
+        }
+        // Construct the vector type of the keys.
+        let vector_type = VectorType::new(mapping_key_type.clone(), *iter.limit())
+            .map_err(|error| anyhow!("Cannot iterate over the keys of '{}': {error}", iter.mapping_name()))?;
+        // Get the destination register.
+        let destination = iter.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Vector(vector_type)))?;
+        Ok(())
+    }
+
     /// Ensure the given `rand.chacha` command is well-formed.
     #[inline]
     fn check_rand_chacha(
@@ -652,6 +701,9 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Vector(..)) => {
+                            bail!("Illegal operation: Cannot cast to a vector.")
+                        }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
                         }
@@ -667,6 +719,13 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::Ecdsa => {
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
             Opcode::Hash(opcode) => RegisterTypes::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -686,6 +745,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::String(opcode) => RegisterTypes::check_string_opcode(opcode, instruction)?,
+            Opcode::Vector(opcode) => RegisterTypes::check_vector_opcode(opcode, instruction)?,
         }
         Ok(())
     }
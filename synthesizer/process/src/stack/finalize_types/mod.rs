@@ -17,7 +17,7 @@ mod matches;
 
 use console::{
     network::prelude::*,
-    program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType},
+    program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType, VectorType},
 };
 use synthesizer_program::{
     Command,
@@ -128,6 +128,10 @@ impl<N: Network> FinalizeTypes<N> {
                 (FinalizeRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not a vector, as its elements are accessed with the vector opcodes.
+                (FinalizeRefType::Plaintext(PlaintextType::Vector(..)), _) => {
+                    bail!("'{register}' references a vector, use the vector opcodes to access its elements.")
+                }
                 // Access the member on the path to output the register type.
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct and check that it exists.
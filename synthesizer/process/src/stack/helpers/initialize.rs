@@ -18,15 +18,18 @@ impl<N: Network> Stack<N> {
     /// Initializes a new stack, given the process and program.
     #[inline]
     pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Remove the cached keys of any previous stack for the program, as they may not match this program.
+        process.key_cache().remove_program(program.id());
+
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
+            optimizations: Default::default(),
             universal_srs: process.universal_srs().clone(),
-            proving_keys: Default::default(),
-            verifying_keys: Default::default(),
+            key_cache: process.key_cache().clone(),
         };
 
         // Add all of the imports into the stack.
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{Argument, FinalizeType};
+use console::program::{Argument, FinalizeType, VectorType};
 
 impl<N: Network> StackMatches<N> for Stack<N> {
     /// Checks that the given value matches the layout of the value type.
@@ -90,7 +90,7 @@ impl<N: Network> StackMatches<N> for Stack<N> {
 
     /// Checks that the given plaintext matches the layout of the plaintext type.
     fn matches_plaintext(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<()> {
-        self.matches_plaintext_internal(plaintext, plaintext_type, 0)
+        self.matches_plaintext_internal(plaintext, plaintext_type, 0, 0)
     }
 
     /// Checks that the given future matches the layout of the future type.
@@ -165,7 +165,7 @@ impl<N: Network> Stack<N> {
             (Entry::Constant(plaintext), EntryType::Constant(plaintext_type))
             | (Entry::Public(plaintext), EntryType::Public(plaintext_type))
             | (Entry::Private(plaintext), EntryType::Private(plaintext_type)) => {
-                match self.matches_plaintext_internal(plaintext, plaintext_type, depth) {
+                match self.matches_plaintext_internal(plaintext, plaintext_type, depth, 0) {
                     Ok(()) => Ok(()),
                     Err(error) => bail!("Invalid record entry '{record_name}.{entry_name}': {error}"),
                 }
@@ -177,11 +177,14 @@ impl<N: Network> Stack<N> {
     }
 
     /// Checks that the given plaintext matches the layout of the plaintext type.
+    /// The `recursion` is the number of times the enclosing struct is nested within itself,
+    /// if the plaintext is (or is an element of) a self-referential vector, and is otherwise zero.
     fn matches_plaintext_internal(
         &self,
         plaintext: &Plaintext<N>,
         plaintext_type: &PlaintextType<N>,
         depth: usize,
+        recursion: usize,
     ) -> Result<()> {
         // If the depth exceeds the maximum depth, then the plaintext type is invalid.
         ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeded maximum depth of {}", N::MAX_DATA_DEPTH);
@@ -249,8 +252,13 @@ impl<N: Network> Stack<N> {
                     }
                     // Ensure the member name is valid.
                     ensure!(!Program::is_reserved_keyword(member_name), "Member name '{member_name}' is reserved");
+                    // Determine the recursion of the member, which is only tracked for a vector of the struct.
+                    let recursion = match struct_.is_recursive_member(expected_type) {
+                        true => recursion + 1,
+                        false => 0,
+                    };
                     // Ensure the member plaintext matches (recursive call).
-                    self.matches_plaintext_internal(member, expected_type, depth + 1)?;
+                    self.matches_plaintext_internal(member, expected_type, depth + 1, recursion)?;
                 }
 
                 Ok(())
@@ -271,11 +279,57 @@ impl<N: Network> Stack<N> {
                     }
                     // Ensure the array elements match.
                     for element in array.iter() {
-                        self.matches_plaintext_internal(element, array_type.next_element_type(), depth + 1)?;
+                        self.matches_plaintext_internal(element, array_type.next_element_type(), depth + 1, 0)?;
                     }
                     Ok(())
                 }
             },
+            PlaintextType::Vector(vector_type) => {
+                // Retrieve the vector members.
+                let members = match plaintext {
+                    Plaintext::Literal(..) => bail!("'{plaintext_type}' is invalid: expected vector, found literal"),
+                    Plaintext::Struct(members, ..) => members,
+                    Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected vector, found array"),
+                };
+
+                // Ensure the vector members are the length and the elements, in the same order.
+                let member_names = members.keys().copied().collect::<Vec<_>>();
+                if member_names != [VectorType::length_member(), VectorType::elements_member()] {
+                    bail!("'{plaintext_type}' is invalid: expected the members 'length' and 'elements'")
+                }
+
+                // Ensure the length does not exceed the maximum length.
+                let length = plaintext.vector_length()?;
+                let max_length = vector_type.max_length();
+                if *length > **max_length {
+                    bail!("'{plaintext_type}' is invalid: the length {length} exceeds the maximum length {max_length}")
+                }
+
+                // Retrieve the elements.
+                let elements = plaintext.vector_elements()?;
+
+                // Past the maximum recursion depth, ensure the self-referential vector is the empty sentinel.
+                // Note: The sentinel is at a fixed depth, so every value of the struct has the same shape.
+                if recursion > N::MAX_STRUCT_RECURSION_DEPTH {
+                    if *length != 0 || !elements.is_empty() {
+                        bail!(
+                            "'{plaintext_type}' is invalid: expected an empty vector past the recursion depth of {}",
+                            N::MAX_STRUCT_RECURSION_DEPTH
+                        )
+                    }
+                    return Ok(());
+                }
+
+                // Ensure the elements are padded to the maximum length.
+                if elements.len() != **max_length as usize {
+                    bail!("'{plaintext_type}' is invalid: expected {max_length} elements, found {}", elements.len())
+                }
+                // Ensure the elements match, including the padding.
+                for element in elements {
+                    self.matches_plaintext_internal(element, vector_type.element_type(), depth + 1, recursion)?;
+                }
+                Ok(())
+            }
         }
     }
 
@@ -308,7 +362,7 @@ impl<N: Network> Stack<N> {
         for (argument, input) in future.arguments().iter().zip_eq(inputs.iter()) {
             match (argument, input.finalize_type()) {
                 (Argument::Plaintext(plaintext), FinalizeType::Plaintext(plaintext_type)) => {
-                    self.matches_plaintext_internal(plaintext, plaintext_type, depth + 1)?
+                    self.matches_plaintext_internal(plaintext, plaintext_type, depth + 1, 0)?
                 }
                 (Argument::Future(future), FinalizeType::Future(locator)) => {
                     self.matches_future_internal(future, locator, depth + 1)?
@@ -16,5 +16,6 @@ use super::*;
 
 mod initialize;
 mod matches;
+mod profile;
 mod sample;
 mod synthesize;
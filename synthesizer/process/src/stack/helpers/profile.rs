@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the circuit profiles for the given function name, on sampled inputs.
+    /// The profile of each function that is called is included before the profile of its caller,
+    /// so the profile of the given function is the last one.
+    #[inline]
+    pub fn profile_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<Vec<CircuitProfile<N>>> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request =
+            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        // Initialize the profiles.
+        let profiles = Profiles::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::Profile(vec![request], burner_private_key, profiles.clone());
+        // Profile the circuit, saving the count of each scope while profiling.
+        A::set_profiling(true);
+        let response = self.execute_function::<A>(call_stack, None);
+        A::set_profiling(false);
+        let _response = response?;
+
+        // Return the profiles.
+        let profiles = profiles.read().clone();
+        Ok(profiles)
+    }
+}
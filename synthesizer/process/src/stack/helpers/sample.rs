@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use console::types::U32;
 
 impl<N: Network> Stack<N> {
     /// Returns a value for the given value type.
@@ -58,7 +59,7 @@ impl<N: Network> Stack<N> {
         rng: &mut R,
     ) -> Result<Plaintext<N>> {
         // Sample a plaintext value.
-        let plaintext = self.sample_plaintext_internal(plaintext_type, 0, rng)?;
+        let plaintext = self.sample_plaintext_internal(plaintext_type, 0, 0, rng)?;
         // Ensure the plaintext value matches the plaintext type.
         self.matches_plaintext(&plaintext, plaintext_type)?;
         // Return the plaintext value.
@@ -121,7 +122,7 @@ impl<N: Network> Stack<N> {
             | EntryType::Public(plaintext_type)
             | EntryType::Private(plaintext_type) => {
                 // Sample the plaintext value.
-                let plaintext = self.sample_plaintext_internal(plaintext_type, depth, rng)?;
+                let plaintext = self.sample_plaintext_internal(plaintext_type, depth, 0, rng)?;
                 // Return the entry.
                 match entry_type {
                     EntryType::Constant(..) => Ok(Entry::Constant(plaintext)),
@@ -133,10 +134,13 @@ impl<N: Network> Stack<N> {
     }
 
     /// Samples a plaintext value according to the given plaintext type.
+    /// The `recursion` is the number of times the enclosing struct is nested within itself,
+    /// if the plaintext type is (or is an element of) a self-referential vector, and is otherwise zero.
     fn sample_plaintext_internal<R: Rng + CryptoRng>(
         &self,
         plaintext_type: &PlaintextType<N>,
         depth: usize,
+        recursion: usize,
         rng: &mut R,
     ) -> Result<Plaintext<N>> {
         // If the depth exceeds the maximum depth, then the plaintext type is invalid.
@@ -157,8 +161,13 @@ impl<N: Network> Stack<N> {
                     .members()
                     .iter()
                     .map(|(member_name, member_type)| {
+                        // Determine the recursion of the member, which is only tracked for a vector of the struct.
+                        let recursion = match struct_.is_recursive_member(member_type) {
+                            true => recursion + 1,
+                            false => 0,
+                        };
                         // Sample the member value.
-                        let member = self.sample_plaintext_internal(member_type, depth + 1, rng)?;
+                        let member = self.sample_plaintext_internal(member_type, depth + 1, recursion, rng)?;
                         // Return the member.
                         Ok((*member_name, member))
                     })
@@ -172,12 +181,27 @@ impl<N: Network> Stack<N> {
                 let elements = (0..**array_type.length())
                     .map(|_| {
                         // Sample the element value.
-                        self.sample_plaintext_internal(array_type.next_element_type(), depth + 1, rng)
+                        self.sample_plaintext_internal(array_type.next_element_type(), depth + 1, 0, rng)
                     })
                     .collect::<Result<Vec<_>>>()?;
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Past the maximum recursion depth, a self-referential vector is the empty sentinel.
+            PlaintextType::Vector(..) if recursion > N::MAX_STRUCT_RECURSION_DEPTH => {
+                Plaintext::new_vector(U32::zero(), vec![])?
+            }
+            // Sample a vector.
+            PlaintextType::Vector(vector_type) => {
+                // Sample the length of the vector.
+                let length = U32::new(rng.gen_range(0..=**vector_type.max_length()));
+                // Sample each element of the vector, including the padding.
+                let elements = (0..**vector_type.max_length())
+                    .map(|_| self.sample_plaintext_internal(vector_type.element_type(), depth + 1, recursion, rng))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Plaintext::new_vector(length, elements)?
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
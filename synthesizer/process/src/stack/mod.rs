@@ -24,6 +24,9 @@ pub use finalize_registers::*;
 mod finalize_types;
 pub use finalize_types::*;
 
+mod optimization;
+pub use optimization::*;
+
 mod register_types;
 pub use register_types::*;
 
@@ -36,7 +39,17 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{
+    traits::*,
+    CallMetrics,
+    CircuitCount,
+    CircuitProfile,
+    InstructionProfile,
+    KeyCache,
+    Process,
+    ScopeProfile,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -74,6 +87,7 @@ use std::sync::Arc;
 use rayon::prelude::*;
 
 pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>::Field>, CallMetrics<N>)>>>;
+pub type Profiles<N> = Arc<RwLock<Vec<CircuitProfile<N>>>>;
 
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
@@ -83,6 +97,7 @@ pub enum CallStack<N: Network> {
     Evaluate(Authorization<N>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
+    Profile(Vec<Request<N>>, PrivateKey<N>, Profiles<N>),
 }
 
 impl<N: Network> CallStack<N> {
@@ -119,6 +134,9 @@ impl<N: Network> CallStack<N> {
             CallStack::PackageRun(requests, private_key, assignments) => {
                 CallStack::PackageRun(requests.clone(), *private_key, Arc::new(RwLock::new(assignments.read().clone())))
             }
+            CallStack::Profile(requests, private_key, profiles) => {
+                CallStack::Profile(requests.clone(), *private_key, Arc::new(RwLock::new(profiles.read().clone())))
+            }
         }
     }
 
@@ -128,7 +146,8 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
-            | CallStack::PackageRun(requests, ..) => requests.push(request),
+            | CallStack::PackageRun(requests, ..)
+            | CallStack::Profile(requests, ..) => requests.push(request),
             CallStack::Evaluate(authorization) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
@@ -141,7 +160,8 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
-            | CallStack::PackageRun(requests, ..) => {
+            | CallStack::PackageRun(requests, ..)
+            | CallStack::Profile(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.next(),
@@ -155,7 +175,8 @@ impl<N: Network> CallStack<N> {
             CallStack::Authorize(requests, ..)
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
-            | CallStack::PackageRun(requests, ..) => {
+            | CallStack::PackageRun(requests, ..)
+            | CallStack::Profile(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
             CallStack::Evaluate(authorization) => authorization.peek_next(),
@@ -174,12 +195,12 @@ pub struct Stack<N: Network> {
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+    /// The mapping of function names to their optimizations, if the stack is optimized.
+    optimizations: IndexMap<Identifier<N>, Optimization<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
-    /// The mapping of function name to proving key.
-    proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
-    /// The mapping of function name to verifying key.
-    verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The cache of proving keys and verifying keys, shared with the process.
+    key_cache: Arc<KeyCache<N>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -206,6 +227,28 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Stack::initialize(process, program)
     }
+
+    /// Initializes a new stack, as in `Stack::new`, whose functions are optimized before circuit generation.
+    /// Note: An optimized function has different circuits than the function, so its circuit keys
+    /// must be synthesized from an optimized stack, and do not verify the executions of an unoptimized stack.
+    #[inline]
+    pub fn new_optimized(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Initialize the stack.
+        let mut stack = Self::new(process, program)?;
+        // Compute the optimizations of the functions.
+        let optimizations = program
+            .functions()
+            .values()
+            .map(|function| {
+                let register_types = stack.get_register_types(function.name())?;
+                Ok((*function.name(), Optimization::from_function(&stack, register_types, function)?))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        // Add the optimizations to the stack.
+        stack.optimizations = optimizations;
+        // Return the stack.
+        Ok(stack)
+    }
 }
 
 impl<N: Network> StackProgram<N> for Stack<N> {
@@ -311,16 +354,22 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the optimization for the given function name, if the stack is optimized.
+    #[inline]
+    pub fn get_optimization(&self, function_name: &Identifier<N>) -> Option<&Optimization<N>> {
+        self.optimizations.get(function_name)
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
-        self.proving_keys.read().contains_key(function_name)
+        self.key_cache.contains_proving_key(self.program.id(), function_name)
     }
 
     /// Returns `true` if the verifying key for the given function name exists.
     #[inline]
     pub fn contains_verifying_key(&self, function_name: &Identifier<N>) -> bool {
-        self.verifying_keys.read().contains_key(function_name)
+        self.key_cache.contains_verifying_key(self.program.id(), function_name)
     }
 
     /// Returns the proving key for the given function name.
@@ -329,8 +378,8 @@ impl<N: Network> Stack<N> {
         // If the program is 'credits.aleo', try to load the proving key, if it does not exist.
         self.try_insert_credits_function_proving_key(function_name)?;
         // Return the proving key, if it exists.
-        match self.proving_keys.read().get(function_name) {
-            Some(proving_key) => Ok(proving_key.clone()),
+        match self.key_cache.get_proving_key(self.program.id(), function_name) {
+            Some(proving_key) => Ok(proving_key),
             None => bail!("Proving key not found for: {}/{function_name}", self.program.id()),
         }
     }
@@ -338,9 +387,11 @@ impl<N: Network> Stack<N> {
     /// Returns the verifying key for the given function name.
     #[inline]
     pub fn get_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        // If the program is 'credits.aleo', try to load the verifying key, if it does not exist.
+        self.try_insert_credits_function_verifying_key(function_name)?;
         // Return the verifying key, if it exists.
-        match self.verifying_keys.read().get(function_name) {
-            Some(verifying_key) => Ok(verifying_key.clone()),
+        match self.key_cache.get_verifying_key(self.program.id(), function_name)? {
+            Some(verifying_key) => Ok(verifying_key),
             None => bail!("Verifying key not found for: {}/{function_name}", self.program.id()),
         }
     }
@@ -355,7 +406,7 @@ impl<N: Network> Stack<N> {
             self.program.id()
         );
         // Insert the proving key.
-        self.proving_keys.write().insert(*function_name, proving_key);
+        self.key_cache.insert_proving_key(*self.program.id(), *function_name, proving_key);
         Ok(())
     }
 
@@ -369,20 +420,20 @@ impl<N: Network> Stack<N> {
             self.program.id()
         );
         // Insert the verifying key.
-        self.verifying_keys.write().insert(*function_name, verifying_key);
+        self.key_cache.insert_verifying_key(*self.program.id(), *function_name, verifying_key);
         Ok(())
     }
 
     /// Removes the proving key for the given function name.
     #[inline]
     pub fn remove_proving_key(&self, function_name: &Identifier<N>) {
-        self.proving_keys.write().remove(function_name);
+        self.key_cache.remove_proving_key(self.program.id(), function_name);
     }
 
     /// Removes the verifying key for the given function name.
     #[inline]
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
-        self.verifying_keys.write().remove(function_name);
+        self.key_cache.remove_verifying_key(self.program.id(), function_name);
     }
 }
 
@@ -391,7 +442,7 @@ impl<N: Network> Stack<N> {
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
         // If the program is 'credits.aleo' and it does not exist yet, load the proving key directly.
         if self.program_id() == &ProgramID::from_str("credits.aleo")?
-            && !self.proving_keys.read().contains_key(function_name)
+            && !self.contains_proving_key(function_name)
         {
             // Load the 'credits.aleo' function proving key.
             let proving_key = N::get_credits_proving_key(function_name.to_string())?;
@@ -400,6 +451,20 @@ impl<N: Network> Stack<N> {
         }
         Ok(())
     }
+
+    /// Inserts the verifying key if the program ID is 'credits.aleo'.
+    fn try_insert_credits_function_verifying_key(&self, function_name: &Identifier<N>) -> Result<()> {
+        // If the program is 'credits.aleo' and it does not exist yet, load the verifying key directly.
+        if self.program_id() == &ProgramID::from_str("credits.aleo")?
+            && !self.contains_verifying_key(function_name)
+        {
+            // Load the 'credits.aleo' function verifying key.
+            let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
+            // Insert the 'credits.aleo' function verifying key.
+            self.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> PartialEq for Stack<N> {
@@ -408,6 +473,7 @@ impl<N: Network> PartialEq for Stack<N> {
             && self.external_stacks == other.external_stacks
             && self.register_types == other.register_types
             && self.finalize_types == other.finalize_types
+            && self.optimizations == other.optimizations
     }
 }
 
@@ -0,0 +1,302 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::RegisterTypes;
+use console::{
+    network::prelude::*,
+    program::{Plaintext, Register, Value},
+    types::{Address, Field},
+};
+use synthesizer_program::{
+    Function,
+    Instruction,
+    InstructionTrait,
+    Opcode,
+    Operand,
+    RegistersLoad,
+    RegistersSigner,
+    RegistersStore,
+    StackMatches,
+    StackProgram,
+};
+
+use indexmap::IndexMap;
+use std::collections::HashSet;
+
+/// A step of an optimized function, which is executed in place of the function instructions.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Step<N: Network> {
+    /// An instruction to execute.
+    Instruction(Instruction<N>),
+    /// A register assigned the constant value of an instruction, which was folded from its constant operands.
+    Constant(Register<N>, Value<N>),
+    /// A register assigned the value of another register, as `(source, destination)`,
+    /// in place of a duplicate hash instruction.
+    Alias(Register<N>, Register<N>),
+}
+
+/// The optimization of a function, which is applied before circuit generation.
+/// It folds the instructions with constant operands, merges the duplicate hash instructions,
+/// and eliminates the instructions whose destination registers are unused.
+///
+/// Note: An unused instruction is only eliminated if it cannot halt, and a constant instruction
+/// is only folded if it succeeds, so the optimized function halts on the same inputs as the function.
+/// The optimization is opt-in, via `Stack::new_optimized`, as it changes the circuit of the function,
+/// so the circuits of programs that are deployed without it are unchanged.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Optimization<N: Network> {
+    /// The steps of the optimized function.
+    steps: Vec<Step<N>>,
+}
+
+impl<N: Network> Optimization<N> {
+    /// Initializes the optimization of the given function.
+    pub fn from_function(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register_types: &RegisterTypes<N>,
+        function: &Function<N>,
+    ) -> Result<Self> {
+        // Fold the constant instructions, and merge the duplicate hash instructions.
+        let steps = Self::fold_and_merge(stack, register_types, function)?;
+        // Eliminate the instructions whose destination registers are unused.
+        let steps = Self::eliminate_unused(function, steps);
+        Ok(Self { steps })
+    }
+
+    /// Returns the steps of the optimized function.
+    pub fn steps(&self) -> &[Step<N>] {
+        &self.steps
+    }
+
+    /// Returns the number of instructions in the optimized function.
+    pub fn num_instructions(&self) -> usize {
+        self.steps.iter().filter(|step| matches!(step, Step::Instruction(..))).count()
+    }
+}
+
+impl<N: Network> Optimization<N> {
+    /// Folds the instructions with constant operands, and merges the duplicate hash instructions.
+    fn fold_and_merge(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register_types: &RegisterTypes<N>,
+        function: &Function<N>,
+    ) -> Result<Vec<Step<N>>> {
+        // Initialize the registers of the constant values.
+        let mut constants = ConstantRegisters::default();
+        // Initialize a mapping of each hash, as `(opcode, operands, type)`, to its destination register.
+        let mut hashes = IndexMap::new();
+
+        let mut steps = Vec::with_capacity(function.instructions().len());
+        for instruction in function.instructions() {
+            // If the operands are constant, fold the instruction.
+            // Note: If the evaluation fails, the instruction is kept, so that it halts at runtime.
+            if constants.is_foldable(instruction) && instruction.evaluate(stack, &mut constants).is_ok() {
+                for destination in instruction.destinations() {
+                    let value = constants.get(&destination)?.clone();
+                    steps.push(Step::Constant(destination, value));
+                }
+                continue;
+            }
+            // If the instruction is a duplicate hash, alias its destination to the destination of the first hash.
+            if let (Opcode::Hash(..), [destination]) = (instruction.opcode(), instruction.destinations().as_slice()) {
+                let hash = (
+                    instruction.opcode(),
+                    instruction.operands().to_vec(),
+                    register_types.get_type(stack, destination)?,
+                );
+                match hashes.get(&hash) {
+                    Some(source) => {
+                        steps.push(Step::Alias(source.clone(), destination.clone()));
+                        continue;
+                    }
+                    None => {
+                        hashes.insert(hash, destination.clone());
+                    }
+                }
+            }
+            steps.push(Step::Instruction(instruction.clone()));
+        }
+        Ok(steps)
+    }
+
+    /// Eliminates the steps whose destination registers are unused, in reverse order.
+    fn eliminate_unused(function: &Function<N>, steps: Vec<Step<N>>) -> Vec<Step<N>> {
+        // Initialize the used registers with the registers in the outputs.
+        let mut used = function
+            .outputs()
+            .iter()
+            .filter_map(|output| match output.operand() {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut retained = Vec::with_capacity(steps.len());
+        for step in steps.into_iter().rev() {
+            match &step {
+                Step::Instruction(instruction) => {
+                    // If the instruction cannot halt, and its destination registers are unused, eliminate it.
+                    let is_unused =
+                        instruction.destinations().iter().all(|register| !used.contains(&register.locator()));
+                    if is_unused && cannot_halt(instruction) {
+                        continue;
+                    }
+                    // Otherwise, mark the registers in its operands as used.
+                    used.extend(instruction.operands().iter().filter_map(|operand| match operand {
+                        Operand::Register(register) => Some(register.locator()),
+                        _ => None,
+                    }));
+                }
+                Step::Constant(destination, _) => {
+                    if !used.contains(&destination.locator()) {
+                        continue;
+                    }
+                }
+                Step::Alias(source, destination) => {
+                    if !used.contains(&destination.locator()) {
+                        continue;
+                    }
+                    used.insert(source.locator());
+                }
+            }
+            retained.push(step);
+        }
+        retained.reverse();
+        retained
+    }
+}
+
+/// Returns `true` if the instruction has no side effects, and cannot halt for operands of a valid type.
+fn cannot_halt<N: Network>(instruction: &Instruction<N>) -> bool {
+    matches!(
+        instruction,
+        Instruction::AbsWrapped(..)
+            | Instruction::AddSaturating(..)
+            | Instruction::AddWrapped(..)
+            | Instruction::And(..)
+            | Instruction::Double(..)
+            | Instruction::GreaterThan(..)
+            | Instruction::GreaterThanOrEqual(..)
+            | Instruction::HashKeccak256(..)
+            | Instruction::HashKeccak384(..)
+            | Instruction::HashKeccak512(..)
+            | Instruction::HashPSD2(..)
+            | Instruction::HashPSD4(..)
+            | Instruction::HashPSD8(..)
+            | Instruction::HashSha3_256(..)
+            | Instruction::HashSha3_384(..)
+            | Instruction::HashSha3_512(..)
+            | Instruction::HashKeccak256Native(..)
+            | Instruction::HashSha3_256Native(..)
+            | Instruction::IsEq(..)
+            | Instruction::IsNeq(..)
+            | Instruction::LessThan(..)
+            | Instruction::LessThanOrEqual(..)
+            | Instruction::MulSaturating(..)
+            | Instruction::MulWrapped(..)
+            | Instruction::Nand(..)
+            | Instruction::Nor(..)
+            | Instruction::Not(..)
+            | Instruction::Or(..)
+            | Instruction::SubSaturating(..)
+            | Instruction::SubWrapped(..)
+            | Instruction::Ternary(..)
+            | Instruction::Xor(..)
+    )
+}
+
+/// The registers of the constant values in a function, which are used to fold its instructions.
+struct ConstantRegisters<N: Network> {
+    /// The mapping of register locators to their constant values.
+    values: IndexMap<u64, Value<N>>,
+}
+
+impl<N: Network> Default for ConstantRegisters<N> {
+    /// Initializes the registers, without any constant values.
+    fn default() -> Self {
+        Self { values: Default::default() }
+    }
+}
+
+impl<N: Network> ConstantRegisters<N> {
+    /// Returns `true` if the instruction can be folded, i.e. it has no side effects and its operands are constant.
+    fn is_foldable(&self, instruction: &Instruction<N>) -> bool {
+        !matches!(instruction, Instruction::Async(..) | Instruction::Call(..))
+            && instruction.operands().iter().all(|operand| match operand {
+                Operand::Literal(..) => true,
+                Operand::Register(Register::Locator(locator)) => self.values.contains_key(locator),
+                _ => false,
+            })
+    }
+
+    /// Returns the constant value of the given register.
+    fn get(&self, register: &Register<N>) -> Result<&Value<N>> {
+        self.values.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' is not a constant"))
+    }
+}
+
+impl<N: Network> RegistersSigner<N> for ConstantRegisters<N> {
+    /// Returns an error, as the signer is not a constant.
+    fn signer(&self) -> Result<Address<N>> {
+        bail!("The signer is not a constant")
+    }
+
+    /// Ignores the signer, as it is not a constant.
+    fn set_signer(&mut self, _signer: Address<N>) {}
+
+    /// Returns an error, as the caller is not a constant.
+    fn caller(&self) -> Result<Address<N>> {
+        bail!("The caller is not a constant")
+    }
+
+    /// Ignores the caller, as it is not a constant.
+    fn set_caller(&mut self, _caller: Address<N>) {}
+
+    /// Returns an error, as the transition view key is not a constant.
+    fn tvk(&self) -> Result<Field<N>> {
+        bail!("The transition view key is not a constant")
+    }
+
+    /// Ignores the transition view key, as it is not a constant.
+    fn set_tvk(&mut self, _tvk: Field<N>) {}
+}
+
+impl<N: Network> RegistersLoad<N> for ConstantRegisters<N> {
+    /// Loads the constant value of the given operand.
+    fn load(&self, _stack: &(impl StackMatches<N> + StackProgram<N>), operand: &Operand<N>) -> Result<Value<N>> {
+        match operand {
+            Operand::Literal(literal) => Ok(Value::Plaintext(Plaintext::from(literal.clone()))),
+            Operand::Register(register @ Register::Locator(..)) => self.get(register).cloned(),
+            _ => bail!("Operand '{operand}' is not a constant"),
+        }
+    }
+}
+
+impl<N: Network> RegistersStore<N> for ConstantRegisters<N> {
+    /// Assigns the given constant value to the given register.
+    fn store(
+        &mut self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+        stack_value: Value<N>,
+    ) -> Result<()> {
+        match register {
+            Register::Locator(locator) => match self.values.insert(*locator, stack_value) {
+                Some(..) => bail!("Attempted to write to register '{register}' again"),
+                None => Ok(()),
+            },
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
+        }
+    }
+}
@@ -272,6 +272,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -323,6 +324,7 @@ impl<N: Network> RegisterTypes<N> {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -538,6 +540,9 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Vector(..)) => {
+                            bail!("Illegal operation: Cannot cast to a vector.")
+                        }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
                             if !stack.program().contains_record(record_name) {
@@ -560,6 +565,13 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::Ecdsa => {
+                // Ensure the instruction has one destination register.
+                ensure!(
+                    instruction.destinations().len() == 1,
+                    "Instruction '{instruction}' has multiple destinations."
+                );
+            }
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -579,6 +591,8 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::String(opcode) => Self::check_string_opcode(opcode, instruction)?,
+            Opcode::Vector(opcode) => Self::check_vector_opcode(opcode, instruction)?,
         }
         Ok(())
     }
@@ -644,10 +658,15 @@ impl<N: Network> RegisterTypes<N> {
 
         // If the struct contains arrays, ensure their base element types are defined in the program.
         for member in struct_.members().values() {
+            // If the member is a vector of the struct itself, it is already being checked.
+            if struct_.is_recursive_member(member) {
+                continue;
+            }
             match member {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                PlaintextType::Vector(vector_type) => Self::check_vector(stack, vector_type)?,
             }
         }
         Ok(())
@@ -668,6 +687,18 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensure the element type of the vector is defined in the program.
+    pub(crate) fn check_vector(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        vector_type: &VectorType<N>,
+    ) -> Result<()> {
+        // If the element type is a struct, check that it is defined in the program.
+        if let PlaintextType::Struct(struct_name) = vector_type.element_type() {
+            Self::check_struct(stack, struct_name)?;
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `commit` instruction.
     #[inline]
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
@@ -779,6 +810,58 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashManyPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.keccak256.native" => ensure!(
+                matches!(instruction, Instruction::HashKeccak256Native(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash.sha3_256.native" => ensure!(
+                matches!(instruction, Instruction::HashSha3_256Native(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to a string instruction.
+    #[inline]
+    pub(crate) fn check_string_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "str.concat" => ensure!(
+                matches!(instruction, Instruction::StrConcat(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.len" => ensure!(
+                matches!(instruction, Instruction::StrLen(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "str.slice" => ensure!(
+                matches!(instruction, Instruction::StrSlice(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+        }
+        Ok(())
+    }
+
+    /// Ensures the opcode is a valid opcode and corresponds to a vector instruction.
+    #[inline]
+    pub(crate) fn check_vector_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
+        // Ensure the instruction is the correct one.
+        match opcode {
+            "vec.get" => ensure!(
+                matches!(instruction, Instruction::VecGet(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "vec.len" => ensure!(
+                matches!(instruction, Instruction::VecLen(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "vec.set" => ensure!(
+                matches!(instruction, Instruction::VecSet(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
@@ -29,6 +29,7 @@ use console::{
         RegisterType,
         StructType,
         ValueType,
+        VectorType,
     },
 };
 use synthesizer_program::{
@@ -204,6 +205,10 @@ impl<N: Network> RegisterTypes<N> {
                 (RegisterRefType::Plaintext(PlaintextType::Literal(..)), _) => {
                     bail!("'{register}' references a literal.")
                 }
+                // Ensure the plaintext type is not a vector, as its elements are accessed with the vector opcodes.
+                (RegisterRefType::Plaintext(PlaintextType::Vector(..)), _) => {
+                    bail!("'{register}' references a vector, use the vector opcodes to access its elements.")
+                }
                 // Traverse the path to output the register type.
                 (RegisterRefType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(identifier)) => {
                     // Retrieve the member type from the struct.
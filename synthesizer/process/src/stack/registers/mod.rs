@@ -59,6 +59,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// A boolean indicating if the registers are of an optimized function, which may skip registers.
+    is_optimized: bool,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -76,9 +78,17 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            is_optimized: false,
         }
     }
 
+    /// Initializes a new set of registers for an optimized function, given the call stack.
+    /// Note: The registers may be assigned out of sequence, as the unused instructions are eliminated.
+    #[inline]
+    pub fn new_optimized(call_stack: CallStack<N>, register_types: RegisterTypes<N>) -> Self {
+        Self { is_optimized: true, ..Self::new(call_stack, register_types) }
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -31,8 +31,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
         match register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.console_registers.len() as u64;
-                ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
+                match self.is_optimized {
+                    // Note: Registers may be skipped, as the unused instructions are eliminated by the optimization.
+                    true => {
+                        if let Some((last, _)) = self.console_registers.last() {
+                            ensure!(*locator > *last, "Out-of-order write operation at '{register}'");
+                        }
+                    }
+                    false => {
+                        let expected_locator = self.console_registers.len() as u64;
+                        ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
+                    }
+                }
                 // Ensure the register does not already exist.
                 ensure!(
                     !self.console_registers.contains_key(locator),
@@ -78,8 +88,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
         match register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.circuit_registers.len() as u64;
-                ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
+                match self.is_optimized {
+                    // Note: Registers may be skipped, as the unused instructions are eliminated by the optimization.
+                    true => {
+                        if let Some((last, _)) = self.circuit_registers.last() {
+                            ensure!(*locator > *last, "Out-of-order write operation at '{register}'");
+                        }
+                    }
+                    false => {
+                        let expected_locator = self.circuit_registers.len() as u64;
+                        ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
+                    }
+                }
                 // Ensure the register does not already exist.
                 ensure!(
                     !self.circuit_registers.contains_key(locator),
@@ -13,17 +13,18 @@
 // limitations under the License.
 
 use crate::{
-    traits::{StackEvaluate, StackExecute},
+    traits::{StackEvaluate, StackExecute, StackMatches},
     CallStack,
     Process,
+    Step,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
-    account::{Address, PrivateKey, ViewKey},
+    account::{Address, PrivateKey, Signature, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{Identifier, Literal, Plaintext, PlaintextType, ProgramID, Record, Value},
+    types::{Field, U32, U64},
 };
 use ledger_block::Fee;
 use ledger_query::Query;
@@ -34,7 +35,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Instruction, Program};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -693,6 +694,68 @@ fn test_process_multirecords() {
     // assert_eq!(178189, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_with_batched_inclusion() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program batched_inclusion.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function join:
+    input r0 as token.record;
+    input r1 as token.record;
+    add r0.amount r1.amount into r2;
+    cast r0.owner r2 into r3 as token.record;
+    output r3 as token.record;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input records.
+    let input_a =
+        Value::from_str(&format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"))
+            .unwrap();
+    let input_b =
+        Value::from_str(&format!("{{ owner: {caller}.private, amount: 200u64.private, _nonce: 0group.public }}"))
+            .unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "join", [input_a, input_b].iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    // Prepare the trace, with the input records in a local state.
+    trace.prepare_local().unwrap();
+
+    // Synthesize the batched inclusion keys, for the two input records.
+    let (proving_key, verifying_key) =
+        trace.synthesize_batched_inclusion_keys::<CurrentAleo>(process.universal_srs()).unwrap();
+    // Prove the execution, with the input records in one batched inclusion circuit.
+    let execution = trace
+        .prove_execution_with_batched_inclusion::<CurrentAleo, _>("batched_inclusion.aleo/join", &proving_key, rng)
+        .unwrap();
+    assert_eq!(execution.len(), 1);
+
+    // Ensure the execution is valid with the batched inclusion verifying key.
+    process.verify_execution_with_batched_inclusion(&execution, &verifying_key).unwrap();
+    // Ensure the execution is invalid with the inclusion verifying key for a single state path.
+    assert!(process.verify_execution(&execution).is_err());
+}
+
 #[test]
 fn test_process_self_caller() {
     // Initialize a new program.
@@ -2357,13 +2420,229 @@ function compute:
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_execute_and_verify_call_to_external_closure() {
+    // Initialize a library program, with closures that are reusable by other programs.
+    let (string, library) = Program::<CurrentNetwork>::parse(
+        r"
+program library.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure sum_of_squares:
+    input r0 as u64;
+    input r1 as u64;
+    mul r0 r0 into r2;
+    mul r1 r1 into r3;
+    add r2 r3 into r4;
+    output r4 as u64;
+
+closure amount_of:
+    input r0 as token.record;
+    output r0.amount as u64;
+
+function square:
+    input r0 as u64.private;
+    call sum_of_squares r0 0u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize a program that calls the closures of the library.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+import library.aleo;
+
+program consumer.aleo;
+
+function distance:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    call library.aleo/sum_of_squares r0 r1 into r2;
+    output r2 as u64.private;
+
+function balance:
+    input r0 as library.aleo/token.record;
+    call library.aleo/amount_of r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("distance").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&library);
+    process.add_program(&program).unwrap();
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("4u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    // Ensure the closure call does not add a request, as the closure is inlined.
+    assert_eq!(authorization.len(), 1);
+
+    let r2 = Value::from_str("25u64").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [r2.clone()]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), [r2]);
+    // Ensure the closure call does not add a transition.
+    assert_eq!(trace.transitions().len(), 1);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("consumer", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure a call to an external closure with mismatched input types is rejected.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+import library.aleo;
+
+program mismatch.aleo;
+
+function distance:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    call library.aleo/sum_of_squares r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_verify_call_to_external_closure_with_record_output() {
+    // Initialize a library program, with a closure that returns a record.
+    let (string, library) = Program::<CurrentNetwork>::parse(
+        r"
+program library.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+closure identity:
+    input r0 as token.record;
+    output r0 as token.record;
+
+closure amount_of:
+    input r0 as token.record;
+    output r0.amount as u64;
+
+function amount:
+    input r0 as token.record;
+    call identity r0 into r1;
+    call amount_of r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize a program that passes a record of the library through its closures.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+import library.aleo;
+
+program consumer.aleo;
+
+function balance:
+    input r0 as library.aleo/token.record;
+    call library.aleo/identity r0 into r1;
+    call library.aleo/amount_of r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("balance").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&library);
+    // Ensure the record output of the external closure is typed as the record of the library.
+    process.add_program(&program).unwrap();
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    let r2 = Value::from_str("100u64").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), [r2.clone()]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), [r2]);
+    assert_eq!(trace.transitions().len(), 1);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("consumer", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
 #[test]
 fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        key_cache: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2483,3 +2762,813 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_sign_verify_struct_message() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that redeems a voucher, signed off-chain by an issuer.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program voucher.aleo;
+
+struct voucher:
+    recipient as address;
+    amount as u64;
+
+function redeem:
+    input r0 as voucher.private;
+    input r1 as signature.private;
+    input r2 as address.public;
+    sign.verify r1 r2 r0 into r3;
+    output r3 as boolean.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the issuer and the caller.
+    let issuer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let issuer = Address::try_from(&issuer_private_key).unwrap();
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Sign the voucher with the issuer key.
+    let voucher = Plaintext::<CurrentNetwork>::from_str(&format!("{{ recipient: {caller}, amount: 100u64 }}")).unwrap();
+    let signature = Signature::sign(&issuer_private_key, &voucher.to_fields().unwrap(), rng).unwrap();
+
+    // Ensure the signature is only valid for the issuer.
+    for (address, is_valid) in [(issuer, true), (caller, false)] {
+        // Authorize the function call.
+        let inputs = [
+            Value::Plaintext(voucher.clone()),
+            Value::from_str(&signature.to_string()).unwrap(),
+            Value::from_str(&address.to_string()).unwrap(),
+        ];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "redeem", inputs.iter(), rng)
+            .unwrap();
+        let expected = Value::from_str(&is_valid.to_string()).unwrap();
+
+        // Evaluate the function.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [expected.clone()]);
+        // Execute the function.
+        let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(response.outputs(), [expected]);
+    }
+}
+
+#[test]
+fn test_process_vector() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that updates an element of a vector, at an index that is not a constant.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program vector.aleo;
+
+function update:
+    input r0 as [field; <=4u32].private;
+    input r1 as u32.private;
+    input r2 as field.private;
+    vec.get r0 r1 into r3;
+    add r3 r2 into r4;
+    vec.set r0 r1 r4 into r5;
+    vec.len r5 into r6;
+    output r5 as [field; <=4u32].private;
+    output r6 as u32.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Prepare the inputs, for a vector of length 2.
+    let vector =
+        Value::<CurrentNetwork>::from_str("{ length: 2u32, elements: [1field, 2field, 0field, 0field] }").unwrap();
+    let inputs = [vector.clone(), Value::from_str("1u32").unwrap(), Value::from_str("5field").unwrap()];
+    let expected = [
+        Value::from_str("{ length: 2u32, elements: [1field, 7field, 0field, 0field] }").unwrap(),
+        Value::from_str("2u32").unwrap(),
+    ];
+
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "update", inputs.iter(), rng).unwrap();
+
+    // Evaluate the function.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+    // Execute the function.
+    let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure an index past the length of the vector is rejected.
+    let inputs = [vector, Value::from_str("2u32").unwrap(), Value::from_str("5field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "update", inputs.iter(), rng).unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+
+    // Ensure a vector cannot be accessed with a constant index.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program vector_access.aleo;
+
+function first:
+    input r0 as [field; <=4u32].private;
+    output r0.elements[0u32] as field.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_recursive_struct() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a struct that references itself through a vector.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program tree.aleo;
+
+struct node:
+    value as u64;
+    children as [node; <=1u32];
+
+function root:
+    input r0 as node.private;
+    output r0.value as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Initialize a helper to construct a node with the given children.
+    let (value, children) = (Identifier::from_str("value").unwrap(), Identifier::from_str("children").unwrap());
+    let node = |length: u32, elements: Vec<Plaintext<CurrentNetwork>>| {
+        let members = IndexMap::from_iter([
+            (value, Plaintext::from(Literal::U64(U64::new(1)))),
+            (children, Plaintext::new_vector(U32::new(length), elements).unwrap()),
+        ]);
+        Plaintext::Struct(members, Default::default())
+    };
+    let node_type = PlaintextType::Struct(Identifier::from_str("node").unwrap());
+
+    // Ensure a sampled node matches, and is nested up to the maximum recursion depth.
+    let sample = stack.sample_plaintext(&node_type, rng).unwrap();
+    stack.matches_plaintext(&sample, &node_type).unwrap();
+    let (mut current, mut nesting) = (sample.clone(), 0);
+    while let Some(child) = current.find(&[children]).unwrap().vector_elements().unwrap().first().cloned() {
+        current = child;
+        nesting += 1;
+    }
+    assert_eq!(nesting, CurrentNetwork::MAX_STRUCT_RECURSION_DEPTH);
+
+    // Ensure a node is padded up to the maximum recursion depth, where it ends in the empty sentinel.
+    let leaf = node(0, vec![]);
+    let mut nested = leaf.clone();
+    for _ in 0..CurrentNetwork::MAX_STRUCT_RECURSION_DEPTH {
+        nested = node(1, vec![nested]);
+    }
+    stack.matches_plaintext(&nested, &node_type).unwrap();
+    // Ensure an empty vector is still padded, so the shape of a node does not depend on its value.
+    let padded = node(0, nested.find(&[children]).unwrap().vector_elements().unwrap().to_vec());
+    stack.matches_plaintext(&padded, &node_type).unwrap();
+    // Ensure a node may not end in the empty sentinel before the maximum recursion depth.
+    assert!(stack.matches_plaintext(&leaf, &node_type).is_err());
+    assert!(stack.matches_plaintext(&node(1, vec![leaf.clone()]), &node_type).is_err());
+    // Ensure a node may not be nested past the maximum recursion depth.
+    assert!(stack.matches_plaintext(&node(1, vec![nested]), &node_type).is_err());
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function on the sampled node.
+    let inputs = [Value::Plaintext(sample.clone())];
+    let expected = [Value::Plaintext(sample.find(&[value]).unwrap())];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "root", inputs.iter(), rng).unwrap();
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+    let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure a struct may not reference itself outside of a vector.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program cycle.aleo;
+
+struct node:
+    value as u64;
+    next as [node; 2u32];
+
+function root:
+    input r0 as node.private;
+    output r0.value as u64.private;",
+    );
+    assert!(program.is_err());
+}
+
+#[test]
+fn test_process_optimization() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a constant instruction, a duplicate hash, and unused registers.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program optimize.aleo;
+
+function main:
+    input r0 as field.private;
+    add 1field 2field into r1;
+    mul r0 r1 into r2;
+    hash.psd2 r0 into r3 as field;
+    hash.psd2 r0 into r4 as field;
+    add r3 r4 into r5;
+    hash.psd4 r0 into r6 as field;
+    div r0 r1 into r7;
+    output r2 as field.private;
+    output r5 as field.private;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("main").unwrap();
+
+    // Construct the process, with the optimization.
+    let mut process = Process::load().unwrap();
+    process.add_program_with_optimization(&program).unwrap();
+
+    // Ensure the constant instruction is folded, the duplicate hash is merged, and the unused hash is eliminated.
+    // Note: The unused division is kept, as it halts on a zero divisor.
+    let optimization = process.get_stack(program.id()).unwrap().get_optimization(&function_name).unwrap();
+    let steps = optimization.steps();
+    assert_eq!(steps.len(), 6);
+    assert!(matches!(&steps[0], Step::Constant(register, _) if register.locator() == 1));
+    assert!(matches!(&steps[3], Step::Alias(source, target) if source.locator() == 3 && target.locator() == 4));
+    assert!(matches!(&steps[5], Step::Instruction(Instruction::Div(..))));
+    assert_eq!(optimization.num_instructions(), 4);
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Ensure the optimized execution matches the evaluation of the function.
+    let expected = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(expected.outputs()[0], Value::from_str("6field").unwrap());
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected.outputs());
+
+    // Ensure the optimized execution is proven and verified.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("optimize", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure a constant instruction that halts is not folded.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program optimize_halt.aleo;
+
+function main:
+    input r0 as u8.private;
+    add 255u8 1u8 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+    process.add_program_with_optimization(&program).unwrap();
+    let optimization = process.get_stack(program.id()).unwrap().get_optimization(&function_name).unwrap();
+    assert_eq!(optimization.num_instructions(), 1);
+}
+
+#[test]
+fn test_process_optimization_is_opt_in() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a constant instruction, a duplicate hash, and unused registers.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program optimize.aleo;
+
+function main:
+    input r0 as field.private;
+    add 1field 2field into r1;
+    mul r0 r1 into r2;
+    hash.psd2 r0 into r3 as field;
+    hash.psd2 r0 into r4 as field;
+    add r3 r4 into r5;
+    hash.psd4 r0 into r6 as field;
+    output r2 as field.private;
+    output r5 as field.private;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("main").unwrap();
+
+    // Construct the process, without the optimization.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+    // Ensure the function is not optimized.
+    assert!(process.get_stack(program.id()).unwrap().get_optimization(&function_name).is_none());
+    // Synthesize the circuit keys without the optimization.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let inputs = [Value::<CurrentNetwork>::from_str("2field").unwrap()];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Ensure the execution is proven and verified against the circuit keys without the optimization.
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("6field").unwrap());
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("optimize", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Construct a process with the optimization, and synthesize its circuit keys.
+    let mut optimized_process = Process::load().unwrap();
+    optimized_process.add_program_with_optimization(&program).unwrap();
+    optimized_process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Ensure the optimization changes the circuit keys.
+    let verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+    let optimized_verifying_key = optimized_process.get_verifying_key(program.id(), function_name).unwrap();
+    assert_ne!(verifying_key, optimized_verifying_key);
+    // Ensure the execution without the optimization does not verify against the optimized circuit keys.
+    assert!(optimized_process.verify_execution(&execution).is_err());
+}
+
+#[test]
+fn test_process_string() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that concatenates two strings, and slices the outcome.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"
+program string.aleo;
+
+function greet:
+    input r0 as string.private;
+    input r1 as u32.private;
+    str.concat "hello " r0 into r2;
+    str.len r2 into r3;
+    sub r3 r1 into r4;
+    str.slice r2 r1 r4 into r5;
+    output r3 as u32.private;
+    output r5 as string.private;"#,
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Prepare the inputs.
+    let inputs = [Value::<CurrentNetwork>::from_str("\"wörld\"").unwrap(), Value::from_str("3u32").unwrap()];
+    let expected = [Value::from_str("12u32").unwrap(), Value::from_str("\"lo wörld\"").unwrap()];
+
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "greet", inputs.iter(), rng).unwrap();
+
+    // Evaluate the function.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+    // Execute the function.
+    let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure a slice that does not lie on character boundaries is rejected.
+    let inputs = [Value::<CurrentNetwork>::from_str("\"wörld\"").unwrap(), Value::from_str("8u32").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "greet", inputs.iter(), rng).unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+}
+
+#[test]
+fn test_process_hash_native() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that computes the Keccak-256 and SHA3-256 digests of its input bytes.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program hash_native.aleo;
+
+function digest:
+    input r0 as [u8; 3u32].private;
+    hash.keccak256.native r0 into r1 as [u8; 32u32];
+    hash.sha3_256.native r0 into r2 as [u8; 32u32];
+    output r1 as [u8; 32u32].private;
+    output r2 as [u8; 32u32].private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns the given bytes as a `[u8; N]` value.
+    let to_value = |bytes: &[u8]| {
+        let bytes = bytes.iter().map(|byte| format!("{byte}u8")).collect::<Vec<_>>().join(", ");
+        Value::<CurrentNetwork>::from_str(&format!("[{bytes}]")).unwrap()
+    };
+
+    // Prepare the inputs, as the bytes of "abc", and the expected digests.
+    let inputs = [to_value(b"abc")];
+    let expected = [
+        to_value(&[
+            0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0, 0xd1,
+            0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+        ]),
+        to_value(&[
+            0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3, 0x90, 0xbd, 0x85, 0x5f,
+            0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45, 0x11, 0x43, 0x15, 0x32,
+        ]),
+    ];
+
+    // Authorize the function call.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "digest", inputs.iter(), rng).unwrap();
+
+    // Evaluate the function.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), expected);
+    // Execute the function.
+    let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected);
+
+    // Ensure the input of a native hash must be an array of `u8`.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program hash_native_field.aleo;
+
+function digest:
+    input r0 as [field; 2u32].private;
+    hash.keccak256.native r0 into r1 as [u8; 32u32];
+    output r1 as [u8; 32u32].private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_ecdsa_verify() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that verifies an ECDSA signature on the Keccak-256 digest of a message.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program ecdsa_verify.aleo;
+
+function verify:
+    input r0 as [u8; 64u32].private;
+    input r1 as [u8; 64u32].private;
+    input r2 as [u8; 3u32].private;
+    hash.keccak256.native r2 into r3 as [u8; 32u32];
+    ecdsa.verify r0 r1 r3 into r4;
+    output r4 as boolean.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns the given hex string as a `[u8; N]` value.
+    let to_value = |string: &str| {
+        let bytes = (0..string.len())
+            .step_by(2)
+            .map(|i| format!("{}u8", u8::from_str_radix(&string[i..i + 2], 16).unwrap()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Value::<CurrentNetwork>::from_str(&format!("[{bytes}]")).unwrap()
+    };
+
+    // Prepare the signature `(r, s)` and public key `(x, y)` for the message "abc".
+    let signature = to_value(concat!(
+        "8a67352e424caa9fe6b94efc36cff2e87827f360bb0272e4016e30d8e66f5a41",
+        "b1d3e11deaf39a71ae2d6b8834a07f7118e9d8b479d096736c2e7b66ef230a6a",
+    ));
+    let public_key = to_value(concat!(
+        "9a2248dea0ac6d8cd585e41c03ecd212ca9670008e6bae48b996c676c0775745",
+        "138a52d5c4c8c334e31c8611c75dd85782c188626b5cb114e464c718089624ed",
+    ));
+
+    // Ensure the signature is valid for "abc", and invalid for "abd".
+    for (message, expected) in [("616263", true), ("616264", false)] {
+        let inputs = [signature.clone(), public_key.clone(), to_value(message)];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "verify", inputs.iter(), rng)
+            .unwrap();
+        let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(response.outputs(), [Value::from_str(&format!("{expected}")).unwrap()]);
+    }
+
+    // Prepare a public key that is not on the curve.
+    let invalid_public_key = to_value(concat!(
+        "9a2248dea0ac6d8cd585e41c03ecd212ca9670008e6bae48b996c676c0775745",
+        "138a52d5c4c8c334e31c8611c75dd85782c188626b5cb114e464c718089624ec",
+    ));
+
+    // Ensure the circuit is satisfied, and the signature is rejected, for the invalid public key.
+    let inputs = [signature, invalid_public_key, to_value("616263")];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "verify", inputs.iter(), rng).unwrap();
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("false").unwrap()]);
+
+    // Prepare the execution.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("ecdsa_verify", rng).unwrap();
+
+    // Ensure the execution is valid.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure the digest must be a `[u8; 32u32]`.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program ecdsa_verify_digest.aleo;
+
+function verify:
+    input r0 as [u8; 64u32].private;
+    input r1 as [u8; 64u32].private;
+    input r2 as [u8; 3u32].private;
+    ecdsa.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_iter() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program iteration.aleo;
+
+mapping scores:
+    key as u8.public;
+    value as u64.public;
+
+mapping summary:
+    key as u8.public;
+    value as u8.public;
+
+function sweep:
+    input r0 as u32.public;
+    async sweep r0 into r1;
+    output r1 as iteration.aleo/sweep.future;
+
+finalize sweep:
+    input r0 as u32.public;
+    iter scores r0 2u32 into r1;
+    vec.len r1 into r2;
+    cast r2 into r3 as u8;
+    set r3 into summary[0u8];
+    vec.get r1 0u32 into r4;
+    set r4 into summary[1u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mappings.
+    let scores = Identifier::from_str("scores").unwrap();
+    let summary = Identifier::from_str("summary").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Insert the scores, out of order.
+    for key in ["9u8", "3u8", "7u8"] {
+        let key = Plaintext::from_str(key).unwrap();
+        finalize_store.insert_key_value(*program_id, scores, key, Value::from_str("1u64").unwrap()).unwrap();
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call, to iterate from the second key.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u32").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program_id, "sweep", inputs.iter(), rng).unwrap();
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("iteration", rng).unwrap();
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Ensure the keys were iterated in order, starting from the second key.
+    let get =
+        |key: &str| finalize_store.get_value_speculative(*program_id, summary, &Plaintext::from_str(key).unwrap());
+    assert_eq!(get("0u8").unwrap().unwrap(), Value::from_str("2u8").unwrap());
+    assert_eq!(get("1u8").unwrap().unwrap(), Value::from_str("7u8").unwrap());
+}
+
+#[test]
+fn test_process_saturating_arithmetic() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a program that clamps the outcome of each arithmetic operation.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program saturating.aleo;
+
+function clamp:
+    input r0 as u8.private;
+    input r1 as i8.private;
+    add.sat r0 200u8 into r2;
+    sub.sat r0 200u8 into r3;
+    mul.sat r1 r1 into r4;
+    mul.sat r1 -100i8 into r5;
+    output r2 as u8.private;
+    output r3 as u8.private;
+    output r4 as i8.private;
+    output r5 as i8.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Ensure each operation saturates at the boundary of the type, and is exact otherwise.
+    for (inputs, expected) in [
+        (["100u8", "20i8"], ["255u8", "0u8", "127i8", "-128i8"]),
+        (["50u8", "1i8"], ["250u8", "0u8", "1i8", "-100i8"]),
+        (["255u8", "-2i8"], ["255u8", "55u8", "4i8", "127i8"]),
+    ] {
+        let inputs = inputs.map(|input| Value::<CurrentNetwork>::from_str(input).unwrap());
+        let expected = expected.map(|output| Value::<CurrentNetwork>::from_str(output).unwrap());
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "clamp", inputs.iter(), rng)
+            .unwrap();
+
+        // Evaluate the function.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), expected);
+        // Execute the function.
+        let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(response.outputs(), expected);
+    }
+}
+
+#[test]
+fn test_process_profile_function() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program profile.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    hash.bhp256 r2 into r3 as field;
+    mul r3 r0 into r4;
+    output r4 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Profile the function.
+    let function_name = Identifier::from_str("compute").unwrap();
+    let profiles = process.profile_function::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    assert_eq!(profiles.len(), 1);
+    let profile = &profiles[0];
+    assert_eq!(profile.function_name, function_name);
+    assert_eq!(profile.instructions.len(), 3);
+
+    // Ensure the hash is the most expensive instruction.
+    assert!(profile.most_expensive_instructions()[0].instruction.starts_with("hash.bhp256"));
+
+    // Ensure the constraints of the request, instructions, and response add up to the total.
+    let num_instruction_constraints =
+        profile.instructions.iter().map(|instruction| instruction.count.num_constraints).sum::<u64>();
+    assert_eq!(
+        profile.request.num_constraints + num_instruction_constraints + profile.response.num_constraints,
+        profile.total.num_constraints
+    );
+
+    // Ensure the profile is displayed as JSON.
+    let json = serde_json::from_str::<serde_json::Value>(&profile.to_string()).unwrap();
+    assert_eq!(json["instructions"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_process_profile_function_with_closure() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program profile_closure.aleo;
+
+closure hash_twice:
+    input r0 as field;
+    hash.bhp256 r0 into r1 as field;
+    add r1 r1 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    call hash_twice r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    // Profile the function.
+    let function_name = Identifier::from_str("compute").unwrap();
+    let profiles = process.profile_function::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    assert_eq!(profiles.len(), 1);
+    let profile = &profiles[0];
+    assert_eq!(profile.instructions.len(), 1);
+
+    // Ensure the instructions of the closure are nested scopes of the `call` instruction.
+    let call = &profile.instructions[0];
+    assert!(call.instruction.starts_with("call hash_twice"));
+    let names = call.scopes.iter().map(|scope| scope.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["hash_bhp256", "add"]);
+    assert!(call.scopes[0].count.num_constraints > 0);
+    assert!(call.scopes.iter().map(|scope| scope.count.num_constraints).sum::<u64>() <= call.count.num_constraints);
+
+    // Ensure the nested scopes are displayed in the JSON.
+    let json = serde_json::from_str::<serde_json::Value>(&profile.to_string()).unwrap();
+    assert_eq!(json["instructions"][0]["scopes"][0]["name"], "hash_bhp256");
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// The number of variables and constraints in a segment of a circuit.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CircuitCount {
+    pub num_constants: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    pub num_constraints: u64,
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl CircuitCount {
+    /// Returns the current count of the circuit.
+    pub fn current<A: circuit::Aleo>() -> Self {
+        Self::from(A::count())
+    }
+
+    /// Returns the count of the circuit segment from the given `start` count to this count.
+    pub fn since(&self, start: &Self) -> Self {
+        Self {
+            num_constants: self.num_constants.saturating_sub(start.num_constants),
+            num_public: self.num_public.saturating_sub(start.num_public),
+            num_private: self.num_private.saturating_sub(start.num_private),
+            num_constraints: self.num_constraints.saturating_sub(start.num_constraints),
+            num_nonzeros: (
+                self.num_nonzeros.0.saturating_sub(start.num_nonzeros.0),
+                self.num_nonzeros.1.saturating_sub(start.num_nonzeros.1),
+                self.num_nonzeros.2.saturating_sub(start.num_nonzeros.2),
+            ),
+        }
+    }
+}
+
+impl From<(u64, u64, u64, u64, (u64, u64, u64))> for CircuitCount {
+    /// Initializes the count from `(num_constants, num_public, num_private, num_constraints, num_nonzeros)`.
+    fn from(count: (u64, u64, u64, u64, (u64, u64, u64))) -> Self {
+        let (num_constants, num_public, num_private, num_constraints, num_nonzeros) = count;
+        Self { num_constants, num_public, num_private, num_constraints, num_nonzeros }
+    }
+}
+
+impl Serialize for CircuitCount {
+    /// Serializes the count into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut count = serializer.serialize_struct("CircuitCount", 5)?;
+        count.serialize_field("constants", &self.num_constants)?;
+        count.serialize_field("public", &self.num_public)?;
+        count.serialize_field("private", &self.num_private)?;
+        count.serialize_field("constraints", &self.num_constraints)?;
+        count.serialize_field("nonzeros", &[self.num_nonzeros.0, self.num_nonzeros.1, self.num_nonzeros.2])?;
+        count.end()
+    }
+}
+
+/// The count of a scope in the circuit, such as an instruction of a closure, or a gadget that enters its own scope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopeProfile {
+    /// The name of the scope.
+    pub name: String,
+    /// The count of the scope, including its nested scopes.
+    pub count: CircuitCount,
+    /// The nested scopes, in the order they exited.
+    pub scopes: Vec<ScopeProfile>,
+}
+
+impl ScopeProfile {
+    /// Returns the scope profiles from the given full names and counts of the exited scopes, nested by their names.
+    /// The scopes must be in the order they exited, so each scope is preceded by its nested scopes.
+    pub fn from_exited_scopes(
+        parent: &str,
+        exited_scopes: &[(String, (u64, u64, u64, u64, (u64, u64, u64)))],
+    ) -> Vec<Self> {
+        // Initialize a list of the scopes that have not been attached to their parent, with their depths.
+        let mut pending: Vec<(usize, ScopeProfile)> = Vec::new();
+        for (scope, count) in exited_scopes {
+            // Retrieve the path of the scope, relative to the parent.
+            let Some(path) = scope.strip_prefix(parent).and_then(|path| path.strip_prefix('.')) else { continue };
+            let (depth, name) = (path.split('.').count(), path.rsplit('.').next().unwrap_or(path));
+            // Attach the preceding scopes that are one level deeper, as they are nested in this scope.
+            let num_nested = pending.iter().rev().take_while(|(nested_depth, _)| *nested_depth > depth).count();
+            let scopes = pending.drain(pending.len() - num_nested..).map(|(_, scope)| scope).collect();
+            pending.push((depth, ScopeProfile { name: name.to_string(), count: CircuitCount::from(*count), scopes }));
+        }
+        pending.into_iter().map(|(_, scope)| scope).collect()
+    }
+}
+
+impl Serialize for ScopeProfile {
+    /// Serializes the scope profile into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut profile = serializer.serialize_struct("ScopeProfile", 3)?;
+        profile.serialize_field("name", &self.name)?;
+        profile.serialize_field("count", &self.count)?;
+        profile.serialize_field("scopes", &self.scopes)?;
+        profile.end()
+    }
+}
+
+/// The count of an instruction in the circuit of a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionProfile {
+    /// The instruction, as it was executed after optimization.
+    pub instruction: String,
+    /// The count of the instruction.
+    pub count: CircuitCount,
+    /// The scopes nested in the instruction, such as the instructions of a closure that it calls.
+    pub scopes: Vec<ScopeProfile>,
+}
+
+impl Serialize for InstructionProfile {
+    /// Serializes the instruction profile into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut profile = serializer.serialize_struct("InstructionProfile", 3)?;
+        profile.serialize_field("instruction", &self.instruction)?;
+        profile.serialize_field("count", &self.count)?;
+        profile.serialize_field("scopes", &self.scopes)?;
+        profile.end()
+    }
+}
+
+/// The counts of the circuit of a function, attributed to the request, each instruction, and the response.
+///
+/// Note: A `call` to a closure is attributed to the `call` instruction, and each instruction of the closure
+/// is a nested scope of it. A `call` to a function is a separate circuit, so it is profiled separately.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitProfile<N: Network> {
+    /// The program ID of the function.
+    pub program_id: ProgramID<N>,
+    /// The name of the function.
+    pub function_name: Identifier<N>,
+    /// The count for verifying the request.
+    pub request: CircuitCount,
+    /// The counts of the instructions, in the order they were executed.
+    pub instructions: Vec<InstructionProfile>,
+    /// The count for constructing and verifying the response.
+    pub response: CircuitCount,
+    /// The count of the whole circuit.
+    pub total: CircuitCount,
+}
+
+impl<N: Network> CircuitProfile<N> {
+    /// Returns the instructions, sorted by their number of constraints, from the most expensive.
+    pub fn most_expensive_instructions(&self) -> Vec<&InstructionProfile> {
+        let mut instructions = self.instructions.iter().collect::<Vec<_>>();
+        instructions.sort_by(|a, b| b.count.num_constraints.cmp(&a.count.num_constraints));
+        instructions
+    }
+}
+
+impl<N: Network> Serialize for CircuitProfile<N> {
+    /// Serializes the circuit profile into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut profile = serializer.serialize_struct("CircuitProfile", 6)?;
+        profile.serialize_field("program_id", &self.program_id)?;
+        profile.serialize_field("function_name", &self.function_name)?;
+        profile.serialize_field("request", &self.request)?;
+        profile.serialize_field("instructions", &self.instructions)?;
+        profile.serialize_field("response", &self.response)?;
+        profile.serialize_field("total", &self.total)?;
+        profile.end()
+    }
+}
+
+impl<N: Network> Display for CircuitProfile<N> {
+    /// Displays the circuit profile as a JSON string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
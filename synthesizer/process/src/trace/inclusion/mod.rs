@@ -113,6 +113,14 @@ impl<N: Network> Inclusion<N> {
     }
 }
 
+impl<N: Network> Inclusion<N> {
+    /// Returns the commitments of the input records that are not from a previous local transition,
+    /// which require a state path to the global state root.
+    pub fn global_commitments(&self) -> impl '_ + Iterator<Item = Field<N>> {
+        self.input_tasks.values().flatten().filter(|task| task.local.is_none()).map(|task| task.commitment)
+    }
+}
+
 impl<N: Network> Inclusion<N> {
     /// Returns the verifier public inputs for the given global state root and transitions.
     pub fn prepare_verifier_inputs<'a>(
@@ -159,6 +167,27 @@ impl<N: Network> Inclusion<N> {
 
         Ok(batch_verifier_inputs)
     }
+
+    /// Returns the verifier public inputs for the batched inclusion circuit, for the given global state root
+    /// and transitions, as a single instance (or no instance, if the transitions have no input records).
+    ///
+    /// The public inputs are the global state root of the anchor, followed by the public inputs of each
+    /// input record, in the order of [`Inclusion::prepare_verifier_inputs`].
+    pub fn prepare_batched_verifier_inputs<'a>(
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+    ) -> Result<Vec<Vec<N::Field>>> {
+        // Construct the verifier inputs for each input record.
+        let verifier_inputs = Self::prepare_verifier_inputs(global_state_root, transitions)?;
+        // If there are no input records, there is no batched inclusion instance.
+        if verifier_inputs.is_empty() {
+            return Ok(vec![]);
+        }
+        // Construct the batched verifier inputs, skipping the leading `one` of each input record.
+        let mut batched_verifier_inputs = vec![N::Field::one(), *global_state_root];
+        batched_verifier_inputs.extend(verifier_inputs.into_iter().flat_map(|inputs| inputs.into_iter().skip(1)));
+        Ok(vec![batched_verifier_inputs])
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -235,4 +264,83 @@ impl<N: Network> InclusionAssignment<N> {
         // Eject the assignment and reset the circuit environment.
         Ok(A::eject_assignment_and_reset())
     }
+
+    /// The circuit for batched state path verification, for a function that consumes multiple records.
+    ///
+    /// Unlike [`InclusionAssignment::to_circuit_assignment`], the block-level hashes of the state paths are
+    /// computed once for the batch, on an anchor, which requires every global state path to be from the same block.
+    /// The anchor is the first global state path (or the first state path, if there are none), and is injected
+    /// first, with its global state root as the first public input. The assignments are then injected in their
+    /// given order, so the circuit does not depend on which of them are global. For every assignment, the public
+    /// inputs are its global state root, local state root, and serial number, as in
+    /// [`Inclusion::prepare_batched_verifier_inputs`].
+    ///
+    /// Note: The inclusion proving and verifying keys are for a single state path, so this circuit
+    /// must be proven and verified with keys synthesized for the same number of assignments.
+    pub fn to_batched_circuit_assignment<A: circuit::Aleo<Network = N>>(
+        assignments: &[Self],
+    ) -> Result<circuit::Assignment<N::Field>> {
+        use circuit::Inject;
+
+        // Ensure there is at least one assignment.
+        ensure!(!assignments.is_empty(), "Inclusion expected at least one assignment to batch");
+        // Anchor the batch on the first global state path, if there is one.
+        let anchor = assignments.iter().find(|assignment| assignment.is_global).unwrap_or(&assignments[0]);
+        // Ensure the global state paths are from the same block.
+        let block_hash = anchor.state_path.block_hash();
+        ensure!(
+            assignments
+                .iter()
+                .all(|assignment| !assignment.is_global || assignment.state_path.block_hash() == block_hash),
+            "Inclusion expected the batched global state paths to be from the same block"
+        );
+
+        // Ensure the circuit environment is clean.
+        assert_eq!(A::count(), (0, 1, 0, 0, (0, 0, 0)));
+        A::reset();
+
+        // Inject the anchor as `Mode::Private` (with a global state root as `Mode::Public`).
+        let anchor = circuit::StatePath::<A>::new(circuit::Mode::Private, anchor.state_path.clone());
+
+        let mut state_paths = Vec::with_capacity(assignments.len());
+        let mut is_global = Vec::with_capacity(assignments.len());
+        let mut local_state_roots = Vec::with_capacity(assignments.len());
+
+        for assignment in assignments {
+            // Inject the state path as `Mode::Private` (with a global state root as `Mode::Public`).
+            let state_path = circuit::StatePath::<A>::new(circuit::Mode::Private, assignment.state_path.clone());
+            // Inject the commitment as `Mode::Private`.
+            let commitment = circuit::Field::<A>::new(circuit::Mode::Private, assignment.commitment);
+            // Inject the gamma as `Mode::Private`.
+            let gamma = circuit::Group::<A>::new(circuit::Mode::Private, assignment.gamma);
+
+            // Inject the local state root as `Mode::Public`.
+            let local_state_root = circuit::Field::<A>::new(circuit::Mode::Public, *assignment.local_state_root);
+
+            // Inject the serial number as `Mode::Public`.
+            let serial_number = circuit::Field::<A>::new(circuit::Mode::Public, assignment.serial_number);
+            // Compute the candidate serial number.
+            let candidate_serial_number =
+                circuit::Record::<A, circuit::Plaintext<A>>::serial_number_from_gamma(&gamma, commitment.clone());
+            // Enforce that the candidate serial number is equal to the serial number.
+            A::assert_eq(&candidate_serial_number, &serial_number);
+
+            // Enforce the starting leaf is the claimed commitment.
+            A::assert_eq(state_path.transition_leaf().id(), commitment);
+
+            state_paths.push(state_path);
+            // Inject the 'is_global' flag as `Mode::Private`.
+            is_global.push(circuit::Boolean::<A>::new(circuit::Mode::Private, assignment.is_global));
+            local_state_roots.push(local_state_root);
+        }
+
+        // Enforce the state paths from leaf to root are correct.
+        A::assert(circuit::StatePath::verify_batch_with_anchor(&anchor, &state_paths, &is_global, &local_state_roots));
+
+        #[cfg(debug_assertions)]
+        Stack::log_circuit::<A, _>(&format!("State Paths for {} inputs", assignments.len()));
+
+        // Eject the assignment and reset the circuit environment.
+        Ok(A::eject_assignment_and_reset())
+    }
 }
@@ -15,6 +15,9 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod circuit_profile;
+pub use circuit_profile::*;
+
 mod inclusion;
 pub use inclusion::*;
 
@@ -24,8 +27,8 @@ use console::{
     program::{InputID, Locator},
 };
 use ledger_block::{Execution, Fee, Transition};
-use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use ledger_query::{LocalQuery, QueryTrait};
+use synthesizer_snark::{Proof, ProvingKey, UniversalSRS, VerifyingKey};
 
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
@@ -132,6 +135,17 @@ impl<N: Network> Trace<N> {
         Ok(())
     }
 
+    /// Returns the inclusion assignments and local state root for the current transition(s),
+    /// placing the input records in a local state, instead of fetching their state paths from the ledger.
+    ///
+    /// Note: The local state root does not exist in the ledger, so the resulting execution is not broadcastable.
+    pub fn prepare_local(&mut self) -> Result<()> {
+        // Construct the local state for the input records.
+        let query = LocalQuery::new(self.inclusion_tasks.global_commitments())?;
+        // Compute the inclusion assignments.
+        self.prepare(query)
+    }
+
     /// Returns the inclusion assignments and global state root for the current transition(s).
     #[cfg(feature = "async")]
     pub async fn prepare_async(&mut self, query: impl QueryTrait<N>) -> Result<()> {
@@ -169,7 +183,64 @@ impl<N: Network> Trace<N> {
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
         let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, None, rng)?;
+        // Return the execution.
+        Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
+    }
+
+    /// Returns the proving and verifying key of the batched inclusion circuit, for the current inclusion assignments.
+    ///
+    /// The keys are for the number of inclusion assignments, and may be reused for any execution
+    /// with the same number of input records.
+    pub fn synthesize_batched_inclusion_keys<A: circuit::Aleo<Network = N>>(
+        &self,
+        universal_srs: &UniversalSRS<N>,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Construct the batched inclusion assignment.
+        let assignment = InclusionAssignment::to_batched_circuit_assignment::<A>(inclusion_assignments)?;
+        // Synthesize the proving and verifying key.
+        universal_srs.to_circuit_key(N::INCLUSION_FUNCTION_NAME, &assignment)
+    }
+
+    /// Returns a new execution with a proof, for the current inclusion assignments and global state root,
+    /// where the inclusion assignments are proven in one batched inclusion circuit, with the given proving key.
+    ///
+    /// The block-level hashes of the state paths are computed once, instead of once per input record,
+    /// which requires every input record from the ledger to be from the same block.
+    /// The proving key must be from `Trace::synthesize_batched_inclusion_keys`.
+    pub fn prove_execution_with_batched_inclusion<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        locator: &str,
+        inclusion_proving_key: &ProvingKey<N>,
+        rng: &mut R,
+    ) -> Result<Execution<N>> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot call 'prove_execution' for a fee type");
+        // Ensure there are no fee transitions.
+        ensure!(
+            self.transitions.iter().all(|transition| !(transition.is_fee_private() || transition.is_fee_public())),
+            "The trace cannot prove execution for a fee, call 'prove_fee' instead"
+        );
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Construct the proving tasks.
+        let proving_tasks = self.transition_tasks.values().cloned().collect();
+        // Compute the proof.
+        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+            locator,
+            proving_tasks,
+            inclusion_assignments,
+            *global_state_root,
+            Some(inclusion_proving_key),
+            rng,
+        )?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
@@ -201,6 +272,7 @@ impl<N: Network> Trace<N> {
             proving_tasks,
             inclusion_assignments,
             *global_state_root,
+            None,
             rng,
         )?;
         // Return the fee.
@@ -223,7 +295,38 @@ impl<N: Network> Trace<N> {
         // Retrieve the proof.
         let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
         // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
+        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), None, proof) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("Execution is invalid - {e}"),
+        }
+    }
+
+    /// Checks the proof for the execution, where the inclusion assignments are proven in one batched
+    /// inclusion circuit, with the given verifying key from `Trace::synthesize_batched_inclusion_keys`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_execution_proof_with_batched_inclusion(
+        locator: &str,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        inclusion_verifying_key: &VerifyingKey<N>,
+        execution: &Execution<N>,
+    ) -> Result<()> {
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        // Verify the execution proof.
+        match Self::verify_batch(
+            locator,
+            verifier_inputs,
+            global_state_root,
+            execution.transitions(),
+            Some(inclusion_verifying_key),
+            proof,
+        ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
@@ -246,6 +349,7 @@ impl<N: Network> Trace<N> {
             vec![verifier_inputs],
             global_state_root,
             [fee.transition()].into_iter(),
+            None,
             proof,
         ) {
             Ok(()) => Ok(()),
@@ -256,11 +360,13 @@ impl<N: Network> Trace<N> {
 
 impl<N: Network> Trace<N> {
     /// Returns the global state root and proof for the given assignments.
+    /// If a batched inclusion proving key is given, the inclusion assignments are proven in one batched circuit.
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
         global_state_root: N::StateRoot,
+        batched_inclusion_proving_key: Option<&ProvingKey<N>>,
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
         // Ensure the global state root is not zero.
@@ -278,15 +384,25 @@ impl<N: Network> Trace<N> {
             if global_state_root != assignment.state_path.global_state_root() {
                 bail!("Inclusion expected the global state root to be the same across iterations")
             }
-            // Add the assignment to the assignments.
-            batch_inclusions.push(assignment.to_circuit_assignment::<A>()?);
+            // Add the assignment to the assignments, unless the assignments are batched.
+            if batched_inclusion_proving_key.is_none() {
+                batch_inclusions.push(assignment.to_circuit_assignment::<A>()?);
+            }
         }
 
-        if !batch_inclusions.is_empty() {
-            // Fetch the inclusion proving key.
-            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+        match batched_inclusion_proving_key {
+            // Insert the batched inclusion proving key and assignment.
+            Some(proving_key) if !inclusion_assignments.is_empty() => {
+                let assignment = InclusionAssignment::to_batched_circuit_assignment::<A>(inclusion_assignments)?;
+                proving_tasks.push((proving_key.clone(), vec![assignment]));
+            }
             // Insert the inclusion proving key and assignments.
-            proving_tasks.push((proving_key, batch_inclusions));
+            None if !batch_inclusions.is_empty() => {
+                // Fetch the inclusion proving key.
+                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+                proving_tasks.push((proving_key, batch_inclusions));
+            }
+            _ => (),
         }
 
         // Compute the proof.
@@ -296,20 +412,28 @@ impl<N: Network> Trace<N> {
     }
 
     /// Checks the proof for the given inputs.
+    /// If a batched inclusion verifying key is given, the inclusion is verified as one batched circuit.
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
         mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+        batched_inclusion_verifying_key: Option<&VerifyingKey<N>>,
         proof: &Proof<N>,
     ) -> Result<()> {
-        // Construct the batch of inclusion verifier inputs.
-        let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
+        // Construct the batch of inclusion verifier inputs, and fetch the inclusion verifying key.
+        let (batch_inclusion_inputs, verifying_key) = match batched_inclusion_verifying_key {
+            Some(verifying_key) => {
+                (Inclusion::prepare_batched_verifier_inputs(global_state_root, transitions)?, verifying_key.clone())
+            }
+            None => (
+                Inclusion::prepare_verifier_inputs(global_state_root, transitions)?,
+                VerifyingKey::<N>::new(N::inclusion_verifying_key().clone()),
+            ),
+        };
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
         if !batch_inclusion_inputs.is_empty() {
-            // Fetch the inclusion verifying key.
-            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
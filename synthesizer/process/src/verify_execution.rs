@@ -19,6 +19,27 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_with(execution, None)
+    }
+
+    /// Verifies the given execution is valid, where the inclusion of its input records is proven in one
+    /// batched inclusion circuit, with the given verifying key from `Trace::synthesize_batched_inclusion_keys`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_with_batched_inclusion(
+        &self,
+        execution: &Execution<N>,
+        inclusion_verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        self.verify_execution_with(execution, Some(inclusion_verifying_key))
+    }
+
+    /// Verifies the given execution is valid, with the batched inclusion verifying key, if one is given.
+    fn verify_execution_with(
+        &self,
+        execution: &Execution<N>,
+        batched_inclusion_verifying_key: Option<&VerifyingKey<N>>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -141,7 +162,15 @@ impl<N: Network> Process<N> {
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
         // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        match batched_inclusion_verifying_key {
+            Some(inclusion_verifying_key) => Trace::verify_execution_proof_with_batched_inclusion(
+                &locator,
+                verifier_inputs,
+                inclusion_verifying_key,
+                execution,
+            )?,
+            None => Trace::verify_execution_proof(&locator, verifier_inputs, execution)?,
+        }
 
         lap!(timer, "Verify the proof");
 
@@ -353,7 +353,9 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if the struct was previously added.
     /// This method will halt if the struct name is already in use in the program.
     /// This method will halt if the struct name is a reserved opcode or keyword.
-    /// This method will halt if any structs in the struct's members are not already defined.
+    /// This method will halt if any structs in the struct's members are not already defined,
+    /// except for a vector of the struct itself, which is bounded by `N::MAX_STRUCT_RECURSION_DEPTH`.
+    /// This method will halt if the struct nests more than `N::MAX_STRUCT_RECURSION_ELEMENTS` copies of itself.
     #[inline]
     fn add_struct(&mut self, struct_: StructType<N>) -> Result<()> {
         // Retrieve the struct name.
@@ -370,10 +372,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(!struct_.members().is_empty(), "Struct '{struct_name}' is missing members.");
 
         // Ensure all struct members are well-formed.
-        // Note: This design ensures cyclic references are not possible.
+        // Note: This design ensures cyclic references are not possible, other than a struct referencing itself
+        // through a vector, which is bounded in depth by `N::MAX_STRUCT_RECURSION_DEPTH`.
         for (identifier, plaintext_type) in struct_.members() {
             // Ensure the member name is not a reserved keyword.
             ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
+            // If the member is a vector of the struct itself, it is well-formed.
+            if struct_.is_recursive_member(plaintext_type) {
+                continue;
+            }
             // Ensure the member type is already defined in the program.
             match plaintext_type {
                 PlaintextType::Literal(_) => continue,
@@ -391,9 +398,25 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Vector(vector_type) => {
+                    if let PlaintextType::Struct(struct_name) = vector_type.element_type() {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
+        // Ensure the padded size of the struct is bounded, as every self-referential vector is padded.
+        let num_elements = struct_.num_recursive_elements(N::MAX_STRUCT_RECURSION_DEPTH);
+        ensure!(
+            num_elements <= N::MAX_STRUCT_RECURSION_ELEMENTS,
+            "Struct '{struct_name}' nests {num_elements} copies of itself, exceeding the maximum of {}",
+            N::MAX_STRUCT_RECURSION_ELEMENTS
+        );
+
         // Add the struct name to the identifiers.
         if self.identifiers.insert(struct_name, ProgramDefinition::Struct).is_some() {
             bail!("'{}' already exists in the program.", struct_name)
@@ -445,6 +468,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Vector(vector_type) => {
+                    if let PlaintextType::Struct(struct_name) = vector_type.element_type() {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
@@ -705,6 +736,49 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_recursive_struct() -> Result<()> {
+        // Create a new struct that references itself through a vector.
+        let struct_ = StructType::<CurrentNetwork>::from_str(
+            r"
+struct node:
+    value as u64;
+    children as [node; <=2u32];",
+        )?;
+
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {struct_}"))?;
+        // Ensure the struct was added.
+        assert_eq!(&struct_, program.get_struct(&Identifier::from_str("node")?)?);
+
+        // Ensure the padded size of the struct is the number of nested copies, up to the maximum recursion depth.
+        assert_eq!(struct_.num_recursive_elements(CurrentNetwork::MAX_STRUCT_RECURSION_DEPTH), 31);
+
+        // Ensure a struct with too many nested copies is rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct node:
+    value as u64;
+    children as [node; <=32u32];",
+        );
+        assert!(program.is_err());
+
+        // Ensure a vector of an undefined struct is still rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct node:
+    value as u64;
+    children as [leaf; <=2u32];",
+        );
+        assert!(program.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, PlaintextType, Register, Value},
+    types::{Address, Boolean, StringType, U32},
+};
+
+/// An iter command, e.g. `iter accounts r0 8u32 into r1;`.
+/// Retrieves up to `limit` keys of `mapping`, starting at the index in `start`, and stores them in `destination`.
+/// The keys are ordered by their serialized bytes in storage, and are stored as a vector of type `[key_type; <=limit]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Iter<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The index of the first key to retrieve.
+    start: Operand<N>,
+    /// The maximum number of keys to retrieve.
+    limit: U32<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Iter<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("iter")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.start.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the start index.
+    #[inline]
+    pub const fn start(&self) -> &Operand<N> {
+        &self.start
+    }
+
+    /// Returns the maximum number of keys to retrieve.
+    #[inline]
+    pub const fn limit(&self) -> &U32<N> {
+        &self.limit
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> Iter<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the start index.
+        let start = match registers.load_literal(stack, &self.start)? {
+            Literal::U32(start) => *start as usize,
+            literal => bail!("Expected the start index of '{}' to be a 'u32', found '{literal}'", Self::opcode()),
+        };
+
+        // Retrieve the keys.
+        let mut keys = store.get_keys_speculative(*stack.program_id(), self.mapping, start, *self.limit as usize)?;
+        let length = U32::new(u32::try_from(keys.len())?);
+
+        // Pad the keys to the limit, with the default value of the key type.
+        if keys.len() < *self.limit as usize {
+            let key_type = stack.program().get_mapping(&self.mapping)?.key().plaintext_type().clone();
+            let padding = default_plaintext(stack, &key_type, 0)?;
+            keys.resize(*self.limit as usize, padding);
+        }
+
+        // Assign the keys to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::new_vector(length, keys)?))
+    }
+}
+
+/// Returns the default value of the given plaintext type, which is used to pad the vector of keys.
+/// The `recursion` is the number of times the enclosing struct is nested within itself,
+/// if the plaintext type is (or is an element of) a self-referential vector, and is otherwise zero.
+fn default_plaintext<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+    recursion: usize,
+) -> Result<Plaintext<N>> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => {
+            let literal = match literal_type {
+                LiteralType::Address => Literal::Address(Address::zero()),
+                LiteralType::Boolean => Literal::Boolean(Boolean::new(false)),
+                LiteralType::Field => Literal::Field(Zero::zero()),
+                LiteralType::Group => Literal::Group(Zero::zero()),
+                LiteralType::I8 => Literal::I8(Zero::zero()),
+                LiteralType::I16 => Literal::I16(Zero::zero()),
+                LiteralType::I32 => Literal::I32(Zero::zero()),
+                LiteralType::I64 => Literal::I64(Zero::zero()),
+                LiteralType::I128 => Literal::I128(Zero::zero()),
+                LiteralType::U8 => Literal::U8(Zero::zero()),
+                LiteralType::U16 => Literal::U16(Zero::zero()),
+                LiteralType::U32 => Literal::U32(Zero::zero()),
+                LiteralType::U64 => Literal::U64(Zero::zero()),
+                LiteralType::U128 => Literal::U128(Zero::zero()),
+                LiteralType::Scalar => Literal::Scalar(Zero::zero()),
+                LiteralType::Signature => bail!("Cannot iterate over a mapping with 'signature' keys"),
+                LiteralType::String => Literal::String(StringType::new("")),
+            };
+            Ok(Plaintext::from(literal))
+        }
+        PlaintextType::Struct(struct_name) => {
+            let struct_ = stack.program().get_struct(struct_name)?;
+            let members = struct_
+                .members()
+                .iter()
+                .map(|(member_name, member_type)| {
+                    // Determine the recursion of the member, which is only tracked for a vector of the struct.
+                    let recursion = match struct_.is_recursive_member(member_type) {
+                        true => recursion + 1,
+                        false => 0,
+                    };
+                    Ok((*member_name, default_plaintext(stack, member_type, recursion)?))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok(Plaintext::Struct(members, Default::default()))
+        }
+        PlaintextType::Array(array_type) => {
+            let element = default_plaintext(stack, array_type.next_element_type(), 0)?;
+            Ok(Plaintext::Array(vec![element; **array_type.length() as usize], Default::default()))
+        }
+        // Past the maximum recursion depth, a self-referential vector is the empty sentinel.
+        PlaintextType::Vector(..) if recursion > N::MAX_STRUCT_RECURSION_DEPTH => {
+            Plaintext::new_vector(U32::zero(), vec![])
+        }
+        PlaintextType::Vector(vector_type) => {
+            let element = default_plaintext(stack, vector_type.element_type(), recursion)?;
+            Plaintext::new_vector(U32::zero(), vec![element; **vector_type.max_length() as usize])
+        }
+    }
+}
+
+impl<N: Network> Parser for Iter<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the start operand from the string.
+        let (string, start) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the limit from the string.
+        let (string, limit) =
+            map_res(U32::parse, |limit| match *limit > 0 && *limit as usize <= N::MAX_ARRAY_ELEMENTS {
+                true => Ok(limit),
+                false => Err(error(format!("Invalid limit for '{}': {limit}", Self::opcode()))),
+            })(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, start, limit, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Iter<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Iter<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Iter<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping, start operand, and limit.
+        write!(f, "{} {} {} into ", self.mapping, self.start, self.limit)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Iter<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the start operand.
+        let start = Operand::read_le(&mut reader)?;
+        // Read the limit.
+        let limit = U32::read_le(&mut reader)?;
+        // Ensure the limit is valid.
+        if *limit == 0 || *limit as usize > N::MAX_ARRAY_ELEMENTS {
+            return Err(error(format!("Invalid limit for '{}': {limit}", Self::opcode())));
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, start, limit, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Iter<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the start operand.
+        self.start.write_le(&mut writer)?;
+        // Write the limit.
+        self.limit.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, iter) = Iter::<CurrentNetwork>::parse("iter account r0 8u32 into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(iter.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(iter.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(iter.start, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(iter.limit, U32::new(8), "The limit is incorrect");
+        assert_eq!(iter.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the limit must be within the bounds of a vector.
+        assert!(Iter::<CurrentNetwork>::parse("iter account r0 0u32 into r1;").is_err());
+        assert!(Iter::<CurrentNetwork>::parse("iter account r0 33u32 into r1;").is_err());
+        assert!(Iter::<CurrentNetwork>::parse("iter account r0 r1 into r2;").is_err());
+    }
+}
@@ -27,6 +27,9 @@ pub use get::*;
 mod get_or_use;
 pub use get_or_use::*;
 
+mod iter;
+pub use iter::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -72,6 +75,8 @@ pub enum Command<N: Network> {
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     /// If the key is not present, `default` is stored `destination`.
     GetOrUse(GetOrUse<N>),
+    /// Gets up to `limit` keys in `mapping`, starting at the `start` operand, and stores the result into `destination`.
+    Iter(Iter<N>),
     /// Generates a random value using the `rand.chacha` command and stores the result into `destination`.
     RandChaCha(RandChaCha<N>),
     /// Removes the (`key`, `value`) entry from the `mapping`.
@@ -95,6 +100,7 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Contains(contains) => vec![contains.destination().clone()],
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::Iter(iter) => vec![iter.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
             Command::Await(_)
             | Command::BranchEq(_)
@@ -164,6 +170,8 @@ impl<N: Network> Command<N> {
             Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'get.or_use' command, and return no finalize operation.
             Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'iter' command, and return no finalize operation.
+            Command::Iter(iter) => iter.finalize(stack, store, registers).map(|_| None),
             // Finalize the `rand.chacha` command, and return no finalize operation.
             Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
             // Finalize the 'remove' command, and return the finalize operation.
@@ -208,8 +216,10 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `iter` command.
+            11 => Ok(Self::Iter(Iter::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            12.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +294,12 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Iter(iter) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `iter` command.
+                iter.write_le(&mut writer)
+            }
         }
     }
 }
@@ -299,6 +315,7 @@ impl<N: Network> Parser for Command<N> {
             map(Contains::parse, |contains| Self::Contains(contains)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
+            map(Iter::parse, |iter| Self::Iter(iter)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
             map(Remove::parse, |remove| Self::Remove(remove)),
             map(Set::parse, |set| Self::Set(set)),
@@ -344,6 +361,7 @@ impl<N: Network> Display for Command<N> {
             Self::Contains(contains) => Display::fmt(contains, f),
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrUse(get_or_use) => Display::fmt(get_or_use, f),
+            Self::Iter(iter) => Display::fmt(iter, f),
             Self::RandChaCha(rand_chacha) => Display::fmt(rand_chacha, f),
             Self::Remove(remove) => Display::fmt(remove, f),
             Self::Set(set) => Display::fmt(set, f),
@@ -401,6 +419,12 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // Iter
+        let expected = "iter object r0 8u32 into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -478,6 +502,12 @@ mod tests {
         assert_eq!(Command::GetOrUse(GetOrUse::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // Iter
+        let expected = "iter object r0 8u32 into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Iter(Iter::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
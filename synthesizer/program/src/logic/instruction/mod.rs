@@ -71,6 +71,8 @@ pub enum Instruction<N: Network> {
     AbsWrapped(AbsWrapped<N>),
     /// Adds `first` with `second`, storing the outcome in `destination`.
     Add(Add<N>),
+    /// Adds `first` with `second`, bounding the sum to the boundary of the type, and storing the outcome in `destination`.
+    AddSaturating(AddSaturating<N>),
     /// Adds `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     AddWrapped(AddWrapped<N>),
     /// Performs a bitwise `and` operation on `first` and `second`, storing the outcome in `destination`.
@@ -105,6 +107,8 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether `signature` is a valid ECDSA signature on `digest` for the secp256k1 `public_key`, storing the outcome in `destination`.
+    EcdsaVerify(EcdsaVerify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -145,6 +149,10 @@ pub enum Instruction<N: Network> {
     HashManyPSD4(HashManyPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashManyPSD8(HashManyPSD8<N>),
+    /// Performs a Keccak hash on the bytes of a `u8` array, outputting the 256-bit digest as bytes.
+    HashKeccak256Native(HashKeccak256Native<N>),
+    /// Performs a SHA3 hash on the bytes of a `u8` array, outputting the 256-bit digest as bytes.
+    HashSha3_256Native(HashSha3_256Native<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -159,6 +167,8 @@ pub enum Instruction<N: Network> {
     Modulo(Modulo<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
     Mul(Mul<N>),
+    /// Multiplies `first` with `second`, bounding the product to the boundary of the type, and storing the outcome in `destination`.
+    MulSaturating(MulSaturating<N>),
     /// Multiplies `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     MulWrapped(MulWrapped<N>),
     /// Returns `false` if `first` and `second` are true, storing the outcome in `destination`.
@@ -179,6 +189,10 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Rotates the bits of `first` left by `second` bits, storing the outcome in `destination`.
+    Rol(Rol<N>),
+    /// Rotates the bits of `first` right by `second` bits, storing the outcome in `destination`.
+    Ror(Ror<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, continuing past the boundary of the type, storing the outcome in `destination`.
@@ -193,12 +207,26 @@ pub enum Instruction<N: Network> {
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
     SquareRoot(SquareRoot<N>),
+    /// Concatenates the strings `first` and `second`, storing the outcome in `destination`.
+    StrConcat(StrConcat<N>),
+    /// Computes the number of bytes in the string `first`, storing the outcome in `destination`.
+    StrLen(StrLen<N>),
+    /// Retrieves `third` bytes of the string `first`, starting at byte `second`, storing the outcome in `destination`.
+    StrSlice(StrSlice<N>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<N>),
+    /// Computes `first - second`, bounding the difference to the boundary of the type, and storing the outcome in `destination`.
+    SubSaturating(SubSaturating<N>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     SubWrapped(SubWrapped<N>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<N>),
+    /// Retrieves the element of the vector `first` at index `second`, storing the element in `destination`.
+    VecGet(VecGet<N>),
+    /// Computes the length of the vector `first`, storing the length in `destination`.
+    VecLen(VecLen<N>),
+    /// Replaces the element of the vector `first` at index `second` with `third`, storing the vector in `destination`.
+    VecSet(VecSet<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
 }
@@ -235,6 +263,7 @@ macro_rules! instruction {
             Abs,
             AbsWrapped,
             Add,
+            AddSaturating,
             AddWrapped,
             And,
             AssertEq,
@@ -252,6 +281,7 @@ macro_rules! instruction {
             Div,
             DivWrapped,
             Double,
+            EcdsaVerify,
             GreaterThan,
             GreaterThanOrEqual,
             HashBHP256,
@@ -272,6 +302,8 @@ macro_rules! instruction {
             HashManyPSD2,
             HashManyPSD4,
             HashManyPSD8,
+            HashKeccak256Native,
+            HashSha3_256Native,
             Inv,
             IsEq,
             IsNeq,
@@ -279,6 +311,7 @@ macro_rules! instruction {
             LessThanOrEqual,
             Modulo,
             Mul,
+            MulSaturating,
             MulWrapped,
             Nand,
             Neg,
@@ -289,6 +322,8 @@ macro_rules! instruction {
             PowWrapped,
             Rem,
             RemWrapped,
+            Rol,
+            Ror,
             Shl,
             ShlWrapped,
             Shr,
@@ -296,9 +331,16 @@ macro_rules! instruction {
             SignVerify,
             Square,
             SquareRoot,
+            StrConcat,
+            StrLen,
+            StrSlice,
             Sub,
+            SubSaturating,
             SubWrapped,
             Ternary,
+            VecGet,
+            VecLen,
+            VecSet,
             Xor,
         }}
     };
@@ -469,7 +511,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            82,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -29,6 +29,8 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for ECDSA signature verification (i.e. `ecdsa.verify`).
+    Ecdsa,
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
@@ -37,6 +39,10 @@ pub enum Opcode {
     Literal(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for a string operation (i.e. `str.concat`).
+    String(&'static str),
+    /// The opcode is for a vector operation (i.e. `vec.get`).
+    Vector(&'static str),
 }
 
 impl Deref for Opcode {
@@ -51,10 +57,13 @@ impl Deref for Opcode {
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::Ecdsa => &"ecdsa.verify",
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::String(opcode) => opcode,
+            Opcode::Vector(opcode) => opcode,
         }
     }
 }
@@ -76,10 +85,13 @@ impl Display for Opcode {
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::Ecdsa => write!(f, "{}", self.deref()),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::String(opcode) => write!(f, "{opcode}"),
+            Self::Vector(opcode) => write!(f, "{opcode}"),
         }
     }
 }
@@ -226,8 +226,37 @@ impl<N: Network> Call<N> {
             if closure.outputs().len() != self.destinations.len() {
                 bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
             }
+            // If the closure is external, ensure the input types match, as the closure is inlined into this program.
+            if is_external {
+                for (input, input_type) in closure.inputs().iter().zip(input_types) {
+                    let expected_type = match input.register_type() {
+                        // If the input is a record, expect the external record type.
+                        RegisterType::Record(record_name) => {
+                            RegisterType::ExternalRecord(Locator::new(*program.id(), *record_name))
+                        }
+                        // Else, expect the register type.
+                        register_type => register_type.clone(),
+                    };
+                    ensure!(
+                        &expected_type == input_type,
+                        "Closure '{}/{resource}' expects an input of type '{expected_type}', found '{input_type}'",
+                        program.id()
+                    );
+                }
+            }
             // Return the output register types.
-            Ok(closure.outputs().iter().map(|output| output.register_type()).cloned().collect())
+            Ok(closure
+                .outputs()
+                .iter()
+                .map(|output| match (is_external, output.register_type()) {
+                    // If the output is a record and the closure is external, return the external record type.
+                    (true, RegisterType::Record(record_name)) => {
+                        RegisterType::ExternalRecord(Locator::new(*program.id(), *record_name))
+                    }
+                    // Else, return the register type.
+                    (_, register_type) => register_type.clone(),
+                })
+                .collect())
         }
         // If the operator is a function, retrieve the function and compute the output types.
         else if let Ok(function) = program.get_function(resource) {
@@ -249,6 +249,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(..)) => bail!("Illegal operation: Cannot cast to a vector."),
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -486,6 +487,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Vector(..)) => bail!("Illegal operation: Cannot cast to a vector."),
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -625,6 +627,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(..)) => bail!("Illegal operation: Cannot cast to a vector."),
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -758,6 +761,7 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Vector(..)) => bail!("Illegal operation: Cannot cast to a vector."),
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -972,7 +976,9 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
             | CastType::GroupYCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         match !operands.is_empty() && (operands.len() <= max_operands) {
@@ -1020,7 +1026,9 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -1065,7 +1073,9 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if num_operands.is_zero() || num_operands > max_operands {
@@ -1086,7 +1096,9 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
             | CastType::GroupXCoordinate
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
-            CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Array(_)) | CastType::Plaintext(PlaintextType::Vector(_)) => {
+                N::MAX_ARRAY_ELEMENTS
+            }
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
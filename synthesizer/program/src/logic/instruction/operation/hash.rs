@@ -19,7 +19,8 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U32,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -64,6 +65,11 @@ pub type HashManyPSD4<N> = HashInstruction<N, { Hasher::HashManyPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashManyPSD8<N> = HashInstruction<N, { Hasher::HashManyPSD8 as u8 }>;
 
+/// Keccak256 over the bytes of a `u8` array, which outputs the 256-bit digest as a `[u8; 32u32]` array.
+pub type HashKeccak256Native<N> = HashInstruction<N, { Hasher::HashKeccak256Native as u8 }>;
+/// SHA3-256 over the bytes of a `u8` array, which outputs the 256-bit digest as a `[u8; 32u32]` array.
+pub type HashSha3_256Native<N> = HashInstruction<N, { Hasher::HashSha3_256Native as u8 }>;
+
 enum Hasher {
     HashBHP256,
     HashBHP512,
@@ -83,6 +89,8 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashKeccak256Native,
+    HashSha3_256Native,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -104,13 +112,51 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
 }
 
 /// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
-    !matches!(
-        destination_type,
-        PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    match variant {
+        // The native variants output the digest as bytes.
+        18 | 19 => *destination_type == native_destination_type(),
+        _ => !matches!(
+            destination_type,
+            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
+        ),
+    }
+}
+
+/// Returns the destination type of the native variants, which is `[u8; 32u32]`.
+fn native_destination_type<N: Network>() -> PlaintextType<N> {
+    // Note: This `expect` is safe, as 32 is within the bounds of an array.
+    PlaintextType::Array(
+        ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(32)]).expect("Invalid array type"),
     )
 }
 
+/// Returns the bits of the given plaintext, for the native variants.
+/// The plaintext must be a (possibly multi-dimensional) array of `u8`, which is hashed as its bytes in order.
+fn native_input_bits<N: Network>(plaintext: &Plaintext<N>) -> Result<Vec<bool>> {
+    match plaintext {
+        Plaintext::Literal(Literal::U8(byte), ..) => Ok(byte.to_bits_le()),
+        Plaintext::Array(elements, ..) => {
+            Ok(elements.iter().map(native_input_bits).collect::<Result<Vec<_>>>()?.concat())
+        }
+        _ => bail!("The input of a native hash must be an array of 'u8'"),
+    }
+}
+
+/// Returns the bits of the given plaintext circuit, for the native variants.
+/// The plaintext must be a (possibly multi-dimensional) array of `u8`, which is hashed as its bytes in order.
+fn native_input_bits_circuit<A: circuit::Aleo>(plaintext: &circuit::Plaintext<A>) -> Result<Vec<circuit::Boolean<A>>> {
+    use circuit::traits::ToBits;
+
+    match plaintext {
+        circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => Ok(byte.to_bits_le()),
+        circuit::Plaintext::Array(elements, ..) => {
+            Ok(elements.iter().map(native_input_bits_circuit).collect::<Result<Vec<_>>>()?.concat())
+        }
+        _ => bail!("The input of a native hash must be an array of 'u8'"),
+    }
+}
+
 /// Hashes the operand into the declared type.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HashInstruction<N: Network, const VARIANT: u8> {
@@ -133,7 +179,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -162,7 +208,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash.keccak256.native"),
+            19 => Opcode::Hash("hash.sha3_256.native"),
+            20.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -203,10 +251,32 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // Hash the bytes of the input into the digest, for the native variants.
+        if matches!(VARIANT, 18 | 19) {
+            let input = match &input {
+                Value::Plaintext(plaintext) => native_input_bits(plaintext)?,
+                Value::Record(..) | Value::Future(..) => bail!("Instruction '{}' expects a plaintext", Self::opcode()),
+            };
+            let digest = match VARIANT {
+                18 => N::hash_keccak256(&input)?,
+                _ => N::hash_sha3_256(&input)?,
+            };
+            let bytes = digest
+                .chunks(8)
+                .map(|bits| Ok(Plaintext::from(Literal::U8(console::types::U8::from_bits_le(bits)?))))
+                .collect::<Result<Vec<_>>>()?;
+            let output = Value::Plaintext(Plaintext::Array(bytes, Default::default()));
+            return registers.store(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -251,12 +321,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -269,15 +341,39 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
     ) -> Result<()> {
-        use circuit::traits::{ToBits, ToFields};
+        use circuit::traits::{FromBits, ToBits, ToFields};
 
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // Hash the bytes of the input into the digest, for the native variants.
+        if matches!(VARIANT, 18 | 19) {
+            let input = match &input {
+                circuit::Value::Plaintext(plaintext) => native_input_bits_circuit(plaintext)?,
+                circuit::Value::Record(..) | circuit::Value::Future(..) => {
+                    bail!("Instruction '{}' expects a plaintext", Self::opcode())
+                }
+            };
+            let digest = match VARIANT {
+                18 => A::hash_keccak256(&input),
+                _ => A::hash_sha3_256(&input),
+            };
+            let bytes = digest
+                .chunks(8)
+                .map(|bits| circuit::Plaintext::from(circuit::Literal::U8(circuit::U8::from_bits_le(bits))))
+                .collect::<Vec<_>>();
+            let output = circuit::Value::Plaintext(circuit::Plaintext::Array(bytes, Default::default()));
+            return registers.store_circuit(stack, &self.destination, output);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -322,12 +418,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -357,14 +455,30 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
             0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            18 | 19 => {
+                // Ensure the input is an array of `u8`.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Array(array_type))
+                        if array_type.base_element_type() == &PlaintextType::Literal(LiteralType::U8) =>
+                    {
+                        Ok(vec![RegisterType::Plaintext(self.destination_type.clone())])
+                    }
+                    input_type => {
+                        bail!("Instruction '{}' expects an array of 'u8', found '{input_type}'", Self::opcode())
+                    }
+                }
+            }
+            20.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -413,13 +527,11 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         // Parse the destination register type from the string.
         let (string, destination_type) = PlaintextType::parse(string)?;
         // Ensure the destination type is allowed.
-        match destination_type {
-            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String) => {
-                map_res(fail, |_: ParserResult<Self>| {
-                    Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
-                })(string)
-            }
-            _ => Ok((string, Self { operands, destination, destination_type })),
+        match is_valid_destination_type(VARIANT, &destination_type) {
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
+            })(string),
+            true => Ok((string, Self { operands, destination, destination_type })),
         }
     }
 }
@@ -530,4 +642,21 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_native() {
+        let (string, hash) =
+            HashKeccak256Native::<CurrentNetwork>::parse("hash.keccak256.native r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, native_destination_type(), "The destination type is incorrect");
+
+        // Ensure the native variants only output a `[u8; 32u32]` array.
+        assert!(HashSha3_256Native::<CurrentNetwork>::parse("hash.sha3_256.native r0 into r1 as [u8; 32u32]").is_ok());
+        assert!(HashSha3_256Native::<CurrentNetwork>::parse("hash.sha3_256.native r0 into r1 as field").is_err());
+        assert!(HashSha3_256Native::<CurrentNetwork>::parse("hash.sha3_256.native r0 into r1 as [u8; 16u32]").is_err());
+        // Ensure the non-native variants do not parse the native opcodes.
+        assert!(HashKeccak256::<CurrentNetwork>::parse("hash.keccak256.native r0 into r1 as [u8; 32u32]").is_err());
+    }
 }
@@ -27,6 +27,9 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -41,6 +44,12 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
+mod vector;
+pub use vector::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -110,6 +119,24 @@ crate::operation!(
     }
 );
 
+/// Adds `first` with `second`, bounding the sum to the boundary of the type, and storing the outcome in `destination`.
+pub type AddSaturating<N> = BinaryLiteral<N, AddSaturatingOperation<N>>;
+
+crate::operation!(
+    pub struct AddSaturatingOperation<console::prelude::AddSaturating, circuit::traits::AddSaturating, add_saturating, "add.sat"> {
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
 /// Adds `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
 pub type AddWrapped<N> = BinaryLiteral<N, AddWrappedOperation<N>>;
 
@@ -323,6 +350,24 @@ crate::operation!(
     }
 );
 
+/// Multiplies `first` with `second`, bounding the product to the boundary of the type, and storing the outcome in `destination`.
+pub type MulSaturating<N> = BinaryLiteral<N, MulSaturatingOperation<N>>;
+
+crate::operation!(
+    pub struct MulSaturatingOperation<console::prelude::MulSaturating, circuit::traits::MulSaturating, mul_saturating, "mul.sat"> {
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
 /// Multiplies `first` and `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
 pub type MulWrapped<N> = BinaryLiteral<N, MulWrappedOperation<N>>;
 
@@ -525,6 +570,52 @@ crate::operation!(
     }
 );
 
+/// Rotates the bits of `first` left by `second` bits, storing the outcome in `destination`.
+pub type Rol<N> = BinaryLiteral<N, RolOperation<N>>;
+
+crate::operation!(
+    pub struct RolOperation<console::prelude::RotateLeft, circuit::traits::RotateLeft, rotate_left, "rol"> {
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
+/// Rotates the bits of `first` right by `second` bits, storing the outcome in `destination`.
+pub type Ror<N> = BinaryLiteral<N, RorOperation<N>>;
+
+crate::operation!(
+    pub struct RorOperation<console::prelude::RotateRight, circuit::traits::RotateRight, rotate_right, "ror"> {
+        (U8, U8) => U8,
+        (U8, U16) => U8,
+        (U8, U32) => U8,
+        (U16, U8) => U16,
+        (U16, U16) => U16,
+        (U16, U32) => U16,
+        (U32, U8) => U32,
+        (U32, U16) => U32,
+        (U32, U32) => U32,
+        (U64, U8) => U64,
+        (U64, U16) => U64,
+        (U64, U32) => U64,
+        (U128, U8) => U128,
+        (U128, U16) => U128,
+        (U128, U32) => U128,
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -716,6 +807,24 @@ crate::operation!(
     }
 );
 
+/// Computes `first - second`, bounding the difference to the boundary of the type, and storing the outcome in `destination`.
+pub type SubSaturating<N> = BinaryLiteral<N, SubSaturatingOperation<N>>;
+
+crate::operation!(
+    pub struct SubSaturatingOperation<console::prelude::SubSaturating, circuit::traits::SubSaturating, sub_saturating, "sub.sat"> {
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
 /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
 pub type SubWrapped<N> = BinaryLiteral<N, SubWrappedOperation<N>>;
 
@@ -0,0 +1,368 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::U32,
+};
+
+/// Concatenates the strings `first` and `second`, storing the outcome in `destination`.
+pub type StrConcat<N> = StringInstruction<N, { Variant::StrConcat as u8 }>;
+/// Computes the number of bytes in the string `first`, storing the length as a `u32` in `destination`.
+pub type StrLen<N> = StringInstruction<N, { Variant::StrLen as u8 }>;
+/// Retrieves `third` bytes of the string `first`, starting at byte `second`, storing the outcome in `destination`.
+pub type StrSlice<N> = StringInstruction<N, { Variant::StrSlice as u8 }>;
+
+enum Variant {
+    StrConcat,
+    StrLen,
+    StrSlice,
+}
+
+/// Computes a string operation on the operands, and stores the outcome in `destination`.
+///
+/// In the circuit, the number of bytes in a string is fixed by the shape of the circuit,
+/// so the output of a string operation is bounded by the maximum number of bytes in a string.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Initializes a new string instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::String("str.concat"),
+            1 => Opcode::String("str.len"),
+            2 => Opcode::String("str.slice"),
+            _ => panic!("Invalid 'str' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            0 => 2,
+            1 => 1,
+            2 => 3,
+            _ => panic!("Invalid 'str' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Invalid number of operands for '{}'",
+            Self::opcode()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Load the operands.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_literal(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match (VARIANT, inputs.as_slice()) {
+            (0, [Literal::String(first), Literal::String(second)]) => Literal::String(first.concat(second)?),
+            (1, [Literal::String(string)]) => Literal::U32(U32::new(u32::try_from(string.len())?)),
+            (2, [Literal::String(string), Literal::U32(start), Literal::U32(length)]) => {
+                Literal::String(string.slice(**start, **length)?)
+            }
+            _ => bail!("Invalid operands for '{}'", Self::opcode()),
+        };
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Load the operands.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_literal_circuit(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match (VARIANT, inputs.as_slice()) {
+            (0, [circuit::Literal::String(first), circuit::Literal::String(second)]) => {
+                circuit::Literal::String(first.concat(second))
+            }
+            (1, [circuit::Literal::String(string)]) => circuit::Literal::U32(string.length()),
+            (2, [circuit::Literal::String(string), circuit::Literal::U32(start), circuit::Literal::U32(length)]) => {
+                circuit::Literal::String(string.slice(start, length))
+            }
+            _ => bail!("Invalid operands for '{}'", Self::opcode()),
+        };
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Ensure the input types are correct.
+        let string_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String));
+        let u32_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32));
+        for (i, input_type) in input_types.iter().enumerate() {
+            let expected_type = match (VARIANT, i) {
+                (0, _) | (_, 0) => &string_type,
+                _ => &u32_type,
+            };
+            if input_type != expected_type {
+                bail!(
+                    "Instruction '{}' expects input {i} to be of type '{expected_type}', found '{input_type}'",
+                    Self::opcode()
+                )
+            }
+        }
+
+        match VARIANT {
+            0 | 2 => Ok(vec![string_type]),
+            1 => Ok(vec![u32_type]),
+            _ => bail!("Invalid 'str' variant: {VARIANT}"),
+        }
+    }
+
+    /// Ensures the number of operands is correct.
+    fn check_num_operands(&self) -> Result<()> {
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for StringInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for StringInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for StringInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for StringInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for StringInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for StringInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = StrConcat::<CurrentNetwork>::parse("str.concat r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, instruction) = StrLen::<CurrentNetwork>::parse("str.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, instruction) = StrSlice::<CurrentNetwork>::parse("str.slice r0 1u32 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Literal(Literal::U32(U32::new(1))));
+        assert_eq!(instruction.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the number of operands is checked.
+        assert!(StrConcat::<CurrentNetwork>::parse("str.concat r0 into r1").is_err());
+        assert!(StrLen::<CurrentNetwork>::parse("str.len r0 r1 into r2").is_err());
+        assert!(StrSlice::<CurrentNetwork>::parse("str.slice r0 r1 into r2").is_err());
+    }
+}
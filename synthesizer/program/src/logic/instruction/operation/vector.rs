@@ -0,0 +1,472 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value, VectorType},
+};
+
+/// Retrieves the element of the vector `first` at index `second`, storing the element in `destination`.
+pub type VecGet<N> = VectorInstruction<N, { Variant::VecGet as u8 }>;
+/// Computes the length of the vector `first`, storing the length as a `u32` in `destination`.
+pub type VecLen<N> = VectorInstruction<N, { Variant::VecLen as u8 }>;
+/// Replaces the element of the vector `first` at index `second` with `third`, storing the vector in `destination`.
+pub type VecSet<N> = VectorInstruction<N, { Variant::VecSet as u8 }>;
+
+enum Variant {
+    VecGet,
+    VecLen,
+    VecSet,
+}
+
+/// Computes a vector operation on the operands, and stores the outcome in `destination`.
+///
+/// In the circuit, the index is not required to be a constant, so an element is accessed
+/// by conditionally selecting over every element of the vector, up to its maximum length.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Initializes a new vector instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operands",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Vector("vec.get"),
+            1 => Opcode::Vector("vec.len"),
+            2 => Opcode::Vector("vec.set"),
+            _ => panic!("Invalid 'vec' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands of the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            0 => 2,
+            1 => 1,
+            2 => 3,
+            _ => panic!("Invalid 'vec' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Invalid number of operands for '{}'",
+            Self::opcode()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Retrieve the vector.
+        let vector = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects the first operand to be a vector", Self::opcode()),
+        };
+        let length = vector.vector_length()?;
+
+        // Retrieve the index, and ensure it is within the length of the vector.
+        let index = match VARIANT {
+            0 | 2 => match registers.load(stack, &self.operands[1])? {
+                Value::Plaintext(Plaintext::Literal(Literal::U32(index), ..)) => {
+                    if *index >= *length {
+                        bail!(
+                            "'{}' failed: index {index} is out of bounds for a vector of length {length}",
+                            Self::opcode()
+                        )
+                    }
+                    *index as usize
+                }
+                _ => bail!("Instruction '{}' expects the second operand to be a 'u32'", Self::opcode()),
+            },
+            _ => 0,
+        };
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => vector.vector_elements()?[index].clone(),
+            1 => Plaintext::from(Literal::U32(length)),
+            2 => {
+                let value = match registers.load(stack, &self.operands[2])? {
+                    Value::Plaintext(plaintext) => plaintext,
+                    _ => bail!("Instruction '{}' expects the third operand to be a plaintext", Self::opcode()),
+                };
+                let mut elements = vector.vector_elements()?.to_vec();
+                elements[index] = value;
+                Plaintext::new_vector(length, elements)?
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::{Compare, Equal, Inject};
+
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Retrieve the vector.
+        let (length, elements) = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(members, ..)) => {
+                let length = members.get(&circuit::Identifier::constant(VectorType::<N>::length_member()));
+                let elements = members.get(&circuit::Identifier::constant(VectorType::<N>::elements_member()));
+                match (length, elements) {
+                    (
+                        Some(circuit::Plaintext::Literal(circuit::Literal::U32(length), ..)),
+                        Some(circuit::Plaintext::Array(elements, ..)),
+                    ) => (length.clone(), elements.clone()),
+                    _ => bail!("Instruction '{}' expects the first operand to be a vector", Self::opcode()),
+                }
+            }
+            _ => bail!("Instruction '{}' expects the first operand to be a vector", Self::opcode()),
+        };
+
+        // Retrieve the index, and enforce it is within the length of the vector.
+        let index = match VARIANT {
+            0 | 2 => match registers.load_circuit(stack, &self.operands[1])? {
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U32(index), ..)) => {
+                    A::assert(index.is_less_than(&length));
+                    index
+                }
+                _ => bail!("Instruction '{}' expects the second operand to be a 'u32'", Self::opcode()),
+            },
+            _ => circuit::U32::constant(console::types::U32::new(0)),
+        };
+        // Returns `true` if the index is equal to the given position.
+        let is_index = |i: usize| index.is_equal(&circuit::U32::constant(console::types::U32::new(i as u32)));
+
+        // Compute the output.
+        let output = match VARIANT {
+            // Select the element at the index.
+            0 => elements
+                .iter()
+                .enumerate()
+                .skip(1)
+                .fold(elements[0].clone(), |output, (i, element)| ternary_circuit(&is_index(i), element, &output)),
+            1 => circuit::Plaintext::from(circuit::Literal::U32(length)),
+            // Replace the element at the index.
+            2 => {
+                let value = match registers.load_circuit(stack, &self.operands[2])? {
+                    circuit::Value::Plaintext(plaintext) => plaintext,
+                    _ => bail!("Instruction '{}' expects the third operand to be a plaintext", Self::opcode()),
+                };
+                let elements = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| ternary_circuit(&is_index(i), &value, element))
+                    .collect::<Vec<_>>();
+                let members = IndexMap::from_iter([
+                    (
+                        circuit::Identifier::constant(VectorType::<N>::length_member()),
+                        circuit::Plaintext::from(circuit::Literal::U32(length)),
+                    ),
+                    (
+                        circuit::Identifier::constant(VectorType::<N>::elements_member()),
+                        circuit::Plaintext::Array(elements, Default::default()),
+                    ),
+                ]);
+                circuit::Plaintext::Struct(members, Default::default())
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        self.check_num_operands()?;
+
+        // Ensure the first operand is a vector.
+        let vector_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => vector_type,
+            input_type => {
+                bail!("Instruction '{}' expects the first input to be a vector, found '{input_type}'", Self::opcode())
+            }
+        };
+        // Ensure the index is a `u32`.
+        if (VARIANT == 0 || VARIANT == 2)
+            && input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))
+        {
+            bail!("Instruction '{}' expects the index to be a 'u32', found '{}'", Self::opcode(), input_types[1])
+        }
+
+        match VARIANT {
+            0 => Ok(vec![RegisterType::Plaintext(vector_type.element_type().clone())]),
+            1 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))]),
+            2 => {
+                // Ensure the value is of the element type.
+                let element_type = RegisterType::Plaintext(vector_type.element_type().clone());
+                if input_types[2] != element_type {
+                    bail!(
+                        "Instruction '{}' expects the value to be of type '{element_type}', found '{}'",
+                        Self::opcode(),
+                        input_types[2]
+                    )
+                }
+                Ok(vec![input_types[0].clone()])
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        }
+    }
+
+    /// Ensures the number of operands is correct.
+    fn check_num_operands(&self) -> Result<()> {
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+        Ok(())
+    }
+}
+
+/// Returns `first` if `condition` is `true`, otherwise returns `second`.
+/// The plaintexts are expected to be of the same type, so they are selected bit by bit.
+fn ternary_circuit<A: circuit::Aleo>(
+    condition: &circuit::Boolean<A>,
+    first: &circuit::Plaintext<A>,
+    second: &circuit::Plaintext<A>,
+) -> circuit::Plaintext<A> {
+    use circuit::traits::{FromBits, Ternary, ToBits};
+
+    let bits_le = first
+        .to_bits_le()
+        .iter()
+        .zip_eq(second.to_bits_le().iter())
+        .map(|(first, second)| circuit::Boolean::ternary(condition, first, second))
+        .collect::<Vec<_>>();
+    circuit::Plaintext::from_bits_le(&bits_le)
+}
+
+impl<N: Network, const VARIANT: u8> Parser for VectorInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for VectorInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for VectorInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for VectorInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for VectorInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for VectorInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, vec) = VecGet::<CurrentNetwork>::parse("vec.get r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(vec.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vec.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, vec) = VecLen::<CurrentNetwork>::parse("vec.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(vec.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, vec) = VecSet::<CurrentNetwork>::parse("vec.set r0 1u32 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(vec.operands[1], Operand::Literal(Literal::U32(console::types::U32::new(1))));
+        assert_eq!(vec.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the number of operands is checked.
+        assert!(VecGet::<CurrentNetwork>::parse("vec.get r0 into r1").is_err());
+        assert!(VecLen::<CurrentNetwork>::parse("vec.len r0 r1 into r2").is_err());
+        assert!(VecSet::<CurrentNetwork>::parse("vec.set r0 r1 into r2").is_err());
+    }
+}
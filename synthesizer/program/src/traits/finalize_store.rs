@@ -39,6 +39,16 @@ pub trait FinalizeStoreTrait<N: Network> {
         key: &Plaintext<N>,
     ) -> Result<Option<Value<N>>>;
 
+    /// Returns up to `limit` speculative keys for the given `program ID` and `mapping name`, starting at `start`.
+    /// The keys are ordered by their serialized bytes, which is the order of the keys in every storage.
+    fn get_keys_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<Plaintext<N>>>;
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![allow(clippy::too_many_arguments)]
+
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -29,9 +31,109 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         priority_fee_in_microcredits: u64,
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        self.deploy_with_progress(private_key, program, fee_record, priority_fee_in_microcredits, query, |_| (), rng)
+    }
+
+    /// Returns a new deploy transaction, reporting the progress of the proving to the given callback.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn deploy_with_progress<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        progress: impl Fn(ProvingProgress),
+        rng: &mut R,
     ) -> Result<Transaction<N>> {
         // Compute the deployment.
+        progress(ProvingProgress::SynthesisStarted(program.id().to_string()));
         let deployment = self.deploy_raw(program, rng)?;
+        progress(ProvingProgress::Done(program.id().to_string()));
+        // Compute the deploy transaction.
+        self.deployment_to_transaction(
+            private_key,
+            deployment,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            &progress,
+            rng,
+        )
+    }
+
+    /// Returns a new deploy transaction, persisting the progress of the key synthesis to the given path,
+    /// so that an interrupted call resumes from the last certified function.
+    ///
+    /// The progress is written after each function is certified, and is removed once the transaction is created.
+    /// If the progress at the given path is for a different program, it is discarded.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn deploy_resumable<P: AsRef<Path>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        progress_path: P,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let progress_path = progress_path.as_ref();
+
+        // Load the progress of a previous call, if it is for the same program.
+        let mut builder = match progress_path.exists() {
+            true => match DeploymentBuilder::load(progress_path) {
+                Ok(builder) if builder.program() == program => builder,
+                _ => DeploymentBuilder::new(program.clone())?,
+            },
+            false => DeploymentBuilder::new(program.clone())?,
+        };
+
+        // Certify the remaining functions, persisting the progress after each one.
+        while self.continue_deployment_raw(&mut builder, rng)?.is_some() {
+            builder.save(progress_path)?;
+        }
+        let deployment = builder.build()?;
+
+        // Compute the deploy transaction.
+        let transaction = self.deployment_to_transaction(
+            private_key,
+            deployment,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            &|_| (),
+            rng,
+        )?;
+        // Remove the progress, as the deployment is complete.
+        if progress_path.exists() {
+            std::fs::remove_file(progress_path)?;
+        }
+        Ok(transaction)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new deploy transaction for the given deployment, by authorizing and proving its fee.
+    fn deployment_to_transaction<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        deployment: Deployment<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        progress: &impl Fn(ProvingProgress),
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
         // Ensure the transaction is not empty.
         ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
         // Compute the deployment ID.
@@ -60,7 +162,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             )?,
         };
         // Compute the fee.
-        let fee = self.execute_fee_authorization(fee_authorization, query, rng)?;
+        let fee = self.execute_fee_authorization_raw(fee_authorization, query, progress, rng)?;
+        progress(ProvingProgress::Proving(100));
 
         // Return the deploy transaction.
         Transaction::from_deployment(owner, deployment, fee)
@@ -88,4 +191,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Compute the deployment");
         result
     }
+
+    /// Certifies the next function of the given deployment builder, and returns its name,
+    /// or `None` if every function in the deployment builder is already certified.
+    #[inline]
+    pub(super) fn continue_deployment_raw<R: Rng + CryptoRng>(
+        &self,
+        builder: &mut DeploymentBuilder<N>,
+        rng: &mut R,
+    ) -> Result<Option<Identifier<N>>> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the deployment builder.
+                let builder = cast_mut_ref!(builder as DeploymentBuilder<$network>);
+                // Certify the next function.
+                let function_name = $process.continue_deployment::<$aleo, _>(builder, rng)?;
+                // Prepare the function name.
+                Ok(function_name.map(|function_name| *cast_ref!(function_name as Identifier<N>)))
+            }};
+        }
+
+        // Certify the next function.
+        let timer = timer!("VM::continue_deployment_raw");
+        let result = process!(self, logic);
+        finish!(timer, "Certify the next function");
+        result
+    }
 }
@@ -16,6 +16,11 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a new execute transaction.
     ///
@@ -32,6 +37,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         priority_fee_in_microcredits: u64,
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        self.execute_with_progress(
+            private_key,
+            (program_id, function_name),
+            inputs,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            |_| (),
+            rng,
+        )
+    }
+
+    /// Returns a new execute transaction, reporting the progress of the proving to the given callback.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    pub fn execute_with_progress<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        progress: impl Fn(ProvingProgress),
+        rng: &mut R,
     ) -> Result<Transaction<N>> {
         // Compute the authorization.
         let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
@@ -39,10 +73,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let is_fee_required = !authorization.is_split();
         // Determine if a priority fee is declared.
         let is_priority_fee_declared = priority_fee_in_microcredits > 0;
+        // Determine if a fee is included.
+        let is_fee_included = is_fee_required || is_priority_fee_declared;
         // Compute the execution.
-        let execution = self.execute_authorization_raw(authorization, query.clone(), rng)?;
+        let execution = self.execute_authorization_raw(authorization, query.clone(), &progress, rng)?;
+        progress(ProvingProgress::Proving(if is_fee_included { 50 } else { 100 }));
         // Compute the fee.
-        let fee = match is_fee_required || is_priority_fee_declared {
+        let fee = match is_fee_included {
             true => {
                 // Compute the minimum execution cost.
                 let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
@@ -67,7 +104,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     )?,
                 };
                 // Execute the fee.
-                Some(self.execute_fee_authorization_raw(authorization, query, rng)?)
+                let fee = self.execute_fee_authorization_raw(authorization, query, &progress, rng)?;
+                progress(ProvingProgress::Proving(100));
+                Some(fee)
             }
             false => None,
         };
@@ -84,16 +123,105 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<Transaction<N>> {
         // Compute the execution.
-        let execution = self.execute_authorization_raw(execute_authorization, query.clone(), rng)?;
+        let execution = self.execute_authorization_raw(execute_authorization, query.clone(), &|_| (), rng)?;
         // Compute the fee.
         let fee = match fee_authorization {
-            Some(authorization) => Some(self.execute_fee_authorization_raw(authorization, query, rng)?),
+            Some(authorization) => Some(self.execute_fee_authorization_raw(authorization, query, &|_| (), rng)?),
             None => None,
         };
         // Return the execute transaction.
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns new execute transactions for the given batch of authorizations,
+    /// as `(execute authorization, fee authorization)`, which must all call the same function.
+    ///
+    /// The transactions are proven in parallel, sharing the proving keys of the function,
+    /// which are synthesized by the first transaction if they are missing.
+    pub fn execute_batch<R: Rng + CryptoRng>(
+        &self,
+        authorizations: Vec<(Authorization<N>, Option<Authorization<N>>)>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        let timer = timer!("VM::execute_batch");
+
+        // Ensure the authorizations all call the same function.
+        let mut locators = authorizations.iter().map(|(authorization, _)| {
+            let request = authorization.peek_next()?;
+            Ok(Locator::new(*request.program_id(), *request.function_name()))
+        });
+        if let Some(first_locator) = locators.next().transpose()? {
+            for locator in locators {
+                let locator = locator?;
+                ensure!(locator == first_locator, "Expected a batch of '{first_locator}' calls, found '{locator}'");
+            }
+        }
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        // Sample an independent RNG for each transaction, so they can be proven concurrently.
+        let mut batch = authorizations.into_iter().map(|authorization| (authorization, StdRng::from_seed(rng.gen())));
+
+        // Prove the first transaction, which synthesizes the missing proving keys of the function.
+        let mut transactions = Vec::with_capacity(batch.len());
+        if let Some(((execute_authorization, fee_authorization), mut rng)) = batch.next() {
+            let query = Some(query.clone());
+            transactions.push(self.execute_authorization(execute_authorization, fee_authorization, query, &mut rng)?);
+        }
+        lap!(timer, "Prove the first transaction");
+
+        // Prove the remaining transactions in parallel.
+        let remaining = cfg_into_iter!(batch.collect::<Vec<_>>())
+            .map(|((execute_authorization, fee_authorization), mut rng)| {
+                let query = Some(query.clone());
+                self.execute_authorization(execute_authorization, fee_authorization, query, &mut rng)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        transactions.extend(remaining);
+        finish!(timer, "Prove the remaining transactions");
+
+        Ok(transactions)
+    }
+
+    /// Returns the response and a local execution of the given function, proven against a local state
+    /// for its input records, which do not need to exist in the ledger.
+    ///
+    /// A local execution is **not broadcastable**, and is intended for testing program logic end-to-end.
+    pub fn execute_local<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, LocalExecution<N>)> {
+        let timer = timer!("VM::execute_local");
+
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        lap!(timer, "Compute the authorization");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Execute the call against a local state.
+                let (response, local_execution) = $process.execute_local::<$aleo, _>(authorization.clone(), rng)?;
+                // Prepare the response and local execution.
+                let response = cast_ref!(response as Response<N>).clone();
+                Ok((response, cast_ref!(local_execution as LocalExecution<N>).clone()))
+            }};
+        }
+
+        // Execute the authorization.
+        let result = process!(self, logic);
+        finish!(timer, "Execute the authorization locally");
+        result
+    }
+
     /// Returns a new fee for the given authorization.
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
@@ -102,7 +230,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<Fee<N>> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
-        self.execute_fee_authorization_raw(authorization, query, rng)
+        self.execute_fee_authorization_raw(authorization, query, &|_| (), rng)
     }
 }
 
@@ -114,6 +242,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         &self,
         authorization: Authorization<N>,
         query: Option<Query<N, C::BlockStorage>>,
+        progress: &dyn Fn(ProvingProgress),
         rng: &mut R,
     ) -> Result<Execution<N>> {
         let timer = timer!("VM::execute_authorization_raw");
@@ -135,7 +264,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Prepare the authorization.
                 let authorization = cast_ref!(authorization as Authorization<$network>);
                 // Execute the call.
+                progress(ProvingProgress::SynthesisStarted(locator.clone()));
                 let (_, mut trace) = $process.execute::<$aleo>(authorization.clone())?;
+                report_transitions(progress, ProvingProgress::WitnessGenerated, trace.transitions().iter());
                 lap!(timer, "Execute the call");
 
                 // Prepare the assignments.
@@ -144,6 +275,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
                 // Compute the proof and construct the execution.
                 let execution = trace.prove_execution::<$aleo, _>(&locator, rng)?;
+                report_transitions(progress, ProvingProgress::Done, execution.transitions());
                 lap!(timer, "Compute the proof");
 
                 // Return the execution.
@@ -160,14 +292,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Executes a call to the program function for the given fee authorization.
     /// Returns the fee.
     #[inline]
-    fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
+    pub(super) fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
         query: Option<Query<N, C::BlockStorage>>,
+        progress: &dyn Fn(ProvingProgress),
         rng: &mut R,
     ) -> Result<Fee<N>> {
         let timer = timer!("VM::execute_fee_authorization_raw");
 
+        // Construct the locator of the fee function.
+        let locator = {
+            let request = authorization.peek_next()?;
+            Locator::new(*request.program_id(), *request.function_name()).to_string()
+        };
         // Prepare the query.
         let query = match query {
             Some(query) => query,
@@ -180,7 +318,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Prepare the authorization.
                 let authorization = cast_ref!(authorization as Authorization<$network>);
                 // Execute the call.
+                progress(ProvingProgress::SynthesisStarted(locator.clone()));
                 let (_, mut trace) = $process.execute::<$aleo>(authorization.clone())?;
+                report_transitions(progress, ProvingProgress::WitnessGenerated, trace.transitions().iter());
                 lap!(timer, "Execute the call");
 
                 // Prepare the assignments.
@@ -189,6 +329,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
                 // Compute the proof and construct the fee.
                 let fee = trace.prove_fee::<$aleo, _>(rng)?;
+                report_transitions(progress, ProvingProgress::Done, std::iter::once(fee.transition()));
                 lap!(timer, "Compute the proof");
 
                 // Return the fee.
@@ -203,6 +344,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 }
 
+/// Reports the given progress event for each of the given transitions, by function locator.
+fn report_transitions<'a, N: Network>(
+    progress: &dyn Fn(ProvingProgress),
+    event: fn(String) -> ProvingProgress,
+    transitions: impl Iterator<Item = &'a Transition<N>>,
+) {
+    for transition in transitions {
+        progress(event(Locator::new(*transition.program_id(), *transition.function_name()).to_string()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +467,139 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_execute_batch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Authorize a batch of public transfers, with public fees.
+        let mut execution_ids = Vec::new();
+        let mut authorizations = Vec::new();
+        for amount in 1..=3 {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str(&format!("{amount}u64")).unwrap(),
+            ];
+            let authorization =
+                vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.into_iter(), rng).unwrap();
+            let execution_id = authorization.to_execution_id().unwrap();
+            let fee_authorization =
+                vm.authorize_fee_public(&caller_private_key, 1_000_000, 0, execution_id, rng).unwrap();
+            execution_ids.push(execution_id);
+            authorizations.push((authorization, Some(fee_authorization)));
+        }
+
+        // Execute the batch.
+        let transactions = vm.execute_batch(authorizations, None, rng).unwrap();
+        assert_eq!(transactions.len(), 3);
+        // Ensure the transactions are valid, and in the order of the authorizations.
+        for (transaction, execution_id) in transactions.iter().zip(&execution_ids) {
+            vm.check_transaction(transaction, None).unwrap();
+            assert_eq!(transaction.execution().unwrap().to_execution_id().unwrap(), *execution_id);
+        }
+
+        // Ensure a batch of different functions is rejected.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transfer =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.into_iter(), rng).unwrap();
+        let inputs = [Value::<CurrentNetwork>::from_str("1u64").unwrap()];
+        let unbond =
+            vm.authorize(&caller_private_key, "credits.aleo", "unbond_public", inputs.into_iter(), rng).unwrap();
+        assert!(vm.execute_batch(vec![(transfer, None), (unbond, None)], None, rng).is_err());
+        // Ensure an empty batch is allowed.
+        assert!(vm.execute_batch(vec![], None, rng).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_execute_local() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Sample a credits record, which does not exist in the ledger.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 1000u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+
+        // Execute a private transfer of the record locally.
+        let inputs = [
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("400u64").unwrap(),
+        ];
+        let (response, local_execution) = vm
+            .execute_local(&caller_private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), rng)
+            .unwrap();
+        assert_eq!(response.outputs().len(), 2);
+        assert_eq!(local_execution.len(), 1);
+
+        // Ensure the local state root is not in the ledger.
+        assert!(!vm.block_store().contains_state_root(&local_execution.local_state_root()).unwrap());
+    }
+
+    #[test]
+    fn test_execute_with_progress() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Execute, while recording the progress.
+        let events = std::cell::RefCell::new(Vec::new());
+        let progress = |event| events.borrow_mut().push(event);
+        vm.execute_with_progress(
+            &caller_private_key,
+            ("credits.aleo", "transfer_public"),
+            inputs,
+            None,
+            0,
+            None,
+            progress,
+            rng,
+        )
+        .unwrap();
+
+        // Ensure the progress is reported for the execution, and then for the fee.
+        let transfer = "credits.aleo/transfer_public".to_string();
+        let fee = "credits.aleo/fee_public".to_string();
+        assert_eq!(events.into_inner(), vec![
+            ProvingProgress::SynthesisStarted(transfer.clone()),
+            ProvingProgress::WitnessGenerated(transfer.clone()),
+            ProvingProgress::Done(transfer),
+            ProvingProgress::Proving(50),
+            ProvingProgress::SynthesisStarted(fee.clone()),
+            ProvingProgress::WitnessGenerated(fee.clone()),
+            ProvingProgress::Done(fee),
+            ProvingProgress::Proving(100),
+        ]);
+    }
+
     #[test]
     fn test_join_transaction_size() {
         let rng = &mut TestRng::default();
@@ -14,6 +14,17 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The outcome of speculating on a single transaction.
+enum SpeculateOutcome<N: Network> {
+    /// The transaction is confirmed, along with the finalize error if it is rejected.
+    Confirmed(ConfirmedTransaction<N>, Option<String>),
+    /// The transaction is aborted, with the given error.
+    Aborted(String),
+}
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM.
     ///
@@ -106,6 +117,61 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(ratified_finalize_operations)
     }
 
+    /// Speculates on the given transaction against the current mappings, without committing any changes.
+    ///
+    /// Returns whether the transaction would be accepted, rejected, or aborted, along with the mapping changes
+    /// of its finalize (or of its fee, if the transaction would be rejected).
+    ///
+    /// Note: The transaction is finalized as the only transaction of the next block, and it is not verified;
+    /// use `VM::check_transaction` to verify it.
+    pub fn speculate_transaction(&self, transaction: &Transaction<N>) -> Result<FinalizeDiff<N>> {
+        let timer = timer!("VM::speculate_transaction");
+
+        // Retrieve the latest block.
+        let block_store = self.block_store();
+        let latest_block = match block_store.heights().max() {
+            Some(height) => match block_store.get_block_hash(*height)? {
+                Some(block_hash) => block_store.get_block(&block_hash)?,
+                None => None,
+            },
+            None => None,
+        };
+        let Some(latest_block) = latest_block else {
+            bail!("Failed to speculate on transaction '{}' - the latest block is missing", transaction.id())
+        };
+
+        // Construct the finalize state of the next block.
+        let state = FinalizeGlobalState::new::<N>(
+            latest_block.round().saturating_add(1),
+            latest_block.height().saturating_add(1),
+            latest_block.cumulative_weight(),
+            latest_block.cumulative_proof_target(),
+            latest_block.hash(),
+        )?;
+        lap!(timer, "Construct the finalize state");
+
+        // Perform a **dry-run** of the transaction.
+        let result = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Acquire the write lock on the process.
+            let process = self.process.write();
+            // Retrieve the finalize store.
+            let store = self.finalize_store();
+
+            // Speculate on the transaction.
+            let status = match Self::atomic_speculate_transaction(&process, state, store, 0, transaction)? {
+                SpeculateOutcome::Confirmed(_, None) => FinalizeStatus::Accepted,
+                SpeculateOutcome::Confirmed(_, Some(error)) => FinalizeStatus::Rejected(error),
+                SpeculateOutcome::Aborted(error) => FinalizeStatus::Aborted(error),
+            };
+
+            // On return, 'atomic_finalize!' will abort the batch, so the mapping changes are collected beforehand.
+            Ok(FinalizeDiff::new(status, store.get_pending_key_values()))
+        });
+
+        finish!(timer, "Finished dry-run of the transaction");
+        result
+    }
+
     /// Finalizes the given transactions into the VM.
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
@@ -120,7 +186,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let ratified_finalize_operations = self.atomic_finalize(None, state, ratifications, solutions, transactions)?;
+
+        finish!(timer, "Finished real-run of finalize");
+        Ok(ratified_finalize_operations)
+    }
+
+    /// Finalizes the given transactions into the VM, as part of the given atomic batch.
+    ///
+    /// Returns the finalize operations from pre-ratify and post-ratify,
+    /// which are only committed to storage once the atomic batch is committed.
+    #[inline]
+    pub(super) fn finalize_in_batch(
+        &self,
+        batch: &AtomicBatch<'_, N, C>,
+        state: FinalizeGlobalState,
+        ratifications: &Ratifications<N>,
+        solutions: Option<&CoinbaseSolution<N>>,
+        transactions: &Transactions<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("VM::finalize_in_batch");
+
+        // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
+        let ratified_finalize_operations =
+            self.atomic_finalize(Some(batch), state, ratifications, solutions, transactions)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
@@ -205,106 +294,72 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize a list of the aborted transactions.
             let mut aborted = Vec::new();
 
-            // Finalize the transactions.
-            'outer: for (index, transaction) in transactions.enumerate() {
+            // Collect the transactions.
+            let transactions = transactions.collect::<Vec<_>>();
+            // Compute the finalize access of each transaction.
+            // Note: If the access of a transaction is unknown, it is speculated on in isolation.
+            let accesses = cfg_iter!(transactions)
+                .map(|transaction| FinalizeAccess::new(&process, transaction).ok().flatten())
+                .collect::<Vec<_>>();
+            lap!(timer, "Compute the finalize accesses");
+
+            // Speculates on the transaction at the given index.
+            let speculate = |index: usize| {
                 // Convert the transaction index to a u32.
                 // Note: On failure, this will abort the entire atomic batch.
-                let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
-
+                let transaction_index =
+                    u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome = match transaction {
-                    // The finalize operation here involves appending the 'stack',
-                    // and adding the program to the finalize tree.
-                    Transaction::Deploy(_, program_owner, deployment, fee) => {
-                        match process.finalize_deployment(state, store, deployment, fee) {
-                            // Construct the accepted deploy transaction.
-                            Ok((_, finalize)) => {
-                                ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize)
-                                    .map_err(|e| e.to_string())
-                            }
-                            // Construct the rejected deploy transaction.
-                            Err(_error) => {
-                                // Finalize the fee, to ensure it is valid.
-                                match process.finalize_fee(state, store, fee).and_then(|finalize| {
-                                    Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
-                                }) {
-                                    Ok((fee_tx, finalize)) => {
-                                        // Construct the rejected deployment.
-                                        let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
-                                        // Construct the rejected deploy transaction.
-                                        ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected, finalize)
-                                            .map_err(|e| e.to_string())
-                                    }
-                                    Err(error) => {
-                                        // Note: On failure, skip this transaction, and continue speculation.
-                                        #[cfg(debug_assertions)]
-                                        eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
-                                        // Store the aborted transaction.
-                                        aborted.push((transaction.clone(), error.to_string()));
-                                        // Continue to the next transaction.
-                                        continue 'outer;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    // The finalize operation here involves calling 'update_key_value',
-                    // and update the respective leaves of the finalize tree.
-                    Transaction::Execute(_, execution, fee) => {
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
-                            // Construct the accepted execute transaction.
-                            Ok(finalize) => {
-                                ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize)
-                                    .map_err(|e| e.to_string())
-                            }
-                            // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
-                                // Finalize the fee, to ensure it is valid.
-                                Some(fee) => {
-                                    match process.finalize_fee(state, store, fee).and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
-                                    }) {
-                                        Ok((fee_tx, finalize)) => {
-                                            // Construct the rejected execution.
-                                            let rejected = Rejected::new_execution(execution.clone());
-                                            // Construct the rejected execute transaction.
-                                            ConfirmedTransaction::rejected_execute(index, fee_tx, rejected, finalize)
-                                                .map_err(|e| e.to_string())
-                                        }
-                                        Err(error) => {
-                                            // Note: On failure, skip this transaction, and continue speculation.
-                                            #[cfg(debug_assertions)]
-                                            eprintln!("Failed to finalize the fee in a rejected execute - {error}");
-                                            // Store the aborted transaction.
-                                            aborted.push((transaction.clone(), error.to_string()));
-                                            // Continue to the next transaction.
-                                            continue 'outer;
-                                        }
-                                    }
-                                }
-                                // This is a foundational bug - the caller is violating protocol rules.
-                                // Note: This will abort the entire atomic batch.
-                                None => Err("Rejected execute transaction has no fee".to_string()),
-                            },
+                Self::atomic_speculate_transaction(&process, state, store, transaction_index, transactions[index])
+            };
+
+            // Finalize the transactions, in waves of consecutive non-conflicting transactions.
+            for wave in finalize_waves(&accesses) {
+                // Speculate on the transactions of the wave in parallel.
+                // Note: As the transactions do not conflict, this is equivalent to speculating on them in order,
+                // as long as they are all accepted. Otherwise, the wave is rewound and speculated on in order,
+                // so that the rejected and aborted transactions are isolated from the others.
+                let mut outcomes = None;
+                if wave.len() > 1 {
+                    store.atomic_checkpoint();
+                    let parallel_outcomes = cfg_into_iter!(wave.clone()).map(speculate).collect::<Vec<_>>();
+                    // Check if all of the transactions are accepted.
+                    let is_accepted =
+                        |outcome: &Result<_, _>| matches!(outcome, Ok(SpeculateOutcome::Confirmed(_, None)));
+                    match parallel_outcomes.iter().all(is_accepted) {
+                        true => {
+                            store.clear_latest_checkpoint();
+                            outcomes = Some(parallel_outcomes);
                         }
+                        false => store.atomic_rewind(),
                     }
-                    // There are no finalize operations here.
-                    // Note: This will abort the entire atomic batch.
-                    Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
+                }
+                // Otherwise, speculate on the transactions of the wave in order.
+                let outcomes = match outcomes {
+                    Some(outcomes) => outcomes,
+                    None => wave.clone().map(speculate).collect::<Vec<_>>(),
                 };
-                lap!(timer, "Speculated on transaction '{}'", transaction.id());
 
-                match outcome {
-                    // If the transaction succeeded, store it and continue to the next transaction.
-                    Ok(confirmed_transaction) => confirmed.push(confirmed_transaction),
-                    // If the transaction failed, abort the entire batch.
-                    Err(error) => {
-                        eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
-                        // Note: This will abort the entire atomic batch.
-                        return Err(format!("Failed to speculate on transaction - {error}"));
+                for (index, outcome) in wave.zip_eq(outcomes) {
+                    // Retrieve the transaction.
+                    let transaction = transactions[index];
+                    match outcome {
+                        // Store the confirmed transaction.
+                        Ok(SpeculateOutcome::Confirmed(confirmed_transaction, _)) => {
+                            confirmed.push(confirmed_transaction)
+                        }
+                        // Store the aborted transaction.
+                        Ok(SpeculateOutcome::Aborted(error)) => aborted.push((transaction.clone(), error)),
+                        // If the transaction failed, abort the entire batch.
+                        Err(error) => {
+                            eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Failed to speculate on transaction - {error}"));
+                        }
                     }
+                    lap!(timer, "Speculated on transaction '{}'", transaction.id());
                 }
             }
 
@@ -373,12 +428,109 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         })
     }
 
+    /// Speculates on the given transaction at the given index, in the atomic batch in progress.
+    ///
+    /// Returns the confirmed transaction, along with the finalize error if it is rejected,
+    /// or the error for which the transaction is aborted.
+    /// On failure, the caller must abort the entire atomic batch.
+    fn atomic_speculate_transaction(
+        process: &Process<N>,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        index: u32,
+        transaction: &Transaction<N>,
+    ) -> Result<SpeculateOutcome<N>, String> {
+        match transaction {
+            // The finalize operation here involves appending the 'stack',
+            // and adding the program to the finalize tree.
+            Transaction::Deploy(_, program_owner, deployment, fee) => {
+                match process.finalize_deployment(state, store, deployment, fee) {
+                    // Construct the accepted deploy transaction.
+                    Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize)
+                        .map(|confirmed| SpeculateOutcome::Confirmed(confirmed, None))
+                        .map_err(|e| e.to_string()),
+                    // Construct the rejected deploy transaction.
+                    Err(rejected_error) => {
+                        // Finalize the fee, to ensure it is valid.
+                        match process
+                            .finalize_fee(state, store, fee)
+                            .and_then(|finalize| Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize)))
+                        {
+                            Ok((fee_tx, finalize)) => {
+                                // Construct the rejected deployment.
+                                let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
+                                // Construct the rejected deploy transaction.
+                                ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected, finalize)
+                                    .map(|confirmed| {
+                                        SpeculateOutcome::Confirmed(confirmed, Some(rejected_error.to_string()))
+                                    })
+                                    .map_err(|e| e.to_string())
+                            }
+                            Err(error) => {
+                                // Note: On failure, skip this transaction, and continue speculation.
+                                #[cfg(debug_assertions)]
+                                eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
+                                // Abort the transaction.
+                                Ok(SpeculateOutcome::Aborted(error.to_string()))
+                            }
+                        }
+                    }
+                }
+            }
+            // The finalize operation here involves calling 'update_key_value',
+            // and update the respective leaves of the finalize tree.
+            Transaction::Execute(_, execution, fee) => {
+                match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                    // Construct the accepted execute transaction.
+                    Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize)
+                        .map(|confirmed| SpeculateOutcome::Confirmed(confirmed, None))
+                        .map_err(|e| e.to_string()),
+                    // Construct the rejected execute transaction.
+                    Err(rejected_error) => match fee {
+                        // Finalize the fee, to ensure it is valid.
+                        Some(fee) => {
+                            match process.finalize_fee(state, store, fee).and_then(|finalize| {
+                                Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                            }) {
+                                Ok((fee_tx, finalize)) => {
+                                    // Construct the rejected execution.
+                                    let rejected = Rejected::new_execution(execution.clone());
+                                    // Construct the rejected execute transaction.
+                                    ConfirmedTransaction::rejected_execute(index, fee_tx, rejected, finalize)
+                                        .map(|confirmed| {
+                                            SpeculateOutcome::Confirmed(confirmed, Some(rejected_error.to_string()))
+                                        })
+                                        .map_err(|e| e.to_string())
+                                }
+                                Err(error) => {
+                                    // Note: On failure, skip this transaction, and continue speculation.
+                                    #[cfg(debug_assertions)]
+                                    eprintln!("Failed to finalize the fee in a rejected execute - {error}");
+                                    // Abort the transaction.
+                                    Ok(SpeculateOutcome::Aborted(error.to_string()))
+                                }
+                            }
+                        }
+                        // This is a foundational bug - the caller is violating protocol rules.
+                        // Note: This will abort the entire atomic batch.
+                        None => Err("Rejected execute transaction has no fee".to_string()),
+                    },
+                }
+            }
+            // There are no finalize operations here.
+            // Note: This will abort the entire atomic batch.
+            Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
+        }
+    }
+
     /// Performs atomic finalization over a list of transactions.
+    /// If an atomic batch is given, the finalization is performed as part of it, instead of in its own batch.
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
     fn atomic_finalize(
         &self,
+        batch: Option<&AtomicBatch<'_, N, C>>,
         state: FinalizeGlobalState,
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
@@ -386,8 +538,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::atomic_finalize");
 
-        // Perform the finalize operation on the preset finalize mode.
-        atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
+        // Prepare the finalize operation.
+        let finalize = || -> Result<_, String> {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => true,
@@ -605,7 +757,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
             Ok(ratified_finalize_operations)
-        })
+        };
+
+        // Perform the finalize operation on the preset finalize mode.
+        match batch {
+            Some(batch) => atomic_finalize_in_batch!(batch, FinalizeMode::RealRun, { finalize() }),
+            None => atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, { finalize() }),
+        }
     }
 
     /// Performs the pre-ratifications before finalizing transactions.
@@ -1542,4 +1700,172 @@ finalize compute:
         let expected = Value::<CurrentNetwork>::from_str("3u8").unwrap();
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn test_speculate_non_conflicting_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Deploy two new programs.
+        let (program_a, deployment_block_a) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block_a).unwrap();
+        let (program_b, deployment_block_b) =
+            new_program_deployment(&vm, &caller_private_key, &deployment_block_a, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block_b).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block_b, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct a mint in each program, and a transfer that will be rejected.
+        let mint_a =
+            sample_mint_public(&vm, caller_private_key, &program_a, caller_address, 10, &mut unspent_records, rng);
+        let mint_b =
+            sample_mint_public(&vm, caller_private_key, &program_b, caller_address, 10, &mut unspent_records, rng);
+        let transfer_b =
+            sample_transfer_public(&vm, caller_private_key, &program_b, caller_address, 30, &mut unspent_records, rng);
+
+        // Ensure the mints do not conflict, and are speculated on in a single wave.
+        let accesses = [&mint_a, &mint_b, &transfer_b]
+            .iter()
+            .map(|transaction| FinalizeAccess::new(&vm.process().read(), transaction).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(finalize_waves(&accesses[..2]), vec![0..2]);
+
+        // Ensure the mints are accepted.
+        let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+            .atomic_speculate(sample_finalize_state(1), None, vec![], None, [mint_a.clone(), mint_b.clone()].iter())
+            .unwrap();
+        assert_eq!(confirmed_transactions.len(), 2);
+        confirmed_transactions.iter().for_each(|confirmed_tx| assert!(confirmed_tx.is_accepted()));
+        assert!(aborted_transaction_ids.is_empty());
+        assert_eq!(confirmed_transactions[0].transaction(), &mint_a);
+        assert_eq!(confirmed_transactions[1].transaction(), &mint_b);
+
+        // Ensure a rejected transaction in a wave is isolated from the other transactions.
+        assert_eq!(finalize_waves(&[accesses[0].clone(), accesses[2].clone()]), vec![0..2]);
+        let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
+            .atomic_speculate(sample_finalize_state(1), None, vec![], None, [mint_a.clone(), transfer_b].iter())
+            .unwrap();
+        assert_eq!(confirmed_transactions.len(), 2);
+        assert!(aborted_transaction_ids.is_empty());
+        assert!(confirmed_transactions[0].is_accepted());
+        assert!(confirmed_transactions[1].is_rejected());
+        assert_eq!(confirmed_transactions[0].transaction(), &mint_a);
+    }
+
+    #[test]
+    fn test_speculate_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample a private key and address for the recipient.
+        let recipient_private_key = PrivateKey::new(rng).unwrap();
+        let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct the initial mint.
+        let initial_mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let initial_mint_block =
+            sample_next_block(&vm, &caller_private_key, &[initial_mint], &splits_block, &mut unspent_records, rng)
+                .unwrap();
+
+        // Add the block to the vm.
+        vm.add_next_block(&initial_mint_block).unwrap();
+
+        // Prepare the mapping and keys.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let caller_key = Plaintext::from_str(&caller_address.to_string()).unwrap();
+        let recipient_key = Plaintext::from_str(&recipient_address.to_string()).unwrap();
+
+        // Speculate on a transfer that is within the balance.
+        let transfer_10 = sample_transfer_public(
+            &vm,
+            caller_private_key,
+            &program_id.to_string(),
+            recipient_address,
+            10,
+            &mut unspent_records,
+            rng,
+        );
+        let diff = vm.speculate_transaction(&transfer_10).unwrap();
+        assert!(diff.is_accepted());
+        assert_eq!(diff.changes().len(), 2);
+        let expected = Value::from_str("10u64").unwrap();
+        assert_eq!(diff.get_change(&program_id, &mapping_name, &caller_key), Some(Some(&expected)));
+        assert_eq!(diff.get_change(&program_id, &mapping_name, &recipient_key), Some(Some(&expected)));
+
+        // Speculate on a transfer that exceeds the balance.
+        let transfer_30 = sample_transfer_public(
+            &vm,
+            caller_private_key,
+            &program_id.to_string(),
+            recipient_address,
+            30,
+            &mut unspent_records,
+            rng,
+        );
+        let diff = vm.speculate_transaction(&transfer_30).unwrap();
+        assert!(matches!(diff.status(), FinalizeStatus::Rejected(_)));
+        assert_eq!(diff.get_change(&program_id, &mapping_name, &caller_key), None);
+        assert_eq!(diff.get_change(&program_id, &mapping_name, &recipient_key), None);
+
+        // Ensure the mappings are unchanged.
+        let balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &caller_key).unwrap();
+        assert_eq!(balance, Some(Value::from_str("20u64").unwrap()));
+        let balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &recipient_key).unwrap();
+        assert_eq!(balance, None);
+        assert!(!vm.finalize_store().is_atomic_in_progress());
+    }
 }
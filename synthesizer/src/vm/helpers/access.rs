@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use ledger_block::{Transaction, Transition};
+use synthesizer_process::Process;
+use synthesizer_program::{Command, MappingLocator};
+
+use indexmap::IndexSet;
+use std::ops::Range;
+
+/// The mappings that may be read and written by the finalize scope of a transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinalizeAccess<N: Network> {
+    /// The mappings that may be read, as (`program ID`, `mapping name`).
+    reads: IndexSet<(ProgramID<N>, Identifier<N>)>,
+    /// The mappings that may be written, as (`program ID`, `mapping name`).
+    writes: IndexSet<(ProgramID<N>, Identifier<N>)>,
+}
+
+impl<N: Network> FinalizeAccess<N> {
+    /// Returns the finalize access of the given transaction,
+    /// or `None` if the transaction must be finalized in isolation.
+    ///
+    /// Note: The access is computed from the finalize logic of every transition, regardless of the branches taken,
+    /// and is therefore a superset of the mappings accessed at runtime. A deployment changes the set of programs,
+    /// so it does not have a finalize access.
+    pub fn new(process: &Process<N>, transaction: &Transaction<N>) -> Result<Option<Self>> {
+        match transaction {
+            Transaction::Deploy(..) | Transaction::Fee(..) => Ok(None),
+            Transaction::Execute(_, execution, fee) => {
+                let mut access = Self::default();
+                // Accumulate the access of the transitions and the fee.
+                for transition in execution.transitions().chain(fee.as_ref().map(|fee| fee.transition())) {
+                    access.add_transition(process, transition)?;
+                }
+                Ok(Some(access))
+            }
+        }
+    }
+
+    /// Returns the mappings that may be read.
+    pub const fn reads(&self) -> &IndexSet<(ProgramID<N>, Identifier<N>)> {
+        &self.reads
+    }
+
+    /// Returns the mappings that may be written.
+    pub const fn writes(&self) -> &IndexSet<(ProgramID<N>, Identifier<N>)> {
+        &self.writes
+    }
+
+    /// Returns `true` if the finalize scopes may not be reordered,
+    /// i.e. if either may write a mapping that the other accesses.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.writes.iter().any(|mapping| other.reads.contains(mapping) || other.writes.contains(mapping))
+            || other.writes.iter().any(|mapping| self.reads.contains(mapping))
+    }
+
+    /// Adds the mappings accessed by the finalize logic of the given transition.
+    fn add_transition(&mut self, process: &Process<N>, transition: &Transition<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *transition.program_id();
+        // Retrieve the function.
+        let function = process.get_program(program_id)?.get_function_ref(transition.function_name())?;
+        // If the function has no finalize logic, there is nothing to add.
+        let Some(finalize) = function.finalize_logic() else {
+            return Ok(());
+        };
+
+        // Resolves the given mapping locator, relative to the program ID.
+        let resolve = |locator: &MappingLocator<N>| match locator {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (program_id, *mapping_name),
+        };
+
+        for command in finalize.commands() {
+            match command {
+                Command::Contains(contains) => self.reads.insert((program_id, *contains.mapping_name())),
+                Command::Get(get) => self.reads.insert(resolve(get.mapping())),
+                Command::GetOrUse(get_or_use) => self.reads.insert(resolve(get_or_use.mapping())),
+                Command::Iter(iter) => self.reads.insert((program_id, *iter.mapping_name())),
+                Command::Set(set) => self.writes.insert((program_id, *set.mapping_name())),
+                Command::Remove(remove) => self.writes.insert((program_id, *remove.mapping_name())),
+                _ => continue,
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Partitions the given finalize accesses into waves of consecutive transactions that do not conflict,
+/// where a transaction without a finalize access is in a wave of its own.
+/// Returns the range of indices of each wave, in order.
+pub fn finalize_waves<N: Network>(accesses: &[Option<FinalizeAccess<N>>]) -> Vec<Range<usize>> {
+    let mut waves = Vec::new();
+    // Initialize the start of the current wave.
+    let mut start = 0;
+    for (index, access) in accesses.iter().enumerate() {
+        // Determine if the transaction conflicts with any transaction in the current wave.
+        let is_conflicting = match access {
+            Some(access) => accesses[start..index]
+                .iter()
+                .any(|other| other.as_ref().map_or(true, |other| other.conflicts_with(access))),
+            None => true,
+        };
+        // If the transaction conflicts, start a new wave.
+        if is_conflicting && index > start {
+            waves.push(start..index);
+            start = index;
+        }
+    }
+    // Add the last wave.
+    if start < accesses.len() {
+        waves.push(start..accesses.len());
+    }
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        program::Value,
+    };
+
+    type CurrentNetwork = crate::vm::test_helpers::CurrentNetwork;
+
+    /// Returns a finalize access over the given mappings of 'test.aleo'.
+    fn sample_access(reads: &[&str], writes: &[&str]) -> FinalizeAccess<CurrentNetwork> {
+        let program_id = ProgramID::from_str("test.aleo").unwrap();
+        let mapping = |name: &&str| (program_id, Identifier::from_str(name).unwrap());
+        FinalizeAccess { reads: reads.iter().map(mapping).collect(), writes: writes.iter().map(mapping).collect() }
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let read_a = sample_access(&["a"], &[]);
+        let read_b = sample_access(&["b"], &[]);
+        let write_a = sample_access(&["a"], &["a"]);
+        let write_b = sample_access(&["b"], &["b"]);
+
+        // Reads never conflict.
+        assert!(!read_a.conflicts_with(&read_a));
+        assert!(!read_a.conflicts_with(&read_b));
+        // Writes conflict with reads and writes of the same mapping, in either order.
+        assert!(write_a.conflicts_with(&read_a));
+        assert!(read_a.conflicts_with(&write_a));
+        assert!(write_a.conflicts_with(&write_a));
+        // Accesses to different mappings do not conflict.
+        assert!(!write_a.conflicts_with(&read_b));
+        assert!(!write_a.conflicts_with(&write_b));
+    }
+
+    #[test]
+    fn test_finalize_waves() {
+        let read_a = Some(sample_access(&["a"], &[]));
+        let write_a = Some(sample_access(&["a"], &["a"]));
+        let write_b = Some(sample_access(&["b"], &["b"]));
+
+        // Ensure the empty case is handled.
+        assert!(finalize_waves::<CurrentNetwork>(&[]).is_empty());
+        // Ensure non-conflicting transactions are in the same wave.
+        let waves = finalize_waves(&[read_a.clone(), read_a.clone(), write_b.clone()]);
+        assert_eq!(waves, vec![0..3]);
+        // Ensure conflicting transactions start a new wave.
+        let waves = finalize_waves(&[read_a.clone(), write_b.clone(), write_a, read_a.clone()]);
+        assert_eq!(waves, vec![0..2, 2..3, 3..4]);
+        // Ensure transactions without a finalize access are in a wave of their own.
+        let waves = finalize_waves(&[read_a.clone(), None, None, read_a, write_b]);
+        assert_eq!(waves, vec![0..1, 1..2, 2..3, 3..5]);
+    }
+
+    #[test]
+    fn test_credits_transfer_public_access() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let recipient = Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Execute a public transfer with a public fee.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&recipient.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+
+        // Ensure the access is the 'credits.aleo/account' mapping.
+        let access = FinalizeAccess::new(&vm.process().read(), &transaction).unwrap().unwrap();
+        let account = (ProgramID::from_str("credits.aleo").unwrap(), Identifier::from_str("account").unwrap());
+        assert_eq!(access.reads().iter().collect::<Vec<_>>(), vec![&account]);
+        assert_eq!(access.writes().iter().collect::<Vec<_>>(), vec![&account]);
+
+        // Ensure a deployment must be finalized in isolation.
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        assert!(FinalizeAccess::new(&vm.process().read(), &deployment).unwrap().is_none());
+    }
+}
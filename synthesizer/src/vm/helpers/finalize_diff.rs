@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// A mapping change, as (`program ID`, `mapping name`, `key`, `value`), where a `None` value is a removal.
+pub type MappingChange<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>);
+
+/// The speculated status of a transaction, if it were included in the next block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinalizeStatus {
+    /// The transaction would be accepted.
+    Accepted,
+    /// The transaction would be rejected, with the given finalize error, and only its fee would be finalized.
+    Rejected(String),
+    /// The transaction would be aborted, with the given error, and nothing would be finalized.
+    Aborted(String),
+}
+
+/// The result of a dry-run of the finalize of a transaction against the current mappings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeDiff<N: Network> {
+    /// The speculated status of the transaction.
+    status: FinalizeStatus,
+    /// The mapping changes.
+    changes: Vec<MappingChange<N>>,
+}
+
+impl<N: Network> FinalizeDiff<N> {
+    /// Initializes a new finalize diff.
+    pub fn new(status: FinalizeStatus, changes: Vec<MappingChange<N>>) -> Self {
+        Self { status, changes }
+    }
+
+    /// Returns the speculated status of the transaction.
+    pub const fn status(&self) -> &FinalizeStatus {
+        &self.status
+    }
+
+    /// Returns `true` if the transaction would be accepted.
+    pub fn is_accepted(&self) -> bool {
+        self.status == FinalizeStatus::Accepted
+    }
+
+    /// Returns the mapping changes.
+    pub fn changes(&self) -> &[MappingChange<N>] {
+        &self.changes
+    }
+
+    /// Returns the new value of the given key, or `Some(None)` if it is removed, or `None` if it is unchanged.
+    pub fn get_change(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Option<Option<&Value<N>>> {
+        self.changes
+            .iter()
+            .find(|(p, m, k, _)| p == program_id && m == mapping_name && k == key)
+            .map(|(_, _, _, value)| value.as_ref())
+    }
+}
@@ -12,13 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod access;
+pub use access::*;
+
 pub(crate) mod committee;
 pub use committee::*;
 
 mod cost;
 pub use cost::*;
 
+mod finalize_diff;
+pub use finalize_diff::*;
+
 mod macros;
 
+mod progress;
+pub use progress::*;
+
 mod rewards;
 pub use rewards::*;
+
+mod snapshot;
+pub use snapshot::*;
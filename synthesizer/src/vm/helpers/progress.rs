@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A progress event of a proving call, such as `VM::execute_with_progress` or `VM::deploy_with_progress`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProvingProgress {
+    /// The synthesis of the given function locator or program ID has started.
+    SynthesisStarted(String),
+    /// The witness for a transition of the given function locator has been generated.
+    WitnessGenerated(String),
+    /// The given percentage of the proofs of the call has been computed.
+    ///
+    /// Note: The prover does not report its progress within a proof, so the percentage
+    /// only advances once each proof is computed (e.g. the execution proof, then the fee proof).
+    Proving(u8),
+    /// The transition of the given function locator, or the deployment of the given program ID, is done.
+    Done(String),
+}
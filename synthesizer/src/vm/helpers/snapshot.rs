@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use synthesizer_process::Process;
+
+/// The entries of a mapping, as (`program ID`, `mapping name`, `entries`).
+pub type MappingEntries<N> = (ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>);
+
+/// A snapshot of the program stacks and the confirmed finalize mappings of a VM, see `VM::snapshot`.
+#[derive(Clone)]
+pub struct VmSnapshot<N: Network> {
+    /// The process, which contains the program stacks.
+    process: Process<N>,
+    /// The confirmed mappings.
+    mappings: Vec<MappingEntries<N>>,
+}
+
+impl<N: Network> VmSnapshot<N> {
+    /// Initializes a new VM snapshot.
+    pub(crate) const fn new(process: Process<N>, mappings: Vec<MappingEntries<N>>) -> Self {
+        Self { process, mappings }
+    }
+
+    /// Returns the process, which contains the program stacks.
+    pub const fn process(&self) -> &Process<N> {
+        &self.process
+    }
+
+    /// Returns the confirmed mappings.
+    pub fn mappings(&self) -> &[MappingEntries<N>] {
+        &self.mappings
+    }
+
+    /// Returns `true` if the snapshot contains the given program.
+    pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.process.contains_program(program_id)
+    }
+}
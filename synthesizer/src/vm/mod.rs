@@ -25,7 +25,7 @@ use crate::{cast_mut_ref, cast_ref, process};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Response, Value},
     types::{Field, U64},
 };
 use ledger_block::{
@@ -40,12 +40,16 @@ use ledger_block::{
     Rejected,
     Transaction,
     Transactions,
+    Transition,
 };
 use ledger_coinbase::CoinbaseSolution;
 use ledger_committee::Committee;
 use ledger_query::Query;
 use ledger_store::{
     atomic_finalize,
+    atomic_finalize_in_batch,
+    helpers::memory::ConsensusMemory,
+    AtomicBatch,
     BlockStore,
     ConsensusStorage,
     ConsensusStore,
@@ -55,13 +59,13 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{Authorization, DeploymentBuilder, LocalExecution, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -140,11 +144,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Load the deployment and its imports.
             load_deployment_and_imports(&mut process, transaction_store, *transaction_id)?;
         }
+        // Reload the evicted verifying keys from the deployments in storage.
+        Self::set_verifying_key_loader(&process, transaction_store);
 
         // Return the new VM.
         Ok(Self { process: Arc::new(RwLock::new(process)), store })
     }
 
+    /// Sets the verifying key loader of the given process, to reload the evicted verifying keys from storage.
+    fn set_verifying_key_loader(process: &Process<N>, transaction_store: &TransactionStore<N, C::TransactionStorage>) {
+        let transaction_store = transaction_store.clone();
+        process.key_cache().set_verifying_key_loader(Arc::new(move |program_id, function_name| {
+            transaction_store.get_verifying_key(program_id, function_name)
+        }));
+    }
+
     /// Returns `true` if a program with the given program ID exists.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -156,6 +170,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Returns the *minimum* fee in microcredits to publish the given deployment or execution,
+    /// broken down into its storage, finalize, and namespace costs.
+    #[inline]
+    pub fn estimate_fee(&self, payload: &impl FeePayload<N>) -> Result<FeeEstimate> {
+        payload.minimum_fee(self)
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -292,21 +313,139 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             block.previous_hash(),
         )?;
 
-        // Attention: The following order is crucial because if 'finalize' fails, we can rollback the block.
-        // If one first calls 'finalize', then calls 'insert(block)' and it fails, there is no way to rollback 'finalize'.
+        // Attention: The block and its finalize operations are written in a single atomic batch,
+        // so that if either fails, or the node crashes midway, neither is written to storage.
+        let batch = self.store.atomic_batch()?;
 
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
-            Ok(_ratified_finalize_operations) => Ok(()),
-            Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
-                // Return the error.
-                Err(error)
+        self.finalize_in_batch(&batch, state, block.ratifications(), block.solutions(), block.transactions())?;
+        // Lastly, commit the block and its finalize operations.
+        batch.commit()
+    }
+
+    /// Reverts the VM to the given block height, removing all blocks above it,
+    /// along with their transactions, transitions, and finalize operations.
+    ///
+    /// Note: As finalize operations are not reversible, the program mappings are recomputed by replaying
+    /// every block from genesis up to the given height into an in-memory scratch VM. This takes time and memory
+    /// linear in the chain length, so it is unsuitable for reorgs on a long chain such as mainnet.
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        let timer = timer!("VM::revert_to_height");
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(height) => *height,
+            None => bail!("Failed to revert to height {height}: no blocks in storage"),
+        };
+        // Ensure the given height is not greater than the latest height.
+        ensure!(height <= latest_height, "Failed to revert to height {height}: the latest block is {latest_height}");
+        // Ensure the blocks to replay are not pruned.
+        // Note: The pruned height is loaded from storage, so this also holds for blocks pruned before a restart.
+        ensure!(self.block_store().pruned_height() == 0, "Failed to revert to height {height}: the blocks are pruned");
+        // If the given height is the latest height, then there is nothing to revert.
+        if height == latest_height {
+            return Ok(());
+        }
+
+        // Initialize a scratch VM, to recompute the program mappings up to the given height.
+        let scratch = VM::<N, ConsensusMemory<N>>::from(ConsensusStore::open(None)?)?;
+        // Replay the finalize operations of the blocks up to the given height.
+        for block in self.block_store().blocks_in_range(0..height + 1) {
+            let block = block?;
+            // Construct the finalize state.
+            let state = FinalizeGlobalState::new::<N>(
+                block.round(),
+                block.height(),
+                block.cumulative_weight(),
+                block.cumulative_proof_target(),
+                block.previous_hash(),
+            )?;
+            // Finalize the transactions.
+            scratch.finalize(state, block.ratifications(), block.solutions(), block.transactions())?;
+        }
+        lap!(timer, "Replay the blocks up to height {}", height);
+
+        // Snapshot the programs and mappings of the scratch VM.
+        let snapshot = scratch.snapshot()?;
+
+        // Attention: The blocks, mappings, and committees are reverted in a single atomic batch,
+        // so that if any step fails, or the node crashes midway, none of the changes are written to storage.
+        let batch = self.store.atomic_batch()?;
+
+        // First, remove the blocks above the given height.
+        self.block_store().remove_last_n(latest_height - height)?;
+        // Next, replace the program mappings with the recomputed ones.
+        self.replace_mappings(snapshot.mappings())?;
+        // Next, remove the committees above the given height.
+        self.finalize_store().committee_store().remove_above(height)?;
+        // Lastly, commit the reverted state.
+        batch.commit()?;
+
+        // Replace the process with the one that only contains the programs up to the given height.
+        self.replace_process(snapshot.process().clone());
+
+        finish!(timer, "Reverted to height {}", height);
+        Ok(())
+    }
+
+    /// Returns a snapshot of the program stacks and the confirmed finalize mappings of the VM.
+    ///
+    /// A snapshot can be restored with `VM::restore`, e.g. to fork a populated VM into many test scenarios,
+    /// without replaying its deployments each time.
+    pub fn snapshot(&self) -> Result<VmSnapshot<N>> {
+        // Acquire the read lock on the process, so no program is added while the mappings are collected.
+        let process = self.process.read();
+
+        // Collect the confirmed mappings.
+        let finalize_store = self.finalize_store();
+        let mut mappings = Vec::new();
+        for program_id in finalize_store.program_ids_confirmed() {
+            let program_id = *program_id;
+            for mapping_name in finalize_store.get_mapping_names_confirmed(&program_id)?.unwrap_or_default() {
+                let entries = finalize_store.get_mapping_confirmed(program_id, mapping_name)?;
+                mappings.push((program_id, mapping_name, entries));
             }
         }
+
+        Ok(VmSnapshot::new(process.clone(), mappings))
+    }
+
+    /// Restores the program stacks and the confirmed finalize mappings of the VM to the given snapshot.
+    ///
+    /// Note: The blocks, transactions, and committees in storage are not restored.
+    pub fn restore(&self, snapshot: &VmSnapshot<N>) -> Result<()> {
+        // Replace the mappings in a single atomic batch.
+        let batch = self.store.atomic_batch()?;
+        self.replace_mappings(snapshot.mappings())?;
+        batch.commit()?;
+
+        // Replace the process.
+        self.replace_process(snapshot.process().clone());
+        Ok(())
+    }
+
+    /// Replaces every confirmed mapping in the finalize store with the given mappings.
+    fn replace_mappings(&self, mappings: &[MappingEntries<N>]) -> Result<()> {
+        // Remove the current programs.
+        let program_ids = self.finalize_store().program_ids_confirmed().map(|id| *id).collect::<Vec<_>>();
+        for program_id in &program_ids {
+            self.finalize_store().remove_program(program_id)?;
+        }
+        // Insert the given mappings.
+        for (program_id, mapping_name, entries) in mappings {
+            self.finalize_store().initialize_mapping(*program_id, *mapping_name)?;
+            self.finalize_store().replace_mapping(*program_id, *mapping_name, entries.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the process of the VM with the given process.
+    fn replace_process(&self, process: Process<N>) {
+        *self.process.write() = process;
+        // Reload the evicted verifying keys from this storage, instead of the storage of the given process.
+        Self::set_verifying_key_loader(&self.process.read(), self.transaction_store());
     }
 }
 
@@ -975,4 +1114,116 @@ function multitransfer:
             .unwrap();
         vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
     }
+
+    #[test]
+    fn test_estimate_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+
+        // Estimate the fee of a deployment.
+        let transaction = sample_deployment_transaction(rng);
+        let deployment = transaction.deployment().unwrap();
+        let estimate = vm.estimate_fee(deployment).unwrap();
+        let (total_cost, (storage_cost, namespace_cost)) = deployment_cost(deployment).unwrap();
+        assert_eq!(estimate.total_cost(), total_cost);
+        assert_eq!(estimate.storage_cost(), storage_cost);
+        assert_eq!(estimate.namespace_cost(), namespace_cost);
+        assert_eq!(estimate.finalize_cost(), 0);
+        // Ensure the deployment pays at least the estimated fee.
+        assert!(*transaction.base_fee_amount().unwrap() >= estimate.total_cost());
+
+        // Estimate the fee of an execution.
+        let transaction = sample_execution_transaction_with_public_fee(rng);
+        let execution = transaction.execution().unwrap();
+        let estimate = vm.estimate_fee(execution).unwrap();
+        let (total_cost, (storage_cost, finalize_cost)) = execution_cost(&vm, execution).unwrap();
+        assert_eq!(estimate.total_cost(), total_cost);
+        assert_eq!(estimate.storage_cost(), storage_cost);
+        assert_eq!(estimate.finalize_cost(), finalize_cost);
+        assert_eq!(estimate.namespace_cost(), 0);
+        assert!(estimate.finalize_cost() > 0);
+        // Ensure the execution pays at least the estimated fee.
+        assert!(*transaction.base_fee_amount().unwrap() >= estimate.total_cost());
+    }
+
+    #[test]
+    fn test_deploy_resumable() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        // Initialize a program with two functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program resumable.aleo;
+
+function first:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;
+
+function second:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Persist the progress of an interrupted deployment, which certified only the first function.
+        let path = std::env::temp_dir().join(format!("resumable-{}.deployment", rng.gen::<u64>()));
+        let mut builder = DeploymentBuilder::new(program.clone()).unwrap();
+        let function_name = vm.continue_deployment_raw(&mut builder, rng).unwrap();
+        assert_eq!(function_name, Some(Identifier::from_str("first").unwrap()));
+        builder.save(&path).unwrap();
+
+        // Resume the deployment.
+        let transaction = vm.deploy_resumable(&caller_private_key, &program, &path, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None).unwrap();
+        // Ensure the deployment reused the persisted certificate of the first function.
+        let deployment = transaction.deployment().unwrap();
+        let first = Identifier::from_str("first").unwrap();
+        assert_eq!(deployment.verifying_keys()[0], (first, builder.verifying_keys()[&first].clone()));
+        // Ensure the progress is removed.
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = sample_genesis_private_key(rng);
+
+        // Initialize the VM, and snapshot it.
+        let vm = sample_vm_with_genesis_block(rng);
+        let genesis_snapshot = vm.snapshot().unwrap();
+
+        // Deploy the program, and snapshot the VM.
+        let program_id = *sample_program().id();
+        let deployment = sample_deployment_transaction(rng);
+        vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap()).unwrap();
+        assert!(vm.contains_program(&program_id));
+        let deployment_snapshot = vm.snapshot().unwrap();
+        assert!(deployment_snapshot.contains_program(&program_id));
+        assert!(!genesis_snapshot.contains_program(&program_id));
+
+        // Restore the VM to the genesis snapshot.
+        vm.restore(&genesis_snapshot).unwrap();
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+        assert_eq!(vm.snapshot().unwrap().mappings(), genesis_snapshot.mappings());
+
+        // Restore the VM to the deployment snapshot.
+        vm.restore(&deployment_snapshot).unwrap();
+        assert!(vm.contains_program(&program_id));
+        assert!(vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+        assert_eq!(vm.snapshot().unwrap().mappings(), deployment_snapshot.mappings());
+    }
 }